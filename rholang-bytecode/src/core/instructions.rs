@@ -3,10 +3,12 @@
 //! Implements 32-bit fixed-width instructions with zero-copy operands
 
 use crate::core::opcodes::{InstructionFlags, Opcode};
-use crate::core::types::{NameRef, ProcessRef, RSpaceType};
+use crate::core::types::{NameRef, ProcessRef, RSpaceType, SimpleType};
 use crate::error::{BytecodeError, Result};
+use alloc::{format, vec::Vec};
 use byteorder::{ByteOrder, LittleEndian};
-use std::fmt;
+use core::fmt;
+use smallvec::{SmallVec, smallvec};
 
 /// 32-bit fixed-width instruction
 /// Layout: [opcode:8][flags:8][operand1:8][operand2:8]
@@ -60,6 +62,17 @@ impl Instruction {
         LittleEndian::read_u16(&self.operands)
     }
 
+    /// Decoded operands, as many as the opcode expects: a single operand is the
+    /// 16-bit immediate, two operands are the two operand bytes.
+    /// Empty if the opcode byte is invalid.
+    pub fn operands(&self) -> SmallVec<[u32; 3]> {
+        match self.opcode().map(|opcode| opcode.operand_count()) {
+            Ok(1) => smallvec![self.op16() as u32],
+            Ok(2) => smallvec![self.op1() as u32, self.op2() as u32],
+            _ => SmallVec::new(),
+        }
+    }
+
     pub fn to_bytes(&self) -> [u8; 4] {
         [self.opcode, self.flags, self.operands[0], self.operands[1]]
     }
@@ -137,7 +150,10 @@ impl Instruction {
             }
 
             // Collection operations - validate size limits
-            Opcode::CREATE_LIST | Opcode::CREATE_TUPLE | Opcode::CREATE_MAP => {
+            Opcode::CREATE_LIST
+            | Opcode::CREATE_TUPLE
+            | Opcode::CREATE_MAP
+            | Opcode::CREATE_PATHMAP => {
                 // Collection sizes should be reasonable (max 65536 elements)
                 // No additional validation needed as u16 already limits this
             }
@@ -169,6 +185,14 @@ impl Instruction {
                 // Pattern indices should be reasonable (max 65536 patterns)
                 // No additional validation needed as u16 already limits this
             }
+            Opcode::MATCH_TYPE => {
+                if SimpleType::from_tag(operand).is_none() {
+                    return Err(BytecodeError::ValidationError(format!(
+                        "Simple type tag {operand} exceeds maximum {}",
+                        SimpleType::ALL.len() - 1
+                    )));
+                }
+            }
 
             // Other operations don't need operand validation
             _ => {}
@@ -181,7 +205,7 @@ impl Instruction {
 impl fmt::Debug for Instruction {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         if let Ok(opcode) = self.opcode() {
-            write!(f, "{opcode:?}")?;
+            f.write_str(opcode.mnemonic())?;
             match opcode.operand_count() {
                 0 => Ok(()),
                 1 => write!(f, " {:#04x}", self.op16()),
@@ -632,6 +656,28 @@ mod tests {
         assert_eq!(inst.op2(), 0x34);
     }
 
+    #[test]
+    fn test_decoded_operands() {
+        assert!(Instruction::nullary(Opcode::ADD).operands().is_empty());
+        assert_eq!(
+            Instruction::unary(Opcode::JUMP, 0x1234)
+                .operands()
+                .as_slice(),
+            &[0x1234]
+        );
+        assert_eq!(
+            Instruction::binary(Opcode::TELL, 0x12, 0x34)
+                .operands()
+                .as_slice(),
+            &[0x12, 0x34]
+        );
+        assert!(
+            Instruction::from_bytes([0xFF, 0, 1, 2])
+                .operands()
+                .is_empty()
+        );
+    }
+
     #[test]
     fn test_instruction_validation() {
         let valid = Instruction::nullary(Opcode::NOP);
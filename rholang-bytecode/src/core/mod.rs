@@ -1,5 +1,7 @@
+#[cfg(feature = "std")]
 pub mod constants;
 pub mod instructions;
+#[cfg(feature = "std")]
 pub mod module;
 pub mod opcodes;
 pub mod types;
@@ -8,13 +10,15 @@ pub mod types;
 // pub mod metadata;
 
 // Re-export core types
+#[cfg(feature = "std")]
 pub use self::constants::{
     BytecodeSerializer, ConstantPool, ProcessTemplate, SerializableConstantPool, StringInterner,
 };
 pub use self::instructions::Instruction;
+#[cfg(feature = "std")]
 pub use self::module::{
     BytecodeModule, BytecodeModuleStats, MmapVec, OptimizationLevel, PatternPool, PatternPoolStats,
     ReferenceTable, ReferenceTableStats, ReferenceType,
 };
 pub use self::opcodes::Opcode;
-pub use self::types::{Key, NameRef, ProcessRef, TypeRef, Value};
+pub use self::types::{Key, NameRef, ProcessRef, SimpleType, TypeRef, Value};
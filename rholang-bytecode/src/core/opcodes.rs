@@ -68,6 +68,11 @@ pub enum Opcode {
     CONCAT = 0x63,
     DIFF = 0x64,
     INTERPOLATE = 0x65,
+    CREATE_PATHMAP = 0x66,
+    PATHMAP_SUBTRACT = 0x67,
+    PATHMAP_RESTRICT = 0x68,
+    PATHMAP_DROP = 0x69,
+    CREATE_SET = 0x6A,
 
     // Process operations (0x70 - 0x7F)
     SPAWN_ASYNC = 0x70,
@@ -92,8 +97,15 @@ pub enum Opcode {
 
     // Pattern matching operations (0x90 - 0x9F)
     PATTERN = 0x90,
+    /// Test the top of stack against a structural pattern, 1 operand
+    /// (constant pool index of the encoded pattern). Pushes a Bool.
     MATCH_TEST = 0x91,
+    /// Spread a contract message over its formals, 2 operands (positional
+    /// formal count, non-zero if a remainder formal follows).
     EXTRACT_BINDINGS = 0x92,
+    /// Test the runtime type of the top of stack, 1 operand (the tag of a
+    /// [`SimpleType`](crate::core::types::SimpleType)). Pushes a Bool.
+    MATCH_TYPE = 0x93,
 
     // Reference operations (0xA0 - 0xAF)
     COPY = 0xA0,
@@ -168,6 +180,11 @@ impl Opcode {
         table[0x63] = Some(Opcode::CONCAT);
         table[0x64] = Some(Opcode::DIFF);
         table[0x65] = Some(Opcode::INTERPOLATE);
+        table[0x66] = Some(Opcode::CREATE_PATHMAP);
+        table[0x67] = Some(Opcode::PATHMAP_SUBTRACT);
+        table[0x68] = Some(Opcode::PATHMAP_RESTRICT);
+        table[0x69] = Some(Opcode::PATHMAP_DROP);
+        table[0x6A] = Some(Opcode::CREATE_SET);
 
         // Process operations (0x70 - 0x7F)
         table[0x70] = Some(Opcode::SPAWN_ASYNC);
@@ -194,6 +211,7 @@ impl Opcode {
         table[0x90] = Some(Opcode::PATTERN);
         table[0x91] = Some(Opcode::MATCH_TEST);
         table[0x92] = Some(Opcode::EXTRACT_BINDINGS);
+        table[0x93] = Some(Opcode::MATCH_TYPE);
 
         // Reference operations (0xA0 - 0xAF)
         table[0xA0] = Some(Opcode::COPY);
@@ -238,14 +256,15 @@ impl Opcode {
         counts[0x73] = 0; // EVAL_STAR
         counts[0x74] = 0; // EXEC
         counts[0x75] = 0; // PROC_NEG
-        counts[0x91] = 0; // MATCH_TEST
-        counts[0x92] = 0; // EXTRACT_BINDINGS
         counts[0xA0] = 0; // COPY
         counts[0xA1] = 0; // MOVE
         counts[0xA2] = 0; // REF
         counts[0x63] = 0; // CONCAT
         counts[0x64] = 0; // DIFF
         counts[0x65] = 0; // INTERPOLATE
+        counts[0x67] = 0; // PATHMAP_SUBTRACT
+        counts[0x68] = 0; // PATHMAP_RESTRICT
+        counts[0x69] = 0; // PATHMAP_DROP
         counts[0x89] = 0; // BUNDLE_BEGIN
         counts[0x8A] = 0; // BUNDLE_END
         counts[0x15] = 0; // PUSH_NIL
@@ -270,6 +289,8 @@ impl Opcode {
         counts[0x60] = 1; // CREATE_LIST
         counts[0x61] = 1; // CREATE_TUPLE
         counts[0x62] = 1; // CREATE_MAP
+        counts[0x66] = 1; // CREATE_PATHMAP
+        counts[0x6A] = 1; // CREATE_SET
         counts[0x70] = 1; // SPAWN_ASYNC
         counts[0x84] = 1; // NAME_CREATE
         counts[0x85] = 1; // NAME_QUOTE
@@ -277,6 +298,8 @@ impl Opcode {
         counts[0x87] = 1; // CONT_STORE
         counts[0x88] = 1; // CONT_RESUME
         counts[0x90] = 1; // PATTERN
+        counts[0x91] = 1; // MATCH_TEST
+        counts[0x93] = 1; // MATCH_TYPE
         counts[0xB0] = 1; // LOAD_METHOD
         counts[0xB1] = 1; // INVOKE_METHOD
 
@@ -285,6 +308,7 @@ impl Opcode {
         counts[0x81] = 2; // ASK
         counts[0x82] = 2; // ASK_NB
         counts[0x83] = 2; // PEEK
+        counts[0x92] = 2; // EXTRACT_BINDINGS
 
         counts
     };
@@ -295,8 +319,170 @@ impl Opcode {
     }
 
     /// Get the number of operands this opcode expects
-    pub fn operand_count(&self) -> u8 {
-        Self::OPERAND_COUNTS[*self as u8 as usize]
+    pub fn operand_count(&self) -> usize {
+        Self::OPERAND_COUNTS[*self as u8 as usize] as usize
+    }
+
+    /// Assembly mnemonic of this opcode, e.g. `"PUSH_INT"`
+    pub fn mnemonic(&self) -> &'static str {
+        match self {
+            Opcode::NOP => "NOP",
+            Opcode::JUMP => "JUMP",
+            Opcode::BRANCH_TRUE => "BRANCH_TRUE",
+            Opcode::BRANCH_FALSE => "BRANCH_FALSE",
+            Opcode::BRANCH_SUCCESS => "BRANCH_SUCCESS",
+            Opcode::RETURN => "RETURN",
+            Opcode::HALT => "HALT",
+            Opcode::PUSH_INT => "PUSH_INT",
+            Opcode::PUSH_STR => "PUSH_STR",
+            Opcode::PUSH_BOOL => "PUSH_BOOL",
+            Opcode::PUSH_PROC => "PUSH_PROC",
+            Opcode::PUSH_NAME => "PUSH_NAME",
+            Opcode::PUSH_NIL => "PUSH_NIL",
+            Opcode::PUSH_CONST => "PUSH_CONST",
+            Opcode::POP => "POP",
+            Opcode::DUP => "DUP",
+            Opcode::SWAP => "SWAP",
+            Opcode::LOAD_VAR => "LOAD_VAR",
+            Opcode::LOAD_LOCAL => "LOAD_LOCAL",
+            Opcode::STORE_LOCAL => "STORE_LOCAL",
+            Opcode::ALLOC_LOCAL => "ALLOC_LOCAL",
+            Opcode::LOAD_ENV => "LOAD_ENV",
+            Opcode::STORE_ENV => "STORE_ENV",
+            Opcode::ADD => "ADD",
+            Opcode::SUB => "SUB",
+            Opcode::MUL => "MUL",
+            Opcode::DIV => "DIV",
+            Opcode::MOD => "MOD",
+            Opcode::NEG => "NEG",
+            Opcode::CMP_EQ => "CMP_EQ",
+            Opcode::CMP_NEQ => "CMP_NEQ",
+            Opcode::CMP_LT => "CMP_LT",
+            Opcode::CMP_LTE => "CMP_LTE",
+            Opcode::CMP_GT => "CMP_GT",
+            Opcode::CMP_GTE => "CMP_GTE",
+            Opcode::NOT => "NOT",
+            Opcode::AND => "AND",
+            Opcode::OR => "OR",
+            Opcode::CREATE_LIST => "CREATE_LIST",
+            Opcode::CREATE_TUPLE => "CREATE_TUPLE",
+            Opcode::CREATE_MAP => "CREATE_MAP",
+            Opcode::CONCAT => "CONCAT",
+            Opcode::DIFF => "DIFF",
+            Opcode::INTERPOLATE => "INTERPOLATE",
+            Opcode::CREATE_PATHMAP => "CREATE_PATHMAP",
+            Opcode::PATHMAP_SUBTRACT => "PATHMAP_SUBTRACT",
+            Opcode::PATHMAP_RESTRICT => "PATHMAP_RESTRICT",
+            Opcode::PATHMAP_DROP => "PATHMAP_DROP",
+            Opcode::CREATE_SET => "CREATE_SET",
+            Opcode::SPAWN_ASYNC => "SPAWN_ASYNC",
+            Opcode::EVAL => "EVAL",
+            Opcode::EVAL_BOOL => "EVAL_BOOL",
+            Opcode::EVAL_STAR => "EVAL_STAR",
+            Opcode::EXEC => "EXEC",
+            Opcode::PROC_NEG => "PROC_NEG",
+            Opcode::TELL => "TELL",
+            Opcode::ASK => "ASK",
+            Opcode::ASK_NB => "ASK_NB",
+            Opcode::PEEK => "PEEK",
+            Opcode::NAME_CREATE => "NAME_CREATE",
+            Opcode::NAME_QUOTE => "NAME_QUOTE",
+            Opcode::NAME_UNQUOTE => "NAME_UNQUOTE",
+            Opcode::CONT_STORE => "CONT_STORE",
+            Opcode::CONT_RESUME => "CONT_RESUME",
+            Opcode::BUNDLE_BEGIN => "BUNDLE_BEGIN",
+            Opcode::BUNDLE_END => "BUNDLE_END",
+            Opcode::PATTERN => "PATTERN",
+            Opcode::MATCH_TEST => "MATCH_TEST",
+            Opcode::EXTRACT_BINDINGS => "EXTRACT_BINDINGS",
+            Opcode::MATCH_TYPE => "MATCH_TYPE",
+            Opcode::COPY => "COPY",
+            Opcode::MOVE => "MOVE",
+            Opcode::REF => "REF",
+            Opcode::LOAD_METHOD => "LOAD_METHOD",
+            Opcode::INVOKE_METHOD => "INVOKE_METHOD",
+        }
+    }
+
+    /// One-line human-readable description of what this opcode does
+    pub fn description(&self) -> &'static str {
+        match self {
+            Opcode::NOP => "No operation",
+            Opcode::JUMP => "Jump to instruction",
+            Opcode::BRANCH_TRUE => "Branch if true",
+            Opcode::BRANCH_FALSE => "Branch if false",
+            Opcode::BRANCH_SUCCESS => "Branch on success",
+            Opcode::RETURN => "Return from current context",
+            Opcode::HALT => "Halt execution",
+            Opcode::PUSH_INT => "Push integer immediate",
+            Opcode::PUSH_STR => "Push string from the string pool",
+            Opcode::PUSH_BOOL => "Push boolean",
+            Opcode::PUSH_PROC => "Push process from the process pool",
+            Opcode::PUSH_NAME => "Push name from the string pool",
+            Opcode::PUSH_NIL => "Push nil",
+            Opcode::PUSH_CONST => "Push constant from the constant pool",
+            Opcode::POP => "Pop top of stack",
+            Opcode::DUP => "Duplicate top of stack",
+            Opcode::SWAP => "Swap top two stack values",
+            Opcode::LOAD_VAR => "Load variable",
+            Opcode::LOAD_LOCAL => "Load local variable",
+            Opcode::STORE_LOCAL => "Store to local variable",
+            Opcode::ALLOC_LOCAL => "Allocate local variable",
+            Opcode::LOAD_ENV => "Load from environment slot",
+            Opcode::STORE_ENV => "Store to environment slot",
+            Opcode::ADD => "Add top two stack values",
+            Opcode::SUB => "Subtract top two stack values",
+            Opcode::MUL => "Multiply top two stack values",
+            Opcode::DIV => "Divide top two stack values",
+            Opcode::MOD => "Modulo top two stack values",
+            Opcode::NEG => "Negate top stack value",
+            Opcode::CMP_EQ => "Compare equal",
+            Opcode::CMP_NEQ => "Compare not equal",
+            Opcode::CMP_LT => "Compare less than",
+            Opcode::CMP_LTE => "Compare less than or equal",
+            Opcode::CMP_GT => "Compare greater than",
+            Opcode::CMP_GTE => "Compare greater than or equal",
+            Opcode::NOT => "Logical NOT",
+            Opcode::AND => "Logical AND",
+            Opcode::OR => "Logical OR",
+            Opcode::CREATE_LIST => "Create list",
+            Opcode::CREATE_TUPLE => "Create tuple",
+            Opcode::CREATE_MAP => "Create map",
+            Opcode::CONCAT => "Concatenate collections",
+            Opcode::DIFF => "Set difference",
+            Opcode::INTERPOLATE => "String interpolation",
+            Opcode::CREATE_PATHMAP => "Create pathmap",
+            Opcode::PATHMAP_SUBTRACT => "Pathmap subtract",
+            Opcode::PATHMAP_RESTRICT => "Pathmap restrict",
+            Opcode::PATHMAP_DROP => "Pathmap drop leading segments",
+            Opcode::CREATE_SET => "Create set",
+            Opcode::SPAWN_ASYNC => "Spawn asynchronous process",
+            Opcode::EVAL => "Evaluate (unquote) name",
+            Opcode::EVAL_BOOL => "Evaluate as boolean",
+            Opcode::EVAL_STAR => "Evaluate all (splat)",
+            Opcode::EXEC => "Execute process",
+            Opcode::PROC_NEG => "Process negation",
+            Opcode::TELL => "Send on channel",
+            Opcode::ASK => "Receive from channel",
+            Opcode::ASK_NB => "Non-blocking receive",
+            Opcode::PEEK => "Peek at channel",
+            Opcode::NAME_CREATE => "Create name",
+            Opcode::NAME_QUOTE => "Quote name",
+            Opcode::NAME_UNQUOTE => "Unquote name",
+            Opcode::CONT_STORE => "Store continuation",
+            Opcode::CONT_RESUME => "Resume continuation",
+            Opcode::BUNDLE_BEGIN => "Begin bundle",
+            Opcode::BUNDLE_END => "End bundle",
+            Opcode::PATTERN => "Pattern match",
+            Opcode::MATCH_TEST => "Test pattern match",
+            Opcode::EXTRACT_BINDINGS => "Extract pattern bindings",
+            Opcode::MATCH_TYPE => "Test runtime type",
+            Opcode::COPY => "Copy value",
+            Opcode::MOVE => "Move value",
+            Opcode::REF => "Create reference",
+            Opcode::LOAD_METHOD => "Load method",
+            Opcode::INVOKE_METHOD => "Invoke method",
+        }
     }
 
     const CONTROL_FLOW_FLAGS: [bool; 256] = {
@@ -391,6 +577,34 @@ mod tests {
         assert!(!Opcode::ADD.is_rspace_op());
     }
 
+    #[test]
+    fn test_opcode_metadata() {
+        let cases = [
+            (Opcode::NOP, "NOP", 0),
+            (Opcode::JUMP, "JUMP", 1),
+            (Opcode::PUSH_INT, "PUSH_INT", 1),
+            (Opcode::PUSH_CONST, "PUSH_CONST", 1),
+            (Opcode::ADD, "ADD", 0),
+            (Opcode::CREATE_LIST, "CREATE_LIST", 1),
+            (Opcode::PATHMAP_DROP, "PATHMAP_DROP", 0),
+            (Opcode::CREATE_SET, "CREATE_SET", 1),
+            (Opcode::TELL, "TELL", 2),
+            (Opcode::INVOKE_METHOD, "INVOKE_METHOD", 1),
+        ];
+        for (opcode, mnemonic, operands) in cases {
+            assert_eq!(opcode.mnemonic(), mnemonic);
+            assert_eq!(opcode.operand_count(), operands, "{mnemonic}");
+        }
+
+        // every valid opcode has a mnemonic matching its name and a description
+        for byte in 0..=u8::MAX {
+            if let Ok(opcode) = Opcode::from_byte(byte) {
+                assert_eq!(opcode.mnemonic(), format!("{opcode:?}"));
+                assert!(!opcode.description().is_empty());
+            }
+        }
+    }
+
     #[test]
     fn test_instruction_flags() {
         let mut flags = InstructionFlags::empty();
@@ -1,14 +1,14 @@
 //! Core type system
 
-use parking_lot::RwLock;
-use std::sync::Arc;
+use crate::sync::RwLock;
+use alloc::{string::ToString, sync::Arc, vec::Vec};
 
 /// Tagged pointer for efficient value representation
 /// Uses the lower 3 bits for type tagging (8-byte aligned pointers)
 #[derive(Clone)]
 pub struct TaggedPtr<T> {
     ptr: usize,
-    _phantom: std::marker::PhantomData<T>,
+    _phantom: core::marker::PhantomData<T>,
 }
 
 impl<T> TaggedPtr<T> {
@@ -25,7 +25,7 @@ impl<T> TaggedPtr<T> {
 
         Self {
             ptr: (ptr as usize) | (tag as usize),
-            _phantom: std::marker::PhantomData,
+            _phantom: core::marker::PhantomData,
         }
     }
 
@@ -203,6 +203,36 @@ impl RSpaceType {
     }
 }
 
+/// The simple types of the language (`Bool`, `Int`, `String`, `Uri`, `ByteArray`),
+/// as tested at runtime by `matches`.
+///
+/// The discriminant is the tag carried by the `MATCH_TYPE` instruction.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum SimpleType {
+    Bool = 0,
+    Int = 1,
+    String = 2,
+    Uri = 3,
+    ByteArray = 4,
+}
+
+impl SimpleType {
+    /// Every simple type, indexed by its tag
+    pub const ALL: [SimpleType; 5] = [
+        SimpleType::Bool,
+        SimpleType::Int,
+        SimpleType::String,
+        SimpleType::Uri,
+        SimpleType::ByteArray,
+    ];
+
+    /// Decode a `MATCH_TYPE` operand.
+    pub fn from_tag(tag: u16) -> Option<Self> {
+        Self::ALL.get(usize::from(tag)).copied()
+    }
+}
+
 /// Environment for captured variables
 #[derive(Clone, Debug)]
 pub struct Environment {
@@ -509,6 +539,15 @@ impl TypeRef {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_simple_type_tags() {
+        for (tag, t) in SimpleType::ALL.into_iter().enumerate() {
+            assert_eq!(t as usize, tag);
+            assert_eq!(SimpleType::from_tag(tag as u16), Some(t));
+        }
+        assert_eq!(SimpleType::from_tag(SimpleType::ALL.len() as u16), None);
+    }
+
     #[test]
     fn test_tagged_pointer() {
         let value = 42u64;
@@ -1,5 +1,6 @@
 //! Error types for bytecode operations
 
+use alloc::string::String;
 use thiserror::Error;
 
 /// Main error type for bytecode operations
@@ -17,6 +18,7 @@ pub enum BytecodeError {
     #[error("Reference counting error: {0}")]
     ReferenceError(String),
 
+    #[cfg(feature = "std")]
     #[error("Memory mapping failed: {0}")]
     MemoryMapError(#[from] std::io::Error),
 
@@ -65,7 +67,7 @@ pub enum BytecodeError {
 }
 
 /// Convenient Result type
-pub type Result<T> = std::result::Result<T, BytecodeError>;
+pub type Result<T> = core::result::Result<T, BytecodeError>;
 
 /// Validation error details
 #[derive(Debug, Clone)]
@@ -2,12 +2,19 @@
 //!
 //! High-performance bytecode layer for Rholang with zero-copy operations
 //! and Graph-Structured Lambda Theory (GSLT) compliance.
+//!
+//! With the default `std` feature disabled the crate is `no_std` (using `alloc`)
+//! and provides only the core `Instruction`, `Opcode` and `Value` types.
 
+#![cfg_attr(not(feature = "std"), no_std)]
 #![warn(rust_2018_idioms)]
 #![deny(unsafe_code)] // We'll use #[allow(unsafe_code)] only where necessary with safety proofs
 
+extern crate alloc;
+
 pub mod core;
 pub mod error;
+mod sync;
 // pub mod memory;
 
 // Re-export commonly used types
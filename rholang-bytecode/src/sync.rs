@@ -0,0 +1,31 @@
+//! Locks shared by the `std` and `no_std` builds
+
+#[cfg(feature = "std")]
+pub(crate) use parking_lot::RwLock;
+
+#[cfg(not(feature = "std"))]
+pub(crate) use self::single_threaded::RwLock;
+
+#[cfg(not(feature = "std"))]
+mod single_threaded {
+    use core::cell::{Ref, RefCell, RefMut};
+
+    /// `RefCell` with the `parking_lot::RwLock` interface, for `no_std` targets
+    /// without threads. Conflicting borrows panic instead of blocking.
+    #[derive(Debug, Default)]
+    pub(crate) struct RwLock<T>(RefCell<T>);
+
+    impl<T> RwLock<T> {
+        pub(crate) const fn new(value: T) -> Self {
+            Self(RefCell::new(value))
+        }
+
+        pub(crate) fn read(&self) -> Ref<'_, T> {
+            self.0.borrow()
+        }
+
+        pub(crate) fn write(&self) -> RefMut<'_, T> {
+            self.0.borrow_mut()
+        }
+    }
+}
@@ -3,20 +3,47 @@
 //! This module implements the core compilation logic that transforms
 //! Rholang AST nodes into bytecode instructions
 
+use crate::error::{CompileError, UnsupportedFeature};
 use anyhow::{anyhow, bail, Result};
-use librho::sem::{BinderId, SemanticDb, SymbolOccurrence, PID};
+use librho::sem::{uris, BinderId, BinderKind, SemanticDb, SymbolOccurrence, PID};
 use num_bigint::BigInt;
 use num_rational::BigRational;
-use rholang_bytecode::core::{instructions::Instruction, opcodes::Opcode};
+use rholang_bytecode::core::{instructions::Instruction, opcodes::Opcode, SimpleType};
 use rholang_parser::ast::{
-    AnnProc, BinaryExpOp, Bind, Collection, Name, Proc, Receipts, Source, Var,
+    self, AnnProc, BinaryExpOp, Bind, Collection, Id, Name, Proc, ProcList, Receipts, Source, Var,
 };
+use rholang_parser::SourceSpan;
 use rholang_process::{Process, Value};
+use rholang_rspace::{quoted_name, system_name};
+use rholang_vm::Pattern;
 use std::collections::HashMap;
 
+/// Upper bounds on the work a compilation may do, see [`CompileError::BudgetExceeded`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CompileBudget {
+    /// Number of instructions that may be emitted
+    pub max_instructions: usize,
+    /// Number of AST nodes that may be visited
+    pub max_nodes: usize,
+}
+
+impl CompileBudget {
+    /// No limits at all
+    pub const UNLIMITED: Self = Self {
+        max_instructions: usize::MAX,
+        max_nodes: usize::MAX,
+    };
+}
+
+impl Default for CompileBudget {
+    fn default() -> Self {
+        Self::UNLIMITED
+    }
+}
+
 /// Compilation context for generating bytecode from Rholang AST
-pub struct CodegenContext<'a> {
-    db: &'a SemanticDb<'a>,
+pub struct CodegenContext<'db, 'a> {
+    db: &'db SemanticDb<'a>,
 
     /// The bytecode instruction stream being generated
     instructions: Vec<Instruction>,
@@ -45,15 +72,30 @@ pub struct CodegenContext<'a> {
 
     /// Process index for source references
     proc_index: usize,
+
+    /// Limits on the instructions emitted and nodes visited
+    budget: CompileBudget,
+
+    /// Number of AST nodes visited so far
+    nodes_visited: usize,
+
+    /// Whether a name used in process position is evaluated as if written `*x`
+    implicit_eval: bool,
 }
 
-impl<'a> CodegenContext<'a> {
+impl<'db, 'a> CodegenContext<'db, 'a> {
     /// Create a new code generation context.
     ///
     /// # Arguments
     /// * `db` - The semantic database for variable resolution
     /// * `proc_index` - Index of the process being compiled (for source references)
-    pub fn new(db: &'a SemanticDb<'a>, proc_index: usize) -> Self {
+    pub fn new(db: &'db SemanticDb<'a>, proc_index: usize) -> Self {
+        Self::with_budget(db, proc_index, CompileBudget::UNLIMITED)
+    }
+
+    /// Create a code generation context that fails with [`CompileError::BudgetExceeded`]
+    /// once it emits more instructions or visits more nodes than `budget` allows.
+    pub fn with_budget(db: &'db SemanticDb<'a>, proc_index: usize, budget: CompileBudget) -> Self {
         Self {
             db,
             instructions: Vec::new(),
@@ -65,6 +107,28 @@ impl<'a> CodegenContext<'a> {
             forward_refs: Vec::new(),
             next_label: 0,
             proc_index,
+            budget,
+            nodes_visited: 0,
+            implicit_eval: true,
+        }
+    }
+
+    /// Whether a name used in process position compiles to an implicit `EVAL` (the
+    /// default), or fails with [`CompileError::ImplicitEvalDisallowed`] so that every
+    /// evaluation has to be written `*x`.
+    pub fn implicit_eval(mut self, enabled: bool) -> Self {
+        self.implicit_eval = enabled;
+        self
+    }
+
+    /// What is left of the budget after the work done so far
+    pub fn remaining_budget(&self) -> CompileBudget {
+        CompileBudget {
+            max_instructions: self
+                .budget
+                .max_instructions
+                .saturating_sub(self.instructions.len()),
+            max_nodes: self.budget.max_nodes.saturating_sub(self.nodes_visited),
         }
     }
 
@@ -74,9 +138,30 @@ impl<'a> CodegenContext<'a> {
     ///
     /// Returns an error if:
     /// - An unsupported process variant is encountered
+    /// - A `Proc::Bad` node produced by parser error recovery is encountered
     /// - Binary operator mapping fails
     /// - Integer literal is out of range for MVP
+    ///
+    /// Unsupported features are reported as [`CompileError::Unsupported`] spanning
+    /// the innermost process that uses them.
     pub fn compile_proc(&mut self, proc: &AnnProc<'a>) -> Result<()> {
+        self.nodes_visited += 1;
+        self.check_budget(proc)?;
+        self.compile_proc_node(proc)
+            .map_err(|err| with_span(err, proc.span))?;
+        self.check_budget(proc)
+    }
+
+    fn check_budget(&self, proc: &AnnProc<'a>) -> Result<()> {
+        if self.nodes_visited > self.budget.max_nodes
+            || self.instructions.len() > self.budget.max_instructions
+        {
+            bail!(CompileError::BudgetExceeded { span: proc.span });
+        }
+        Ok(())
+    }
+
+    fn compile_proc_node(&mut self, proc: &AnnProc<'a>) -> Result<()> {
         match proc.proc {
             Proc::Nil => {
                 self.emit(Instruction::nullary(Opcode::PUSH_NIL));
@@ -124,6 +209,20 @@ impl<'a> CodegenContext<'a> {
                 self.emit(Instruction::unary(Opcode::PUSH_STR, idx));
             }
 
+            Proc::BinaryExp {
+                op: BinaryExpOp::Matches,
+                left,
+                right,
+            } => {
+                self.compile_proc(left)?;
+                if let Proc::SimpleType(t) = right.proc {
+                    self.emit(Instruction::unary(Opcode::MATCH_TYPE, simple_type_tag(*t)));
+                } else {
+                    let idx = self.add_constant(compile_pattern(right)?.to_value());
+                    self.emit(Instruction::unary(Opcode::MATCH_TEST, idx));
+                }
+            }
+
             Proc::BinaryExp { op, left, right } => {
                 // Compile operands first (stack-based evaluation)
                 self.compile_proc(left)?;
@@ -131,6 +230,14 @@ impl<'a> CodegenContext<'a> {
                 self.emit_binop(*op)?;
             }
 
+            Proc::Method {
+                receiver,
+                name,
+                args,
+            } => {
+                self.compile_method(receiver, name, args)?;
+            }
+
             Proc::ProcVar(var) => {
                 // Variable used in process position - may need implicit EVAL
                 // SAFETY: We cast proc to the correct lifetime since it comes from the AST
@@ -196,14 +303,36 @@ impl<'a> CodegenContext<'a> {
                 self.compile_for_comprehension(pid, receipts, body)?;
             }
 
+            Proc::Contract { .. } => {
+                self.compile_contracts(&[proc])?;
+            }
+
+            Proc::Let {
+                bindings,
+                body,
+                concurrent,
+            } => {
+                // SAFETY: We cast proc to the correct lifetime since it comes from the AST
+                let pid = match self.db.lookup(unsafe { &*(proc as *const AnnProc<'a>) }) {
+                    Some(pid) => pid,
+                    None => bail!("Let at {} not indexed", proc.span.start),
+                };
+                self.compile_let(pid, bindings, body, *concurrent)?;
+            }
+
             Proc::Par { left, right } => {
                 self.compile_par(left, right)?;
             }
 
-            _ => bail!(
-                "Unsupported process variant in MVP: {:?}",
-                std::mem::discriminant(proc.proc)
+            Proc::Bad => bail!(
+                "Malformed process at {} (left behind by parser error recovery)",
+                proc.span
             ),
+
+            _ => bail!(CompileError::Unsupported {
+                feature: format!("Process variant {:?}", std::mem::discriminant(proc.proc)),
+                span: proc.span,
+            }),
         }
 
         Ok(())
@@ -252,16 +381,19 @@ impl<'a> CodegenContext<'a> {
             .parse()
             .map_err(|e| anyhow!("Invalid signed integer literal '{}': {}", value, e))?;
 
-        let max = 1i128 << (bits - 1);
-        let min = -max;
-        if n < min || n >= max {
-            bail!(
-                "Signed integer literal {} out of range for i{} ({} to {})",
-                value,
-                bits,
-                min,
-                max - 1
-            );
+        // i128 literals are range checked by the parse above
+        if bits < 128 {
+            let max = 1i128 << (bits - 1);
+            let min = -max;
+            if n < min || n >= max {
+                bail!(
+                    "Signed integer literal {} out of range for i{} ({} to {})",
+                    value,
+                    bits,
+                    min,
+                    max - 1
+                );
+            }
         }
 
         if bits <= 64 {
@@ -413,8 +545,11 @@ impl<'a> CodegenContext<'a> {
             BinaryExpOp::And => Opcode::AND,
             BinaryExpOp::Or => Opcode::OR,
 
+            // String operators
+            BinaryExpOp::Interpolation => Opcode::INTERPOLATE,
+
             // Unsupported
-            _ => bail!("Unsupported binary operator: {:?}", op),
+            _ => bail!(UnsupportedFeature(format!("Binary operator {:?}", op))),
         };
 
         self.emit(Instruction::nullary(opcode));
@@ -428,6 +563,7 @@ impl<'a> CodegenContext<'a> {
     /// Returns an error if:
     /// - The variable is not found in the semantic database
     /// - The variable is not allocated to a local slot
+    /// - A name is used in process position while implicit eval is off
     fn compile_var(&mut self, var: &Var<'_>, pid: PID, as_process: bool) -> Result<()> {
         match var {
             Var::Wildcard => {
@@ -464,6 +600,11 @@ impl<'a> CodegenContext<'a> {
                         // When a name binder is used in process position, we need to
                         // unquote it to get the underlying process value
                         if as_process && self.db.is_name(binder_id) {
+                            if !self.implicit_eval {
+                                bail!(CompileError::ImplicitEvalDisallowed {
+                                    span: id.pos.span_of(id.name.chars().count()),
+                                });
+                            }
                             self.emit(Instruction::nullary(Opcode::EVAL));
                         }
                     }
@@ -490,7 +631,7 @@ impl<'a> CodegenContext<'a> {
     ) -> Result<()> {
         self.compile_proc(condition)?;
 
-        // Create a label for the else branch (or end if no else)
+        // Create a label for the else branch
         let label_else = self.new_label();
 
         // Emit BRANCH_FALSE with placeholder
@@ -502,34 +643,31 @@ impl<'a> CodegenContext<'a> {
         // Compile the then branch
         self.compile_proc(if_true)?;
 
-        if let Some(else_proc) = if_false {
-            // If there's an else branch, we need a jump over it
-            let label_end = self.new_label();
-
-            // Jump to end after then branch
-            let jump_idx = self.instructions.len();
-            self.emit(Instruction::nullary(Opcode::NOP)); // Placeholder
-            self.forward_refs.push((jump_idx, label_end, Opcode::JUMP));
-
-            self.define_label(label_else);
+        // Jump over the else branch after the then branch
+        let label_end = self.new_label();
+        let jump_idx = self.instructions.len();
+        self.emit(Instruction::nullary(Opcode::NOP)); // Placeholder
+        self.forward_refs.push((jump_idx, label_end, Opcode::JUMP));
 
-            self.compile_proc(else_proc)?;
+        self.define_label(label_else);
 
-            self.define_label(label_end);
-        } else {
-            self.define_label(label_else);
+        // A missing else branch evaluates to Nil, so both branches leave one value on the stack
+        match if_false {
+            Some(else_proc) => self.compile_proc(else_proc)?,
+            None => self.emit(Instruction::nullary(Opcode::PUSH_NIL)),
         }
 
+        self.define_label(label_end);
+
         Ok(())
     }
 
-    /// Compile a collection (list or tuple)
+    /// Compile a collection (list, tuple, map or pathmap)
     ///
     /// # Errors
     ///
     /// Returns an error if:
     /// - A collection with remainder is encountered (not supported in MVP)
-    /// - Maps or Sets are encountered (not supported in MVP)
     /// - Element compilation fails
     /// - Element count exceeds u16::MAX
     fn compile_collection(&mut self, coll: &Collection<'a>) -> Result<()> {
@@ -539,7 +677,7 @@ impl<'a> CodegenContext<'a> {
                 remainder,
             } => {
                 if remainder.is_some() {
-                    bail!("List remainder not supported in MVP");
+                    bail!(UnsupportedFeature("List remainder".into()));
                 }
 
                 for elem in elements {
@@ -567,24 +705,112 @@ impl<'a> CodegenContext<'a> {
                 self.emit(Instruction::unary(Opcode::CREATE_TUPLE, count as u16));
             }
 
-            Collection::Set { .. } => {
-                bail!("Sets not supported in MVP");
+            Collection::Set {
+                elements,
+                remainder,
+            } => {
+                if remainder.is_some() {
+                    bail!(UnsupportedFeature("Set remainder".into()));
+                }
+
+                for elem in elements {
+                    self.compile_proc(elem)?;
+                }
+
+                let count = elements.len();
+                if count > u16::MAX as usize {
+                    bail!("Set has too many elements (max {})", u16::MAX);
+                }
+
+                self.emit(Instruction::unary(Opcode::CREATE_SET, count as u16));
             }
 
-            Collection::Map { .. } => {
-                bail!("Maps not supported in MVP");
+            Collection::Map {
+                elements,
+                remainder,
+            } => {
+                if remainder.is_some() {
+                    bail!(UnsupportedFeature("Map remainder".into()));
+                }
+
+                for (key, value) in elements {
+                    self.compile_proc(key)?;
+                    self.compile_proc(value)?;
+                }
+
+                let count = elements.len();
+                if count > u16::MAX as usize {
+                    bail!("Map has too many entries (max {})", u16::MAX);
+                }
+
+                self.emit(Instruction::unary(Opcode::CREATE_MAP, count as u16));
             }
 
-            Collection::PathMap { .. } => {
-                bail!("PathMaps not supported in MVP");
+            Collection::PathMap {
+                elements,
+                remainder,
+            } => {
+                if remainder.is_some() {
+                    bail!(UnsupportedFeature("PathMap remainder".into()));
+                }
+
+                for elem in elements {
+                    self.compile_proc(elem)?;
+                }
+
+                let count = elements.len();
+                if count > u16::MAX as usize {
+                    bail!("PathMap has too many paths (max {})", u16::MAX);
+                }
+
+                self.emit(Instruction::unary(Opcode::CREATE_PATHMAP, count as u16));
             }
         }
 
         Ok(())
     }
 
+    /// Compile a method call
+    ///
+    /// Only the pathmap methods are supported: `subtract(other)`, `restrict(other)`
+    /// and `drop(n)`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The method is unknown or called with the wrong number of arguments
+    /// - Receiver or argument compilation fails
+    fn compile_method(
+        &mut self,
+        receiver: &AnnProc<'a>,
+        name: &Id<'_>,
+        args: &ProcList<'a>,
+    ) -> Result<()> {
+        let opcode = match name.name {
+            "subtract" => Opcode::PATHMAP_SUBTRACT,
+            "restrict" => Opcode::PATHMAP_RESTRICT,
+            "drop" => Opcode::PATHMAP_DROP,
+            other => bail!(UnsupportedFeature(format!("Method '{}'", other))),
+        };
+        if args.len() != 1 {
+            bail!(
+                "Method '{}' expects 1 argument, got {}",
+                name.name,
+                args.len()
+            );
+        }
+
+        self.compile_proc(receiver)?;
+        self.compile_proc(&args[0])?;
+        self.emit(Instruction::nullary(opcode));
+        Ok(())
+    }
+
     /// Compile a new channel declaration
     ///
+    /// Names declared with a known system URI (e.g. `rho:io:stdout`) are bound to
+    /// that well-known channel; all other names get a fresh channel.
+    ///
     /// # Errors
     ///
     /// Returns an error if:
@@ -599,11 +825,26 @@ impl<'a> CodegenContext<'a> {
 
         // Iterate over all binders introduced by this new declaration
         // Each binder corresponds to a channel name in the declaration
-        for (binder_id, _binder) in self.db.binders_full(scope) {
-            // Create a fresh channel name
-            // For MVP, we use a default kind (3 = persistent concurrent storage)
-            const DEFAULT_NAME_KIND: u16 = 3;
-            self.emit(Instruction::unary(Opcode::NAME_CREATE, DEFAULT_NAME_KIND));
+        for (binder_id, binder) in self.db.binders_full(scope) {
+            let system_uri = match binder.kind {
+                BinderKind::Name(Some(uri)) => self
+                    .db
+                    .resolve_symbol_owned(uri)
+                    .filter(|uri| uris::is_known_uri(uri)),
+                _ => None,
+            };
+
+            if let Some(uri) = system_uri {
+                // Known system URIs are bound to their reserved channel, which no
+                // quoted name can reach
+                let idx = self.add_string(&system_name(&uri));
+                self.emit(Instruction::unary(Opcode::PUSH_NAME, idx));
+            } else {
+                // Create a fresh channel name
+                // For MVP, we use a default kind (3 = persistent concurrent storage)
+                const DEFAULT_NAME_KIND: u16 = 3;
+                self.emit(Instruction::unary(Opcode::NAME_CREATE, DEFAULT_NAME_KIND));
+            }
 
             // Allocate a local slot on the VM stack
             self.emit(Instruction::nullary(Opcode::ALLOC_LOCAL));
@@ -635,15 +876,16 @@ impl<'a> CodegenContext<'a> {
             self.compile_proc(input)?;
         }
 
-        // Package inputs into a list (only if multiple inputs)
-        // For MVP, single values are sent directly without wrapping
+        // Package inputs into a tuple (only if there is not exactly one); a single
+        // value is sent directly, and TELL marks it if it is a tuple itself
         let count = inputs.len();
         if count > u16::MAX as usize {
             bail!("Too many send inputs (max {})", u16::MAX);
         }
 
-        if count != 1 {
-            self.emit(Instruction::unary(Opcode::CREATE_LIST, count as u16));
+        let polyadic = count != 1;
+        if polyadic {
+            self.emit(Instruction::unary(Opcode::CREATE_TUPLE, count as u16));
         }
 
         // Send the message
@@ -651,7 +893,7 @@ impl<'a> CodegenContext<'a> {
         self.emit(Instruction::binary(
             Opcode::TELL,
             DEFAULT_SEND_KIND,
-            0, // reserved
+            u8::from(polyadic),
         ));
 
         Ok(())
@@ -682,7 +924,7 @@ impl<'a> CodegenContext<'a> {
                                 self.compile_name(name, pid)?;
                             }
                             Source::ReceiveSend { .. } | Source::SendReceive { .. } => {
-                                bail!("Complex sources not supported in MVP");
+                                bail!(UnsupportedFeature("Complex sources".into()));
                             }
                         }
 
@@ -694,52 +936,15 @@ impl<'a> CodegenContext<'a> {
                             0, // reserved
                         ));
 
-                        // Bind received values to variables
-                        // For MVP, we expect the result to be a list that we unpack
-                        for name in &lhs.names {
-                            match name {
-                                Name::NameVar(Var::Id(id)) => {
-                                    // Resolve the binder for this variable
-                                    let symbol = self.db.intern(id.name);
-                                    let occ = SymbolOccurrence {
-                                        symbol,
-                                        position: id.pos,
-                                    };
-
-                                    match self.db.binder_of(occ) {
-                                        Some(binding) => {
-                                            // Resolve the binding (handles both Bound and Free)
-                                            let binder_id =
-                                                self.db.resolve_var_binding(pid, binding);
-
-                                            self.emit(Instruction::nullary(Opcode::ALLOC_LOCAL));
-
-                                            let slot = self.alloc_local(binder_id)?;
-                                            self.emit(Instruction::unary(
-                                                Opcode::STORE_LOCAL,
-                                                slot,
-                                            ));
-                                        }
-                                        None => {
-                                            bail!("Unbound variable '{}' at {}", id.name, id.pos);
-                                        }
-                                    }
-                                }
-                                Name::NameVar(Var::Wildcard) => {
-                                    // Wildcard binding - pop the value
-                                    self.emit(Instruction::nullary(Opcode::POP));
-                                }
-                                Name::Quote(_) => {
-                                    bail!("Quote patterns not supported in MVP");
-                                }
-                            }
-                        }
+                        // Spread the received message over the bound names
+                        let slots = self.alloc_formals(pid, lhs)?;
+                        self.bind_message(lhs, &slots)?;
                     }
                     Bind::Repeated { .. } => {
-                        bail!("Repeated receives not supported in MVP");
+                        bail!(UnsupportedFeature("Repeated receives".into()));
                     }
                     Bind::Peek { .. } => {
-                        bail!("Peek receives not supported in MVP");
+                        bail!(UnsupportedFeature("Peek receives".into()));
                     }
                 }
             }
@@ -751,36 +956,293 @@ impl<'a> CodegenContext<'a> {
         Ok(())
     }
 
-    /// Compile a parallel composition
-    /// For MVP, parallel composition is executed sequentially
+    /// Compile the contracts of a parallel composition
+    ///
+    /// For MVP, contracts run after the other branches of their parallel
+    /// composition (see [`Self::compile_par`]) and serve the messages sent on
+    /// their channels until all of them are empty. Each round takes one message
+    /// from the first contract channel that has one, spreads it over the formals
+    /// of that contract and runs its body, so that messages the body sends, also
+    /// to another contract of the composition, are served too. The contracts
+    /// evaluate to the result of the last invocation (Nil if there was none).
+    /// Messages sent after the process has moved on are not served.
     ///
     /// # Errors
     ///
-    /// Returns an error if compilation of either side fails
-    fn compile_par(&mut self, left: &AnnProc<'a>, right: &AnnProc<'a>) -> Result<()> {
-        // Compile left side
-        self.compile_proc(left)?;
+    /// Returns an error if:
+    /// - A formal is a quoted pattern other than `@x` (not supported in MVP)
+    /// - A contract has more than u8::MAX positional formals
+    /// - Channel or body compilation fails
+    fn compile_contracts(&mut self, contracts: &[&AnnProc<'a>]) -> Result<()> {
+        // Allocate a slot per formal up front, outside of the rounds
+        let mut compiled = Vec::with_capacity(contracts.len());
+        for contract in contracts {
+            let Proc::Contract {
+                name,
+                formals,
+                body,
+            } = contract.proc
+            else {
+                bail!("Expected a contract at {}", contract.span.start);
+            };
+            // SAFETY: We cast proc to the correct lifetime since it comes from the AST
+            let pid = match self
+                .db
+                .lookup(unsafe { &*(*contract as *const AnnProc<'a>) })
+            {
+                Some(pid) => pid,
+                None => bail!("Contract at {} not indexed", contract.span.start),
+            };
+            let slots = self
+                .alloc_formals(pid, formals)
+                .map_err(|err| with_span(err, contract.span))?;
+            compiled.push((pid, name, formals, body, slots));
+        }
+
+        // Result of the last invocation
+        self.emit(Instruction::nullary(Opcode::PUSH_NIL));
+
+        let label_round = self.new_label();
+        self.define_label(label_round);
+
+        for (pid, name, formals, body, slots) in compiled {
+            let label_next = self.new_label();
+
+            const DEFAULT_RECEIVE_KIND: u8 = 3;
+            self.compile_name(name, pid)?;
+            self.emit(Instruction::binary(
+                Opcode::ASK_NB,
+                DEFAULT_RECEIVE_KIND,
+                0, // reserved
+            ));
+            let branch_idx = self.instructions.len();
+            self.emit(Instruction::nullary(Opcode::NOP)); // Placeholder
+            self.forward_refs
+                .push((branch_idx, label_next, Opcode::BRANCH_FALSE));
+
+            // Replace the previous result with the message and spread it over the formals
+            self.emit(Instruction::nullary(Opcode::SWAP));
+            self.emit(Instruction::nullary(Opcode::POP));
+            self.bind_message(formals, &slots)?;
 
-        // Discard the result of left side
-        self.emit(Instruction::nullary(Opcode::POP));
+            self.compile_proc(body)?;
 
-        // Compile right side (its result stays on stack)
-        self.compile_proc(right)?;
+            // Look for the next message starting from the first contract again
+            let jump_idx = self.instructions.len();
+            self.emit(Instruction::nullary(Opcode::NOP)); // Placeholder
+            self.forward_refs
+                .push((jump_idx, label_round, Opcode::JUMP));
+
+            self.define_label(label_next);
+        }
+
+        Ok(())
+    }
+
+    /// Allocate a local slot per name a message is spread over, `None` for a wildcard
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - A name is a quoted pattern other than `@x` (not supported in MVP)
+    /// - There are more than u8::MAX positional names
+    /// - A name is unbound or no slot is left
+    #[allow(clippy::cast_possible_truncation)]
+    fn alloc_formals(&mut self, pid: PID, names: &ast::Names<'a>) -> Result<Vec<Option<u16>>> {
+        if names.names.len() > u8::MAX as usize {
+            bail!("Too many formals (max {})", u8::MAX);
+        }
+
+        let mut slots = Vec::with_capacity(names.names.len() + 1);
+        for name in &names.names {
+            slots.push(self.alloc_formal(pid, formal_var(name)?)?);
+        }
+        if let Some(rest) = &names.remainder {
+            slots.push(self.alloc_formal(pid, rest)?);
+        }
+        Ok(slots)
+    }
+
+    /// Spread the message on top of the stack over `names`, storing the values into
+    /// `slots` as allocated by [`Self::alloc_formals`]
+    #[allow(clippy::cast_possible_truncation)]
+    fn bind_message(&mut self, names: &ast::Names<'a>, slots: &[Option<u16>]) -> Result<()> {
+        self.emit(Instruction::binary(
+            Opcode::EXTRACT_BINDINGS,
+            names.names.len() as u8,
+            u8::from(names.remainder.is_some()),
+        ));
+        self.store_formals(slots);
+        Ok(())
+    }
+
+    /// Pop one value per slot, the last slot's first, storing it or dropping it for a
+    /// wildcard
+    fn store_formals(&mut self, slots: &[Option<u16>]) {
+        for slot in slots.iter().rev() {
+            match slot {
+                Some(slot) => self.emit(Instruction::unary(Opcode::STORE_LOCAL, *slot)),
+                None => self.emit(Instruction::nullary(Opcode::POP)),
+            }
+        }
+    }
+
+    /// Compile a `let`
+    ///
+    /// A sequential `let` evaluates each right-hand side with the bindings before it in
+    /// scope, a concurrent one evaluates every right-hand side before binding any name.
+    /// The values of a binding are bound to its names in order, a remainder collecting
+    /// those left over as a list. The number of values is checked at compile time.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - A name is a quoted pattern other than `@x` (not supported in MVP)
+    /// - A binding has too few values for its names, or too many without a remainder
+    /// - A binding has more than u8::MAX names or u16::MAX values
+    /// - Compilation of a value or the body fails
+    fn compile_let(
+        &mut self,
+        pid: PID,
+        bindings: &[ast::LetBinding<'a>],
+        body: &AnnProc<'a>,
+        concurrent: bool,
+    ) -> Result<()> {
+        if concurrent {
+            for binding in bindings {
+                self.compile_let_values(binding)?;
+            }
+            // The values of the last binding are on top of the stack
+            for binding in bindings.iter().rev() {
+                self.bind_let_names(pid, binding)?;
+            }
+        } else {
+            for binding in bindings {
+                self.compile_let_values(binding)?;
+                self.bind_let_names(pid, binding)?;
+            }
+        }
+
+        self.compile_proc(body)
+    }
+
+    /// Push the values of a `let` binding, one by one
+    fn compile_let_values(&mut self, binding: &ast::LetBinding<'a>) -> Result<()> {
+        if binding.rhs.len() > u16::MAX as usize {
+            bail!("Too many let values (max {})", u16::MAX);
+        }
+        for value in &binding.rhs {
+            self.compile_proc(value)?;
+        }
+        Ok(())
+    }
+
+    /// Bind the values of a `let` binding on top of the stack to its names
+    #[allow(clippy::cast_possible_truncation)]
+    fn bind_let_names(&mut self, pid: PID, binding: &ast::LetBinding<'a>) -> Result<()> {
+        let names = &binding.lhs;
+        let (positional, values) = (names.names.len(), binding.rhs.len());
+        let has_rest = names.remainder.is_some();
+        if values < positional || (!has_rest && values > positional) {
+            bail!(
+                "let expects {}{} value(s), got {}",
+                if has_rest { "at least " } else { "" },
+                positional,
+                values
+            );
+        }
+
+        let slots = self.alloc_formals(pid, names)?;
+        if has_rest {
+            self.emit(Instruction::unary(
+                Opcode::CREATE_LIST,
+                (values - positional) as u16,
+            ));
+        }
+        self.store_formals(&slots);
+        Ok(())
+    }
+
+    /// Allocate a local slot for a contract formal or a `let` name, or `None` for a wildcard
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the formal is unbound or no slot is left
+    fn alloc_formal(&mut self, pid: PID, var: &Var<'a>) -> Result<Option<u16>> {
+        match var {
+            Var::Id(id) => {
+                let occ = SymbolOccurrence {
+                    symbol: self.db.intern(id.name),
+                    position: id.pos,
+                };
+                let Some(binding) = self.db.binder_of(occ) else {
+                    bail!("Unbound variable '{}' at {}", id.name, id.pos);
+                };
+                let binder_id = self.db.resolve_var_binding(pid, binding);
+                self.emit(Instruction::nullary(Opcode::ALLOC_LOCAL));
+                Ok(Some(self.alloc_local(binder_id)?))
+            }
+            Var::Wildcard => Ok(None),
+        }
+    }
+
+    /// Compile a parallel composition
+    /// For MVP, parallel composition is executed sequentially. Receives are moved
+    /// after the other branches, and contracts after those, so that they see the
+    /// messages sent to them whatever the order of the branches in the source
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if compilation of any branch fails
+    fn compile_par(&mut self, left: &AnnProc<'a>, right: &AnnProc<'a>) -> Result<()> {
+        let mut branches = Vec::new();
+        collect_par_branches(left, &mut branches);
+        collect_par_branches(right, &mut branches);
+        let (contracts, mut others): (Vec<&AnnProc<'a>>, Vec<_>) = branches
+            .into_iter()
+            .partition(|branch| matches!(branch.proc, Proc::Contract { .. }));
+        // A stable sort keeps the source order among the sends and among the receives
+        others.sort_by_key(|branch| matches!(branch.proc, Proc::ForComprehension { .. }));
+
+        for (i, branch) in others.iter().enumerate() {
+            // Discard the result of every branch but the last
+            if i > 0 {
+                self.emit(Instruction::nullary(Opcode::POP));
+            }
+            self.compile_proc(branch)?;
+        }
+        if !contracts.is_empty() {
+            if !others.is_empty() {
+                self.emit(Instruction::nullary(Opcode::POP));
+            }
+            self.compile_contracts(&contracts)?;
+        }
 
         Ok(())
     }
 
     /// Compile a channel name
     ///
+    /// A quoted string such as `@"c"` is a public channel: every process that
+    /// names it, in this or any later run on the same RSpace, reaches the same
+    /// channel.
+    ///
     /// # Errors
     ///
     /// Returns an error if:
-    /// - Name is a Quote (not supported in MVP)
+    /// - Name is a Quote of anything but a string literal (not supported in MVP)
     /// - Variable compilation fails
     fn compile_name(&mut self, name: &Name<'a>, pid: PID) -> Result<()> {
         match name {
             Name::NameVar(var) => self.compile_var(var, pid, false),
-            Name::Quote(_) => bail!("Quote not supported in MVP"),
+            Name::Quote(quoted) => match quoted.proc {
+                Proc::StringLiteral(channel) => {
+                    let idx = self.add_string(&quoted_name(channel));
+                    self.emit(Instruction::unary(Opcode::PUSH_NAME, idx));
+                    Ok(())
+                }
+                _ => bail!(UnsupportedFeature("Quote".into())),
+            },
         }
     }
 
@@ -882,6 +1344,86 @@ impl<'a> CodegenContext<'a> {
     }
 }
 
+/// Attach `span` to an [`UnsupportedFeature`] error, leaving other errors alone
+fn with_span(err: anyhow::Error, span: SourceSpan) -> anyhow::Error {
+    match err.downcast::<UnsupportedFeature>() {
+        Ok(UnsupportedFeature(feature)) => CompileError::Unsupported { feature, span }.into(),
+        Err(err) => err,
+    }
+}
+
+/// The variable a message or `let` value is bound to: `x` and `@x` bind the same value
+fn formal_var<'x, 'a>(name: &'x Name<'a>) -> Result<&'x Var<'a>> {
+    match name {
+        Name::NameVar(var) => Ok(var),
+        Name::Quote(quoted) => match quoted.proc {
+            Proc::ProcVar(var) => Ok(var),
+            _ => bail!(UnsupportedFeature("Quote patterns".into())),
+        },
+    }
+}
+
+/// The branches of a (possibly nested) parallel composition, left to right
+fn collect_par_branches<'x, 'a>(proc: &'x AnnProc<'a>, out: &mut Vec<&'x AnnProc<'a>>) {
+    match proc.proc {
+        Proc::Par { left, right } => {
+            collect_par_branches(left, out);
+            collect_par_branches(right, out);
+        }
+        _ => out.push(proc),
+    }
+}
+
+/// The `MATCH_TYPE` operand for a simple type
+fn simple_type_tag(t: ast::SimpleType) -> u16 {
+    simple_type(t) as u16
+}
+
+fn simple_type(t: ast::SimpleType) -> SimpleType {
+    match t {
+        ast::SimpleType::Bool => SimpleType::Bool,
+        ast::SimpleType::Int => SimpleType::Int,
+        ast::SimpleType::String => SimpleType::String,
+        ast::SimpleType::Uri => SimpleType::Uri,
+        ast::SimpleType::ByteArray => SimpleType::ByteArray,
+    }
+}
+
+/// Translate the right-hand side of `matches` into a [`Pattern`].
+///
+/// Variables in the pattern only test the shape of the value: nothing they
+/// would bind is visible after the `matches` expression.
+fn compile_pattern(proc: &AnnProc<'_>) -> Result<Pattern> {
+    let pattern = match proc.proc {
+        Proc::ProcVar(_) => Pattern::Wildcard,
+        Proc::Nil => Pattern::Ground(Value::Nil),
+        Proc::Unit => Pattern::Ground(Value::Tuple(Vec::new())),
+        Proc::BoolLiteral(b) => Pattern::Ground(Value::Bool(*b)),
+        Proc::LongLiteral(n) => Pattern::Ground(Value::Int(*n)),
+        Proc::StringLiteral(s) => Pattern::Ground(Value::Str(s.to_string())),
+        Proc::SimpleType(t) => Pattern::Type(simple_type(*t)),
+        Proc::Collection(Collection::List {
+            elements,
+            remainder,
+        }) => Pattern::List {
+            elements: compile_patterns(elements)?,
+            rest: remainder.is_some(),
+        },
+        Proc::Collection(Collection::Tuple(elements)) => {
+            Pattern::Tuple(compile_patterns(elements)?)
+        }
+        _ => bail!(CompileError::Unsupported {
+            feature: "This pattern on the right of 'matches'".into(),
+            span: proc.span,
+        }),
+    };
+    Ok(pattern)
+}
+
+fn compile_patterns(procs: &[AnnProc<'_>]) -> Result<Vec<Pattern>> {
+    procs.iter().map(compile_pattern).collect()
+}
+
 /// Parse a fixed-point literal value string into an unscaled BigInt.
 ///
 /// For example, `"3.3"` with scale=1 → unscaled=33.
@@ -1037,6 +1579,18 @@ mod tests {
         assert_eq!(ctx.strings.len(), 2); // Only 2 unique strings
     }
 
+    #[test]
+    fn test_compile_bad_is_error() {
+        let db = SemanticDb::new();
+        let proc = Proc::Bad;
+        let mut ctx = CodegenContext::new(&db, 0);
+
+        let result = ctx.compile_proc(&ann_proc(&proc));
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("Malformed process"));
+        assert!(ctx.instructions.is_empty());
+    }
+
     #[test]
     fn test_finalize_adds_halt() {
         let db = SemanticDb::new();
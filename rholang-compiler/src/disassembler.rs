@@ -268,92 +268,38 @@ impl Disassembler {
             Opcode::PUSH_STR => format!("Push string at index {}", inst.op16()),
             Opcode::PUSH_PROC => format!("Push process at index {}", inst.op16()),
             Opcode::PUSH_NAME => format!("Push name at index {}", inst.op16()),
-            Opcode::PUSH_NIL => "Push nil".to_string(),
             Opcode::PUSH_CONST => format!("Push constant at index {}", inst.op16()),
-            Opcode::POP => "Pop top of stack".to_string(),
-            Opcode::DUP => "Duplicate top of stack".to_string(),
-            Opcode::SWAP => "Swap top two stack values".to_string(),
 
             // Variable operations
             Opcode::LOAD_VAR => format!("Load variable #{}", inst.op16()),
             Opcode::LOAD_LOCAL => format!("Load local variable #{}", inst.op16()),
             Opcode::STORE_LOCAL => format!("Store to local variable #{}", inst.op16()),
-            Opcode::ALLOC_LOCAL => "Allocate local variable".to_string(),
             Opcode::LOAD_ENV => format!("Load from environment slot #{}", inst.op16()),
             Opcode::STORE_ENV => format!("Store to environment slot #{}", inst.op16()),
 
-            // Arithmetic operations
-            Opcode::ADD => "Add top two stack values".to_string(),
-            Opcode::SUB => "Subtract top two stack values".to_string(),
-            Opcode::MUL => "Multiply top two stack values".to_string(),
-            Opcode::DIV => "Divide top two stack values".to_string(),
-            Opcode::MOD => "Modulo top two stack values".to_string(),
-            Opcode::NEG => "Negate top stack value".to_string(),
-
-            // Comparison operations
-            Opcode::CMP_EQ => "Compare equal".to_string(),
-            Opcode::CMP_NEQ => "Compare not equal".to_string(),
-            Opcode::CMP_LT => "Compare less than".to_string(),
-            Opcode::CMP_LTE => "Compare less than or equal".to_string(),
-            Opcode::CMP_GT => "Compare greater than".to_string(),
-            Opcode::CMP_GTE => "Compare greater than or equal".to_string(),
-
-            // Logical operations
-            Opcode::NOT => "Logical NOT".to_string(),
-            Opcode::AND => "Logical AND".to_string(),
-            Opcode::OR => "Logical OR".to_string(),
-
             // Control flow
-            Opcode::NOP => "No operation".to_string(),
             Opcode::JUMP => format!("Jump to instruction {}", inst.op16()),
             Opcode::BRANCH_TRUE => format!("Branch to {} if true", inst.op16()),
             Opcode::BRANCH_FALSE => format!("Branch to {} if false", inst.op16()),
             Opcode::BRANCH_SUCCESS => format!("Branch to {} on success", inst.op16()),
-            Opcode::RETURN => "Return from current context".to_string(),
-            Opcode::HALT => "Halt execution".to_string(),
 
             // Collection operations
             Opcode::CREATE_LIST => format!("Create list with {} elements", inst.op16()),
             Opcode::CREATE_TUPLE => format!("Create tuple with {} elements", inst.op16()),
             Opcode::CREATE_MAP => format!("Create map with {} pairs", inst.op16()),
-            Opcode::CONCAT => "Concatenate collections".to_string(),
-            Opcode::DIFF => "Set difference".to_string(),
-            Opcode::INTERPOLATE => "String interpolation".to_string(),
-
-            // Process operations
-            Opcode::SPAWN_ASYNC => "Spawn asynchronous process".to_string(),
-            Opcode::EVAL => "Evaluate (unquote) name".to_string(),
-            Opcode::EVAL_BOOL => "Evaluate as boolean".to_string(),
-            Opcode::EVAL_STAR => "Evaluate all (splat)".to_string(),
-            Opcode::EXEC => "Execute process".to_string(),
-            Opcode::PROC_NEG => "Process negation".to_string(),
+            Opcode::CREATE_PATHMAP => format!("Create pathmap with {} paths", inst.op16()),
 
             // RSpace operations
             Opcode::NAME_CREATE => format!("Create name with kind {}", inst.op16()),
-            Opcode::NAME_QUOTE => "Quote name".to_string(),
-            Opcode::NAME_UNQUOTE => "Unquote name".to_string(),
             Opcode::TELL => format!("Send on channel (kind: {})", inst.op1()),
             Opcode::ASK => format!("Receive from channel (kind: {})", inst.op1()),
-            Opcode::ASK_NB => "Non-blocking receive".to_string(),
-            Opcode::PEEK => "Peek at channel".to_string(),
-            Opcode::CONT_STORE => "Store continuation".to_string(),
-            Opcode::CONT_RESUME => "Resume continuation".to_string(),
-            Opcode::BUNDLE_BEGIN => "Begin bundle".to_string(),
-            Opcode::BUNDLE_END => "End bundle".to_string(),
-
-            // Pattern matching
-            Opcode::PATTERN => "Pattern match".to_string(),
-            Opcode::MATCH_TEST => "Test pattern match".to_string(),
-            Opcode::EXTRACT_BINDINGS => "Extract pattern bindings".to_string(),
-
-            // Reference operations
-            Opcode::COPY => "Copy value".to_string(),
-            Opcode::MOVE => "Move value".to_string(),
-            Opcode::REF => "Create reference".to_string(),
 
             // Method operations
             Opcode::LOAD_METHOD => format!("Load method #{}", inst.op16()),
             Opcode::INVOKE_METHOD => format!("Invoke method with {} args", inst.op16()),
+
+            // Opcodes whose operands are not worth spelling out
+            _ => opcode.description().to_string(),
         };
 
         Ok(comment)
@@ -0,0 +1,79 @@
+//! Compilation error types
+
+use std::error::Error;
+use std::fmt;
+
+use rholang_parser::SourceSpan;
+
+/// Error produced while compiling a top-level process
+///
+/// Every variant carries a span so that editors can underline the offending source.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CompileError {
+    /// A language feature that the bytecode compiler does not support yet. The span
+    /// points at the innermost process using it.
+    Unsupported { feature: String, span: SourceSpan },
+    /// Any other compilation failure, reported against the top-level process
+    Failed { message: String, span: SourceSpan },
+    /// Compilation gave up because it went over its [`CompileBudget`](crate::CompileBudget).
+    /// The span points at the process being compiled when the budget ran out.
+    BudgetExceeded { span: SourceSpan },
+    /// A name is used in process position, which would evaluate it implicitly, while the
+    /// compiler is set to require an explicit `*x`. The span points at the name.
+    ImplicitEvalDisallowed { span: SourceSpan },
+}
+
+impl CompileError {
+    /// The source span this error refers to
+    pub fn span(&self) -> SourceSpan {
+        match self {
+            CompileError::Unsupported { span, .. }
+            | CompileError::Failed { span, .. }
+            | CompileError::BudgetExceeded { span }
+            | CompileError::ImplicitEvalDisallowed { span } => *span,
+        }
+    }
+
+    /// Convert an error returned by codegen, falling back to `span` if it has none
+    pub(crate) fn from_anyhow(err: anyhow::Error, span: SourceSpan) -> Self {
+        match err.downcast::<CompileError>() {
+            Ok(err) => err,
+            Err(err) => CompileError::Failed {
+                message: err.to_string(),
+                span,
+            },
+        }
+    }
+}
+
+impl fmt::Display for CompileError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CompileError::Unsupported { feature, span } => {
+                write!(f, "{} not supported in MVP (at {})", feature, span)
+            }
+            CompileError::Failed { message, span } => write!(f, "{} (at {})", message, span),
+            CompileError::BudgetExceeded { span } => {
+                write!(f, "compilation budget exceeded (at {})", span)
+            }
+            CompileError::ImplicitEvalDisallowed { span } => {
+                write!(f, "name used as a process without `*` (at {})", span)
+            }
+        }
+    }
+}
+
+impl Error for CompileError {}
+
+/// An unsupported feature raised where no span is at hand. The innermost enclosing
+/// `compile_proc` turns it into [`CompileError::Unsupported`] with its own span.
+#[derive(Debug)]
+pub(crate) struct UnsupportedFeature(pub(crate) String);
+
+impl fmt::Display for UnsupportedFeature {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} not supported in MVP", self.0)
+    }
+}
+
+impl Error for UnsupportedFeature {}
@@ -0,0 +1,72 @@
+//! Pre-flight estimate of the bytecode size of a process
+//!
+//! Walks the AST once and sums, for every node, the number of instructions
+//! [`CodegenContext`](crate::CodegenContext) emits for that node itself
+//! (its sub-processes are counted when they are visited). Where codegen emits a
+//! varying number of instructions the larger count is taken, so the estimate
+//! bounds the instruction count of a successful compilation from above.
+
+use rholang_parser::ast::{AnnProc, Names, Proc};
+
+/// Estimate how many instructions compiling `proc` produces, including the
+/// final HALT
+///
+/// This is cheap compared to compilation and needs no semantic analysis, so
+/// it can be used to reject programs that would generate excessive bytecode
+/// before compiling them. Processes that codegen does not support are counted
+/// as one instruction per node.
+pub fn estimate_instructions<'a>(proc: &'a AnnProc<'a>) -> usize {
+    // HALT appended by `CodegenContext::finalize`
+    1 + proc
+        .iter_preorder_dfs()
+        .map(own_instructions)
+        .sum::<usize>()
+}
+
+/// Instructions emitted for `proc` excluding those of its sub-processes
+fn own_instructions(proc: &AnnProc) -> usize {
+    match proc.proc {
+        // LOAD_LOCAL, plus EVAL for a name used as a process
+        Proc::ProcVar(_) => 2,
+
+        // BRANCH_FALSE and JUMP, plus PUSH_NIL for a missing else branch
+        Proc::IfThenElse { if_false, .. } => 2 + usize::from(if_false.is_none()),
+
+        // NAME_CREATE (or PUSH_NAME), ALLOC_LOCAL and STORE_LOCAL per declaration
+        Proc::New { decls, .. } => 3 * decls.len(),
+
+        // channel, CREATE_TUPLE and TELL
+        Proc::Send { .. } => 3,
+
+        // channel, ASK and EXTRACT_BINDINGS per bind, ALLOC_LOCAL and STORE_LOCAL
+        // (or POP) per name
+        Proc::ForComprehension { receipts, .. } => receipts
+            .iter()
+            .flatten()
+            .map(|bind| 3 + 2 * formal_count(bind.names()))
+            .sum(),
+
+        // PUSH_NIL, channel, ASK_NB, BRANCH_FALSE, SWAP, POP, EXTRACT_BINDINGS and
+        // JUMP, plus ALLOC_LOCAL and STORE_LOCAL per formal
+        Proc::Contract { formals, .. } => 8 + 2 * formal_count(formals),
+
+        // ALLOC_LOCAL and STORE_LOCAL (or POP) per name, plus CREATE_LIST for a
+        // remainder
+        Proc::Let { bindings, .. } => bindings
+            .iter()
+            .map(|binding| {
+                2 * formal_count(&binding.lhs) + usize::from(binding.lhs.remainder.is_some())
+            })
+            .sum(),
+
+        // POP between the branches
+        Proc::Par { .. } => 1,
+
+        // a single push, or the opcode following the operands
+        _ => 1,
+    }
+}
+
+fn formal_count(names: &Names) -> usize {
+    names.names.len() + usize::from(names.remainder.is_some())
+}
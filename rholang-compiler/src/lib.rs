@@ -18,32 +18,58 @@
 
 mod codegen;
 mod disassembler;
+mod error;
+mod estimate;
 
 use anyhow::Result;
 use librho::sem::SemanticDb;
-use rholang_parser::ast::AnnProc;
+use rholang_parser::ast::{AnnProc, Proc};
+use rholang_parser::SourceSpan;
 pub use rholang_process::Process;
+use validated::Validated;
 
-pub use codegen::CodegenContext;
+pub use codegen::{CodegenContext, CompileBudget};
 pub use disassembler::{Disassembler, DisassemblerConfig, DisassemblyFormat};
+pub use error::CompileError;
+pub use estimate::estimate_instructions;
 
 /// The main compiler that transforms Rholang AST into bytecode processes
 ///
 /// The compiler is stateless and uses the SemanticDb for variable resolution
 /// and semantic information
-pub struct Compiler<'a> {
-    db: &'a SemanticDb<'a>,
+pub struct Compiler<'db, 'a> {
+    db: &'db SemanticDb<'a>,
+    budget: CompileBudget,
+    implicit_eval: bool,
 }
 
-impl<'a> Compiler<'a> {
-    pub fn new(db: &'a SemanticDb<'a>) -> Self {
-        Self { db }
+impl<'db, 'a> Compiler<'db, 'a> {
+    pub fn new(db: &'db SemanticDb<'a>) -> Self {
+        Self::with_budget(db, CompileBudget::UNLIMITED)
+    }
+
+    /// A compiler whose `compile*` calls fail with [`CompileError::BudgetExceeded`] once
+    /// the processes of one call, taken together, go over `budget`
+    pub fn with_budget(db: &'db SemanticDb<'a>, budget: CompileBudget) -> Self {
+        Self {
+            db,
+            budget,
+            implicit_eval: true,
+        }
+    }
+
+    /// Whether names used in process position are evaluated implicitly (the default), see
+    /// [`CodegenContext::implicit_eval`]
+    pub fn implicit_eval(mut self, enabled: bool) -> Self {
+        self.implicit_eval = enabled;
+        self
     }
 
     /// Compile a list of top-level processes into executable bytecode processes
     ///
     /// Each process in the input list is compiled independently and produces
-    /// one output Process with its instruction stream
+    /// one output Process with its instruction stream. An empty program compiles
+    /// to a single process that yields `Nil`, as if the source were `Nil`.
     ///
     /// # Errors
     ///
@@ -51,30 +77,77 @@ impl<'a> Compiler<'a> {
     /// - Unsupported language features are encountered
     /// - Compilation fails due to invalid AST structure
     pub fn compile(&self, procs: &[AnnProc<'a>]) -> Result<Vec<Process>> {
+        if procs.is_empty() {
+            return Ok(vec![self.compile_single(&empty_program())?]);
+        }
+
         let mut results = Vec::with_capacity(procs.len());
+        let mut budget = self.budget;
 
         for (idx, proc) in procs.iter().enumerate() {
-            let mut ctx = CodegenContext::new(self.db, idx);
+            let mut ctx = self.context(idx, budget);
             ctx.compile_proc(proc)?;
+            budget = ctx.remaining_budget();
             results.push(ctx.finalize()?);
         }
 
         Ok(results)
     }
 
+    /// Compile a list of top-level processes, collecting every error instead of
+    /// stopping at the first
+    ///
+    /// Each process is compiled independently as in `compile`. On failure the
+    /// result holds one [`CompileError`] per process that did not compile, in
+    /// source order. An empty program compiles to a `Nil` process as in `compile`.
+    pub fn compile_validated(
+        &self,
+        procs: &[AnnProc<'a>],
+    ) -> Validated<Vec<Process>, CompileError> {
+        if procs.is_empty() {
+            return self.compile_validated(&[empty_program()]);
+        }
+
+        let mut budget = self.budget;
+        procs
+            .iter()
+            .enumerate()
+            .map(|(idx, proc)| {
+                let mut ctx = self.context(idx, budget);
+                let compiled = ctx.compile_proc(proc);
+                budget = ctx.remaining_budget();
+                compiled
+                    .and_then(|()| ctx.finalize())
+                    .map_err(|err| CompileError::from_anyhow(err, proc.span))
+            })
+            .collect()
+    }
+
     /// Compile a single top-level process into an executable bytecode process
     ///
     /// # Errors
     ///
     /// Returns an error if compilation fails (see `compile` for details)
     pub fn compile_single(&self, proc: &AnnProc<'a>) -> Result<Process> {
-        let mut ctx = CodegenContext::new(self.db, 0);
+        let mut ctx = self.context(0, self.budget);
         ctx.compile_proc(proc)?;
         ctx.finalize()
     }
+
+    fn context(&self, proc_index: usize, budget: CompileBudget) -> CodegenContext<'db, 'a> {
+        CodegenContext::with_budget(self.db, proc_index, budget).implicit_eval(self.implicit_eval)
+    }
 }
 
-// -------------------- High-level async facade (parsing + sem + codegen) --------------------
+/// What an empty program means: a lone `Nil`
+fn empty_program<'a>() -> AnnProc<'a> {
+    AnnProc {
+        proc: &Proc::Nil,
+        span: SourceSpan::default(),
+    }
+}
+
+// -------------------- High-level facade (parsing + sem + codegen) --------------------
 use librho::sem::{
     pipeline::Pipeline, EnclosureAnalysisPass, ForCompElaborationPass, ResolverPass,
 };
@@ -82,9 +155,32 @@ use rholang_parser::RholangParser;
 
 /// Parse, run semantic pipeline, and compile all top-level processes from a Rholang source string.
 ///
-/// This is an async function because the semantic `Pipeline::run` is async. On wasm this runs
-/// sequentially; on native it may use concurrency internally.
+/// This is an async function for callers that already live in an async context; it delegates
+/// to [`compile_source`], which does not depend on any async runtime.
 pub async fn compile_source_async(src: &str) -> Result<Vec<Process>> {
+    compile_source(src)
+}
+
+/// Like [`compile_source_async`], but compilation stops with
+/// [`CompileError::BudgetExceeded`] as soon as it goes over `budget`.
+///
+/// Meant for untrusted input, complementing the input-size limit of the parser.
+pub async fn compile_source_async_bounded(
+    src: &str,
+    budget: CompileBudget,
+) -> Result<Vec<Process>> {
+    compile_source_bounded(src, budget)
+}
+
+/// Blocking counterpart of [`compile_source_async`] for synchronous embedders.
+///
+/// Never creates an async runtime, so it is safe to call from inside one.
+pub fn compile_source(src: &str) -> Result<Vec<Process>> {
+    compile_source_bounded(src, CompileBudget::UNLIMITED)
+}
+
+/// Blocking counterpart of [`compile_source_async_bounded`].
+pub fn compile_source_bounded(src: &str, budget: CompileBudget) -> Result<Vec<Process>> {
     // Parse
     let parser = RholangParser::new();
     let validated = parser.parse(src);
@@ -96,31 +192,67 @@ pub async fn compile_source_async(src: &str) -> Result<Vec<Process>> {
         }
     };
 
-    if ast_vec.is_empty() {
-        return Ok(Vec::new());
+    compile_ast_bounded(&ast_vec, budget)
+}
+
+/// Run the semantic pipeline over already parsed top-level processes and compile them.
+///
+/// This is [`compile_source`] without the parsing step, for callers that hold an AST of
+/// their own, e.g. one produced by a transformation.
+pub fn compile_ast<'a>(procs: &'a [AnnProc<'a>]) -> Result<Vec<Process>> {
+    compile_ast_bounded(procs, CompileBudget::UNLIMITED)
+}
+
+fn compile_ast_bounded<'a>(
+    procs: &'a [AnnProc<'a>],
+    budget: CompileBudget,
+) -> Result<Vec<Process>> {
+    if procs.is_empty() {
+        return Compiler::with_budget(&SemanticDb::new(), budget).compile(procs);
     }
 
-    // Build semantic DB and run essential passes
+    // Build semantic DB and run essential passes over every top-level process
     let mut db = SemanticDb::new();
-    let root = db.build_index(&ast_vec[0]);
-
-    let pipeline = Pipeline::new()
-        .add_fact(ResolverPass::new(root))
-        .add_fact(ForCompElaborationPass::new(root))
-        .add_fact(EnclosureAnalysisPass::new(root));
-    pipeline.run(&mut db).await;
+    let mut pipeline = Pipeline::new();
+    for proc in procs {
+        let root = db.build_index(proc);
+        pipeline = pipeline
+            .add_fact(ResolverPass::new(root))
+            .add_fact(ForCompElaborationPass::new(root))
+            .add_fact(EnclosureAnalysisPass::new(root));
+    }
+    run_fact_pipeline(&pipeline, &mut db)?;
 
     // Compile all procs
-    let compiler = Compiler::new(&db);
-    compiler.compile(&ast_vec)
+    let compiler = Compiler::with_budget(&db, budget);
+    compiler.compile(procs)
+}
+
+/// Run a pipeline made only of fact passes on the current thread.
+///
+/// `Pipeline::run` only suspends inside diagnostic groups, so a fact-only pipeline
+/// completes on its first poll and needs no executor. Should it suspend anyway, this
+/// gives up with an error rather than blocking.
+fn run_fact_pipeline(pipeline: &Pipeline, db: &mut SemanticDb<'_>) -> Result<()> {
+    use std::future::Future;
+    use std::task::{Context, Poll, Waker};
+
+    let mut run = std::pin::pin!(pipeline.run(db));
+    let mut cx = Context::from_waker(Waker::noop());
+    match run.as_mut().poll(&mut cx) {
+        Poll::Ready(()) => Ok(()),
+        Poll::Pending => Err(anyhow::anyhow!(
+            "semantic analysis suspended and cannot be completed synchronously"
+        )),
+    }
 }
 
 /// Convenience: compile only the first top-level process in the source.
 pub async fn compile_first_process_async(src: &str) -> Result<Process> {
     let procs = compile_source_async(src).await?;
-    Ok(procs
+    // `compile` turns an empty source into a single `Nil` process
+    procs
         .into_iter()
         .next()
-        // Treat empty sources as a valid no-op process so callers (e.g. WASM) get `Nil` instead of an error.
-        .unwrap_or_else(|| Process::new(Vec::new(), "<empty>")))
+        .ok_or_else(|| anyhow::anyhow!("No process compiled"))
 }
@@ -0,0 +1,56 @@
+//! Tests for:
+//! - Executing a process restored from bytes gives the same result as the freshly compiled one
+//! - Serialized processes keep their instruction stream and pools
+
+use rholang_compiler::{compile_source, Process};
+
+const PROGRAMS: &[&str] = &[
+    "1 + 2 * 3",
+    "\"hello\"",
+    "[1, (2, 3), Nil]",
+    "{\"a\": 1, \"b\": 2}",
+    "1.5f64 + 2.25f64",
+    "123456789012345678901234567890n * 2n",
+    "1r / 3r",
+    "1.25p2 + 0.50p2",
+    "if (3 > 2) { \"yes\" } else { \"no\" }",
+    "new x in { x!(42) | for (y <- x) { y } }",
+    "new ch in { ch!(5) | for (x <- ch) { x * 2 + 3 } }",
+];
+
+fn compile_one(source: &str) -> Process {
+    let mut processes = compile_source(source).unwrap();
+    assert_eq!(processes.len(), 1, "expected a single process for {source}");
+    processes.remove(0)
+}
+
+#[test]
+fn test_loaded_process_executes_like_compiled() {
+    for source in PROGRAMS {
+        let mut compiled = compile_one(source);
+        let mut loaded = Process::from_bytes(&compiled.to_bytes().unwrap()).unwrap();
+
+        let expected = compiled.execute();
+        let actual = loaded.execute();
+        assert_eq!(
+            actual.map_err(|e| e.to_string()),
+            expected.map_err(|e| e.to_string()),
+            "results differ for {source}"
+        );
+    }
+}
+
+#[test]
+fn test_round_trip_keeps_program() {
+    for source in PROGRAMS {
+        let compiled = compile_one(source);
+        let loaded = Process::from_bytes(&compiled.to_bytes().unwrap()).unwrap();
+
+        assert_eq!(loaded.code, compiled.code, "code differs for {source}");
+        assert_eq!(loaded.names, compiled.names, "names differ for {source}");
+        assert_eq!(
+            loaded.constants, compiled.constants,
+            "constants differ for {source}"
+        );
+    }
+}
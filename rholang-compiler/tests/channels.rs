@@ -4,6 +4,7 @@
 //! - Receive operations with for-comprehension
 //! - Parallel composition
 //! - Combined channel and collection operations
+//! - System channels bound by URI
 
 mod common;
 
@@ -296,3 +297,257 @@ fn test_new_unused_channel() {
     let result = compile_and_run(source).unwrap();
     assert_eq!(result, Value::Int(42));
 }
+
+#[test]
+fn test_send_unit_is_not_nil() {
+    let source = r#"
+        new ch in {
+            ch!(()) |
+            for (u <- ch) { [u, u == (), u == Nil] }
+        }
+    "#;
+    let result = compile_and_run(source).unwrap();
+    assert_eq!(
+        result,
+        Value::List(vec![
+            Value::Tuple(vec![]),
+            Value::Bool(true),
+            Value::Bool(false)
+        ])
+    );
+}
+
+// === System Channels ===
+
+#[test]
+fn test_stdout_uri_prints() {
+    let source = r#"new out(`rho:io:stdout`) in { out!("hi") }"#;
+    let (_, output) = compile_and_capture_stdout(source).unwrap();
+    assert_eq!(output, "hi\n");
+}
+
+#[test]
+fn test_stdout_uri_prints_values() {
+    let source = r#"new out(`rho:io:stdout`) in { out!(1 + 2) | out!([true]) }"#;
+    let (_, output) = compile_and_capture_stdout(source).unwrap();
+    assert_eq!(output, "3\n[true]\n");
+}
+
+#[test]
+fn test_unknown_uri_is_a_fresh_channel() {
+    let source = r#"
+        new ch(`rho:io:mailbox`) in {
+            ch!(7) |
+            for (x <- ch) { x }
+        }
+    "#;
+    let (result, output) = compile_and_capture_stdout(source).unwrap();
+    assert_eq!(result, Value::Int(7));
+    assert!(output.is_empty());
+}
+
+#[test]
+fn test_quoted_uri_is_not_the_system_channel() {
+    let source = r#"
+        @"rho:io:stdout"!(7) |
+        for (@v <- @"rho:io:stdout") { v }
+    "#;
+    let (result, output) = compile_and_capture_stdout(source).unwrap();
+    assert_eq!(result, Value::Int(7));
+    assert!(output.is_empty());
+}
+
+#[test]
+fn test_quoted_string_is_a_public_channel() {
+    let source = r#"
+        @"c"!(7) |
+        for (@v <- @"c") { v + 1 }
+    "#;
+    let result = compile_and_run(source).unwrap();
+    assert_eq!(result, Value::Int(8));
+}
+
+#[test]
+fn test_quoted_string_cannot_forge_a_new_name() {
+    // the receive must block instead of taking 99
+    let source = r#"@"@3:1"!(99) | @"@3:2"!(99) | new y in { for (a <- y) { a } }"#;
+    let err = compile_and_run(source).unwrap_err();
+    assert!(err.to_string().contains("blocked"), "{err}");
+}
+
+#[test]
+fn test_new_names_are_unique_across_runs() {
+    let source = r#"new out(`rho:io:stdout`), x in { out!(x) }"#;
+    let (_, first) = compile_and_capture_stdout(source).unwrap();
+    let (_, second) = compile_and_capture_stdout(source).unwrap();
+    assert_ne!(first, second);
+}
+
+// === Contract Tests ===
+
+#[test]
+fn test_contract_binds_remainder() {
+    let source = r#"
+        new f in {
+            contract f(x ...@rest) = { rest } |
+            f!(1, 2, 3)
+        }
+    "#;
+    let result = compile_and_run(source).unwrap();
+    assert_eq!(result, Value::List(vec![Value::Int(2), Value::Int(3)]));
+}
+
+#[test]
+fn test_contract_binds_positional_formals() {
+    let source = r#"
+        new add in {
+            add!(1, 2) |
+            contract add(x, y) = { x + y }
+        }
+    "#;
+    let result = compile_and_run(source).unwrap();
+    assert_eq!(result, Value::Int(3));
+}
+
+#[test]
+fn test_contract_empty_remainder() {
+    let source = r#"
+        new f in {
+            contract f(x ...@rest) = { [x, rest] } |
+            f!(1)
+        }
+    "#;
+    let result = compile_and_run(source).unwrap();
+    assert_eq!(
+        result,
+        Value::List(vec![Value::Int(1), Value::List(vec![])])
+    );
+}
+
+#[test]
+fn test_contract_too_few_arguments_is_error() {
+    let source = r#"
+        new f in {
+            contract f(x, y ...@rest) = { rest } |
+            f!(1)
+        }
+    "#;
+    let err = compile_and_run(source).unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("expects at least 2 argument(s), got 1"),
+        "unexpected error: {err}"
+    );
+}
+
+#[test]
+fn test_contract_list_argument_is_one_argument() {
+    let source = r#"
+        new f in {
+            contract f(x ...@rest) = { [x, rest] } |
+            f!([1, 2])
+        }
+    "#;
+    let result = compile_and_run(source).unwrap();
+    assert_eq!(
+        result,
+        Value::List(vec![
+            Value::List(vec![Value::Int(1), Value::Int(2)]),
+            Value::List(vec![])
+        ])
+    );
+}
+
+#[test]
+fn test_contract_tuple_argument_is_one_argument() {
+    let source = r#"
+        new f in {
+            contract f(x) = { x } |
+            f!((1, 2))
+        }
+    "#;
+    let result = compile_and_run(source).unwrap();
+    assert_eq!(result, Value::Tuple(vec![Value::Int(1), Value::Int(2)]));
+}
+
+#[test]
+fn test_contract_arity_mismatch_is_error() {
+    for (contract, expected) in [
+        (
+            "contract f(x, y) = { x } | f!([1, 2])",
+            "expects 2 argument(s), got 1",
+        ),
+        (
+            "contract f(x) = { x } | f!(1, 2)",
+            "expects 1 argument(s), got 2",
+        ),
+        (
+            "contract f(x) = { x } | f!()",
+            "expects 1 argument(s), got 0",
+        ),
+    ] {
+        let source = format!("new f in {{ {contract} }}");
+        let err = compile_and_run(&source).unwrap_err();
+        assert!(
+            err.to_string().contains(expected),
+            "unexpected error: {err}"
+        );
+    }
+}
+
+#[test]
+fn test_contract_serves_every_message() {
+    let source = r#"
+        new out(`rho:io:stdout`), f in {
+            f!(1) | f!(2) | f!(3) |
+            contract f(x) = { out!(x) }
+        }
+    "#;
+    let (_, output) = compile_and_capture_stdout(source).unwrap();
+    assert_eq!(output, "1\n2\n3\n");
+}
+
+#[test]
+fn test_contract_serves_messages_sent_by_contracts() {
+    let source = r#"
+        new out(`rho:io:stdout`), count, report in {
+            count!(3) |
+            contract count(n) = {
+                if (n > 0) { count!(n - 1) } else { report!("done") }
+            } |
+            contract report(msg) = { out!(msg) }
+        }
+    "#;
+    let (_, output) = compile_and_capture_stdout(source).unwrap();
+    assert_eq!(output, "done\n");
+}
+
+#[test]
+fn test_receive_binds_several_names() {
+    let source = r#"
+        new ch in {
+            ch!(1, [2, 3]) |
+            for (x, y <- ch) { (x, y) }
+        }
+    "#;
+    let result = compile_and_run(source).unwrap();
+    assert_eq!(
+        result,
+        Value::Tuple(vec![
+            Value::Int(1),
+            Value::List(vec![Value::Int(2), Value::Int(3)])
+        ])
+    );
+}
+
+#[test]
+fn test_receive_of_a_tuple_message() {
+    let source = r#"
+        new ch in {
+            ch!((1, 2)) |
+            for (x <- ch) { x }
+        }
+    "#;
+    let result = compile_and_run(source).unwrap();
+    assert_eq!(result, Value::Tuple(vec![Value::Int(1), Value::Int(2)]));
+}
@@ -3,6 +3,8 @@
 //! - Tuple creation
 //! - Nested collections
 //! - Empty collections
+//! - Set creation and equality
+//! - PathMap subtract, restrict and drop
 
 mod common;
 
@@ -214,3 +216,79 @@ fn test_tuple_with_comparisons() {
         ])
     );
 }
+
+// === Set Tests ===
+
+#[test]
+fn test_set_drops_duplicates() {
+    let result = compile_and_run("Set(1, 1, 2)").unwrap();
+    assert_eq!(result, Value::Set(vec![Value::Int(1), Value::Int(2)]));
+}
+
+#[test]
+fn test_empty_set() {
+    let result = compile_and_run("Set()").unwrap();
+    assert_eq!(result, Value::Set(vec![]));
+}
+
+#[test]
+fn test_set_equality_ignores_insertion_order() {
+    let result = compile_and_run("Set(1, 2, 3) == Set(3, 1, 2, 1)").unwrap();
+    assert_eq!(result, Value::Bool(true));
+
+    let result = compile_and_run("Set(1, 2) == [1, 2]").unwrap();
+    assert_eq!(result, Value::Bool(false));
+}
+
+// === PathMap Tests ===
+
+fn str(s: &str) -> Value {
+    Value::Str(s.to_string())
+}
+
+fn path(segments: &[&str]) -> Value {
+    Value::List(segments.iter().map(|s| str(s)).collect())
+}
+
+#[test]
+fn test_pathmap_literal() {
+    let source = r#"{| "a", ["b", "c"], "a" |}"#;
+    let result = compile_and_run(source).unwrap();
+    assert_eq!(result, Value::PathMap(vec![str("a"), path(&["b", "c"])]));
+}
+
+#[test]
+fn test_pathmap_subtract() {
+    let source = r#"{| "a", ["b", "c"], "d" |} - {| ["b", "c"], "x" |}"#;
+    let result = compile_and_run(source).unwrap();
+    assert_eq!(result, Value::PathMap(vec![str("a"), str("d")]));
+}
+
+#[test]
+fn test_pathmap_subtract_method() {
+    let source = r#"{| "a", "b" |}.subtract({| "a" |})"#;
+    let result = compile_and_run(source).unwrap();
+    assert_eq!(result, Value::PathMap(vec![str("b")]));
+}
+
+#[test]
+fn test_pathmap_restrict() {
+    let source = r#"{| ["a", "x"], ["a", "y"], ["b", "z"], "c" |}.restrict({| "a", "c" |})"#;
+    let result = compile_and_run(source).unwrap();
+    assert_eq!(
+        result,
+        Value::PathMap(vec![path(&["a", "x"]), path(&["a", "y"]), str("c")])
+    );
+}
+
+#[test]
+fn test_pathmap_drop() {
+    let source = r#"{| ["a", "x", "1"], ["b", "x", "1"], ["a", "y"], "c" |}.drop(1)"#;
+    let result = compile_and_run(source).unwrap();
+    assert_eq!(result, Value::PathMap(vec![path(&["x", "1"]), str("y")]));
+}
+
+#[test]
+fn test_pathmap_drop_negative_fails() {
+    assert!(compile_and_run(r#"{| "a" |}.drop(-1)"#).is_err());
+}
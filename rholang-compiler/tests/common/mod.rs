@@ -3,9 +3,10 @@ use librho::sem::{
     pipeline::Pipeline, DiagnosticKind, EnclosureAnalysisPass, ErrorKind, ForCompElaborationPass,
     ResolverPass, SemanticDb,
 };
-use rholang_compiler::Compiler;
+use rholang_compiler::{Compiler, Process};
 use rholang_parser::parser::RholangParser;
 use rholang_vm::api::Value;
+use std::sync::{Arc, Mutex};
 use validated::Validated;
 
 /// Compile and run a Rholang source string, returning the final result
@@ -22,6 +23,38 @@ use validated::Validated;
 /// Returns an error if parsing, semantic analysis, compilation, or execution fails.
 #[allow(dead_code)]
 pub fn compile_and_run(source: &str) -> Result<Value> {
+    let mut processes = compile(source)?;
+
+    // Execute (VM is already embedded in Process)
+    let result = processes[0].execute()?;
+
+    Ok(result)
+}
+
+/// Compile and run a Rholang source string, capturing everything sent on `rho:io:stdout`
+///
+/// # Errors
+///
+/// Returns an error if parsing, semantic analysis, compilation, or execution fails.
+#[allow(dead_code)]
+pub fn compile_and_capture_stdout(source: &str) -> Result<(Value, String)> {
+    let mut processes = compile(source)?;
+
+    let stdout = Arc::new(Mutex::new(Vec::<u8>::new()));
+    let process = &mut processes[0];
+    process.vm = process.vm.clone().with_stdout(stdout.clone());
+    let result = process.execute()?;
+
+    let output = String::from_utf8(stdout.lock().unwrap().clone())?;
+    Ok((result, output))
+}
+
+/// Parse, analyze and compile a Rholang source string
+///
+/// # Errors
+///
+/// Returns an error if parsing, semantic analysis or compilation fails.
+fn compile(source: &str) -> Result<Vec<Process>> {
     // Parse
     let parser = RholangParser::new();
     let ast = match parser.parse(source) {
@@ -31,9 +64,9 @@ pub fn compile_and_run(source: &str) -> Result<Value> {
         }
     };
 
-    // For simplicity, we assume there's at least one process
+    // An empty program has nothing to analyze; it compiles to a lone `Nil`
     if ast.is_empty() {
-        return Err(anyhow::anyhow!("Empty AST"));
+        return Compiler::new(&SemanticDb::new()).compile(&ast);
     }
 
     // Semantic analysis - build index for first process
@@ -66,10 +99,5 @@ pub fn compile_and_run(source: &str) -> Result<Value> {
 
     // Compile
     let compiler = Compiler::new(&db);
-    let mut processes = compiler.compile(&ast)?;
-
-    // Execute (VM is already embedded in Process)
-    let result = processes[0].execute()?;
-
-    Ok(result)
+    compiler.compile(&ast)
 }
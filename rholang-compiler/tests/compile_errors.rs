@@ -0,0 +1,107 @@
+//! Tests for:
+//! - Collecting the compilation errors of several top-level processes at once
+//! - Source spans on unsupported-feature errors
+//! - Rejecting implicit evals when they are turned off
+
+use librho::sem::{
+    pipeline::Pipeline, EnclosureAnalysisPass, ForCompElaborationPass, ResolverPass, SemanticDb,
+};
+use rholang_compiler::{CompileError, Compiler};
+use rholang_parser::parser::RholangParser;
+use validated::Validated;
+
+#[test]
+fn test_compile_validated_collects_every_error() {
+    let source = "Set(1, 2 ...rest)\n1 + 2\n[1, 2 ...rest]";
+    let parser = RholangParser::new();
+    let Validated::Good(ast) = parser.parse(source) else {
+        panic!("source should parse");
+    };
+    let db = SemanticDb::new();
+    let compiler = Compiler::new(&db);
+
+    let Validated::Fail(errors) = compiler.compile_validated(&ast) else {
+        panic!("two of the processes should fail to compile");
+    };
+    let errors: Vec<CompileError> = errors.into_iter().collect();
+
+    assert_eq!(
+        errors,
+        vec![
+            CompileError::Unsupported {
+                feature: "Set remainder".into(),
+                span: ast[0].span,
+            },
+            CompileError::Unsupported {
+                feature: "List remainder".into(),
+                span: ast[2].span,
+            },
+        ]
+    );
+    assert_eq!(errors[1].span().start.line, 3);
+}
+
+#[test]
+fn test_compile_validated_good() {
+    let parser = RholangParser::new();
+    let Validated::Good(ast) = parser.parse("1 + 2\n\"done\"") else {
+        panic!("source should parse");
+    };
+    let db = SemanticDb::new();
+    let compiler = Compiler::new(&db);
+
+    match compiler.compile_validated(&ast) {
+        Validated::Good(processes) => assert_eq!(processes.len(), 2),
+        Validated::Fail(errors) => panic!("unexpected errors: {:?}", errors),
+    }
+}
+
+#[test]
+fn test_unsupported_error_spans_innermost_process() {
+    let source = "1 + 2 matches [_, {1: _}]";
+    let parser = RholangParser::new();
+    let Validated::Good(ast) = parser.parse(source) else {
+        panic!("source should parse");
+    };
+    let db = SemanticDb::new();
+    let compiler = Compiler::new(&db);
+
+    let Validated::Fail(errors) = compiler.compile_validated(&ast) else {
+        panic!("map pattern in 'matches' should fail to compile");
+    };
+    let span = errors.first().span();
+    assert!(matches!(errors.first(), CompileError::Unsupported { .. }));
+    assert_eq!(&source[span.start.col - 1..span.end.col - 1], "{1: _}");
+}
+
+#[test]
+fn test_implicit_eval_can_be_disallowed() {
+    let source = "new c in { for(x <- c) { x } }";
+    let parser = RholangParser::new();
+    let Validated::Good(ast) = parser.parse(source) else {
+        panic!("source should parse");
+    };
+    let mut db = SemanticDb::new();
+    let root = db.build_index(&ast[0]);
+    let pipeline = Pipeline::new()
+        .add_fact(ResolverPass::new(root))
+        .add_fact(ForCompElaborationPass::new(root))
+        .add_fact(EnclosureAnalysisPass::new(root));
+    tokio::runtime::Runtime::new()
+        .unwrap()
+        .block_on(pipeline.run(&mut db));
+
+    assert!(Compiler::new(&db).compile(&ast).is_ok());
+
+    let err = Compiler::new(&db)
+        .implicit_eval(false)
+        .compile(&ast)
+        .expect_err("implicit eval should be rejected")
+        .downcast::<CompileError>()
+        .unwrap();
+    let CompileError::ImplicitEvalDisallowed { span } = err else {
+        panic!("unexpected error: {err}");
+    };
+    assert_eq!(&source[span.start.col - 1..span.end.col - 1], "x");
+    assert_eq!(span.start.col, 26);
+}
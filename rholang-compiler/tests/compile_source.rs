@@ -0,0 +1,110 @@
+//! Tests for:
+//! - The blocking `compile_source` entry point
+//! - Analysis of every top-level process, not just the first
+//! - Agreement between `compile_source` and `compile_source_async`
+//! - Compile budgets of `compile_source_async_bounded`
+//! - Empty programs compiling to a single `Nil` process
+
+use librho::sem::SemanticDb;
+use rholang_compiler::{
+    compile_ast, compile_first_process_async, compile_source, compile_source_async,
+    compile_source_async_bounded, CompileBudget, CompileError, Compiler, Process,
+};
+use rholang_vm::api::Value;
+
+const SOURCE: &str = "new x in { x!(1) }";
+
+#[test]
+fn test_compile_source_single_process() {
+    let processes = compile_source(SOURCE).unwrap();
+    assert_eq!(processes.len(), 1);
+}
+
+#[test]
+fn test_compile_source_analyzes_every_process() {
+    let source = "new x in { x!(1) }\nnew y in { y!(2) | for (z <- y) { z } }";
+    let mut processes = compile_source(source).unwrap();
+    assert_eq!(processes.len(), 2);
+    assert_eq!(processes[1].execute().unwrap(), Value::Int(2));
+}
+
+#[test]
+fn test_compile_source_matches_async() {
+    let sync = compile_source(SOURCE).unwrap();
+    let async_ = tokio::runtime::Runtime::new()
+        .unwrap()
+        .block_on(compile_source_async(SOURCE))
+        .unwrap();
+    assert_eq!(sync, async_);
+}
+
+#[tokio::test]
+async fn test_compile_source_inside_runtime() {
+    let processes = compile_source(SOURCE).unwrap();
+    assert_eq!(processes.len(), 1);
+}
+
+#[tokio::test]
+async fn test_compile_source_bounded_stops_at_instruction_budget() {
+    let source = format!("[{}]", vec!["1 + 2"; 10_000].join(", "));
+    let budget = CompileBudget {
+        max_instructions: 16,
+        ..CompileBudget::UNLIMITED
+    };
+    let err = compile_source_async_bounded(&source, budget)
+        .await
+        .unwrap_err();
+    let Ok(CompileError::BudgetExceeded { span }) = err.downcast::<CompileError>() else {
+        panic!("expected the budget to be exceeded");
+    };
+    // gave up within the first few elements of the list
+    assert!(span.start.col < 80, "{span}");
+}
+
+#[tokio::test]
+async fn test_compile_source_bounded_node_budget() {
+    let tight = CompileBudget {
+        max_nodes: 2,
+        ..CompileBudget::UNLIMITED
+    };
+    let err = compile_source_async_bounded("1 + 2", tight)
+        .await
+        .unwrap_err();
+    assert!(matches!(
+        err.downcast_ref::<CompileError>(),
+        Some(CompileError::BudgetExceeded { .. })
+    ));
+
+    let enough = CompileBudget {
+        max_nodes: 3,
+        ..CompileBudget::UNLIMITED
+    };
+    let bounded = compile_source_async_bounded("1 + 2", enough).await.unwrap();
+    assert_eq!(bounded, compile_source("1 + 2").unwrap());
+}
+
+fn run_alone(processes: Vec<Process>) -> Value {
+    assert_eq!(processes.len(), 1);
+    processes.into_iter().next().unwrap().execute().unwrap()
+}
+
+#[tokio::test]
+async fn test_empty_programs_yield_nil() {
+    for source in ["", "   "] {
+        assert_eq!(run_alone(compile_source(source).unwrap()), Value::Nil);
+        assert_eq!(
+            run_alone(compile_source_async(source).await.unwrap()),
+            Value::Nil
+        );
+        let mut first = compile_first_process_async(source).await.unwrap();
+        assert_eq!(first.execute().unwrap(), Value::Nil);
+    }
+
+    assert_eq!(run_alone(compile_ast(&[]).unwrap()), Value::Nil);
+
+    let db = SemanticDb::new();
+    let compiler = Compiler::new(&db);
+    assert_eq!(run_alone(compiler.compile(&[]).unwrap()), Value::Nil);
+    let validated = compiler.compile_validated(&[]).ok().unwrap();
+    assert_eq!(run_alone(validated), Value::Nil);
+}
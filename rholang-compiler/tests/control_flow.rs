@@ -41,6 +41,13 @@ fn test_if_no_else_false() {
     assert_eq!(result, Value::Nil);
 }
 
+#[test]
+fn test_if_no_else_false_inside_collection() {
+    let source = "[if (false) { 1 }, 2]";
+    let result = compile_and_run(source).unwrap();
+    assert_eq!(result, Value::List(vec![Value::Nil, Value::Int(2)]));
+}
+
 // === If with Comparisons ===
 
 #[test]
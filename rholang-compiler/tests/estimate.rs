@@ -0,0 +1,169 @@
+//! Tests for:
+//! - `estimate_instructions` bounding the size of the compiled bytecode
+
+use std::collections::BTreeSet;
+
+use rholang_compiler::{compile_source, estimate_instructions};
+use rholang_parser::ast::Proc;
+use rholang_parser::RholangParser;
+
+/// One program per process variant, each compiled on its own. Programs that
+/// the compiler rejects still count towards variant coverage, so supporting
+/// a new variant in codegen puts it under the bound check without a new fixture
+const FIXTURES: &[&str] = &[
+    "Nil",
+    "()",
+    "true",
+    "100000",
+    "-52i64",
+    "170141183460469231731687303715884105727i128",
+    "255u8",
+    "18446744073709551615u64",
+    "10n",
+    "3r",
+    "3.14f64",
+    "3.3p1",
+    r#""text""#,
+    "`rho:io:stdout`",
+    "1 matches Int",
+    "[1, 2] matches [_, _]",
+    r#"[[1], (2, 3), {4: 5}, Set(6), {| "a", ["b", "c"] |}]"#,
+    r#"{| "a", "b" |} - {| "a" |}"#,
+    r#"{| "a", ["b", "c"] |}.drop(1)"#,
+    "new x in { x!(1) | for (y <- x) { y } }",
+    "1 | 2 | 3",
+    "if (1 < 2) { 3 }",
+    "new x, y in { x!() | x!(1) | y!(1, 2, 3) | for (a <- x; b, c <- y) { [a, b, c] } }",
+    "match 1 { 1 => 2 _ => 3 }",
+    "new x in { bundle+ { *x } }",
+    "let x = 1; y = 2 in { [x, y] }",
+    "let x <- 1 & y, ...@rest <- 2, 3 in { [x, y, rest] }",
+    "new out(`rho:io:stdout`), x in { out!(1) | x!(2) }",
+    "new f in { contract f(x, _ ...@rest) = { rest } | contract f() = { Nil } | f!(1, 2, 3) }",
+    "new x in { x!?(1). }",
+    "new x in { *x }",
+    "not true",
+    "-(1 + 2)",
+    "(1 - 2) * 3 % 4 / 5 >= 6 or 7 != 8",
+    r#""${a}" %% {"a": 1}"#,
+    "new x in { for (y <- x) { match 1 { =y => Nil } } }",
+];
+
+/// The name of a process variant; matching exhaustively makes a new variant
+/// fail to build until it is covered by a fixture
+fn variant(proc: &Proc) -> &'static str {
+    match proc {
+        Proc::Nil => "Nil",
+        Proc::Unit => "Unit",
+        Proc::BoolLiteral(_) => "BoolLiteral",
+        Proc::LongLiteral(_) => "LongLiteral",
+        Proc::SignedIntLiteral { .. } => "SignedIntLiteral",
+        Proc::UnsignedIntLiteral { .. } => "UnsignedIntLiteral",
+        Proc::BigIntLiteral(_) => "BigIntLiteral",
+        Proc::BigRatLiteral(_) => "BigRatLiteral",
+        Proc::FloatLiteral { .. } => "FloatLiteral",
+        Proc::FixedPointLiteral { .. } => "FixedPointLiteral",
+        Proc::StringLiteral(_) => "StringLiteral",
+        Proc::UriLiteral(_) => "UriLiteral",
+        Proc::SimpleType(_) => "SimpleType",
+        Proc::Collection(_) => "Collection",
+        Proc::ProcVar(_) => "ProcVar",
+        Proc::Par { .. } => "Par",
+        Proc::IfThenElse { .. } => "IfThenElse",
+        Proc::Send { .. } => "Send",
+        Proc::ForComprehension { .. } => "ForComprehension",
+        Proc::Match { .. } => "Match",
+        Proc::Select { .. } => "Select",
+        Proc::Bundle { .. } => "Bundle",
+        Proc::Let { .. } => "Let",
+        Proc::New { .. } => "New",
+        Proc::Contract { .. } => "Contract",
+        Proc::SendSync { .. } => "SendSync",
+        Proc::Eval { .. } => "Eval",
+        Proc::Method { .. } => "Method",
+        Proc::UnaryExp { .. } => "UnaryExp",
+        Proc::BinaryExp { .. } => "BinaryExp",
+        Proc::VarRef { .. } => "VarRef",
+        Proc::Bad => "Bad",
+    }
+}
+
+/// Assert that the estimate for every top-level process of `source` is at
+/// least the number of instructions it compiles to
+fn assert_estimate_bounds(source: &str) {
+    let parser = RholangParser::new();
+    let procs = parser.parse(source).unwrap();
+    let processes = compile_source(source).unwrap();
+    assert_eq!(procs.len(), processes.len());
+
+    for (proc, process) in procs.iter().zip(&processes) {
+        let estimate = estimate_instructions(proc);
+        assert!(
+            estimate >= process.code.len(),
+            "estimate {estimate} below actual {} for {source}",
+            process.code.len()
+        );
+    }
+}
+
+#[test]
+fn test_estimate_literals_and_expressions() {
+    assert_estimate_bounds("42");
+    assert_estimate_bounds("(1 + 2) * 3 - 100000");
+    assert_estimate_bounds(r#""a" == "b" and true"#);
+    assert_estimate_bounds("[1, (2, 3), {4: 5}]");
+}
+
+#[test]
+fn test_estimate_control_flow() {
+    assert_estimate_bounds("if (1 < 2) { 3 } else { 4 }");
+    assert_estimate_bounds("[if (false) { 1 }, 2]");
+}
+
+#[test]
+fn test_estimate_channels() {
+    assert_estimate_bounds("new x, y in { x!(1, 2) | y!(3) | for (a <- x; b <- y) { [a, b] } }");
+    assert_estimate_bounds("new f in { contract f(x, _ ...@rest) = { rest } | f!(1, 2, 3) }");
+}
+
+#[test]
+fn test_estimate_let() {
+    assert_estimate_bounds("let x = 1; y = 2; z = 3; w = 4 in { w }");
+    assert_estimate_bounds("let x, ...@rest <- 1, 2, 3 & y <- 4 in { [x, rest, y] }");
+}
+
+#[test]
+fn test_estimate_bounds_every_process_variant() {
+    let mut covered = BTreeSet::new();
+
+    for source in FIXTURES {
+        let parser = RholangParser::new();
+        let procs = parser.parse(source).unwrap();
+        covered.extend(
+            procs
+                .iter()
+                .flat_map(|proc| proc.iter_preorder_dfs())
+                .map(|node| variant(node.proc)),
+        );
+        if compile_source(source).is_ok() {
+            assert_estimate_bounds(source);
+        }
+    }
+
+    // `Bad` only comes out of a failed parse, and the parser does not build `Select` yet
+    covered.extend(["Bad", "Select"]);
+    // one entry per arm of `variant`
+    assert_eq!(
+        covered.len(),
+        32,
+        "uncovered variants, covered: {covered:?}"
+    );
+}
+
+#[test]
+fn test_estimate_grows_with_program_size() {
+    let parser = RholangParser::new();
+    let small = parser.parse("[1]").unwrap();
+    let large = parser.parse("[1, 2, 3, 4, 5, 6, 7, 8]").unwrap();
+    assert!(estimate_instructions(&large[0]) > estimate_instructions(&small[0]));
+}
@@ -5,6 +5,8 @@
 //! - Operator precedence
 //! - Mixed type expressions
 //! - Nested expressions
+//! - String interpolation (%%)
+//! - Runtime type and pattern tests (matches)
 
 mod common;
 
@@ -398,6 +400,131 @@ fn test_complex_comparison() {
     assert_eq!(result, Value::Bool(true)); // 5 > 3
 }
 
+// === String Interpolation ===
+
+#[test]
+fn test_interpolation_of_expression() {
+    let source = r#""sum=${s}" %% {"s": 1 + 2}"#;
+    let result = compile_and_run(source).unwrap();
+    assert_eq!(result, Value::Str("sum=3".to_string()));
+}
+
+#[test]
+fn test_interpolation_renders_values() {
+    let source = r#""${name} has ${items} and ${nothing}" %% {"name": "x", "items": [1, true], "nothing": Nil}"#;
+    let result = compile_and_run(source).unwrap();
+    assert_eq!(result, Value::Str("x has [1, true] and Nil".to_string()));
+}
+
+#[test]
+fn test_interpolation_keeps_unbound_and_escaped_placeholders() {
+    let source = r#""${a} ${missing} $${a} ${unclosed" %% {"a": 1}"#;
+    let result = compile_and_run(source).unwrap();
+    assert_eq!(
+        result,
+        Value::Str("1 ${missing} ${a} ${unclosed".to_string())
+    );
+}
+
+#[test]
+fn test_nested_interpolation() {
+    let source = r#"("${outer}" %% {"outer": "<${inner}>"}) %% {"inner": 42}"#;
+    let result = compile_and_run(source).unwrap();
+    assert_eq!(result, Value::Str("<42>".to_string()));
+}
+
+#[test]
+fn test_interpolation_requires_string_and_map() {
+    assert!(compile_and_run(r#"Nil %% {"a": 1}"#).is_err());
+    assert!(compile_and_run(r#""${a}" %% Nil"#).is_err());
+}
+
+// === Runtime Type Tests ===
+
+#[test]
+fn test_int_matches_int() {
+    let result = compile_and_run("42 matches Int").unwrap();
+    assert_eq!(result, Value::Bool(true));
+}
+
+#[test]
+fn test_string_does_not_match_int() {
+    let result = compile_and_run(r#""s" matches Int"#).unwrap();
+    assert_eq!(result, Value::Bool(false));
+}
+
+#[test]
+fn test_list_does_not_match_string() {
+    let result = compile_and_run("[1] matches String").unwrap();
+    assert_eq!(result, Value::Bool(false));
+}
+
+#[test]
+fn test_matches_simple_types() {
+    let cases = [
+        ("true matches Bool", true),
+        ("1 + 2 matches Int", true),
+        (r#""s" matches String"#, true),
+        ("Nil matches Bool", false),
+        ("42 matches ByteArray", false),
+    ];
+    for (source, expected) in cases {
+        let result = compile_and_run(source).unwrap();
+        assert_eq!(result, Value::Bool(expected), "{source}");
+    }
+}
+
+#[test]
+fn test_matches_structural_patterns() {
+    let cases = [
+        ("[1, 2] matches [_, 2]", true),
+        ("[1, 3] matches [_, 2]", false),
+        ("42 matches _", true),
+        ("[1, 2, 3] matches [1 ...rest]", true),
+        ("[] matches [1 ...rest]", false),
+        ("[1, 2] matches [x]", false),
+        (r#"(1, "a") matches (Int, String)"#, true),
+        ("(1, 2) matches [1, 2]", false),
+        ("[Nil, true] matches [Nil, Bool]", true),
+    ];
+    for (source, expected) in cases {
+        let result = compile_and_run(source).unwrap();
+        assert_eq!(result, Value::Bool(expected), "{source}");
+    }
+}
+
+#[test]
+fn test_matches_does_not_bind() {
+    let result = compile_and_run("[1, 2] matches [y, 2]").unwrap();
+    assert_eq!(result, Value::Bool(true));
+    // the variable of the pattern is not in scope next to the expression
+    let err = compile_and_run("([1, 2] matches [y, 2]) and y == 1").unwrap_err();
+    assert!(err.to_string().contains("UnboundVariable"), "{err}");
+}
+
+#[test]
+fn test_matches_map_pattern_is_unsupported() {
+    assert!(compile_and_run("42 matches {1: _}").is_err());
+}
+
+// === Unit and Nil ===
+
+#[test]
+fn test_unit_is_distinct_from_nil() {
+    let cases = [
+        ("Nil == ()", false),
+        ("() == Nil", false),
+        ("() == ()", true),
+        ("() != Nil", true),
+    ];
+    for (source, expected) in cases {
+        let result = compile_and_run(source).unwrap();
+        assert_eq!(result, Value::Bool(expected), "{source}");
+    }
+    assert_eq!(compile_and_run("()").unwrap(), Value::Tuple(vec![]));
+    assert_eq!(compile_and_run("()").unwrap().to_string(), "()");
+}
+
 // === Edge Cases ===
 
 #[test]
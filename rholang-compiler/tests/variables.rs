@@ -1,6 +1,7 @@
 //! Tests for:
 //! - Variable binding through new declarations
 //! - Variable binding through for-comprehension
+//! - Variable binding through let
 //! - Variable references and scoping
 //! - Multiple variables
 //! - Nested scopes
@@ -61,6 +62,72 @@ fn test_variable_in_expression() {
     assert_eq!(result, Value::Int(13)); // 5 * 2 + 3
 }
 
+// === Variable Binding via let ===
+
+#[test]
+fn test_let() {
+    assert_eq!(
+        compile_and_run("let x = 1 in x + 1").unwrap(),
+        Value::Int(2)
+    );
+    assert_eq!(
+        compile_and_run("let x <- 1 in { x + 1 }").unwrap(),
+        Value::Int(2)
+    );
+}
+
+#[test]
+fn test_let_with_several_names() {
+    let source = "let x, y <- 1, 2 in { x * 10 + y }";
+    assert_eq!(compile_and_run(source).unwrap(), Value::Int(12));
+
+    let source = "let x, ...@rest <- 1, 2, 3 in { (x, rest) }";
+    assert_eq!(
+        compile_and_run(source).unwrap(),
+        Value::Tuple(vec![
+            Value::Int(1),
+            Value::List(vec![Value::Int(2), Value::Int(3)])
+        ])
+    );
+
+    // a single value is not spread over the names, even if it is a list
+    let source = "let x, ...@rest <- [1, 2] in { (x, rest) }";
+    assert_eq!(
+        compile_and_run(source).unwrap(),
+        Value::Tuple(vec![
+            Value::List(vec![Value::Int(1), Value::Int(2)]),
+            Value::List(vec![])
+        ])
+    );
+}
+
+#[test]
+fn test_let_binds_tuples_and_lists_whole() {
+    let source = "let x, y <- (1, 2), [3, 4] in { [x, y] }";
+    assert_eq!(
+        compile_and_run(source).unwrap(),
+        Value::List(vec![
+            Value::Tuple(vec![Value::Int(1), Value::Int(2)]),
+            Value::List(vec![Value::Int(3), Value::Int(4)])
+        ])
+    );
+}
+
+#[test]
+fn test_sequential_let_sees_earlier_bindings() {
+    let source = "let x <- 10 in { let x <- 1; y <- x + 1 in { y } }";
+    assert_eq!(compile_and_run(source).unwrap(), Value::Int(2));
+}
+
+#[test]
+fn test_concurrent_let_does_not_see_siblings() {
+    let source = "let x <- 10 in { let x <- 1 & y <- x + 1 in { (x, y) } }";
+    assert_eq!(
+        compile_and_run(source).unwrap(),
+        Value::Tuple(vec![Value::Int(1), Value::Int(11)])
+    );
+}
+
 // === Multiple Variables ===
 
 #[test]
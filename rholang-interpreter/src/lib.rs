@@ -3,10 +3,16 @@ use anyhow::Result;
 
 use std::collections::HashMap;
 use std::fmt;
-use std::sync::{Arc, Mutex};
+use std::future::Future;
+use std::sync::{Arc, Mutex, PoisonError};
 
-use rholang_compiler::compile_source_async;
-use rholang_vm::api::Value as VmValue;
+use rholang_compiler::{compile_ast, compile_source_async, Process};
+use rholang_parser::ast::AnnProc;
+use rholang_parser::unparse;
+use rholang_vm::api::{
+    InMemoryRSpace, NameRenderStyle, ProcessHolder, RSpace, SharedRSpace, Value as VmValue, VM,
+};
+use rholang_vm::ExecError;
 
 #[cfg(feature = "native-runtime")]
 use tokio::sync::oneshot;
@@ -52,6 +58,13 @@ impl InterpretationResult {
 #[async_trait::async_trait(?Send)]
 pub trait InterpreterProvider {
     async fn interpret(&self, code: &str) -> InterpretationResult;
+    /// Like [`interpret`](InterpreterProvider::interpret), but for top-level processes
+    /// that are already parsed. By default they are pretty-printed back to source and
+    /// handed to `interpret`
+    async fn interpret_ast<'a>(&self, procs: &'a [AnnProc<'a>]) -> InterpretationResult {
+        let code = procs.iter().map(unparse).collect::<Vec<_>>().join("\n");
+        self.interpret(&code).await
+    }
     fn list_processes(&self) -> Result<Vec<(usize, String)>>;
     fn kill_process(&self, _pid: usize) -> Result<bool>;
     fn kill_all_processes(&self) -> Result<usize>;
@@ -63,21 +76,372 @@ struct ProcessInfo {
     cancel: Option<tokio::sync::oneshot::Sender<()>>,
 }
 
-#[derive(Clone, Default)]
+/// Removes a pid from the process map when dropped, so that an interpretation which
+/// panics or whose future is dropped early does not stay registered
+struct ProcessRegistration<'a> {
+    processes: &'a Mutex<HashMap<usize, ProcessInfo>>,
+    pid: usize,
+}
+
+impl Drop for ProcessRegistration<'_> {
+    fn drop(&mut self) {
+        self.processes
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .remove(&self.pid);
+    }
+}
+
+/// How a batch of top-level processes reacts to a runtime error in one of them
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ExecutionMode {
+    /// Stop at the first failing process and report only its error
+    #[default]
+    FailFast,
+    /// Run every process and report each result or error on its own line
+    Independent,
+}
+
+/// What running one top-level process produced
+type Outcome = std::result::Result<VmValue, ExecError>;
+
+/// Default size limit (in bytes) for a rendered result
+pub const DEFAULT_MAX_OUTPUT: usize = 64 * 1024;
+
+/// Render the outcome of a process that did not produce a value. A receive still
+/// waiting for a message at the end of the run is suspended, not failed.
+pub fn render_exec_error(e: &ExecError) -> String {
+    match e {
+        ExecError::Blocked { channel } => format!("Suspended: waiting on channel {}", channel),
+        other => format!("Execution error: {}", other),
+    }
+}
+
+/// Whether `outcome` stops a fail-fast batch: a suspended receive does not
+pub fn is_failure(outcome: &std::result::Result<VmValue, ExecError>) -> bool {
+    matches!(outcome, Err(e) if !matches!(e, ExecError::Blocked { .. }))
+}
+
+#[derive(Clone)]
 pub struct RholangCompilerInterpreterProvider {
     processes: Arc<Mutex<HashMap<usize, ProcessInfo>>>,
     next_pid: Arc<Mutex<usize>>,
+    mode: ExecutionMode,
+    parallel: bool,
+    max_output: usize,
+    name_style: NameRenderStyle,
+    /// Tuple space shared by every process this provider runs, so that messages sent by
+    /// one `interpret` call can be received by a later one
+    rspace: SharedRSpace,
 }
 
-impl RholangCompilerInterpreterProvider {
-    pub fn new() -> Result<Self> {
-        Ok(Self {
+impl Default for RholangCompilerInterpreterProvider {
+    fn default() -> Self {
+        Self {
             processes: Arc::new(Mutex::new(HashMap::new())),
             next_pid: Arc::new(Mutex::new(1)),
+            mode: ExecutionMode::default(),
+            parallel: false,
+            max_output: DEFAULT_MAX_OUTPUT,
+            name_style: NameRenderStyle::default(),
+            rspace: {
+                let rspace: Box<dyn RSpace> = Box::new(InMemoryRSpace::new());
+                Arc::new(Mutex::new(rspace))
+            },
+        }
+    }
+}
+
+impl RholangCompilerInterpreterProvider {
+    pub fn new() -> Result<Self> {
+        Ok(Self::default())
+    }
+
+    /// Truncate rendered results longer than `max_bytes` with an ellipsis
+    pub fn with_max_output(mut self, max_bytes: usize) -> Self {
+        self.max_output = max_bytes;
+        self
+    }
+
+    /// Select how names in rendered results are written
+    pub fn with_name_style(mut self, style: NameRenderStyle) -> Self {
+        self.name_style = style;
+        self
+    }
+
+    /// Select how runtime errors in one of several top-level processes are handled
+    pub fn with_execution_mode(mut self, mode: ExecutionMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Run the top-level processes of a program all at once, each on its own thread,
+    /// instead of one after the other
+    ///
+    /// This is a thread-per-process engine over the session's shared RSpace, not a
+    /// scheduler: a process blocked on a receive is run again once the others have
+    /// finished, until a round unblocks none of them. Outcomes are still reported in
+    /// source order. In fail-fast mode every process runs to completion and only the
+    /// outcomes after the first error are dropped.
+    /// Threads are not available on `wasm32`, so keep this off there.
+    pub fn with_parallel(mut self, parallel: bool) -> Self {
+        self.parallel = parallel;
+        self
+    }
+
+    /// The tuple space of this session
+    pub fn rspace(&self) -> SharedRSpace {
+        Arc::clone(&self.rspace)
+    }
+
+    /// Number of interpretations currently running
+    pub fn process_count(&self) -> usize {
+        self.processes
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .len()
+    }
+
+    /// Compile `code` and run every top-level process independently of the others.
+    ///
+    /// Returns one outcome per top-level process, in source order. Only compilation
+    /// and storage failures are reported as an outer error; a process that fails at
+    /// runtime does not prevent the following ones from running.
+    pub async fn interpret_each(
+        &self,
+        code: &str,
+    ) -> std::result::Result<Vec<std::result::Result<VmValue, ExecError>>, InterpreterError> {
+        let registration = self.register(
+            code.to_string(),
+            #[cfg(feature = "native-runtime")]
+            None,
+        )?;
+        Self::execute_all(
+            registration.pid,
+            compile_source_async(code),
+            ExecutionMode::Independent,
+            self.parallel,
+            self.rspace(),
+        )
+        .await
+    }
+
+    /// Allocate a pid for `code` and list it in
+    /// [`list_processes`](InterpreterProvider::list_processes) until the returned
+    /// registration is dropped
+    fn register(
+        &self,
+        code: String,
+        #[cfg(feature = "native-runtime")] cancel: Option<oneshot::Sender<()>>,
+    ) -> std::result::Result<ProcessRegistration<'_>, InterpreterError> {
+        let pid = self.allocate_pid()?;
+        self.processes
+            .lock()
+            .map_err(|e| InterpreterError::new(format!("Lock error: {}", e)))?
+            .insert(
+                pid,
+                ProcessInfo {
+                    code,
+                    #[cfg(feature = "native-runtime")]
+                    cancel,
+                },
+            );
+        Ok(ProcessRegistration {
+            processes: &self.processes,
+            pid,
+        })
+    }
+
+    fn allocate_pid(&self) -> std::result::Result<usize, InterpreterError> {
+        let mut guard = self
+            .next_pid
+            .lock()
+            .map_err(|e| InterpreterError::new(format!("Lock error: {}", e)))?;
+        let pid = *guard;
+        *guard += 1;
+        Ok(pid)
+    }
+
+    /// Run the top-level processes produced by `compiled` against `rspace`. In fail-fast
+    /// mode the outcomes stop at the first runtime error. Processes blocked on a receive
+    /// are run again, in rounds, until a round unblocks none of them, so that a receive
+    /// does not depend on whether its process comes before or after the send.
+    async fn execute_all(
+        pid: usize,
+        compiled: impl Future<Output = Result<Vec<Process>>>,
+        mode: ExecutionMode,
+        parallel: bool,
+        rspace: SharedRSpace,
+    ) -> std::result::Result<Vec<std::result::Result<VmValue, ExecError>>, InterpreterError> {
+        let mut processes = compiled
+            .await
+            .map_err(|e| InterpreterError::new(e.to_string()))?;
+        for proc in &mut processes {
+            proc.vm = VM::with_shared_rspace(Arc::clone(&rspace));
+        }
+
+        let mut outcomes = if parallel {
+            Self::execute_parallel(pid, processes)?
+        } else {
+            Self::execute_serial(pid, processes, mode)?
+        };
+        if mode == ExecutionMode::FailFast {
+            if let Some(failed) = outcomes.iter().position(is_failure) {
+                outcomes.truncate(failed + 1);
+            }
+        }
+        Ok(outcomes)
+    }
+
+    /// Run every process in turn, then the processes left blocked on a receive. Fail-fast
+    /// mode stops at the first runtime error.
+    fn execute_serial(
+        pid: usize,
+        processes: Vec<Process>,
+        mode: ExecutionMode,
+    ) -> std::result::Result<Vec<Outcome>, InterpreterError> {
+        let mut holders = Vec::with_capacity(processes.len());
+        let mut outcomes = Vec::with_capacity(processes.len());
+        for proc in processes {
+            let mut holder = Self::store_and_retrieve(pid, proc)?;
+            let outcome = holder.execute();
+            let failed = is_failure(&outcome);
+            holders.push(holder);
+            outcomes.push(outcome);
+            if failed && mode == ExecutionMode::FailFast {
+                return Ok(outcomes);
+            }
+        }
+        Self::retry_blocked(&mut holders, &mut outcomes, |blocked| {
+            Ok(blocked.into_iter().map(|holder| holder.execute()).collect())
+        })?;
+        Ok(outcomes)
+    }
+
+    /// Run every process on a thread of its own and wait for all of them, then do the
+    /// same for the processes left blocked on a receive
+    fn execute_parallel(
+        pid: usize,
+        processes: Vec<Process>,
+    ) -> std::result::Result<Vec<Outcome>, InterpreterError> {
+        let mut holders = processes
+            .into_iter()
+            .map(|proc| Self::store_and_retrieve(pid, proc))
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+
+        let mut outcomes = Self::execute_threads(holders.iter_mut().collect())?;
+        Self::retry_blocked(&mut holders, &mut outcomes, Self::execute_threads)?;
+        Ok(outcomes)
+    }
+
+    /// Execute `holders` on a thread each and collect their outcomes in order
+    fn execute_threads(
+        holders: Vec<&mut Box<dyn ProcessHolder>>,
+    ) -> std::result::Result<Vec<Outcome>, InterpreterError> {
+        std::thread::scope(|scope| {
+            let handles: Vec<_> = holders
+                .into_iter()
+                .map(|holder| scope.spawn(move || holder.execute()))
+                .collect();
+            handles
+                .into_iter()
+                .map(|handle| {
+                    handle
+                        .join()
+                        .map_err(|_| InterpreterError::new("Execution thread panicked"))
+                })
+                .collect()
         })
     }
 
-    fn render_value(v: &VmValue) -> String {
+    /// Resume the holders whose outcome is a blocked receive with `run`, round after
+    /// round, until none is blocked or a round leaves every outcome as it was
+    fn retry_blocked(
+        holders: &mut [Box<dyn ProcessHolder>],
+        outcomes: &mut [Outcome],
+        run: impl Fn(
+            Vec<&mut Box<dyn ProcessHolder>>,
+        ) -> std::result::Result<Vec<Outcome>, InterpreterError>,
+    ) -> std::result::Result<(), InterpreterError> {
+        loop {
+            let blocked: Vec<usize> = outcomes
+                .iter()
+                .enumerate()
+                .filter(|(_, outcome)| matches!(outcome, Err(ExecError::Blocked { .. })))
+                .map(|(i, _)| i)
+                .collect();
+            if blocked.is_empty() {
+                return Ok(());
+            }
+
+            let retried = run(holders
+                .iter_mut()
+                .enumerate()
+                .filter(|(i, _)| blocked.contains(i))
+                .map(|(_, holder)| holder)
+                .collect())?;
+            let mut progressed = false;
+            for (i, outcome) in blocked.into_iter().zip(retried) {
+                progressed |= outcome != outcomes[i];
+                outcomes[i] = outcome;
+            }
+            if !progressed {
+                return Ok(());
+            }
+        }
+    }
+
+    /// Store the process in its VM's RSpace, then retrieve it for execution
+    /// (the VM is already embedded, the RSpace is shared via Arc)
+    fn store_and_retrieve(
+        pid: usize,
+        proc: Process,
+    ) -> std::result::Result<Box<dyn ProcessHolder>, InterpreterError> {
+        let process_id = format!("proc_{}", pid);
+        let channel = format!("@0:{}", process_id);
+
+        let rspace_arc = proc.vm.rspace.clone();
+        let mut rspace = rspace_arc
+            .lock()
+            .map_err(|e| InterpreterError::new(format!("RSpace lock error: {}", e)))?;
+
+        rspace
+            .tell(&channel, VmValue::Par(vec![proc.boxed()]))
+            .map_err(|e| InterpreterError::new(format!("RSpace tell error: {}", e)))?;
+
+        match rspace.ask(&channel) {
+            Ok(Some(VmValue::Par(mut procs))) if !procs.is_empty() => Ok(procs.remove(0)),
+            Ok(Some(other)) => Err(InterpreterError::new(format!(
+                "Expected process in RSpace, found: {:?}",
+                other
+            ))),
+            Ok(None) => Err(InterpreterError::new(
+                "Process not found in RSpace after tell",
+            )),
+            Err(e) => Err(InterpreterError::new(format!("RSpace ask error: {}", e))),
+        }
+    }
+
+    /// Render one line per top-level process: its value, or its error
+    fn render_outcomes(
+        outcomes: &[std::result::Result<VmValue, ExecError>],
+        max_output: usize,
+        name_style: NameRenderStyle,
+    ) -> String {
+        if outcomes.is_empty() {
+            return "Nil".to_string();
+        }
+        outcomes
+            .iter()
+            .map(|outcome| match outcome {
+                Ok(val) => Self::render_value(val, max_output, name_style),
+                Err(e) => render_exec_error(e),
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    fn render_value(v: &VmValue, max_output: usize, name_style: NameRenderStyle) -> String {
         match v {
             VmValue::Par(procs) => {
                 let inner: Vec<String> = procs
@@ -86,145 +450,64 @@ impl RholangCompilerInterpreterProvider {
                     .collect();
                 inner.join(" | ")
             }
-            other => other.to_string(),
+            other => other.to_string_truncated_with(max_output, name_style),
         }
     }
-}
-
-#[async_trait::async_trait(?Send)]
-impl InterpreterProvider for RholangCompilerInterpreterProvider {
-    async fn interpret(&self, code: &str) -> InterpretationResult {
-        // Allocate a pid and record the process
-        let pid = {
-            let mut guard = match self.next_pid.lock() {
-                Ok(g) => g,
-                Err(e) => {
-                    return InterpretationResult::Error(InterpreterError::new(format!(
-                        "Lock error: {}",
-                        e
-                    )))
-                }
-            };
-            let pid = *guard;
-            *guard += 1;
-            pid
-        };
 
+    /// Run what `compiled` yields as one interpretation, registered under `code` for
+    /// [`list_processes`](InterpreterProvider::list_processes)
+    async fn interpret_compiled(
+        &self,
+        code: String,
+        compiled: impl Future<Output = Result<Vec<Process>>>,
+    ) -> InterpretationResult {
         #[cfg(feature = "native-runtime")]
         let (tx, mut rx) = oneshot::channel();
 
-        {
-            let mut processes = match self.processes.lock() {
-                Ok(g) => g,
-                Err(e) => {
-                    return InterpretationResult::Error(InterpreterError::new(format!(
-                        "Lock error: {}",
-                        e
-                    )))
-                }
-            };
-            processes.insert(
-                pid,
-                ProcessInfo {
-                    code: code.to_string(),
-                    #[cfg(feature = "native-runtime")]
-                    cancel: Some(tx),
-                },
-            );
-        }
-
-        // Core async compile + sync execute. Compile all top-level processes and return the
-        // result of the last one (mirrors shell semantics and avoids "No process" errors).
+        let registration = match self.register(
+            code,
+            #[cfg(feature = "native-runtime")]
+            Some(tx),
+        ) {
+            Ok(registration) => registration,
+            Err(e) => return InterpretationResult::Error(e),
+        };
+        let pid = registration.pid;
+
+        // Core async compile + sync execute. Compile all top-level processes; fail-fast mode
+        // returns the result of the last one (mirrors shell semantics and avoids "No process"
+        // errors), independent mode renders every outcome on its own line.
+        let (mode, parallel) = (self.mode, self.parallel);
+        let (max_output, name_style) = (self.max_output, self.name_style);
+        let rspace = self.rspace();
         let fut = async move {
-            let processes = match compile_source_async(code).await {
-                Ok(ps) => ps,
-                Err(e) => return InterpretationResult::Error(InterpreterError::new(e.to_string())),
+            let outcomes = match Self::execute_all(pid, compiled, mode, parallel, rspace).await {
+                Ok(outcomes) => outcomes,
+                Err(e) => return InterpretationResult::Error(e),
             };
 
-            if processes.is_empty() {
-                return InterpretationResult::Success("Nil".to_string());
-            }
-
-            let mut last_val = VmValue::Nil;
-            for proc in processes.into_iter() {
-                // --- NEW FLOW: store in RSpace then retrieve and execute ---
-                let process_id = format!("proc_{}", pid);
-                let channel = format!("@0:{}", process_id);
-
-                // Use the process's VM's rspace for storage (Arc is shared)
-                let rspace_arc = proc.vm.rspace.clone();
-
-                // Store the process in RSpace
-                {
-                    let mut rspace = match rspace_arc.lock() {
-                        Ok(r) => r,
-                        Err(e) => {
-                            return InterpretationResult::Error(InterpreterError::new(format!(
-                                "RSpace lock error: {}",
-                                e
-                            )))
-                        }
-                    };
-                    if let Err(e) = rspace.tell(&channel, VmValue::Par(vec![proc.boxed()])) {
-                        return InterpretationResult::Error(InterpreterError::new(format!(
-                            "RSpace tell error: {}",
-                            e
-                        )));
-                    }
-                }
-
-                // Retrieve the process from RSpace
-                let mut retrieved_proc = {
-                    let mut rspace = match rspace_arc.lock() {
-                        Ok(r) => r,
-                        Err(e) => {
-                            return InterpretationResult::Error(InterpreterError::new(format!(
-                                "RSpace lock error: {}",
-                                e
-                            )))
-                        }
-                    };
-                    match rspace.ask(&channel) {
-                        Ok(Some(VmValue::Par(mut procs))) if !procs.is_empty() => procs.remove(0),
-                        Ok(Some(other)) => {
-                            return InterpretationResult::Error(InterpreterError::new(format!(
-                                "Expected process in RSpace, found: {:?}",
-                                other
-                            )))
-                        }
-                        Ok(None) => {
-                            return InterpretationResult::Error(InterpreterError::new(
-                                "Process not found in RSpace after tell",
-                            ))
-                        }
-                        Err(e) => {
-                            return InterpretationResult::Error(InterpreterError::new(format!(
-                                "RSpace ask error: {}",
-                                e
-                            )))
-                        }
-                    }
-                };
-
-                // Execute the retrieved process (VM is already embedded, RSpace is shared via Arc)
-                match retrieved_proc.execute() {
-                    Ok(val) => {
-                        last_val = val;
+            match mode {
+                ExecutionMode::FailFast => match outcomes.into_iter().last() {
+                    None => InterpretationResult::Success("Nil".to_string()),
+                    Some(Ok(val)) => InterpretationResult::Success(Self::render_value(
+                        &val, max_output, name_style,
+                    )),
+                    Some(Err(e @ ExecError::Blocked { .. })) => {
+                        InterpretationResult::Success(render_exec_error(&e))
                     }
-                    Err(e) => {
-                        return InterpretationResult::Error(InterpreterError::new(format!(
-                            "Execution error: {}",
-                            e
-                        )))
-                    }
-                }
+                    Some(Err(e)) => InterpretationResult::Error(InterpreterError::new(format!(
+                        "Execution error: {}",
+                        e
+                    ))),
+                },
+                ExecutionMode::Independent => InterpretationResult::Success(Self::render_outcomes(
+                    &outcomes, max_output, name_style,
+                )),
             }
-
-            InterpretationResult::Success(Self::render_value(&last_val))
         };
 
         #[cfg(feature = "native-runtime")]
-        let result = {
+        let fut = async move {
             let timed = timeout(Duration::from_secs(30), fut);
             tokio::select! {
                 r = timed => r.unwrap_or_else(|_| InterpretationResult::Error(InterpreterError::new("Execution timed out"))),
@@ -232,15 +515,21 @@ impl InterpreterProvider for RholangCompilerInterpreterProvider {
             }
         };
 
-        #[cfg(not(feature = "native-runtime"))]
-        let result = fut.await;
+        fut.await
+    }
+}
 
-        // Cleanup
-        if let Ok(mut procs) = self.processes.lock() {
-            procs.remove(&pid);
-        }
+#[async_trait::async_trait(?Send)]
+impl InterpreterProvider for RholangCompilerInterpreterProvider {
+    async fn interpret(&self, code: &str) -> InterpretationResult {
+        self.interpret_compiled(code.to_string(), compile_source_async(code))
+            .await
+    }
 
-        result
+    async fn interpret_ast<'a>(&self, procs: &'a [AnnProc<'a>]) -> InterpretationResult {
+        let code = procs.iter().map(unparse).collect::<Vec<_>>().join("\n");
+        self.interpret_compiled(code, async { compile_ast(procs) })
+            .await
     }
 
     fn list_processes(&self) -> Result<Vec<(usize, String)>> {
@@ -315,4 +604,315 @@ mod tests {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn test_empty_programs_yield_nil() -> Result<()> {
+        let provider = RholangCompilerInterpreterProvider::new()?;
+
+        for code in ["", "   "] {
+            assert_eq!(provider.interpret(code).await.unwrap(), "Nil");
+            let outcomes = provider
+                .interpret_each(code)
+                .await
+                .map_err(|e| anyhow!("{e}"))?;
+            assert_eq!(outcomes.len(), 1);
+            assert_eq!(outcomes[0].as_ref().unwrap(), &VmValue::Nil);
+        }
+        assert_eq!(provider.interpret_ast(&[]).await.unwrap(), "Nil");
+
+        Ok(())
+    }
+
+    const BATCH: &str = "1 + 2\n10 / 0\n\"ok\"";
+
+    #[tokio::test]
+    async fn test_interpret_each_reports_every_outcome() -> Result<()> {
+        let provider = RholangCompilerInterpreterProvider::new()?;
+
+        let outcomes = provider
+            .interpret_each(BATCH)
+            .await
+            .map_err(|e| anyhow!("{e}"))?;
+
+        assert_eq!(outcomes.len(), 3);
+        assert_eq!(outcomes[0].as_ref().unwrap(), &VmValue::Int(3));
+        assert!(outcomes[1].is_err());
+        assert_eq!(
+            outcomes[2].as_ref().unwrap(),
+            &VmValue::Str("ok".to_string())
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_independent_mode_renders_one_line_per_process() -> Result<()> {
+        let provider = RholangCompilerInterpreterProvider::new()?
+            .with_execution_mode(ExecutionMode::Independent);
+
+        let rendered = provider.interpret(BATCH).await.unwrap();
+        let lines: Vec<_> = rendered.lines().collect();
+
+        assert_eq!(lines.len(), 3);
+        assert_eq!(lines[0], "3");
+        assert!(lines[1].starts_with("Execution error:"), "{}", lines[1]);
+        assert_eq!(lines[2], "\"ok\"");
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_fail_fast_mode_stops_at_first_error() -> Result<()> {
+        let provider = RholangCompilerInterpreterProvider::new()?;
+
+        let result = provider.interpret(BATCH).await;
+
+        assert!(!result.is_success());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_large_results_are_truncated() -> Result<()> {
+        let provider = RholangCompilerInterpreterProvider::new()?.with_max_output(100);
+
+        let elements: Vec<String> = (0..10_000).map(|i| i.to_string()).collect();
+        let rendered = provider
+            .interpret(&format!("[{}]", elements.join(", ")))
+            .await
+            .unwrap();
+
+        assert!(
+            rendered.len() < 200,
+            "not truncated: {} bytes",
+            rendered.len()
+        );
+        assert!(rendered.ends_with(" more)]"), "{rendered}");
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_name_style_applies_to_results() -> Result<()> {
+        let provider =
+            RholangCompilerInterpreterProvider::new()?.with_name_style(NameRenderStyle::Index);
+
+        let rendered = provider
+            .interpret("new x, y in { [x, y, x] }")
+            .await
+            .unwrap();
+
+        assert_eq!(rendered, "[@#0, @#1, @#0]");
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_parallel_and_serial_engines_agree() -> Result<()> {
+        let code = r#"
+            new ch in { ch!(20) | for (x <- ch) { x + 1 } }
+            [6, 6 * 7]
+            "done"
+        "#;
+
+        for mode in [ExecutionMode::FailFast, ExecutionMode::Independent] {
+            let serial = RholangCompilerInterpreterProvider::new()?.with_execution_mode(mode);
+            let parallel = serial.clone().with_parallel(true);
+
+            let expected = serial.interpret(code).await.unwrap();
+            assert_eq!(parallel.interpret(code).await.unwrap(), expected);
+        }
+
+        let serial = RholangCompilerInterpreterProvider::new()?;
+        let outcomes = serial
+            .interpret_each(code)
+            .await
+            .map_err(|e| anyhow!("{e}"))?;
+        let parallel = serial.with_parallel(true);
+        let parallel_outcomes = parallel
+            .interpret_each(code)
+            .await
+            .map_err(|e| anyhow!("{e}"))?;
+        assert_eq!(outcomes.len(), 3);
+        assert_eq!(parallel_outcomes, outcomes);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_engines_agree_on_receives_across_processes() -> Result<()> {
+        for (code, expected) in [
+            ("@\"c\"!(1)\nfor(@y <- @\"c\") { y }", "true\n1"),
+            ("for(@y <- @\"c\") { y }\n@\"c\"!(1)", "1\ntrue"),
+        ] {
+            let serial = RholangCompilerInterpreterProvider::new()?
+                .with_execution_mode(ExecutionMode::Independent);
+            assert_eq!(serial.interpret(code).await.unwrap(), expected, "{code:?}");
+
+            // each run gets a fresh session, so the consumer never sees an earlier message
+            for _ in 0..40 {
+                let parallel = RholangCompilerInterpreterProvider::new()?
+                    .with_execution_mode(ExecutionMode::Independent)
+                    .with_parallel(true);
+                assert_eq!(
+                    parallel.interpret(code).await.unwrap(),
+                    expected,
+                    "{code:?}"
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_parallel_processes_get_distinct_new_names() -> Result<()> {
+        let code = (0..8)
+            .map(|i| format!("new x in {{ x!({i}) | for (y <- x) {{ y }} }}"))
+            .collect::<Vec<_>>()
+            .join("\n");
+        let provider = RholangCompilerInterpreterProvider::new()?
+            .with_execution_mode(ExecutionMode::Independent)
+            .with_parallel(true);
+
+        assert_eq!(
+            provider.interpret(&code).await.unwrap(),
+            "0\n1\n2\n3\n4\n5\n6\n7"
+        );
+
+        // a name left with a message by one call is not handed out again by the next
+        provider.interpret("new x in { x!(1) }").await.unwrap();
+        let receive = provider.interpret("new x in { for (y <- x) { y } }").await;
+        assert!(receive.unwrap().starts_with("Suspended:"));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_par_branch_order_does_not_block_receives() -> Result<()> {
+        let provider = RholangCompilerInterpreterProvider::new()?;
+
+        for code in [
+            "new c in { for(@x <- c) { x } | c!(1) }",
+            "new c in { c!(1) | for(@x <- c) { x } }",
+        ] {
+            assert_eq!(provider.interpret(code).await.unwrap(), "1", "{code}");
+        }
+
+        // a receive that nothing sends to is left suspended, not failed
+        let pending = provider
+            .interpret("new c, d in { for(@x <- c) { x } | d!(1) }")
+            .await
+            .unwrap();
+        assert!(pending.starts_with("Suspended:"), "{pending}");
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_messages_persist_across_interpret_calls() -> Result<()> {
+        let provider = RholangCompilerInterpreterProvider::new()?;
+
+        assert!(provider.interpret("@\"c\"!(1)").await.is_success());
+        let receive = "for(@v <- @\"c\") { v }";
+        assert_eq!(provider.interpret(receive).await.unwrap(), "1");
+
+        // the message was consumed, and a separate session never saw it
+        let suspended = provider.interpret(receive).await.unwrap();
+        assert!(suspended.starts_with("Suspended:"), "{suspended}");
+        provider.interpret("@\"c\"!(2)").await.unwrap();
+        let other = RholangCompilerInterpreterProvider::new()?;
+        let suspended = other.interpret(receive).await.unwrap();
+        assert!(suspended.starts_with("Suspended:"), "{suspended}");
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_interpret_ast_matches_interpret() -> Result<()> {
+        let provider = RholangCompilerInterpreterProvider::new()?;
+        let render = |result: InterpretationResult| match result {
+            InterpretationResult::Success(value) => Ok(value),
+            InterpretationResult::Error(e) => Err(e.to_string()),
+        };
+
+        for code in ["x!(1+2)", "new x in { x!(1+2) }", "1 + 2\n[3, 4]"] {
+            let parser = rholang_parser::RholangParser::new();
+            let validated::Validated::Good(procs) = parser.parse(code) else {
+                panic!("{code} should parse");
+            };
+            assert_eq!(
+                render(provider.interpret_ast(&procs).await),
+                render(provider.interpret(code).await),
+                "{code}"
+            );
+        }
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_interpret_ast_built_in_code() -> Result<()> {
+        use rholang_parser::{ast::BinaryExpOp, RholangAst};
+
+        let provider = RholangCompilerInterpreterProvider::new()?;
+        let ast = RholangAst::new();
+        let channel = || ast.quote(ast.string("x"));
+        let sum = ast.binary(BinaryExpOp::Add, ast.int(1), ast.int(2));
+        let send = [ast.send(channel(), &[sum])];
+        let receive = [ast.receive([ast.quote(ast.var("v"))], channel(), ast.var("v"))];
+
+        assert!(provider.interpret_ast(&send).await.is_success());
+        assert_eq!(provider.interpret_ast(&receive).await.unwrap(), "3");
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_default_interpret_ast_interprets_the_unparsed_source() {
+        struct Echo;
+
+        #[async_trait::async_trait(?Send)]
+        impl InterpreterProvider for Echo {
+            async fn interpret(&self, code: &str) -> InterpretationResult {
+                InterpretationResult::Success(code.to_string())
+            }
+            fn list_processes(&self) -> Result<Vec<(usize, String)>> {
+                Ok(vec![])
+            }
+            fn kill_process(&self, _pid: usize) -> Result<bool> {
+                Ok(false)
+            }
+            fn kill_all_processes(&self) -> Result<usize> {
+                Ok(0)
+            }
+        }
+
+        let parser = rholang_parser::RholangParser::new();
+        let validated::Validated::Good(procs) = parser.parse("x!(1 + 2)\n[3, 4]") else {
+            panic!("should parse");
+        };
+        let expected = procs.iter().map(unparse).collect::<Vec<_>>().join("\n");
+        assert_eq!(Echo.interpret_ast(&procs).await.unwrap(), expected);
+    }
+
+    #[test]
+    fn test_panicking_interpretation_is_unregistered() -> Result<()> {
+        let provider = RholangCompilerInterpreterProvider::new()?;
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()?;
+
+        let crashing =
+            provider.interpret_compiled("boom".to_string(), async { panic!("compiler crashed") });
+        let panicked =
+            std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| runtime.block_on(crashing)));
+        assert!(panicked.is_err());
+        assert_eq!(provider.process_count(), 0);
+
+        runtime.block_on(provider.interpret("1 + 2")).unwrap();
+        assert_eq!(provider.process_count(), 0);
+
+        Ok(())
+    }
 }
@@ -16,19 +16,174 @@ const DEFAULT_INDEX_CAPACITY: usize = 64;
 const DEFAULT_BINDERS_CAPACITY: usize = 16;
 const DEFAULT_SCOPES_CAPACITY: usize = 16;
 
+/// Deep-copies every table of the database, so the clone can be analyzed further
+/// (new diagnostics, scopes, bindings) without affecting the original.
+///
+/// The AST itself is not copied: the clone indexes the very same nodes and is bound
+/// to the same arena lifetime `'a`.
+impl<'a> Clone for SemanticDb<'a> {
+    fn clone(&self) -> Self {
+        Self {
+            rev: self.rev.clone(),
+            interner: self.interner.clone(),
+            diagnostics: self.diagnostics.clone(),
+            diagnostic_filter: self.diagnostic_filter,
+            has_errors: self.has_errors,
+            binder_is_name: self.binder_is_name.clone(),
+            binders: self.binders.clone(),
+            proc_to_scope: self.proc_to_scope.clone(),
+            enclosing_pids: self.enclosing_pids.clone(),
+            var_to_binder: self.var_to_binder.clone(),
+            shadowings: self.shadowings.clone(),
+        }
+    }
+}
+
 impl<'a> SemanticDb<'a> {
     pub fn new() -> Self {
+        Self::with_seeds(SEED0, SEED1, SEED2, SEED3)
+    }
+
+    /// Creates an empty database whose hash tables are keyed with the given seeds instead of
+    /// the fixed default ones.
+    ///
+    /// Analysis results must not depend on the seeds; this exists so tests can check that
+    /// nothing relies on hash iteration order.
+    pub fn with_seeds(s0: u64, s1: u64, s2: u64, s3: u64) -> Self {
         Self {
-            rev: IndexMap::with_capacity_and_hasher(DEFAULT_INDEX_CAPACITY, RandomState::new()),
-            interner: Interner::new(),
+            rev: IndexMap::with_capacity_and_hasher(
+                DEFAULT_INDEX_CAPACITY,
+                RandomState::with_seeds(s0, s1, s2, s3),
+            ),
+            interner: Interner::with_hasher(RandomState::with_seeds(s0, s1, s2, s3)),
             diagnostics: Vec::new(),
+            diagnostic_filter: DiagnosticFilter::default(),
             has_errors: false,
             binder_is_name: BitVec::with_capacity(DEFAULT_BINDERS_CAPACITY),
             binders: Vec::with_capacity(DEFAULT_BINDERS_CAPACITY),
             proc_to_scope: IntMap::with_capacity(DEFAULT_SCOPES_CAPACITY),
             enclosing_pids: Vec::new(),
             var_to_binder: BTreeMap::new(),
+            shadowings: Vec::new(),
+        }
+    }
+
+    /// Resets the database to the state of a freshly created one.
+    ///
+    /// Drops the index, symbols, binders, scopes and diagnostics, and releases
+    /// excess capacity, so a single `SemanticDb` can be reused across analyses
+    /// without growing unboundedly. [`PID`]s, [`Symbol`]s and [`BinderId`]s are
+    /// handed out from zero again; any ids obtained before the call are invalid.
+    pub fn clear(&mut self) {
+        self.rev.clear();
+        self.rev.shrink_to(DEFAULT_INDEX_CAPACITY);
+        self.interner.clear();
+
+        self.diagnostics = Vec::new();
+        self.diagnostic_filter = DiagnosticFilter::default();
+        self.has_errors = false;
+
+        self.binder_is_name.clear();
+        self.binder_is_name.shrink_to_fit();
+        self.binders.clear();
+        self.binders.shrink_to(DEFAULT_BINDERS_CAPACITY);
+        self.proc_to_scope = IntMap::with_capacity(DEFAULT_SCOPES_CAPACITY);
+        self.enclosing_pids = Vec::new();
+
+        self.var_to_binder.clear();
+        self.shadowings = Vec::new();
+    }
+
+    /// Absorbs a database that was analyzed independently of this one.
+    ///
+    /// The processes, binders, scopes, variable bindings and diagnostics of `other`
+    /// are appended to this database. [`PID`]s and [`BinderId`]s of `other` are
+    /// shifted past the ones already handed out here, and its [`Symbol`]s are
+    /// re-interned, so every lookup that worked on `other` works on the merged
+    /// database after translating the ids. Diagnostics go through this database's
+    /// [`DiagnosticFilter`].
+    ///
+    /// Variable occurrences are keyed by name and source position, so the two
+    /// databases are expected to cover distinct source regions (e.g. different
+    /// top-level processes of one file). If both record an occurrence at the same
+    /// position, the binding from `self` is kept.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a process is indexed in both databases.
+    pub fn merge(&mut self, other: SemanticDb<'a>) {
+        let rebase = Rebase {
+            pids: self.pid_count() as u32,
+            binders: self.binders.len() as u32,
+            symbols: (0..other.interner.len() as u32)
+                .map(|i| {
+                    let str = other
+                        .interner
+                        .resolve(Symbol(i))
+                        .expect("symbol not interned");
+                    self.intern(str)
+                })
+                .collect(),
+        };
+
+        for (proc, pid) in other.rev {
+            let old = self.rev.insert(proc, rebase.pid(pid));
+            assert!(
+                old.is_none(),
+                "process indexed in both databases: {:#?}",
+                *proc
+            );
+        }
+
+        self.binder_is_name
+            .extend_from_bitslice(&other.binder_is_name);
+        self.binders
+            .extend(other.binders.into_iter().map(|binder| Binder {
+                name: rebase.symbol(binder.name),
+                kind: match binder.kind {
+                    BinderKind::Name(uri) => BinderKind::Name(uri.map(|uri| rebase.symbol(uri))),
+                    BinderKind::Proc => BinderKind::Proc,
+                },
+                scope: rebase.pid(binder.scope),
+                ..binder
+            }));
+
+        for (pid, scope) in other.proc_to_scope {
+            self.proc_to_scope
+                .insert(rebase.pid(pid), scope.rebased(rebase.binders));
+        }
+
+        if !other.enclosing_pids.is_empty() {
+            self.enclosing_pids
+                .resize(rebase.pids as usize, PID::TOP_LEVEL);
+            self.enclosing_pids
+                .extend(other.enclosing_pids.into_iter().map(|pid| rebase.pid(pid)));
+        }
+
+        for (occ, binding) in other.var_to_binder {
+            let binding = match binding {
+                VarBinding::Bound(bid) => VarBinding::Bound(rebase.binder(bid)),
+                free @ VarBinding::Free { .. } => free,
+            };
+            self.var_to_binder
+                .entry(rebase.occurrence(occ))
+                .or_insert(binding);
         }
+
+        self.shadowings.extend(
+            other
+                .shadowings
+                .into_iter()
+                .map(|(inner, outer)| (rebase.binder(inner), rebase.binder(outer))),
+        );
+
+        self.has_errors |= other.has_errors;
+        self.push_diagnostics(
+            other
+                .diagnostics
+                .into_iter()
+                .map(|diagnostic| rebase.diagnostic(diagnostic)),
+        );
     }
 
     /// Returns a [`Symbol`] that uniquely represents the given string.
@@ -54,6 +209,21 @@ impl<'a> SemanticDb<'a> {
         self.interner.resolve_owned(sym)
     }
 
+    /// Every interned string with its [`Symbol`], in the order they were interned.
+    ///
+    /// Symbols interned while the iterator is live are not visited.
+    pub fn symbols(&self) -> impl Iterator<Item = (Symbol, &str)> {
+        (0..self.symbol_count() as u32).map(|i| {
+            let sym = Symbol(i);
+            (sym, &self.interner[sym])
+        })
+    }
+
+    /// Number of strings interned in this database so far
+    pub fn symbol_count(&self) -> usize {
+        self.interner.len()
+    }
+
     pub(super) fn fresh_binder(&mut self, binder: Binder) -> BinderId {
         let id = self.next_binder();
         if id == BinderId::MAX {
@@ -158,27 +328,58 @@ impl<'a> SemanticDb<'a> {
         self.iter().filter(move |candidate| predicate(candidate.1))
     }
 
+    /// Returns the [`PID`] of the process at `span`: the one whose span equals it, or else the
+    /// one with the tightest span containing it.
+    ///
+    /// When a process and its child share a span, the child wins. This maps an editor selection
+    /// or a diagnostic's span back to a process for queries like [`Self::scope_chain`].
+    pub fn pid_at_span(&self, span: SourceSpan) -> Option<PID> {
+        let encloses = |outer: SourceSpan| outer.start <= span.start && span.end <= outer.end;
+        self.iter()
+            .filter(|(_, proc)| encloses(proc.span))
+            .reduce(|best, candidate| {
+                let tighter = best.1.span.start <= candidate.1.span.start
+                    && candidate.1.span.end <= best.1.span.end;
+                if tighter { candidate } else { best }
+            })
+            .map(|(pid, _)| pid)
+    }
+
+    /// Records a diagnostic, subject to the configured [`DiagnosticFilter`].
+    ///
+    /// An error always marks the database as erroneous, even if the filter drops it, and
+    /// so does a warning if the filter denies warnings.
     pub fn emit_diagnostic(&mut self, diagnostic: Diagnostic) {
-        self.diagnostics.push(diagnostic);
-        if let DiagnosticKind::Error(_) = diagnostic.kind {
+        if self.diagnostic_filter.is_fatal(&diagnostic) {
             self.has_errors = true;
         }
+        if self.diagnostic_filter.accepts(&diagnostic) && !self.is_diagnostics_full() {
+            self.diagnostics.push(diagnostic);
+        }
     }
 
     pub fn push_diagnostics<D>(&mut self, diagnostics: D)
     where
         D: IntoIterator<Item = Diagnostic>,
     {
-        if self.has_errors {
-            self.diagnostics.extend(diagnostics);
-            return;
+        for diagnostic in diagnostics {
+            self.emit_diagnostic(diagnostic);
         }
+    }
 
-        let old_len = self.diagnostics.len();
-        self.diagnostics.extend(diagnostics);
-        self.has_errors = self.diagnostics[old_len..]
-            .iter()
-            .any(|d| matches!(d.kind, DiagnosticKind::Error(_)));
+    pub fn diagnostic_filter(&self) -> DiagnosticFilter {
+        self.diagnostic_filter
+    }
+
+    /// Sets the filter applied to diagnostics recorded from now on. Diagnostics
+    /// collected earlier are kept.
+    pub fn set_diagnostic_filter(&mut self, filter: DiagnosticFilter) {
+        self.diagnostic_filter = filter;
+    }
+
+    /// Returns `true` once the filter's `max_count` is reached
+    pub fn is_diagnostics_full(&self) -> bool {
+        self.diagnostic_filter.is_full(self.diagnostics.len())
     }
 
     pub fn error(&mut self, pid: PID, kind: ErrorKind, pos: Option<SourcePos>) {
@@ -209,6 +410,42 @@ impl<'a> SemanticDb<'a> {
         &self.diagnostics
     }
 
+    /// Every binder that shadows a binder of an enclosing scope, in source order of the
+    /// shadowing binder.
+    ///
+    /// Unlike [`WarningKind::ShadowedVar`] diagnostics, the report is kept regardless of the
+    /// [`DiagnosticFilter`].
+    pub fn shadowing_report(&self) -> Vec<Shadowing> {
+        let mut report: Vec<_> = self
+            .shadowings
+            .iter()
+            .map(|&(inner, outer)| Shadowing {
+                inner: self[inner].into(),
+                outer: self[outer].into(),
+            })
+            .collect();
+        report.sort_by_key(|shadowing| (shadowing.inner.position, shadowing.outer.position));
+        report
+    }
+
+    pub(super) fn record_shadowing(&mut self, inner: BinderId, outer: BinderId) {
+        self.shadowings.push((inner, outer));
+    }
+
+    /// Diagnostics grouped by source position, in source order. A diagnostic without an
+    /// exact position is filed under the start of the process it was reported on.
+    /// Within a position, diagnostics keep the order they were recorded in.
+    pub fn diagnostics_by_position(&self) -> BTreeMap<SourcePos, Vec<&Diagnostic>> {
+        let mut by_position: BTreeMap<SourcePos, Vec<&Diagnostic>> = BTreeMap::new();
+        for diagnostic in &self.diagnostics {
+            let position = diagnostic
+                .exact_position
+                .unwrap_or_else(|| self[diagnostic.pid].span.start);
+            by_position.entry(position).or_default().push(diagnostic);
+        }
+        by_position
+    }
+
     #[inline]
     fn assert_scope_ib(&self, rng: &std::ops::Range<usize>) {
         let next_binder = self.binders.len();
@@ -574,6 +811,104 @@ impl<'a> SemanticDb<'a> {
     }
 }
 
+/// Translation of the ids of a database merged into another one
+struct Rebase {
+    pids: u32,
+    binders: u32,
+    symbols: Vec<Symbol>,
+}
+
+impl Rebase {
+    fn pid(&self, pid: PID) -> PID {
+        if pid == PID::TOP_LEVEL {
+            pid
+        } else {
+            PID(pid.0 + self.pids)
+        }
+    }
+
+    fn binder(&self, bid: BinderId) -> BinderId {
+        if bid == BinderId::MAX {
+            bid
+        } else {
+            bid + self.binders
+        }
+    }
+
+    fn symbol(&self, sym: Symbol) -> Symbol {
+        self.symbols.get(sym.0 as usize).copied().unwrap_or(sym)
+    }
+
+    fn occurrence(&self, occ: SymbolOccurrence) -> SymbolOccurrence {
+        SymbolOccurrence {
+            symbol: self.symbol(occ.symbol),
+            ..occ
+        }
+    }
+
+    fn diagnostic(&self, diagnostic: Diagnostic) -> Diagnostic {
+        let kind = match diagnostic.kind {
+            DiagnosticKind::Info(info) => DiagnosticKind::Info(info),
+            DiagnosticKind::Warning(warning) => DiagnosticKind::Warning(match warning {
+                WarningKind::ShadowedVar { original } => WarningKind::ShadowedVar {
+                    original: self.occurrence(original),
+                },
+                WarningKind::UnusedVariable(bid, sym) => {
+                    WarningKind::UnusedVariable(self.binder(bid), self.symbol(sym))
+                }
+                WarningKind::UnusedCapture(bid, sym) => {
+                    WarningKind::UnusedCapture(self.binder(bid), self.symbol(sym))
+                }
+                WarningKind::UnmatchedReceive { channel } => WarningKind::UnmatchedReceive {
+                    channel: self.binder(channel),
+                },
+                WarningKind::UnknownUri { uri } => WarningKind::UnknownUri {
+                    uri: self.symbol(uri),
+                },
+                WarningKind::ImplicitEval(bid, sym) => {
+                    WarningKind::ImplicitEval(self.binder(bid), self.symbol(sym))
+                }
+                WarningKind::ImplicitQuote(bid, sym) => {
+                    WarningKind::ImplicitQuote(self.binder(bid), self.symbol(sym))
+                }
+                span @ (WarningKind::TopLevelPatternExpr { .. }
+                | WarningKind::DuplicateMapKey { .. }
+                | WarningKind::DuplicateSetElement { .. }
+                | WarningKind::IfWithoutElse
+                | WarningKind::NonExhaustiveMatch { .. }) => span,
+            }),
+            DiagnosticKind::Error(error) => DiagnosticKind::Error(match error {
+                ErrorKind::DuplicateVarDef { original } => ErrorKind::DuplicateVarDef {
+                    original: self.occurrence(original),
+                },
+                ErrorKind::NameInProcPosition(bid, sym) => {
+                    ErrorKind::NameInProcPosition(self.binder(bid), self.symbol(sym))
+                }
+                ErrorKind::ProcInNamePosition(bid, sym) => {
+                    ErrorKind::ProcInNamePosition(self.binder(bid), self.symbol(sym))
+                }
+                ErrorKind::UnmatchedVarInDisjunction(sym) => {
+                    ErrorKind::UnmatchedVarInDisjunction(self.symbol(sym))
+                }
+                ErrorKind::FreeVariable(occ) => ErrorKind::FreeVariable(self.occurrence(occ)),
+                other @ (ErrorKind::UnboundVariable
+                | ErrorKind::ConnectiveOutsidePattern
+                | ErrorKind::BundleInsidePattern
+                | ErrorKind::BadCode
+                | ErrorKind::MixedNumericTypes { .. }
+                | ErrorKind::UnsupportedNumericOperator { .. }
+                | ErrorKind::UnsupportedUnaryNumericOperator { .. }) => other,
+            }),
+        };
+
+        Diagnostic {
+            pid: self.pid(diagnostic.pid),
+            kind,
+            ..diagnostic
+        }
+    }
+}
+
 /// Enable `db[pid]` syntax to access the process by PID.
 impl<'a> Index<PID> for SemanticDb<'a> {
     type Output = ProcRef<'a>;
@@ -783,6 +1118,385 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_diagnostics_do_not_depend_on_seeds() {
+        use crate::sem::diagnostics::{DeadReceivePass, DuplicateKeysPass, UnusedVarsPass};
+        use crate::sem::pipeline::Pipeline;
+        use crate::sem::{EnclosureAnalysisPass, ResolverPass};
+
+        let code = r#"
+        new stdout(`rho:io:stdout`), ret, unused in {
+          for (@x, @y <- ret; @z <- unused) { stdout!(x + w) } |
+          for (@a <- private) { Nil } |
+          {1: "a", 1: "b", "k": z}
+        }"#;
+        let parser = RholangParser::new();
+        let ast = parser.parse(code).unwrap();
+
+        fn analyze<'x>(mut db: SemanticDb<'x>, proc: ProcRef<'x>) -> Vec<Diagnostic> {
+            let root = db.build_index(proc);
+            let pipeline = Pipeline::new()
+                .add_fact(ResolverPass::new(root))
+                .add_fact(EnclosureAnalysisPass::new(root))
+                .add_diagnostic(UnusedVarsPass)
+                .add_diagnostic(DeadReceivePass)
+                .add_diagnostic(DuplicateKeysPass);
+            let rt = tokio::runtime::Runtime::new().unwrap();
+            rt.block_on(pipeline.run(&mut db));
+            db.diagnostics().to_vec()
+        }
+
+        let expected = analyze(SemanticDb::new(), &ast[0]);
+        assert!(!expected.is_empty());
+        for seed in [0, 1, 0xDEAD_BEEF, u64::MAX] {
+            let seeded = SemanticDb::with_seeds(seed, seed ^ 1, seed.rotate_left(7), !seed);
+            assert_eq!(analyze(seeded, &ast[0]), expected, "seed {seed:#x}");
+        }
+    }
+
+    #[test]
+    fn test_with_seeds_keys_the_hash_tables() {
+        fn hashes(db: &SemanticDb) -> (u64, u64) {
+            let probe = "stdout";
+            (
+                db.rev.hasher().hash_one(probe),
+                db.interner.hasher().hash_one(probe),
+            )
+        }
+
+        let default = hashes(&SemanticDb::new());
+        assert_eq!(
+            hashes(&SemanticDb::with_seeds(SEED0, SEED1, SEED2, SEED3)),
+            default
+        );
+        for seed in [0, 1, 0xDEAD_BEEF, u64::MAX] {
+            let seeded = SemanticDb::with_seeds(seed, seed ^ 1, seed.rotate_left(7), !seed);
+            let (rev, interner) = hashes(&seeded);
+            assert_ne!(rev, default.0, "seed {seed:#x}");
+            assert_ne!(interner, default.1, "seed {seed:#x}");
+        }
+    }
+
+    #[test]
+    fn test_symbols_lists_interned_names() {
+        use crate::sem::{FactPass, ResolverPass};
+
+        let parser = RholangParser::new();
+        let ast = parser.parse("new foo, bar in { foo!(bar) }").unwrap();
+        let mut db = SemanticDb::new();
+        let root = db.build_index(&ast[0]);
+        ResolverPass::new(root).run(&mut db);
+
+        let symbols: Vec<(Symbol, &str)> = db.symbols().collect();
+        assert_eq!(symbols.len(), db.symbol_count());
+        for name in ["foo", "bar"] {
+            let sym = db.intern(name);
+            assert!(
+                symbols.contains(&(sym, name)),
+                "{name} missing from {symbols:?}"
+            );
+        }
+        assert!(
+            symbols
+                .iter()
+                .all(|&(sym, name)| db.resolve_symbol(sym) == Some(name))
+        );
+    }
+
+    #[test]
+    fn test_clear_resets_to_fresh_state() {
+        use crate::sem::{FactPass, ResolverPass};
+
+        let code = r#"
+        new stdout(`rho:io:stdout`), ret in {
+          for (@x <- ret) { stdout!(x + y) }
+        }"#;
+        let parser = RholangParser::new();
+        let ast = parser.parse(code).unwrap();
+        let other = parser.parse("for (@a, @b <- c) { Nil }").unwrap();
+
+        fn analyze<'x>(db: &mut SemanticDb<'x>, proc: ProcRef<'x>) -> PID {
+            let root = db.build_index(proc);
+            ResolverPass::new(root).run(db);
+            db.intern("extra");
+            root
+        }
+
+        let mut fresh = SemanticDb::new();
+        let fresh_root = analyze(&mut fresh, &ast[0]);
+
+        // build some unrelated state first so all counters have moved
+        let mut reused = SemanticDb::new();
+        analyze(&mut reused, &other[0]);
+        analyze(&mut reused, &ast[0]);
+        reused.intern("leftover");
+        reused.clear();
+
+        assert_eq!(reused.pid_count(), 0);
+        assert_eq!(reused.next_binder(), BinderId(0));
+        assert!(reused.diagnostics().is_empty());
+        assert!(!reused.has_errors());
+        assert_eq!(reused.resolve_symbol(Symbol(0)), None);
+
+        let reused_root = analyze(&mut reused, &ast[0]);
+
+        assert_eq!(fresh_root, reused_root);
+        assert_eq!(
+            fresh.iter().collect::<Vec<_>>(),
+            reused.iter().collect::<Vec<_>>()
+        );
+        assert_eq!(fresh.intern("stdout"), reused.intern("stdout"));
+        assert_eq!(fresh.intern("extra"), reused.intern("extra"));
+        assert_eq!(fresh.next_binder(), reused.next_binder());
+        assert_eq!(fresh.diagnostics(), reused.diagnostics());
+        let scopes = |db: &SemanticDb| {
+            db.scopes_full()
+                .map(|(pid, scope)| (pid, scope.as_range(), scope.num_captures()))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(scopes(&fresh), scopes(&reused));
+    }
+
+    #[test]
+    fn test_merge_keeps_both_analyses_resolvable() {
+        use crate::sem::{EnclosureAnalysisPass, FactPass, ResolverPass};
+
+        let parser = RholangParser::new();
+        let left_ast = parser
+            .parse("new x, y in { for (@a <- x) { y!(a) } }")
+            .unwrap();
+        // the second program lives further down the same file
+        let right_ast = parser
+            .parse("\n\nnew z in { for (@a, @b <- z) { z!(a + b, q) } }")
+            .unwrap();
+
+        fn analyze<'x>(proc: ProcRef<'x>, noise: &str) -> SemanticDb<'x> {
+            let mut db = SemanticDb::new();
+            db.intern(noise); // make the symbol tables disagree
+            let root = db.build_index(proc);
+            ResolverPass::new(root).run(&mut db);
+            EnclosureAnalysisPass::new(root).run(&mut db);
+            db
+        }
+
+        // (position, variable, name of its binder, scope of its binder)
+        fn bindings<'x>(db: &SemanticDb<'x>) -> Vec<(SourcePos, String, String, ProcRef<'x>)> {
+            let mut res: Vec<_> = db
+                .bound_positions()
+                .filter_map(|occ| match occ.binding {
+                    VarBinding::Bound(bid) => Some((
+                        occ.occurence.position,
+                        db[occ.occurence.symbol].to_string(),
+                        db[db[bid].name].to_string(),
+                        db[db[bid].scope],
+                    )),
+                    VarBinding::Free { .. } => None,
+                })
+                .collect();
+            res.sort_by_key(|(pos, name, ..)| (*pos, name.clone()));
+            res
+        }
+
+        let left = analyze(&left_ast[0], "left");
+        let right = analyze(&right_ast[0], "right");
+        let mut expected = bindings(&left);
+        expected.extend(bindings(&right));
+        let (left_pids, left_binders) = (left.pid_count(), left.next_binder());
+        let right_pids = right.pid_count();
+        let right_errors: Vec<_> = right.errors().map(|d| right[d.pid]).collect();
+        assert_eq!(right_errors.len(), 1, "q is unbound");
+
+        let mut merged = left;
+        merged.merge(right);
+
+        assert_eq!(merged.pid_count(), left_pids + right_pids);
+        assert_eq!(bindings(&merged), expected);
+        assert!(merged.has_errors());
+        assert_eq!(
+            merged.errors().map(|d| merged[d.pid]).collect::<Vec<_>>(),
+            right_errors
+        );
+
+        // scope chains of the right program resolve to its (shifted) binders
+        let send = merged
+            .find_proc(|p| matches!(p.proc, Send { .. }) && p.span.start.line == 3)
+            .map(|(pid, _)| pid)
+            .unwrap();
+        let z = merged
+            .lookup_in_scope_chain(merged.intern("z"), send)
+            .unwrap();
+        assert!(z >= left_binders);
+        assert_eq!(merged[merged[z].scope], &right_ast[0]);
+        assert_eq!(merged.lookup(&right_ast[0]), Some(PID(left_pids as u32)));
+        assert_eq!(merged.lookup(&left_ast[0]), Some(PID(0)));
+    }
+
+    #[test]
+    fn test_clone_is_independent_of_original() {
+        use crate::sem::{FactPass, ResolverPass};
+
+        let parser = RholangParser::new();
+        let ast = parser.parse("new x in { x!(1) }").unwrap();
+
+        let mut original = SemanticDb::new();
+        let root = original.build_index(&ast[0]);
+        ResolverPass::new(root).run(&mut original);
+        assert!(!original.has_errors());
+
+        let mut clone = original.clone();
+        assert_eq!(clone.pid_count(), original.pid_count());
+        assert_eq!(clone.lookup(&ast[0]), Some(root));
+
+        clone.emit_diagnostic(Diagnostic::error(root, ErrorKind::UnboundVariable, None));
+        clone.intern("what-if");
+
+        assert!(clone.has_errors());
+        assert_eq!(clone.diagnostics().len(), 1);
+        assert!(!original.has_errors());
+        assert!(original.diagnostics().is_empty());
+        assert_eq!(clone.interner.len(), original.interner.len() + 1);
+    }
+
+    #[test]
+    fn test_pid_at_span() {
+        let parser = RholangParser::new();
+        let ast = parser
+            .parse("new ch in { ch!(1) | for (x <- ch) { Nil } }")
+            .unwrap();
+
+        let mut db = SemanticDb::new();
+        let root = db.build_index(&ast[0]);
+        let (for_pid, for_proc) = db
+            .find_proc(|p| matches!(p.proc, ForComprehension { .. }))
+            .unwrap();
+
+        assert_eq!(db.pid_at_span(for_proc.span), Some(for_pid));
+        assert_eq!(db.pid_at_span(ast[0].span), Some(root));
+        // `ch) { Nil` is only contained in the `for`
+        let selection = SourceSpan {
+            start: SourcePos { line: 1, col: 31 },
+            end: SourcePos { line: 1, col: 41 },
+        };
+        assert_eq!(db.pid_at_span(selection), Some(for_pid));
+        // just `Nil` is the innermost process
+        let nil = SourceSpan {
+            start: SourcePos { line: 1, col: 38 },
+            end: SourcePos { line: 1, col: 41 },
+        };
+        assert_matches!(db[db.pid_at_span(nil).unwrap()].proc, Nil);
+
+        let elsewhere = SourceSpan::empty_at(SourcePos { line: 2, col: 1 });
+        assert_eq!(db.pid_at_span(elsewhere), None);
+    }
+
+    #[test]
+    fn test_diagnostics_by_position() {
+        let parser = RholangParser::new();
+        let ast = parser.parse("Nil |\n  Nil").unwrap();
+
+        let mut db = SemanticDb::new();
+        let root = db.build_index(&ast[0]);
+        let Par { left, right } = ast[0].proc else {
+            panic!("expected a par, got {:?}", ast[0].proc);
+        };
+        let left = db.lookup(left).unwrap();
+        let right = db.lookup(right).unwrap();
+
+        let second_line = SourcePos { line: 2, col: 3 };
+        db.warning(right, WarningKind::IfWithoutElse, Some(second_line));
+        db.error(root, ErrorKind::BadCode, None);
+        db.error(left, ErrorKind::UnboundVariable, Some(second_line));
+
+        let by_position = db.diagnostics_by_position();
+        let positions: Vec<_> = by_position.keys().copied().collect();
+        assert_eq!(positions, [SourcePos { line: 1, col: 1 }, second_line]);
+
+        let kinds: Vec<_> = by_position[&second_line]
+            .iter()
+            .map(|diagnostic| diagnostic.kind)
+            .collect();
+        assert_eq!(
+            kinds,
+            [
+                DiagnosticKind::Warning(WarningKind::IfWithoutElse),
+                DiagnosticKind::Error(ErrorKind::UnboundVariable),
+            ]
+        );
+        assert_eq!(by_position[&SourcePos { line: 1, col: 1 }][0].pid, root);
+    }
+
+    #[test]
+    fn test_unused_capture_merged_into_scope() {
+        let parser = RholangParser::new();
+        let ast = parser
+            .parse("new x, z in { for (y <- z) { for (w <- y) { w!(Nil) } } | x!(Nil) }")
+            .unwrap();
+
+        let mut db = SemanticDb::new();
+        let root = db.build_index(&ast[0]);
+        ResolverPass::new(root).run(&mut db);
+        assert_eq!(diagnostics::UnusedCapturesPass.run(&db), []);
+
+        let root_scope = db.get_scope(root).unwrap();
+        let x = db
+            .find_binder_for_symbol(db.intern("x"), root_scope)
+            .unwrap();
+        let (inner_for, _) = db
+            .iter()
+            .rfind(|(_, proc)| matches!(proc.proc, ForComprehension { .. }))
+            .unwrap();
+
+        // the nested `for` absorbs a scope that captured `x`, but nothing in it refers to `x`
+        let inner_scope = db.proc_to_scope.get_mut(inner_for).unwrap();
+        let merged = ScopeInfo::var_ref(inner_scope.binder_end(), x, SourceSpan::default());
+        inner_scope.absorb(merged);
+
+        let diagnostics = diagnostics::UnusedCapturesPass.run(&db);
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].pid, inner_for);
+        assert_eq!(
+            diagnostics[0].kind,
+            DiagnosticKind::Warning(WarningKind::UnusedCapture(x, db.intern("x")))
+        );
+    }
+
+    #[test]
+    fn test_unused_capture_after_merging_programs_at_the_same_positions() {
+        let parser = RholangParser::new();
+        let code = "new x in { for (@a <- x) { x!(a) } }";
+        let left_ast = parser.parse(code).unwrap();
+        let right_ast = parser.parse(code).unwrap();
+
+        let analyze = |proc| {
+            let mut db = SemanticDb::new();
+            let root = db.build_index(proc);
+            ResolverPass::new(root).run(&mut db);
+            assert_eq!(diagnostics::UnusedCapturesPass.run(&db), []);
+            db
+        };
+        let left = analyze(&left_ast[0]);
+        let right = analyze(&right_ast[0]);
+        let (right_for, _) = right
+            .find_proc(|p| matches!(p.proc, ForComprehension { .. }))
+            .unwrap();
+        let right_for = right[right_for];
+
+        // every occurrence of the right program collides with one of the left and is dropped,
+        // so the captures of its `for` are no longer backed by any reference
+        let mut merged = left;
+        merged.merge(right);
+
+        let diagnostics = diagnostics::UnusedCapturesPass.run(&merged);
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(merged[diagnostics[0].pid], right_for);
+        let DiagnosticKind::Warning(WarningKind::UnusedCapture(x, name)) = diagnostics[0].kind
+        else {
+            panic!("expected an unused capture, got {:?}", diagnostics[0].kind);
+        };
+        assert_eq!(name, merged.intern("x"));
+        assert_eq!(merged[merged[x].scope], &right_ast[0]);
+    }
+
     #[test]
     fn iterates_complex_nested_structure_correctly() {
         let code = r#"
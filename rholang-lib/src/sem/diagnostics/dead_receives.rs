@@ -0,0 +1,99 @@
+use std::borrow::Cow;
+use std::collections::BTreeSet;
+
+use rholang_parser::{SourcePos, ast};
+
+use crate::sem::{
+    BinderId, BinderKind, Diagnostic, DiagnosticPass, PID, Pass, SemanticDb, SymbolOccurrence,
+    VarBinding, WarningKind, diagnostics::DeadReceivePass,
+};
+
+impl Pass for DeadReceivePass {
+    fn name(&self) -> Cow<'static, str> {
+        Cow::Borrowed("DeadReceive")
+    }
+}
+
+/// A linear receive `for (... <- chan)` whose source is a plain variable
+struct Receive {
+    site: PID,
+    channel: BinderId,
+    position: SourcePos,
+}
+
+impl DiagnosticPass for DeadReceivePass {
+    fn run(&self, db: &SemanticDb) -> Vec<Diagnostic> {
+        // Occurrences of a channel used as the source of a receive. Any other
+        // occurrence (a send, passing the name along, a contract, ...) means
+        // somebody may eventually send on it, so the channel is not reported.
+        let mut receive_sites = BTreeSet::new();
+        let mut linear = Vec::new();
+
+        for (pid, proc) in db {
+            let ast::Proc::ForComprehension { receipts, .. } = proc.proc else {
+                continue;
+            };
+            for bind in receipts.iter().flat_map(|receipt| receipt.iter()) {
+                let is_linear = match bind {
+                    ast::Bind::Linear {
+                        rhs: ast::Source::Simple { .. },
+                        ..
+                    } => true,
+                    // receive-send and send-receive put a message on the channel themselves
+                    ast::Bind::Linear { .. } => continue,
+                    ast::Bind::Repeated { .. } | ast::Bind::Peek { .. } => false,
+                };
+                let ast::Name::NameVar(ast::Var::Id(id)) = bind.source_name() else {
+                    continue;
+                };
+                let occurrence = SymbolOccurrence::from_id(*id, db);
+                let Some(VarBinding::Bound(channel)) = db.binder_of(occurrence) else {
+                    continue;
+                };
+                receive_sites.insert(occurrence);
+                if is_linear {
+                    linear.push(Receive {
+                        site: pid,
+                        channel,
+                        position: id.pos,
+                    });
+                }
+            }
+        }
+
+        let mut escaping = BTreeSet::new();
+        for bound in db.bound_positions() {
+            if let VarBinding::Bound(binder) = bound.binding
+                && !receive_sites.contains(&bound.occurence)
+            {
+                escaping.insert(binder);
+            }
+        }
+
+        linear
+            .into_iter()
+            .filter(|receive| {
+                is_local_channel(db, receive.channel) && !escaping.contains(&receive.channel)
+            })
+            .map(|receive| {
+                Diagnostic::warning(
+                    receive.site,
+                    WarningKind::UnmatchedReceive {
+                        channel: receive.channel,
+                    },
+                    Some(receive.position),
+                )
+            })
+            .collect()
+    }
+}
+
+/// Only names introduced by `new` without a system URI are statically known to
+/// be private to the program.
+fn is_local_channel(db: &SemanticDb, channel: BinderId) -> bool {
+    let binder = &db[channel];
+    binder.kind == BinderKind::Name(None)
+        && db
+            .get(binder.scope)
+            .is_some_and(|scope| matches!(scope.proc, ast::Proc::New { .. }))
+}
@@ -0,0 +1,164 @@
+use std::borrow::Cow;
+
+use rholang_parser::ast::{self, AnnProc};
+
+use crate::sem::{
+    Diagnostic, DiagnosticPass, PID, Pass, SemanticDb, WarningKind, diagnostics::DuplicateKeysPass,
+};
+
+impl Pass for DuplicateKeysPass {
+    fn name(&self) -> Cow<'static, str> {
+        Cow::Borrowed("DuplicateKeys")
+    }
+}
+
+impl DiagnosticPass for DuplicateKeysPass {
+    fn run(&self, db: &SemanticDb) -> Vec<Diagnostic> {
+        let mut result = Vec::new();
+
+        for (pid, proc) in db {
+            match proc.proc {
+                ast::Proc::Collection(ast::Collection::Map { elements, .. }) => {
+                    let keys: Vec<AnnProc> = elements.iter().map(|(key, _)| *key).collect();
+                    report_duplicates(pid, &keys, &mut result, |span| {
+                        WarningKind::DuplicateMapKey { span }
+                    });
+                }
+                ast::Proc::Collection(ast::Collection::Set { elements, .. }) => {
+                    report_duplicates(pid, elements, &mut result, |span| {
+                        WarningKind::DuplicateSetElement { span }
+                    });
+                }
+                _ => {}
+            }
+        }
+
+        result
+    }
+}
+
+/// Warns at every element structurally equal to an earlier one; the warning
+/// carries the span of the first occurrence
+fn report_duplicates(
+    pid: PID,
+    elements: &[AnnProc],
+    result: &mut Vec<Diagnostic>,
+    kind: impl Fn(rholang_parser::SourceSpan) -> WarningKind,
+) {
+    for (i, element) in elements.iter().enumerate() {
+        if let Some(original) = elements[..i]
+            .iter()
+            .find(|earlier| structurally_eq(earlier, element))
+        {
+            result.push(Diagnostic::warning(
+                pid,
+                kind(original.span),
+                Some(element.span.start),
+            ));
+        }
+    }
+}
+
+/// Equality of two constant processes, ignoring their source spans.
+///
+/// Only literals, and collections and expressions built from them, are
+/// compared; anything else (variables, names, sends, ...) may take different
+/// values at runtime and is never considered equal.
+pub(crate) fn structurally_eq<'a>(left: &AnnProc<'a>, right: &AnnProc<'a>) -> bool {
+    use ast::Proc;
+
+    match (left.proc, right.proc) {
+        (Proc::Nil, Proc::Nil) | (Proc::Unit, Proc::Unit) => true,
+        (Proc::BoolLiteral(_), _)
+        | (Proc::LongLiteral(_), _)
+        | (Proc::SignedIntLiteral { .. }, _)
+        | (Proc::UnsignedIntLiteral { .. }, _)
+        | (Proc::BigIntLiteral(_), _)
+        | (Proc::BigRatLiteral(_), _)
+        | (Proc::FloatLiteral { .. }, _)
+        | (Proc::FixedPointLiteral { .. }, _)
+        | (Proc::StringLiteral(_), _)
+        | (Proc::UriLiteral(_), _)
+        | (Proc::SimpleType(_), _) => left.proc == right.proc,
+        (Proc::Collection(left), Proc::Collection(right)) => collections_eq(left, right),
+        (
+            Proc::UnaryExp { op, arg },
+            Proc::UnaryExp {
+                op: other_op,
+                arg: other_arg,
+            },
+        ) => op == other_op && structurally_eq(arg, other_arg),
+        (
+            Proc::BinaryExp { op, left, right },
+            Proc::BinaryExp {
+                op: other_op,
+                left: other_left,
+                right: other_right,
+            },
+        ) => {
+            op == other_op
+                && structurally_eq(left, other_left)
+                && structurally_eq(right, other_right)
+        }
+        _ => false,
+    }
+}
+
+fn collections_eq<'a>(left: &ast::Collection<'a>, right: &ast::Collection<'a>) -> bool {
+    use ast::Collection;
+
+    match (left, right) {
+        (
+            Collection::List {
+                elements: left,
+                remainder: None,
+            },
+            Collection::List {
+                elements: right,
+                remainder: None,
+            },
+        )
+        | (
+            Collection::Set {
+                elements: left,
+                remainder: None,
+            },
+            Collection::Set {
+                elements: right,
+                remainder: None,
+            },
+        )
+        | (
+            Collection::PathMap {
+                elements: left,
+                remainder: None,
+            },
+            Collection::PathMap {
+                elements: right,
+                remainder: None,
+            },
+        )
+        | (Collection::Tuple(left), Collection::Tuple(right)) => all_eq(left, right),
+        (
+            Collection::Map {
+                elements: left,
+                remainder: None,
+            },
+            Collection::Map {
+                elements: right,
+                remainder: None,
+            },
+        ) => {
+            left.len() == right.len()
+                && left
+                    .iter()
+                    .zip(right)
+                    .all(|((lk, lv), (rk, rv))| structurally_eq(lk, rk) && structurally_eq(lv, rv))
+        }
+        _ => false,
+    }
+}
+
+fn all_eq<'a>(left: &[AnnProc<'a>], right: &[AnnProc<'a>]) -> bool {
+    left.len() == right.len() && left.iter().zip(right).all(|(l, r)| structurally_eq(l, r))
+}
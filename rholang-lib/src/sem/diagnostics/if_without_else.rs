@@ -0,0 +1,24 @@
+use std::borrow::Cow;
+
+use rholang_parser::ast;
+
+use crate::sem::{
+    Diagnostic, DiagnosticPass, Pass, SemanticDb, WarningKind, diagnostics::IfWithoutElsePass,
+};
+
+impl Pass for IfWithoutElsePass {
+    fn name(&self) -> Cow<'static, str> {
+        Cow::Borrowed("IfWithoutElse")
+    }
+}
+
+impl DiagnosticPass for IfWithoutElsePass {
+    fn run(&self, db: &SemanticDb) -> Vec<Diagnostic> {
+        db.into_iter()
+            .filter(|(_, proc)| matches!(proc.proc, ast::Proc::IfThenElse { if_false: None, .. }))
+            .map(|(pid, proc)| {
+                Diagnostic::warning(pid, WarningKind::IfWithoutElse, Some(proc.span.start))
+            })
+            .collect()
+    }
+}
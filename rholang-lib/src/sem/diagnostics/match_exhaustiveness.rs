@@ -0,0 +1,47 @@
+use std::borrow::Cow;
+
+use rholang_parser::ast::{self, Case};
+
+use crate::sem::{
+    Diagnostic, DiagnosticPass, Pass, SemanticDb, WarningKind, diagnostics::MatchExhaustivenessPass,
+};
+
+impl Pass for MatchExhaustivenessPass {
+    fn name(&self) -> Cow<'static, str> {
+        Cow::Borrowed("MatchExhaustiveness")
+    }
+}
+
+impl DiagnosticPass for MatchExhaustivenessPass {
+    fn run(&self, db: &SemanticDb) -> Vec<Diagnostic> {
+        db.into_iter()
+            .filter(|(_, proc)| match proc.proc {
+                ast::Proc::Match { cases, .. } => !is_exhaustive(cases),
+                _ => false,
+            })
+            .map(|(pid, proc)| {
+                Diagnostic::warning(
+                    pid,
+                    WarningKind::NonExhaustiveMatch { span: proc.span },
+                    Some(proc.span.start),
+                )
+            })
+            .collect()
+    }
+}
+
+/// Whether the cases match every value: one of them catches everything, or they
+/// cover both booleans. Guarded cases may fall through, so they never count.
+fn is_exhaustive(cases: &[Case]) -> bool {
+    let patterns = || {
+        cases
+            .iter()
+            .filter(|case| case.guard.is_none())
+            .map(|case| case.pattern.proc)
+    };
+    let covers_bool =
+        |b: bool| patterns().any(|p| matches!(p, ast::Proc::BoolLiteral(v) if *v == b));
+
+    patterns().any(|p| matches!(p, ast::Proc::ProcVar(_)))
+        || (covers_bool(true) && covers_bool(false))
+}
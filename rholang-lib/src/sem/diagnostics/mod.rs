@@ -1,7 +1,41 @@
+mod dead_receives;
 mod disjunctions;
+mod duplicate_keys;
+mod if_without_else;
+mod match_exhaustiveness;
 mod numeric_types;
+mod strict_kinds;
+mod unused_captures;
 mod unused_vars;
 
+/// Heuristic warning for linear receives on a locally `new`-bound channel that
+/// is never sent on (or otherwise handed out), and so can never fire.
+#[derive(Default)]
+pub struct DeadReceivePass;
+#[derive(Default)]
 pub struct DisjunctionConsistencyCheck;
+/// Warns about structurally equal constant keys in a map literal and repeated
+/// constant elements in a set literal.
+#[derive(Default)]
+pub struct DuplicateKeysPass;
+/// Opt-in style lint for an `if` without an `else` branch, which evaluates to
+/// `Nil` when its condition is false.
+#[derive(Default)]
+pub struct IfWithoutElsePass;
+/// Opt-in lint for a `match` that may not match its value: it has no catch-all
+/// case (`_` or a variable) and its literal cases are not both booleans.
+#[derive(Default)]
+pub struct MatchExhaustivenessPass;
+#[derive(Default)]
 pub struct NumericTypeConsistencyCheck;
+/// Opt-in audit of name/process kind mismatches: restates each
+/// `NameInProcPosition` and `ProcInNamePosition` error reported by the resolver
+/// as an `ImplicitEval` or `ImplicitQuote` warning at the same position.
+#[derive(Default)]
+pub struct StrictKindsPass;
+/// Warns about outer binders recorded as captured by a scope that never
+/// references them.
+#[derive(Default)]
+pub struct UnusedCapturesPass;
+#[derive(Default)]
 pub struct UnusedVarsPass;
@@ -0,0 +1,35 @@
+use std::borrow::Cow;
+
+use crate::sem::{
+    Diagnostic, DiagnosticKind, DiagnosticPass, ErrorKind, Pass, SemanticDb, WarningKind,
+    diagnostics::StrictKindsPass,
+};
+
+impl Pass for StrictKindsPass {
+    fn name(&self) -> Cow<'static, str> {
+        Cow::Borrowed("StrictKinds")
+    }
+}
+
+impl DiagnosticPass for StrictKindsPass {
+    fn run(&self, db: &SemanticDb) -> Vec<Diagnostic> {
+        db.errors()
+            .filter_map(|diagnostic| {
+                let warning = match diagnostic.kind {
+                    DiagnosticKind::Error(ErrorKind::NameInProcPosition(binder, symbol)) => {
+                        WarningKind::ImplicitEval(binder, symbol)
+                    }
+                    DiagnosticKind::Error(ErrorKind::ProcInNamePosition(binder, symbol)) => {
+                        WarningKind::ImplicitQuote(binder, symbol)
+                    }
+                    _ => return None,
+                };
+                Some(Diagnostic::warning(
+                    diagnostic.pid,
+                    warning,
+                    diagnostic.exact_position,
+                ))
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,39 @@
+use crate::sem::{Diagnostic, DiagnosticPass, Pass, SemanticDb, VarBinding, WarningKind};
+use std::borrow::Cow;
+
+impl Pass for super::UnusedCapturesPass {
+    fn name(&self) -> Cow<'static, str> {
+        Cow::Borrowed("UnusedCapture")
+    }
+}
+
+impl DiagnosticPass for super::UnusedCapturesPass {
+    fn run(&self, db: &SemanticDb) -> Vec<Diagnostic> {
+        let mut result = Vec::new();
+        for (pid, scope) in db.scopes_full() {
+            let mut captures = scope.captures().peekable();
+            if captures.peek().is_none() {
+                continue;
+            }
+            // A capture is only genuine if some variable inside the scope still resolves to
+            // it. Anything else was merged in by `absorb` without a reference to back it up.
+            let referenced: Vec<_> = db
+                .bound_in_scope(scope)
+                .filter_map(|bound| match bound.binding {
+                    VarBinding::Bound(bid) => Some(bid),
+                    VarBinding::Free { .. } => None,
+                })
+                .collect();
+            result.extend(captures.filter(|bid| !referenced.contains(bid)).map(|bid| {
+                let binder = &db[bid];
+                Diagnostic::warning(
+                    pid,
+                    WarningKind::UnusedCapture(bid, binder.name),
+                    Some(binder.source_position),
+                )
+            }));
+        }
+
+        result
+    }
+}
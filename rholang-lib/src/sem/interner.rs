@@ -9,18 +9,33 @@ pub(super) struct Interner {
     rev: RwLock<IndexSet<String, ahash::RandomState>>,
 }
 
+impl Clone for Interner {
+    fn clone(&self) -> Self {
+        Self {
+            rev: RwLock::new(self.rev.read().clone()),
+        }
+    }
+}
+
 const DEFAULT_INTERNER_CAPACITY: usize = 32;
 
 impl Interner {
-    pub(super) fn new() -> Self {
+    pub(super) fn with_hasher(hasher: ahash::RandomState) -> Self {
         Self {
             rev: RwLock::new(IndexSet::with_capacity_and_hasher(
                 DEFAULT_INTERNER_CAPACITY,
-                super::stable_hasher(),
+                hasher,
             )),
         }
     }
 
+    /// Forgets every interned string, so the next symbol handed out is `Symbol(0)` again.
+    pub(super) fn clear(&mut self) {
+        let rev = self.rev.get_mut();
+        rev.clear();
+        rev.shrink_to(DEFAULT_INTERNER_CAPACITY);
+    }
+
     pub(super) fn intern(&self, name: &str) -> Symbol {
         // First try immutable borrow to check for existing symbol
         if let Some(sym) = self.rev.read().get_index_of(name) {
@@ -37,6 +52,16 @@ impl Interner {
         }
     }
 
+    #[cfg(test)]
+    pub(super) fn hasher(&self) -> ahash::RandomState {
+        self.rev.read().hasher().clone()
+    }
+
+    /// Number of interned strings; symbols are `0..len`.
+    pub(super) fn len(&self) -> usize {
+        self.rev.read().len()
+    }
+
     pub(super) fn resolve(&self, sym: Symbol) -> Option<&str> {
         let rev = self.rev.read();
         rev.get_index(sym.0 as usize).map(|s| unsafe {
@@ -15,6 +15,7 @@ mod enclosure_analysis;
 mod interner;
 pub mod pipeline;
 mod resolver;
+pub mod uris;
 
 /// A generic semantic analysis pass.
 ///
@@ -345,6 +346,52 @@ impl ScopeInfo {
         Free::new(&self.free, self.binder_start.0)
     }
 
+    /// Renders the scope for debugging: one line per binder, by name, marked as used or unused
+    /// and free, followed by the outer binders it captures.
+    pub fn describe(&self, db: &SemanticDb) -> String {
+        use std::fmt::Write;
+
+        let name_of = |bid: BinderId| {
+            db.get_binder(bid)
+                .and_then(|binder| db.resolve_symbol(binder.name))
+                .unwrap_or("?")
+        };
+
+        let mut out = format!("scope {} with {} binder(s)", self.span, self.num_binders);
+        for (i, bid) in self.binder_range().enumerate() {
+            let usage = if self.uses[i] { "used" } else { "unused" };
+            let _ = write!(out, "\n  {} #{bid}: {usage}", name_of(bid));
+            if self.free[i] {
+                out.push_str(", free");
+            }
+        }
+        if !self.captures.is_clear() {
+            let captured: Vec<String> = self
+                .captures()
+                .map(|bid| format!("{} #{bid}", name_of(bid)))
+                .collect();
+            let _ = write!(out, "\n  captures: {}", captured.join(", "));
+        }
+
+        out
+    }
+
+    /// Shifts every binder id referenced by this scope (its own binders and its
+    /// captures) by `offset`.
+    pub(super) fn rebased(self, offset: u32) -> Self {
+        let binder_start = self.binder_start + offset;
+        let mut captures = BitSet::with_capacity(self.captures.len() + offset as usize);
+        for captured in self.captures.ones() {
+            captures.insert(captured + offset as usize);
+        }
+
+        Self {
+            binder_start,
+            captures,
+            ..self
+        }
+    }
+
     pub fn absorb(&mut self, rhs: ScopeInfo) {
         assert_eq!(
             self.binder_end(),
@@ -428,6 +475,7 @@ pub struct SemanticDb<'a> {
     interner: interner::Interner,                                   // name <-> Symbol
 
     diagnostics: Vec<Diagnostic>,
+    diagnostic_filter: DiagnosticFilter,
     has_errors: bool,
 
     binder_is_name: BitVec,                // fast BinderId -> name or proc
@@ -436,6 +484,15 @@ pub struct SemanticDb<'a> {
     enclosing_pids: Vec<PID>,              // the enclosing scope for a given process
 
     var_to_binder: BTreeMap<SymbolOccurrence, VarBinding>, // var -> where it is bound
+    shadowings: Vec<(BinderId, BinderId)>,                 // (inner, outer) binder pairs
+}
+
+/// A binder that hides a binder of the same name from an enclosing scope,
+/// see [`SemanticDb::shadowing_report`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Shadowing {
+    pub inner: SymbolOccurrence,
+    pub outer: SymbolOccurrence,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -470,14 +527,107 @@ pub enum DiagnosticKind {
     Error(ErrorKind),
 }
 
+impl DiagnosticKind {
+    pub fn severity(&self) -> Severity {
+        match self {
+            DiagnosticKind::Info(_) => Severity::Info,
+            DiagnosticKind::Warning(_) => Severity::Warning,
+            DiagnosticKind::Error(_) => Severity::Error,
+        }
+    }
+}
+
+/// Severity of a [`Diagnostic`], ordered from least to most severe
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum Severity {
+    #[default]
+    Info,
+    Warning,
+    Error,
+}
+
+/// Decides which diagnostics a [`SemanticDb`] keeps.
+///
+/// Diagnostics below `min_severity` are discarded, and once `max_count` diagnostics
+/// have been collected any further ones are dropped as well. With `deny_warnings`, a
+/// warning fails the analysis like an error does. The default keeps everything and
+/// lets warnings pass.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct DiagnosticFilter {
+    pub min_severity: Severity,
+    pub max_count: Option<usize>,
+    pub deny_warnings: bool,
+}
+
+impl DiagnosticFilter {
+    /// Returns `true` if the diagnostic is severe enough to be kept
+    pub fn accepts(&self, diagnostic: &Diagnostic) -> bool {
+        diagnostic.kind.severity() >= self.min_severity
+    }
+
+    /// Returns `true` if the diagnostic fails the analysis: an error, or a warning
+    /// when warnings are denied
+    pub fn is_fatal(&self, diagnostic: &Diagnostic) -> bool {
+        match diagnostic.kind {
+            DiagnosticKind::Error(_) => true,
+            DiagnosticKind::Warning(_) => self.deny_warnings,
+            DiagnosticKind::Info(_) => false,
+        }
+    }
+
+    /// Returns `true` if `count` collected diagnostics exhaust the cap
+    pub fn is_full(&self, count: usize) -> bool {
+        self.max_count.is_some_and(|max| count >= max)
+    }
+
+    /// The stricter of two filters in every respect: the higher minimum severity, the
+    /// lower cap, and denied warnings if either denies them
+    pub fn merge(self, other: DiagnosticFilter) -> DiagnosticFilter {
+        DiagnosticFilter {
+            min_severity: self.min_severity.max(other.min_severity),
+            max_count: match (self.max_count, other.max_count) {
+                (Some(a), Some(b)) => Some(a.min(b)),
+                (a, b) => a.or(b),
+            },
+            deny_warnings: self.deny_warnings || other.deny_warnings,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum InfoKind {}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum WarningKind {
-    ShadowedVar { original: SymbolOccurrence },
+    ShadowedVar {
+        original: SymbolOccurrence,
+    },
     UnusedVariable(BinderId, Symbol),
-    TopLevelPatternExpr { span: SourceSpan },
+    UnusedCapture(BinderId, Symbol),
+    TopLevelPatternExpr {
+        span: SourceSpan,
+    },
+    UnmatchedReceive {
+        channel: BinderId,
+    },
+    UnknownUri {
+        uri: Symbol,
+    },
+    DuplicateMapKey {
+        span: SourceSpan,
+    },
+    DuplicateSetElement {
+        span: SourceSpan,
+    },
+    IfWithoutElse,
+    /// A name binder used as a process, which the compiler evaluates implicitly
+    ImplicitEval(BinderId, Symbol),
+    /// A process binder used as a name, which would have to be quoted implicitly
+    ImplicitQuote(BinderId, Symbol),
+    /// A `match` without a catch-all case whose cases may not cover every value
+    NonExhaustiveMatch {
+        span: SourceSpan,
+    },
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -534,10 +684,6 @@ const SEED1: u64 = 0x0BAD_F00D_F00D_BAAD;
 const SEED2: u64 = 0xCAFEBABE_DEADC0DE;
 const SEED3: u64 = 0x1234_5678_9ABC_DEF0;
 
-fn stable_hasher() -> ahash::RandomState {
-    ahash::RandomState::with_seeds(SEED0, SEED1, SEED2, SEED3)
-}
-
 pub struct Free<'a> {
     inner: bitvec::slice::IterOnes<'a, usize, Lsb0>,
     binder_start: u32,
@@ -837,7 +983,8 @@ mod tests {
             assert_eq!(
                 expected.num_binders(),
                 expected_binders,
-                "expect::scope {expected:#?} with {expected_binders} binder(s)"
+                "expect::scope {} with {expected_binders} binder(s)",
+                expected.describe(db)
             );
 
             expected
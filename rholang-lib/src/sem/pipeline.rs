@@ -1,15 +1,67 @@
-use super::{DiagnosticPass, FactPass, Pass, SemanticDb};
+use super::{DiagnosticFilter, DiagnosticPass, FactPass, Pass, SemanticDb, Severity};
 use as_any::Downcast;
 use nonempty_collections::NEVec;
-use std::{borrow::Cow, fmt, num::NonZeroUsize};
+use std::{
+    borrow::Cow,
+    fmt,
+    num::NonZeroUsize,
+    sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+    },
+};
+
+/// A cheaply clonable flag used to abandon a running [`Pipeline`].
+///
+/// All clones share the same flag, so one clone can be handed to the analysis and another
+/// kept by whoever decides the analysis has become stale.
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken(Arc<AtomicBool>);
+
+impl CancellationToken {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Requests cancellation; every clone of this token observes it
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Release);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Acquire)
+    }
+}
 
 pub struct Pipeline {
     passes: Vec<Box<dyn Pass>>,
+    diagnostic_filter: DiagnosticFilter,
 }
 
 impl Pipeline {
     pub fn new() -> Self {
-        Self { passes: Vec::new() }
+        Self {
+            passes: Vec::new(),
+            diagnostic_filter: DiagnosticFilter::default(),
+        }
+    }
+
+    /// Only collect diagnostics of at least the given severity
+    pub fn with_severity(mut self, min: Severity) -> Self {
+        self.diagnostic_filter.min_severity = min;
+        self
+    }
+
+    /// Stop collecting after `n` diagnostics; the remaining diagnostic passes are skipped
+    pub fn with_max_diagnostics(mut self, n: usize) -> Self {
+        self.diagnostic_filter.max_count = Some(n);
+        self
+    }
+
+    /// Let warnings fail the analysis: any warning marks the database as having errors
+    pub fn with_deny_warnings(mut self, deny: bool) -> Self {
+        self.diagnostic_filter.deny_warnings = deny;
+        self
     }
 
     pub fn add_fact<F: FactPass>(mut self, pass: F) -> Self {
@@ -37,26 +89,55 @@ impl Pipeline {
     }
 
     pub async fn run(&self, db: &mut super::SemanticDb<'_>) {
+        self.run_cancellable(db, CancellationToken::new()).await
+    }
+
+    /// Like [`Pipeline::run`], but stops as soon as `token` is cancelled.
+    ///
+    /// The token is checked between passes and before each pass of a diagnostic group. Once
+    /// cancelled, no further passes are started and the diagnostics collected so far are
+    /// pushed to `db`.
+    ///
+    /// The pipeline's diagnostic filter is [merged](DiagnosticFilter::merge) into the one
+    /// already set on `db`, and the result stays on `db` after the run.
+    pub async fn run_cancellable(&self, db: &mut super::SemanticDb<'_>, token: CancellationToken) {
+        let filter = db.diagnostic_filter().merge(self.diagnostic_filter);
+        db.set_diagnostic_filter(filter);
+
         let mut all_diags = Vec::new();
+        // diagnostics in `all_diags` that will survive the filter
+        let mut pending = 0;
 
         for pass in &self.passes {
+            if token.is_cancelled() {
+                break;
+            }
+
             // Try FactPass
             if let Some(fact) = pass.as_any().downcast_ref::<FactPassWrapper>() {
                 fact.run(db);
                 continue;
             }
 
+            let full = filter.is_full(db.diagnostics().len() + pending);
+
             // Try DiagnosticGroup
             if let Some(diag_group) = pass.as_any().downcast_ref::<DiagnosticGroup>() {
-                let diags = diag_group.run_async(db).await;
-                all_diags.extend(diags);
+                if !full {
+                    let diags = diag_group.run_async(db, &token).await;
+                    pending += diags.iter().filter(|d| filter.accepts(d)).count();
+                    all_diags.extend(diags);
+                }
                 continue;
             }
 
             // Try standalone diagnostic
             if let Some(diag) = pass.as_any().downcast_ref::<DiagnosticPassWrapper>() {
-                let diags = diag.run(db);
-                all_diags.extend(diags);
+                if !full {
+                    let diags = diag.run(db);
+                    pending += diags.iter().filter(|d| filter.accepts(d)).count();
+                    all_diags.extend(diags);
+                }
                 continue;
             }
 
@@ -66,51 +147,76 @@ impl Pipeline {
         db.push_diagnostics(all_diags);
     }
 
-    /// Produces a tree-like textual description of all passes.
+    /// Every pass in execution order.
+    ///
+    /// The pipeline runs in stages, one after another. A stage is a single fact pass, a single
+    /// ungrouped diagnostic pass, or a group of consecutive diagnostic passes that run in
+    /// parallel. Each pass depends on all fact passes of the stages before it.
+    pub fn passes(&self) -> Vec<PassDescriptor> {
+        let mut out = Vec::new();
+        let mut facts: Vec<Cow<'static, str>> = Vec::new();
+
+        for (stage, pass) in self.passes.iter().enumerate() {
+            let any = pass.as_any();
+            let mut describe = |name, kind, parallel| {
+                out.push(PassDescriptor {
+                    name,
+                    kind,
+                    stage,
+                    parallel,
+                    depends_on: facts.clone(),
+                })
+            };
+
+            if let Some(group) = any.downcast_ref::<DiagnosticGroup>() {
+                let parallel = group.passes.len() > NonZeroUsize::MIN;
+                for diag in &group.passes {
+                    describe(diag.name(), PassKind::Diagnostic, parallel);
+                }
+            } else if any.is::<FactPassWrapper>() {
+                describe(pass.name(), PassKind::Fact, false);
+                facts.push(pass.name());
+            } else {
+                describe(pass.name(), PassKind::Diagnostic, false);
+            }
+        }
+
+        out
+    }
+
+    /// Produces a textual description of the execution plan: the stages in order, what kind
+    /// each pass is, and which fact passes it waits for.
     pub fn describe(&self) -> String {
         use std::fmt::Write;
 
+        let passes = self.passes();
         let mut out = String::new();
-        let mut iter = self.passes.iter().peekable();
-
-        // helper: render a diagnostic group
-        fn render_group(out: &mut String, group: &DiagnosticGroup, indent: &str) {
-            let group_iter = group.passes.iter();
-            let group_len = group_iter.len();
-            for (i, diag) in group_iter.enumerate() {
-                let connector = if i + 1 == group_len {
-                    "└─"
-                } else {
-                    "├─"
-                };
-                let _ = writeln!(out, "{indent}{connector} {:<22} (Diagnostic)", diag.name());
-            }
-        }
-
-        while let Some(pass) = iter.next() {
-            // Try to downcast to a FactPass
-            if let Some(fact) = pass.as_any().downcast_ref::<FactPassWrapper>() {
-                let _ = writeln!(out, "{:<25} (Fact)", fact.name());
-
-                // If the next pass is a diagnostic group, display its members under this fact
-                if let Some(next) = iter.peek()
-                    && let Some(group) = next.as_any().downcast_ref::<DiagnosticGroup>()
-                {
-                    render_group(&mut out, group, " ");
-                    iter.next(); // consume group
+        for stage in passes.chunk_by(|a, b| a.stage == b.stage) {
+            let first = &stage[0];
+            let after = if first.depends_on.is_empty() {
+                String::new()
+            } else {
+                format!(", after {}", first.depends_on.join(", "))
+            };
+
+            if first.parallel {
+                let _ = writeln!(out, "{}. in parallel{after}", first.stage + 1);
+                for (i, pass) in stage.iter().enumerate() {
+                    let connector = if i + 1 == stage.len() {
+                        "└─"
+                    } else {
+                        "├─"
+                    };
+                    let _ = writeln!(out, "   {connector} {:<22} ({})", pass.name, pass.kind);
                 }
-            }
-            // Diagnostic group that stands on its own
-            else if let Some(group) = pass.as_any().downcast_ref::<DiagnosticGroup>() {
-                render_group(&mut out, group, "");
-            }
-            // Single ungrouped diagnostic pass
-            else if let Some(diag) = pass.as_any().downcast_ref::<DiagnosticPassWrapper>() {
-                let _ = writeln!(out, "{:<25} (Diagnostic)", diag.name());
-            }
-            // Unknown or unrecognized type
-            else {
-                let _ = writeln!(out, "{:<25} (Unknown)", pass.name());
+            } else {
+                let _ = writeln!(
+                    out,
+                    "{}. {:<22} ({}){after}",
+                    first.stage + 1,
+                    first.name,
+                    first.kind
+                );
             }
         }
 
@@ -118,6 +224,35 @@ impl Pipeline {
     }
 }
 
+/// Whether a pass computes facts or reports diagnostics
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PassKind {
+    Fact,
+    Diagnostic,
+}
+
+impl fmt::Display for PassKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PassKind::Fact => f.write_str("Fact"),
+            PassKind::Diagnostic => f.write_str("Diagnostic"),
+        }
+    }
+}
+
+/// A pass of a [`Pipeline`] and its place in the execution plan, see [`Pipeline::passes`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PassDescriptor {
+    pub name: Cow<'static, str>,
+    pub kind: PassKind,
+    /// Index of the stage the pass runs in
+    pub stage: usize,
+    /// Whether the pass runs concurrently with the other passes of its stage
+    pub parallel: bool,
+    /// Names of the fact passes that have run before the pass starts
+    pub depends_on: Vec<Cow<'static, str>>,
+}
+
 impl fmt::Display for Pipeline {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.write_str(&self.describe())
@@ -148,7 +283,11 @@ impl DiagnosticGroup {
 
     /// Run all diagnostics concurrently (native) or sequentially (wasm)
     #[cfg(not(target_arch = "wasm32"))]
-    async fn run_async<'d>(&self, db: &SemanticDb<'d>) -> Vec<super::Diagnostic> {
+    async fn run_async<'d>(
+        &self,
+        db: &SemanticDb<'d>,
+        token: &CancellationToken,
+    ) -> Vec<super::Diagnostic> {
         if self.passes.len() == NonZeroUsize::MIN {
             return self.passes.first().run(db);
         }
@@ -156,7 +295,13 @@ impl DiagnosticGroup {
         let mut all = Vec::new();
         let (_, results) = async_scoped::TokioScope::scope_and_block(|scope| {
             for pass in &self.passes {
-                scope.spawn(async { pass.run(db) });
+                scope.spawn(async {
+                    if token.is_cancelled() {
+                        Vec::new()
+                    } else {
+                        pass.run(db)
+                    }
+                });
             }
         });
 
@@ -172,10 +317,17 @@ impl DiagnosticGroup {
     }
 
     #[cfg(target_arch = "wasm32")]
-    async fn run_async<'d>(&self, db: &SemanticDb<'d>) -> Vec<super::Diagnostic> {
+    async fn run_async<'d>(
+        &self,
+        db: &SemanticDb<'d>,
+        token: &CancellationToken,
+    ) -> Vec<super::Diagnostic> {
         // No multi-threading on wasm; run sequentially
         let mut all = Vec::new();
         for pass in &self.passes {
+            if token.is_cancelled() {
+                break;
+            }
             all.extend(pass.run(db));
         }
         all
@@ -203,7 +355,7 @@ impl Pass for DiagnosticGroup {
 #[cfg(not(target_arch = "wasm32"))]
 impl DiagnosticPass for DiagnosticGroup {
     fn run(&self, db: &SemanticDb) -> Vec<super::Diagnostic> {
-        tokio::runtime::Handle::current().block_on(self.run_async(db))
+        tokio::runtime::Handle::current().block_on(self.run_async(db, &CancellationToken::new()))
     }
 }
 
@@ -266,3 +418,194 @@ impl DiagnosticPass for DiagnosticPassWrapper {
         self.pass.run(db)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::AtomicUsize;
+
+    use super::*;
+    use crate::sem::{Diagnostic, PID, WarningKind};
+
+    /// Counts its runs and cancels `token` once it has run
+    struct CancelAfter {
+        token: CancellationToken,
+        runs: Arc<AtomicUsize>,
+    }
+
+    impl Pass for CancelAfter {
+        fn name(&self) -> Cow<'static, str> {
+            Cow::Borrowed("CancelAfter")
+        }
+    }
+
+    impl FactPass for CancelAfter {
+        fn run(&self, _db: &mut SemanticDb) {
+            self.runs.fetch_add(1, Ordering::SeqCst);
+            self.token.cancel();
+        }
+    }
+
+    struct CountRuns(Arc<AtomicUsize>);
+
+    impl Pass for CountRuns {
+        fn name(&self) -> Cow<'static, str> {
+            Cow::Borrowed("CountRuns")
+        }
+    }
+
+    impl DiagnosticPass for CountRuns {
+        fn run(&self, _db: &SemanticDb) -> Vec<Diagnostic> {
+            self.0.fetch_add(1, Ordering::SeqCst);
+            Vec::new()
+        }
+    }
+
+    #[test]
+    fn test_cancelled_pipeline_skips_later_passes() {
+        let token = CancellationToken::new();
+        let first = Arc::new(AtomicUsize::new(0));
+        let later = Arc::new(AtomicUsize::new(0));
+
+        let pipeline = Pipeline::new()
+            .add_fact(CancelAfter {
+                token: token.clone(),
+                runs: first.clone(),
+            })
+            .add_diagnostic(CountRuns(later.clone()))
+            .add_diagnostic(CountRuns(later.clone()))
+            .add_diagnostic_ungrouped(CountRuns(later.clone()));
+
+        let mut db = SemanticDb::new();
+        tokio::runtime::Runtime::new()
+            .unwrap()
+            .block_on(pipeline.run_cancellable(&mut db, token.clone()));
+
+        assert!(token.is_cancelled());
+        assert_eq!(first.load(Ordering::SeqCst), 1);
+        assert_eq!(later.load(Ordering::SeqCst), 0);
+    }
+
+    #[test]
+    fn test_uncancelled_pipeline_runs_all_passes() {
+        let runs = Arc::new(AtomicUsize::new(0));
+        let pipeline = Pipeline::new()
+            .add_diagnostic(CountRuns(runs.clone()))
+            .add_diagnostic(CountRuns(runs.clone()))
+            .add_diagnostic_ungrouped(CountRuns(runs.clone()));
+
+        let mut db = SemanticDb::new();
+        tokio::runtime::Runtime::new()
+            .unwrap()
+            .block_on(pipeline.run_cancellable(&mut db, CancellationToken::new()));
+
+        assert_eq!(runs.load(Ordering::SeqCst), 3);
+    }
+
+    struct Warns(usize);
+
+    impl Pass for Warns {
+        fn name(&self) -> Cow<'static, str> {
+            Cow::Borrowed("Warns")
+        }
+    }
+
+    impl DiagnosticPass for Warns {
+        fn run(&self, _db: &SemanticDb) -> Vec<Diagnostic> {
+            let warning = Diagnostic::warning(PID(0), WarningKind::IfWithoutElse, None);
+            vec![warning; self.0]
+        }
+    }
+
+    #[test]
+    fn test_pipeline_filter_is_merged_with_the_db_filter() {
+        use crate::sem::DiagnosticFilter;
+
+        let pipeline = Pipeline::new()
+            .with_max_diagnostics(2)
+            .add_diagnostic(Warns(3));
+
+        let mut db = SemanticDb::new();
+        db.set_diagnostic_filter(DiagnosticFilter {
+            max_count: Some(5),
+            deny_warnings: true,
+            ..DiagnosticFilter::default()
+        });
+        tokio::runtime::Runtime::new()
+            .unwrap()
+            .block_on(pipeline.run(&mut db));
+
+        assert_eq!(db.diagnostics().len(), 2);
+        assert!(db.has_errors());
+        assert_eq!(
+            db.diagnostic_filter(),
+            DiagnosticFilter {
+                min_severity: Severity::Info,
+                max_count: Some(2),
+                deny_warnings: true,
+            }
+        );
+    }
+
+    #[test]
+    fn test_passes_describe_the_execution_plan() {
+        use crate::sem::{ResolverPass, diagnostics::UnusedVarsPass};
+
+        let runs = Arc::new(AtomicUsize::new(0));
+        let pipeline = Pipeline::new()
+            .add_fact(ResolverPass::new(PID(0)))
+            .add_diagnostic(UnusedVarsPass)
+            .add_diagnostic(CountRuns(runs.clone()))
+            .add_diagnostic_ungrouped(CountRuns(runs));
+
+        let summary: Vec<_> = pipeline
+            .passes()
+            .into_iter()
+            .map(|pass| {
+                (
+                    pass.name,
+                    pass.kind,
+                    pass.stage,
+                    pass.parallel,
+                    pass.depends_on,
+                )
+            })
+            .collect();
+        let resolver = Cow::Borrowed("ResolverPass(0)");
+        assert_eq!(
+            summary,
+            vec![
+                (resolver.clone(), PassKind::Fact, 0, false, vec![]),
+                (
+                    Cow::Borrowed("UnusedVar"),
+                    PassKind::Diagnostic,
+                    1,
+                    true,
+                    vec![resolver.clone()]
+                ),
+                (
+                    Cow::Borrowed("CountRuns"),
+                    PassKind::Diagnostic,
+                    1,
+                    true,
+                    vec![resolver.clone()]
+                ),
+                (
+                    Cow::Borrowed("CountRuns"),
+                    PassKind::Diagnostic,
+                    2,
+                    false,
+                    vec![resolver]
+                ),
+            ]
+        );
+
+        assert_eq!(
+            pipeline.describe(),
+            "1. ResolverPass(0)        (Fact)\n\
+             2. in parallel, after ResolverPass(0)\n\
+             \x20  ├─ UnusedVar              (Diagnostic)\n\
+             \x20  └─ CountRuns              (Diagnostic)\n\
+             3. CountRuns              (Diagnostic), after ResolverPass(0)\n"
+        );
+    }
+}
@@ -278,6 +278,7 @@ impl ShadowedStrategy for DisallowDups {
                     Some(new_binder.source_position),
                 );
             } else {
+                db.record_shadowing(shadow.new, shadow.old);
                 db.warning(
                     current,
                     WarningKind::ShadowedVar {
@@ -297,6 +298,7 @@ impl ShadowedStrategy for AllowDups {
         for shadow in shadowed {
             let new_binder = db[shadow.new];
             let old_binder = db[shadow.old];
+            db.record_shadowing(shadow.new, shadow.old);
             db.warning(
                 current,
                 WarningKind::ShadowedVar {
@@ -14,6 +14,10 @@ pub(super) fn resolve_proc_pattern<'a>(
     let first_binder = db.next_binder();
     // shortcut, we can return right away for simple patterns
     let pattern_proc = pattern.proc;
+    if let Bad = pattern_proc {
+        db.error(pid, ErrorKind::BadCode, Some(pattern.span.start));
+        return ScopeInfo::ground(first_binder, span);
+    }
     if pattern_proc.is_trivially_ground() {
         return ScopeInfo::ground(first_binder, span);
     }
@@ -392,7 +396,9 @@ fn resolve_proc_pattern_rec<'a>(
         Select { branches: _ } => {
             unimplemented!("Select is not implemented in this version of Rholang")
         }
-        Bad => unreachable!(),
+        Bad => {
+            db.error(res.id, ErrorKind::BadCode, Some(pattern.span.start));
+        }
     }
 }
 
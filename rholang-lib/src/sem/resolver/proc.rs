@@ -40,15 +40,37 @@ fn resolve_unguarded<'a>(db: &mut SemanticDb<'a>, stack: &mut BindingStack, this
 
     match this.proc {
         // -- connectives (can only be used in patterns) --
-        SimpleType(_)
-        | VarRef { .. }
-        | ProcVar(Wildcard)
-        | UnaryExp { op: Negation, .. }
-        | BinaryExp {
+        SimpleType(_) | VarRef { .. } | ProcVar(Wildcard) => {
+            db.error(
+                db[this],
+                ErrorKind::ConnectiveOutsidePattern,
+                Some(this.span.start),
+            );
+        }
+        UnaryExp {
+            op: Negation,
+            arg: proc,
+        } => {
+            db.error(
+                db[this],
+                ErrorKind::ConnectiveOutsidePattern,
+                Some(this.span.start),
+            );
+            // operands are still in process position: report nested connectives too
+            resolve_rec(db, stack, proc);
+        }
+        BinaryExp {
             op: Conjunction | Disjunction,
-            ..
+            left,
+            right,
         } => {
-            db.error(db[this], ErrorKind::ConnectiveOutsidePattern, None);
+            db.error(
+                db[this],
+                ErrorKind::ConnectiveOutsidePattern,
+                Some(this.span.start),
+            );
+            resolve_rec(db, stack, left);
+            resolve_unguarded(db, stack, right);
         }
         Collection(
             List {
@@ -83,8 +105,12 @@ fn resolve_unguarded<'a>(db: &mut SemanticDb<'a>, stack: &mut BindingStack, this
         | FloatLiteral { .. }
         | FixedPointLiteral { .. }
         | StringLiteral(_)
-        | UriLiteral(_)
-        | Bad => {}
+        | UriLiteral(_) => {}
+
+        // -- error recovery: the parser could not make sense of this node --
+        Bad => {
+            db.error(db[this], ErrorKind::BadCode, Some(this.span.start));
+        }
 
         // -- variables --
         ProcVar(Id(id)) => {
@@ -209,6 +235,11 @@ fn resolve_unguarded<'a>(db: &mut SemanticDb<'a>, stack: &mut BindingStack, this
                 for (i, n) in decls.iter().enumerate() {
                     let name = db.intern(n.id.name);
                     let interned_uri = n.uri.map(|uri| db.intern(&uri));
+                    if let (Some(uri), Some(sym)) = (n.uri, interned_uri)
+                        && !uris::is_known_uri(&uri)
+                    {
+                        db.warning(new, WarningKind::UnknownUri { uri: sym }, Some(n.id.pos));
+                    }
                     db.fresh_binder(Binder {
                         name,
                         kind: BinderKind::Name(interned_uri),
@@ -3,18 +3,24 @@ use test_macros::test_rholang_code;
 use crate::{
     match_proc,
     sem::{
-        diagnostics::{DisjunctionConsistencyCheck, NumericTypeConsistencyCheck},
+        DiagnosticPass,
+        diagnostics::{
+            DeadReceivePass, DisjunctionConsistencyCheck, DuplicateKeysPass, IfWithoutElsePass,
+            MatchExhaustivenessPass, NumericTypeConsistencyCheck, StrictKindsPass,
+            UnusedCapturesPass,
+        },
         pipeline::Pipeline,
         tests::expect::{self, matches},
     },
 };
 
 use super::{
-    BinderId, BinderKind, ErrorKind, NumericType, PID, ProcRef, ResolverPass, SemanticDb,
-    VarBinding, WarningKind, diagnostics::UnusedVarsPass,
+    BinderId, BinderKind, DiagnosticKind, ErrorKind, NumericType, PID, ProcRef, ResolverPass,
+    SemanticDb, Severity, Shadowing, SymbolOccurrence, VarBinding, WarningKind,
+    diagnostics::UnusedVarsPass,
 };
 
-use rholang_parser::ast;
+use rholang_parser::{SourcePos, ast};
 
 fn pipeline<I>(roots: I) -> Pipeline
 where
@@ -29,6 +35,19 @@ where
         .add_diagnostic(NumericTypeConsistencyCheck)
 }
 
+/// Resolves `roots` and runs the single diagnostic pass `D` over the result
+fn resolver_with<D, I>(roots: I) -> Pipeline
+where
+    D: DiagnosticPass + Default,
+    I: Iterator<Item = PID>,
+{
+    roots
+        .fold(Pipeline::new(), |pipeline, root| {
+            pipeline.add_fact(ResolverPass::new(root))
+        })
+        .add_diagnostic(D::default())
+}
+
 #[test_rholang_code(
     r#"
     new anyone, rtn in {
@@ -480,6 +499,52 @@ fn test_connectives<'test>(tree: ProcRef<'test>, db: &'test SemanticDb<'test>) {
     expect::no_warnings_or_errors(db);
 }
 
+#[test_rholang_code(r#"
+new x in {
+  x!(1 \/ 2) |
+  x!(~(3 /\ 4))
+}"#, pipeline = pipeline)]
+fn test_connectives_in_send_arguments<'test>(_tree: ProcRef<'test>, db: &'test SemanticDb<'test>) {
+    let is_connective = |node: ProcRef<'test>| {
+        matches!(
+            node.proc,
+            ast::Proc::BinaryExp {
+                op: ast::BinaryExpOp::Disjunction | ast::BinaryExpOp::Conjunction,
+                ..
+            } | ast::Proc::UnaryExp {
+                op: ast::UnaryExpOp::Negation,
+                ..
+            }
+        )
+    };
+
+    // the nested conjunction under the negation is reported as well
+    expect::errors(db, 3);
+    for diagnostic in db.errors() {
+        assert_eq!(
+            diagnostic.kind,
+            DiagnosticKind::Error(ErrorKind::ConnectiveOutsidePattern)
+        );
+        let node = db[diagnostic.pid];
+        assert!(is_connective(node), "unexpected node {node:#?}");
+        assert_eq!(diagnostic.exact_position, Some(node.span.start));
+    }
+    expect::error(db, ErrorKind::ConnectiveOutsidePattern, is_connective);
+}
+
+#[test_rholang_code(r#"
+new x in {
+  for (@v <- x) {
+    match v {
+      1 \/ 2 => x!(v)
+      ~3 => Nil
+    }
+  }
+}"#, pipeline = pipeline)]
+fn test_connectives_in_match_patterns<'test>(_tree: ProcRef<'test>, db: &'test SemanticDb<'test>) {
+    expect::no_warnings_or_errors(db);
+}
+
 #[test_rholang_code(
     r#"
 new helloNameAge, getOlder, stdout(`rho:io:stdout`) in {
@@ -1477,3 +1542,358 @@ fn test_concurrent_join_guard_refs_other_group<'test>(
 ) {
     expect::no_warnings_or_errors(db);
 }
+
+// `Proc::Bad` is what the parser leaves behind when it recovers from a syntax error. Both in
+// process and in pattern position it must be reported as `BadCode` rather than crash the resolver.
+#[test]
+fn test_bad_nodes_are_reported() {
+    use rholang_parser::SourcePos;
+
+    let bad = ast::Proc::Bad;
+    let nil = ast::Proc::Nil;
+    let bad_pattern = ast::Proc::Collection(ast::Collection::List {
+        elements: vec![bad.ann(SourcePos::at_col(10).span_of(1))],
+        remainder: None,
+    });
+    let match_proc = ast::Proc::Match {
+        expression: nil.ann(SourcePos::at_col(7).span_of(3)),
+        cases: vec![ast::Case {
+            pattern: bad_pattern.ann(SourcePos::at_col(9).span_of(3)),
+            guard: None,
+            proc: bad.ann(SourcePos::at_col(16).span_of(1)),
+        }],
+    };
+    let root = match_proc.ann(SourcePos::default().span_of(18));
+
+    let mut db = SemanticDb::new();
+    let root_pid = db.build_index(&root);
+    let pipeline = pipeline(std::iter::once(root_pid));
+    tokio::runtime::Builder::new_multi_thread()
+        .worker_threads(2)
+        .build()
+        .unwrap()
+        .block_on(pipeline.run(&mut db));
+
+    expect::error(&db, ErrorKind::BadCode, |node: ProcRef<'_>| {
+        matches!(node.proc, ast::Proc::Bad)
+    });
+    expect::error(&db, ErrorKind::BadCode, |node: ProcRef<'_>| {
+        matches!(node.proc, ast::Proc::Collection(_))
+    });
+    expect::errors(&db, 2);
+}
+
+#[test_rholang_code(
+    r#"new c in { for(@x <- c) { Nil } }"#,
+    pipeline = resolver_with::<DeadReceivePass, _>
+)]
+fn test_dead_receive<'test>(tree: ProcRef<'test>, db: &'test SemanticDb<'test>) {
+    let root_scope = expect::scope(db, tree, 1);
+    let c = root_scope.binder_range().next().unwrap();
+
+    expect::warning(
+        db,
+        WarningKind::UnmatchedReceive { channel: c },
+        matches::first_for_comprehension(),
+    );
+    assert_eq!(db.warnings().count(), 1);
+}
+
+#[test_rholang_code(
+    r#"new c in { c!(1) | for(@x <- c) { Nil } }"#,
+    pipeline = resolver_with::<DeadReceivePass, _>
+)]
+fn test_dead_receive_matched_by_send<'test>(_tree: ProcRef<'test>, db: &'test SemanticDb<'test>) {
+    expect::no_warnings_or_errors(db);
+}
+
+#[test_rholang_code(
+    r#"
+new c, out, stdout(`rho:io:stdout`) in {
+  out!(*c) | for(@x <- c) { Nil } | for(@y <- stdout) { Nil }
+}"#, pipeline = resolver_with::<DeadReceivePass, _>
+)]
+fn test_dead_receive_ignores_escaping_and_system_names<'test>(
+    _tree: ProcRef<'test>,
+    db: &'test SemanticDb<'test>,
+) {
+    expect::no_warnings_or_errors(db);
+}
+
+#[test_rholang_code(r#"{1: "a", 1: "b"}"#, pipeline = resolver_with::<DuplicateKeysPass, _>)]
+fn test_duplicate_map_key<'test>(tree: ProcRef<'test>, db: &'test SemanticDb<'test>) {
+    let ast::Proc::Collection(ast::Collection::Map { elements, .. }) = tree.proc else {
+        panic!("expected a map literal, got {:?}", tree.proc);
+    };
+    let (first_key, _) = elements[0];
+
+    expect::warning(
+        db,
+        WarningKind::DuplicateMapKey {
+            span: first_key.span,
+        },
+        tree,
+    );
+    assert_eq!(db.warnings().count(), 1);
+}
+
+#[test_rholang_code(
+    r#"new ch in { for(@x, @y <- ch) { {x: 1, y: 2} } }"#,
+    pipeline = resolver_with::<DuplicateKeysPass, _>
+)]
+fn test_variable_map_keys_are_not_duplicates<'test>(
+    _tree: ProcRef<'test>,
+    db: &'test SemanticDb<'test>,
+) {
+    expect::no_warnings_or_errors(db);
+}
+
+#[test_rholang_code(
+    r#"Set(1, [2, "x"], 1, [2, "x"], 3)"#,
+    pipeline = resolver_with::<DuplicateKeysPass, _>
+)]
+fn test_duplicate_set_elements<'test>(tree: ProcRef<'test>, db: &'test SemanticDb<'test>) {
+    let ast::Proc::Collection(ast::Collection::Set { elements, .. }) = tree.proc else {
+        panic!("expected a set literal, got {:?}", tree.proc);
+    };
+
+    for original in &elements[..2] {
+        expect::warning(
+            db,
+            WarningKind::DuplicateSetElement {
+                span: original.span,
+            },
+            tree,
+        );
+    }
+    assert_eq!(db.warnings().count(), 2);
+}
+
+#[test_rholang_code(
+    r#"if (true) { 1 } | if (false) { 2 } else { 3 }"#,
+    pipeline = resolver_with::<IfWithoutElsePass, _>
+)]
+fn test_if_without_else<'test>(_tree: ProcRef<'test>, db: &'test SemanticDb<'test>) {
+    expect::warning(db, WarningKind::IfWithoutElse, |node: ProcRef<'_>| {
+        matches!(node.proc, ast::Proc::IfThenElse { if_false: None, .. })
+    });
+    assert_eq!(db.warnings().count(), 1);
+}
+
+#[test_rholang_code(
+    r#"match true { true => 1 } | match 5 { 1 => 1 2 => 2 } | match false { _ => 0 true => 1 }"#,
+    pipeline = resolver_with::<MatchExhaustivenessPass, _>
+)]
+fn test_non_exhaustive_match<'test>(tree: ProcRef<'test>, db: &'test SemanticDb<'test>) {
+    let mut matches = tree
+        .iter_preorder_dfs()
+        .filter(|node| matches!(node.proc, ast::Proc::Match { .. }));
+    for non_exhaustive in matches.by_ref().take(2) {
+        expect::warning(
+            db,
+            WarningKind::NonExhaustiveMatch {
+                span: non_exhaustive.span,
+            },
+            |node: ProcRef<'_>| node.span == non_exhaustive.span,
+        );
+    }
+    assert_eq!(db.warnings().count(), 2);
+}
+
+#[test_rholang_code(
+    r#"match true { true => 1 false => 0 } | match 5 { 1 => 1 _ => 0 } | match 5 { x => x }"#,
+    pipeline = resolver_with::<MatchExhaustivenessPass, _>
+)]
+fn test_exhaustive_match<'test>(_tree: ProcRef<'test>, db: &'test SemanticDb<'test>) {
+    expect::no_warnings_or_errors(db);
+}
+
+#[test_rholang_code(
+    r#"match true { true => 1 false where false => 0 }"#,
+    pipeline = resolver_with::<MatchExhaustivenessPass, _>
+)]
+fn test_guarded_case_does_not_make_match_exhaustive<'test>(
+    tree: ProcRef<'test>,
+    db: &'test SemanticDb<'test>,
+) {
+    expect::warning(
+        db,
+        WarningKind::NonExhaustiveMatch { span: tree.span },
+        tree,
+    );
+}
+
+#[test_rholang_code(r#"new x in { new x in { x } }"#, pipeline = pipeline)]
+fn test_shadowing_report<'test>(_tree: ProcRef<'test>, db: &'test SemanticDb<'test>) {
+    let x = db.intern("x");
+    let at = |line, col| SymbolOccurrence {
+        symbol: x,
+        position: SourcePos { line, col },
+    };
+    assert_eq!(
+        db.shadowing_report(),
+        [Shadowing {
+            inner: at(1, 16),
+            outer: at(1, 5),
+        }]
+    );
+}
+
+#[test_rholang_code(
+    r#"new c in { for(x <- c) { x } | for(@y <- c) { y!(1) } }"#,
+    pipeline = resolver_with::<StrictKindsPass, _>
+)]
+fn test_strict_kinds<'test>(_tree: ProcRef<'test>, db: &'test SemanticDb<'test>) {
+    let x_scope = expect::scope(db, matches::first_for_comprehension(), 1);
+    let x = expect::binder(db, "x", x_scope);
+    expect::warning(
+        db,
+        WarningKind::ImplicitEval(x, db[x].name),
+        matches::proc_var("x"),
+    );
+    // the lenient errors are still there for the compiler to filter
+    expect::error(
+        db,
+        ErrorKind::NameInProcPosition(x, db[x].name),
+        matches::proc_var("x"),
+    );
+
+    let implicit_quotes: Vec<_> = db
+        .warnings()
+        .filter(|d| {
+            matches!(
+                d.kind,
+                DiagnosticKind::Warning(WarningKind::ImplicitQuote(_, _))
+            )
+        })
+        .collect();
+    assert_eq!(implicit_quotes.len(), 1);
+    assert_eq!(db.warnings().count(), 2);
+}
+
+fn errors_only_pipeline<I>(roots: I) -> Pipeline
+where
+    I: Iterator<Item = PID>,
+{
+    pipeline(roots).with_severity(Severity::Error)
+}
+
+fn capped_pipeline<I>(roots: I) -> Pipeline
+where
+    I: Iterator<Item = PID>,
+{
+    pipeline(roots).with_max_diagnostics(2)
+}
+
+#[test_rholang_code(r#"new ch in { for(@a, @b, @c, @d <- ch) { Nil } }"#, pipeline = pipeline)]
+fn test_diagnostics_collect_everything_by_default<'test>(
+    _tree: ProcRef<'test>,
+    db: &'test SemanticDb<'test>,
+) {
+    assert_eq!(db.warnings().count(), 4);
+}
+
+#[test_rholang_code(
+    r#"new ch in { for(@a, @b <- ch) { x } }"#,
+    pipeline = errors_only_pipeline
+)]
+fn test_diagnostics_severity_filter<'test>(_tree: ProcRef<'test>, db: &'test SemanticDb<'test>) {
+    assert!(db.has_errors());
+    assert_eq!(db.warnings().count(), 0);
+    assert!(
+        db.diagnostics()
+            .iter()
+            .all(|d| d.kind.severity() == Severity::Error)
+    );
+}
+
+#[test_rholang_code(
+    r#"new ch in { for(@a, @b, @c, @d <- ch) { Nil } }"#,
+    pipeline = capped_pipeline
+)]
+fn test_diagnostics_max_count<'test>(_tree: ProcRef<'test>, db: &'test SemanticDb<'test>) {
+    assert_eq!(db.diagnostics().len(), 2);
+    assert!(db.is_diagnostics_full());
+}
+
+fn deny_warnings_pipeline<I>(roots: I) -> Pipeline
+where
+    I: Iterator<Item = PID>,
+{
+    pipeline(roots).with_deny_warnings(true)
+}
+
+#[test_rholang_code(r#"new x in { Nil }"#, pipeline = pipeline)]
+fn test_warnings_pass_by_default<'test>(_tree: ProcRef<'test>, db: &'test SemanticDb<'test>) {
+    assert_eq!(db.warnings().count(), 1);
+    assert!(!db.has_errors());
+}
+
+#[test_rholang_code(r#"new x in { Nil }"#, pipeline = deny_warnings_pipeline)]
+fn test_deny_warnings<'test>(_tree: ProcRef<'test>, db: &'test SemanticDb<'test>) {
+    // the unused variable is still reported as a warning, but fails the analysis
+    assert_eq!(db.warnings().count(), 1);
+    assert_eq!(db.errors().count(), 0);
+    assert!(db.has_errors());
+}
+
+#[test_rholang_code(r#"new out(`rho:io:stdout`), box(`rho:io:mailbox`) in { out!(*box) }"#, pipeline = pipeline)]
+fn test_unknown_uri<'test>(tree: ProcRef<'test>, db: &'test SemanticDb<'test>) {
+    let uri = db.intern("rho:io:mailbox");
+    expect::warning(db, WarningKind::UnknownUri { uri }, tree);
+    assert_eq!(db.warnings().count(), 1);
+}
+
+#[test_rholang_code(r#"new x, y in { for (@v <- x) { Nil } }"#, pipeline = pipeline)]
+fn test_scope_describe<'test>(tree: ProcRef<'test>, db: &'test SemanticDb<'test>) {
+    let root_scope = expect::scope(db, tree, 2);
+    let description = root_scope.describe(db);
+    let binder_lines: Vec<&str> = description.lines().skip(1).collect();
+    assert_eq!(binder_lines, ["  x #0: used", "  y #1: unused"]);
+
+    let inner_scope = expect::scope(db, matches::first_for_comprehension(), 1);
+    let description = inner_scope.describe(db);
+    let binder_lines: Vec<&str> = description.lines().skip(1).collect();
+    assert_eq!(binder_lines, ["  v #2: unused, free", "  captures: x #0"]);
+}
+
+#[test_rholang_code(
+    r#"new x, z in { for (y <- z) { for (w <- y) { x!(*w) } } }"#,
+    pipeline = resolver_with::<UnusedCapturesPass, _>
+)]
+fn test_nested_for_uses_capture<'test>(tree: ProcRef<'test>, db: &'test SemanticDb<'test>) {
+    let root_scope = expect::scope(db, tree, 2);
+    let x = expect::binder(db, "x", root_scope);
+    let inner_scope = expect::scope(db, matches::for_with_channel("y"), 1);
+
+    assert!(inner_scope.captures().any(|bid| bid == x));
+    expect::no_warnings_or_errors(db);
+}
+
+#[test_rholang_code(
+    r#"new x, z in { for (y <- z) { for (w <- y) { Nil } } | x!(Nil) }"#,
+    pipeline = resolver_with::<UnusedCapturesPass, _>
+)]
+fn test_nested_for_without_capture<'test>(tree: ProcRef<'test>, db: &'test SemanticDb<'test>) {
+    let root_scope = expect::scope(db, tree, 2);
+    let x = expect::binder(db, "x", root_scope);
+    let inner_scope = expect::scope(db, matches::for_with_channel("y"), 1);
+
+    // `x` is never mentioned inside the nested `for`, so it is not captured at all
+    assert!(inner_scope.captures().all(|bid| bid != x));
+    expect::no_warnings_or_errors(db);
+}
+
+#[test_rholang_code(
+    r#"new z in { for (a <- z; @{=*a} <- z) { Nil } }"#,
+    pipeline = resolver_with::<UnusedCapturesPass, _>
+)]
+fn test_capture_absorbed_into_use<'test>(tree: ProcRef<'test>, db: &'test SemanticDb<'test>) {
+    let root_scope = expect::scope(db, tree, 1);
+    let z = expect::binder(db, "z", root_scope);
+    let for_scope = expect::scope(db, matches::first_for_comprehension(), 1);
+
+    // the capture of `a` by the second pattern became a use when it was absorbed
+    expect::captures(&[z], for_scope);
+    expect::no_warnings_or_errors(db);
+}
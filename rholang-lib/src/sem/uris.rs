@@ -0,0 +1,44 @@
+//! Registry of the system URIs recognized in `new x(`uri`) in { ... }` declarations.
+
+/// Standard output: every message sent on this channel is printed.
+pub const STDOUT: &str = "rho:io:stdout";
+/// Standard error: every message sent on this channel is printed to the error stream.
+pub const STDERR: &str = "rho:io:stderr";
+
+/// All system URIs known to the toolchain, sorted.
+pub const KNOWN_URIS: &[&str] = &[
+    "rho:block:data",
+    "rho:crypto:blake2b256Hash",
+    "rho:crypto:ed25519Verify",
+    "rho:crypto:keccak256Hash",
+    "rho:crypto:secp256k1Verify",
+    "rho:crypto:sha256Hash",
+    STDERR,
+    "rho:io:stderrAck",
+    STDOUT,
+    "rho:io:stdoutAck",
+    "rho:rchain:deployId",
+    "rho:rchain:deployerId",
+    "rho:registry:insertArbitrary",
+    "rho:registry:insertSigned:secp256k1",
+    "rho:registry:lookup",
+    "rho:rev:address",
+];
+
+/// Returns `true` if `uri` names a known system channel
+pub fn is_known_uri(uri: &str) -> bool {
+    KNOWN_URIS.binary_search(&uri).is_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_known_uris_are_sorted() {
+        assert!(KNOWN_URIS.is_sorted());
+        assert!(is_known_uri("rho:io:stdout"));
+        assert!(is_known_uri("rho:registry:lookup"));
+        assert!(!is_known_uri("rho:io:stdin"));
+    }
+}
@@ -0,0 +1,433 @@
+//! Random AST generation for fuzzing, enabled by the `arbitrary` feature.
+//!
+//! [`arbitrary_proc`] builds an [`AnnProc`] inside an [`ASTBuilder`] arena out of fuzzer input.
+//! The generated trees are syntactically valid: rendering them with [`unparse`] and parsing the
+//! text back yields the same tree. They are *not* guaranteed to be semantically valid (unbound
+//! variables, connectives outside patterns and so on are all fair game), which is exactly what
+//! a fuzz target for the later phases wants to see. [`ArbitraryProgram`] wraps this into an owned
+//! value that can be used directly as a `cargo fuzz` target input.
+//!
+//! Every [`Proc`] variant is generated except `Select`, which the parser does not support yet,
+//! and `Bad`, which has no source representation.
+
+use crate::{
+    SourcePos, SourceSpan,
+    ast::{
+        AnnProc, BinaryExpOp, Bind, BundleType, Id, LetBinding, Name, NameDecl, Names, Proc,
+        ProcList, SendType, SimpleType, Source, UnaryExpOp, Uri, Var, VarRefKind,
+    },
+    parser::ASTBuilder,
+    unparse,
+};
+use arbitrary::{Arbitrary, Result, Unstructured};
+
+/// Depth bound used by [`ArbitraryProgram`]
+pub const DEFAULT_MAX_DEPTH: usize = 4;
+
+const IDENTS: &[&str] = &["x", "y", "z", "ack", "ret", "chan", "state", "value"];
+const METHODS: &[&str] = &["length", "nth", "toString", "union", "get", "slice"];
+const URIS: &[&str] = &[
+    "rho:io:stdout",
+    "rho:registry:lookup",
+    "rho:rchain:deployerId",
+];
+const INT_BITS: &[u32] = &[8, 16, 32, 64, 128, 256];
+const FLOAT_BITS: &[u16] = &[32, 64, 128, 256];
+const STRING_CHARS: &[u8] = b"abcxyz ABC0123456789_-:.";
+
+const SIMPLE_TYPES: &[SimpleType] = &[
+    SimpleType::Bool,
+    SimpleType::Int,
+    SimpleType::String,
+    SimpleType::Uri,
+    SimpleType::ByteArray,
+];
+
+const BUNDLE_TYPES: &[BundleType] = &[
+    BundleType::BundleEquiv,
+    BundleType::BundleWrite,
+    BundleType::BundleRead,
+    BundleType::BundleReadWrite,
+];
+
+const UNARY_OPS: &[UnaryExpOp] = &[UnaryExpOp::Not, UnaryExpOp::Neg, UnaryExpOp::Negation];
+
+const BINARY_OPS: &[BinaryExpOp] = &[
+    BinaryExpOp::Or,
+    BinaryExpOp::And,
+    BinaryExpOp::Matches,
+    BinaryExpOp::Eq,
+    BinaryExpOp::Neq,
+    BinaryExpOp::Lt,
+    BinaryExpOp::Lte,
+    BinaryExpOp::Gt,
+    BinaryExpOp::Gte,
+    BinaryExpOp::Concat,
+    BinaryExpOp::Diff,
+    BinaryExpOp::Add,
+    BinaryExpOp::Sub,
+    BinaryExpOp::Interpolation,
+    BinaryExpOp::Mult,
+    BinaryExpOp::Div,
+    BinaryExpOp::Mod,
+    BinaryExpOp::Disjunction,
+    BinaryExpOp::Conjunction,
+];
+
+/// Generate a random process in `builder`'s arena.
+///
+/// `max_depth` bounds the nesting of compound processes; at depth zero only leaves (literals,
+/// variables, empty collections etc.) are produced. Once `u` runs out of data the generator
+/// keeps producing the smallest choices, so this always terminates.
+pub fn arbitrary_proc<'ast>(
+    builder: &'ast ASTBuilder<'ast>,
+    u: &mut Unstructured<'_>,
+    max_depth: usize,
+) -> Result<AnnProc<'ast>> {
+    Generator { builder, u }.proc(max_depth)
+}
+
+/// Source code of a randomly generated, syntactically valid Rholang program.
+///
+/// Intended as the input type of fuzz targets exercising the parse → compile → execute pipeline.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ArbitraryProgram(pub String);
+
+impl<'a> Arbitrary<'a> for ArbitraryProgram {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let builder = ASTBuilder::new();
+        let proc = arbitrary_proc(&builder, u, DEFAULT_MAX_DEPTH)?;
+        Ok(ArbitraryProgram(unparse(&proc)))
+    }
+}
+
+struct Generator<'ast, 'u, 'data> {
+    builder: &'ast ASTBuilder<'ast>,
+    u: &'u mut Unstructured<'data>,
+}
+
+fn ann<'ast>(proc: &'ast Proc<'ast>) -> AnnProc<'ast> {
+    proc.ann(SourceSpan::default())
+}
+
+impl<'ast> Generator<'ast, '_, '_> {
+    fn proc(&mut self, depth: usize) -> Result<AnnProc<'ast>> {
+        if depth == 0 || self.u.ratio(1, 4)? {
+            return self.leaf();
+        }
+        let depth = depth - 1;
+        let b = self.builder;
+
+        let proc = match self.u.choose_index(17)? {
+            0 => return self.collection(depth),
+            1 => b.alloc_par(self.proc(depth)?, self.proc(depth)?),
+            2 => {
+                let condition = self.proc(depth)?;
+                let if_true = self.proc(depth)?;
+                let if_false = self.optional(|g| g.proc(depth))?;
+                b.alloc_if_then_else_opt(condition, if_true, if_false)
+            }
+            3 => {
+                let send_type = if self.u.arbitrary()? {
+                    SendType::Single
+                } else {
+                    SendType::Multiple
+                };
+                let channel = self.name(depth)?;
+                let inputs = self.proc_list(depth, 0, 3)?;
+                b.alloc_send(send_type, channel, &inputs)
+            }
+            4 => {
+                let receipts = self.repeat(1, 2, |g| g.receipt(depth))?;
+                let proc = self.proc(depth)?;
+                b.alloc_for_with_guards(receipts, proc)
+            }
+            5 => {
+                let expression = self.proc(depth)?;
+                let cases = self.repeat(1, 3, |g| {
+                    Ok((
+                        g.proc(depth)?,
+                        g.optional(|g| g.proc(depth))?,
+                        g.proc(depth)?,
+                    ))
+                })?;
+                b.alloc_match_with_guards(expression, cases)
+            }
+            6 => {
+                let bundle_type = *self.u.choose(BUNDLE_TYPES)?;
+                b.alloc_bundle(bundle_type, self.proc(depth)?)
+            }
+            7 => {
+                let bindings = self.repeat(1, 3, |g| g.let_binding(depth))?;
+                // a single binding always reads back as a sequential `let`
+                let concurrent = bindings.len() > 1 && self.u.arbitrary()?;
+                b.alloc_let(bindings, self.proc(depth)?, concurrent)
+            }
+            8 => {
+                let mut decls = self.repeat(1, 3, |g| g.name_decl())?;
+                // duplicate declarations are a parse error
+                decls.sort();
+                decls.dedup();
+                b.alloc_new(self.proc(depth)?, decls)
+            }
+            9 => {
+                let name = self.name(depth)?;
+                let formals = self.names(depth, true)?;
+                b.alloc_contract(name, formals, self.proc(depth)?)
+            }
+            10 => {
+                let channel = self.name(depth)?;
+                let inputs = self.proc_list(depth, 0, 3)?;
+                if self.u.arbitrary()? {
+                    b.alloc_send_sync_with_cont(channel, &inputs, self.proc(depth)?)
+                } else {
+                    b.alloc_send_sync(channel, &inputs)
+                }
+            }
+            11 => b.alloc_eval(self.name(depth)?),
+            12 => {
+                let name = Id {
+                    name: self.u.choose(METHODS)?,
+                    pos: SourcePos::default(),
+                };
+                let receiver = self.proc(depth)?;
+                let args = self.proc_list(depth, 0, 2)?;
+                b.alloc_method(name, receiver, &args)
+            }
+            13 => {
+                let op = *self.u.choose(UNARY_OPS)?;
+                b.alloc_unary_exp(op, self.proc(depth)?)
+            }
+            14 => {
+                let op = *self.u.choose(BINARY_OPS)?;
+                b.alloc_binary_exp(op, self.proc(depth)?, self.proc(depth)?)
+            }
+            15 => b.alloc_proc_var(self.var()?),
+            _ => return self.leaf(),
+        };
+
+        Ok(ann(proc))
+    }
+
+    fn leaf(&mut self) -> Result<AnnProc<'ast>> {
+        let b = self.builder;
+
+        let proc = match self.u.choose_index(18)? {
+            0 => b.const_nil(),
+            1 => b.const_unit(),
+            2 => {
+                if self.u.arbitrary()? {
+                    b.const_true()
+                } else {
+                    b.const_false()
+                }
+            }
+            3 => b.alloc_long_literal(self.u.arbitrary()?),
+            4 => {
+                let value = self.u.arbitrary::<i64>()?.to_string();
+                let bits = *self.u.choose(INT_BITS)?;
+                b.alloc_signed_int_literal(b.alloc_str(&value), bits)
+            }
+            5 => {
+                let value = self.u.arbitrary::<u64>()?.to_string();
+                let bits = *self.u.choose(INT_BITS)?;
+                b.alloc_unsigned_int_literal(b.alloc_str(&value), bits)
+            }
+            6 => {
+                let value = self.u.arbitrary::<i64>()?.to_string();
+                b.alloc_bigint_literal(b.alloc_str(&value))
+            }
+            7 => {
+                let value = self.u.arbitrary::<i64>()?.to_string();
+                b.alloc_bigrat_literal(b.alloc_str(&value))
+            }
+            8 => {
+                let value = self.decimal()?;
+                let bits = *self.u.choose(FLOAT_BITS)?;
+                b.alloc_float_literal(b.alloc_str(&value), bits)
+            }
+            9 => {
+                let value = self.decimal()?;
+                let scale = self.u.int_in_range(0..=18)?;
+                b.alloc_fixed_point_literal(b.alloc_str(&value), scale)
+            }
+            10 => {
+                let len = self.u.int_in_range(0..=8)?;
+                let mut value = String::with_capacity(len);
+                for _ in 0..len {
+                    value.push(*self.u.choose(STRING_CHARS)? as char);
+                }
+                b.alloc_string_literal(b.alloc_str(&value))
+            }
+            11 => b.alloc_uri_literal(self.u.choose(URIS)?),
+            12 => b.alloc_simple_type(*self.u.choose(SIMPLE_TYPES)?),
+            13 => {
+                let kind = if self.u.arbitrary()? {
+                    VarRefKind::Proc
+                } else {
+                    VarRefKind::Name
+                };
+                b.alloc_var_ref(kind, self.id()?)
+            }
+            14 => b.alloc_eval(Name::NameVar(self.var()?)),
+            15 => b.alloc_list(&[]),
+            16 => b.alloc_map(&[]),
+            _ => b.alloc_proc_var(self.var()?),
+        };
+
+        Ok(ann(proc))
+    }
+
+    fn collection(&mut self, depth: usize) -> Result<AnnProc<'ast>> {
+        let b = self.builder;
+        let remainder = self.optional(|g| g.var())?;
+
+        let proc = match self.u.choose_index(5)? {
+            0 => {
+                let elements = self.proc_list(depth, 0, 3)?;
+                match remainder {
+                    Some(remainder) => b.alloc_list_with_remainder(&elements, remainder),
+                    None => b.alloc_list(&elements),
+                }
+            }
+            1 => b.alloc_tuple(&self.proc_list(depth, 1, 3)?),
+            2 => {
+                let elements = self.proc_list(depth, 0, 3)?;
+                match remainder {
+                    Some(remainder) => b.alloc_set_with_remainder(&elements, remainder),
+                    None => b.alloc_set(&elements),
+                }
+            }
+            3 => {
+                let pairs = self.repeat(0, 2, |g| Ok([g.proc(depth)?, g.proc(depth)?]))?;
+                let pairs = pairs.concat();
+                match remainder {
+                    Some(remainder) => b.alloc_map_with_remainder(&pairs, remainder),
+                    None => b.alloc_map(&pairs),
+                }
+            }
+            _ => {
+                let elements = self.proc_list(depth, 0, 3)?;
+                match remainder {
+                    Some(remainder) => b.alloc_pathmap_with_remainder(&elements, remainder),
+                    None => b.alloc_pathmap(&elements),
+                }
+            }
+        };
+
+        Ok(ann(proc))
+    }
+
+    fn receipt(&mut self, depth: usize) -> Result<(Vec<Bind<'ast>>, Option<AnnProc<'ast>>)> {
+        let binds = self.repeat(1, 2, |g| g.bind(depth))?;
+        let guard = self.optional(|g| g.proc(depth))?;
+        Ok((binds, guard))
+    }
+
+    fn bind(&mut self, depth: usize) -> Result<Bind<'ast>> {
+        let lhs = self.names(depth, true)?;
+        let bind = match self.u.choose_index(3)? {
+            0 => {
+                let name = self.name(depth)?;
+                let rhs = match self.u.choose_index(3)? {
+                    0 => Source::Simple { name },
+                    1 => Source::ReceiveSend { name },
+                    _ => Source::SendReceive {
+                        name,
+                        inputs: self.proc_list(depth, 0, 2)?,
+                    },
+                };
+                Bind::Linear { lhs, rhs }
+            }
+            1 => Bind::Repeated {
+                lhs,
+                rhs: self.name(depth)?,
+            },
+            _ => Bind::Peek {
+                lhs,
+                rhs: self.name(depth)?,
+            },
+        };
+        Ok(bind)
+    }
+
+    fn let_binding(&mut self, depth: usize) -> Result<LetBinding<'ast>> {
+        // declarations with several names on the left are not read back faithfully by the
+        // parser yet, so stick to one name per declaration
+        Ok(LetBinding::single(self.name(depth)?, self.proc(depth)?))
+    }
+
+    fn name_decl(&mut self) -> Result<NameDecl<'ast>> {
+        let id = self.id()?;
+        let uri = self.optional(|g| Ok(Uri::from(*g.u.choose(URIS)?)))?;
+        Ok(NameDecl {
+            id,
+            uri,
+            span: SourceSpan::default(),
+        })
+    }
+
+    fn name(&mut self, depth: usize) -> Result<Name<'ast>> {
+        if self.u.arbitrary()? {
+            Ok(Name::NameVar(self.var()?))
+        } else {
+            Ok(Name::Quote(self.proc(depth)?))
+        }
+    }
+
+    fn names(&mut self, depth: usize, with_remainder: bool) -> Result<Names<'ast>> {
+        let names = self.repeat(0, 2, |g| g.name(depth))?;
+        let remainder = if with_remainder {
+            self.optional(|g| g.var())?
+        } else {
+            None
+        };
+        Ok(Names {
+            names: names.into(),
+            remainder,
+        })
+    }
+
+    fn var(&mut self) -> Result<Var<'ast>> {
+        if self.u.ratio(1, 8)? {
+            Ok(Var::Wildcard)
+        } else {
+            Ok(Var::Id(self.id()?))
+        }
+    }
+
+    fn id(&mut self) -> Result<Id<'ast>> {
+        Ok(Id {
+            name: self.u.choose(IDENTS)?,
+            pos: SourcePos::default(),
+        })
+    }
+
+    fn decimal(&mut self) -> Result<String> {
+        let int: i32 = self.u.arbitrary()?;
+        let frac: u16 = self.u.arbitrary()?;
+        Ok(format!("{int}.{frac}"))
+    }
+
+    fn proc_list(&mut self, depth: usize, min: usize, max: usize) -> Result<ProcList<'ast>> {
+        Ok(self.repeat(min, max, |g| g.proc(depth))?.into())
+    }
+
+    fn optional<T>(&mut self, f: impl FnOnce(&mut Self) -> Result<T>) -> Result<Option<T>> {
+        if self.u.arbitrary()? {
+            f(self).map(Some)
+        } else {
+            Ok(None)
+        }
+    }
+
+    fn repeat<T>(
+        &mut self,
+        min: usize,
+        max: usize,
+        mut f: impl FnMut(&mut Self) -> Result<T>,
+    ) -> Result<Vec<T>> {
+        let len = self.u.int_in_range(min..=max)?;
+        (0..len).map(|_| f(self)).collect()
+    }
+}
@@ -169,6 +169,33 @@ impl<'a> Proc<'a> {
             _ => None,
         }
     }
+
+    /// The number of messages of a send, synchronous or not
+    pub fn send_arity(&self) -> Option<usize> {
+        match self {
+            Proc::Send { inputs, .. } | Proc::SendSync { inputs, .. } => Some(inputs.len()),
+            _ => None,
+        }
+    }
+
+    /// The channel of a send, synchronous or not
+    pub fn send_channel(&self) -> Option<&Name<'a>> {
+        match self {
+            Proc::Send { channel, .. } | Proc::SendSync { channel, .. } => Some(channel),
+            _ => None,
+        }
+    }
+
+    /// The source channels of every receipt of a for-comprehension, in source order.
+    /// See [`Receipt::source_names`].
+    pub fn receive_channels(&self) -> Option<impl Iterator<Item = &Name<'a>>> {
+        match self {
+            Proc::ForComprehension { receipts, .. } => {
+                Some(receipts.iter().flat_map(Receipt::source_names))
+            }
+            _ => None,
+        }
+    }
 }
 
 impl<'a> From<Var<'a>> for Proc<'a> {
@@ -194,7 +221,15 @@ impl<'a> AnnProc<'a> {
     /// - Use a higher-level iterator (such as [`NameAwareDfsEventIter`])
     ///   if you need to see [`Name`] occurrences as well.
     pub fn iter_preorder_dfs(&'a self) -> impl Iterator<Item = &'a Self> {
-        PreorderDfsIter::<16>::new(self)
+        self.iter_preorder_dfs_with_stack::<16>()
+    }
+
+    /// [`iter_preorder_dfs`](Self::iter_preorder_dfs) keeping up to `S` pending nodes inline
+    /// before the traversal stack spills to the heap.
+    pub fn iter_preorder_dfs_with_stack<const S: usize>(
+        &'a self,
+    ) -> impl Iterator<Item = &'a Self> {
+        PreorderDfsIter::<S>::new(self)
     }
 
     /// Depth-first traversal over the *process structure* of the AST.
@@ -210,7 +245,16 @@ impl<'a> AnnProc<'a> {
     /// - Use a higher-level iterator (such as [`NameAwareDfsEventIter`])
     ///   if you need to see [`Name`] occurrences as well.
     pub fn iter_dfs_event(&'a self) -> impl Iterator<Item = DfsEvent<'a>> {
-        DfsEventIter::<32>::new(self)
+        self.iter_dfs_event_with_stack::<32>()
+    }
+
+    /// [`iter_dfs_event`](Self::iter_dfs_event) keeping up to `S` pending entries inline before
+    /// the traversal stack spills to the heap. A larger `S` avoids allocating for deep trees; a
+    /// smaller one saves stack space for shallow ones.
+    pub fn iter_dfs_event_with_stack<const S: usize>(
+        &'a self,
+    ) -> impl Iterator<Item = DfsEvent<'a>> {
+        DfsEventIter::<S>::new(self)
     }
 
     /// A decorator over a *process-only* DFS iterator that re-emits the process `Enter`/`Exit` events
@@ -231,7 +275,26 @@ impl<'a> AnnProc<'a> {
     ///  [`DfsEventExt::Name`] events for the names that appear directly in `p` (the names are emitted in
     /// a deterministic left-to-right order).
     pub fn iter_dfs_event_with_names(&'a self) -> impl Iterator<Item = DfsEventExt<'a>> {
-        NameAwareDfsEventIter::<32>::new(self)
+        self.iter_dfs_event_with_names_with_stack::<32>()
+    }
+
+    /// [`iter_dfs_event_with_names`](Self::iter_dfs_event_with_names) keeping up to `S` pending
+    /// entries inline before the traversal stack spills to the heap.
+    pub fn iter_dfs_event_with_names_with_stack<const S: usize>(
+        &'a self,
+    ) -> impl Iterator<Item = DfsEventExt<'a>> {
+        NameAwareDfsEventIter::<S>::new(self)
+    }
+
+    /// Direct process children of this node, without descending any further.
+    ///
+    /// Children are returned in the order [`iter_dfs_event`](Self::iter_dfs_event) visits them,
+    /// e.g. `left, right` for `Par`, the body followed by the receipt inputs and guards for a
+    /// for-comprehension, and the expression followed by `pattern, guard, body` of every case
+    /// for a `Match`. Like the DFS iterators, names (and quoted processes inside them) are not
+    /// considered children.
+    pub fn children(&self) -> SmallVec<[&'a AnnProc<'a>; 4]> {
+        direct_children(self)
     }
 
     pub fn is_trivially_ground(&self) -> bool {
@@ -407,6 +470,20 @@ impl<'a> Name<'a> {
     pub fn iter_into_deep(&'a self) -> impl Iterator<Item = DfsEventExt<'a>> {
         DeepDfsIter::<4>::new(self)
     }
+
+    /// Whether this name, used as a pattern, binds at least one variable: it is a name
+    /// variable, or a quote containing a process or name variable. Wildcards and
+    /// `=x` references do not bind.
+    pub fn is_binder(&'a self) -> bool {
+        self.iter_into_deep().any(|ev| {
+            let var = match ev {
+                DfsEventExt::Enter(ann_proc) => ann_proc.as_var(),
+                DfsEventExt::Name(name) => name.as_var(),
+                DfsEventExt::Exit(_) => None,
+            };
+            matches!(var, Some(Var::Id(_)))
+        })
+    }
 }
 
 impl<'a> From<Id<'a>> for Name<'a> {
@@ -486,6 +563,12 @@ impl<'a> Names<'a> {
         }
     }
 
+    /// Pairs each name of this pattern with whether it is a binding occurrence (see
+    /// [`Name::is_binder`]). The remainder, if any, is always a binder and is not included.
+    pub fn binders(&'a self) -> impl Iterator<Item = (&'a Name<'a>, bool)> {
+        self.names.iter().map(|name| (name, name.is_binder()))
+    }
+
     pub fn is_empty(&self) -> bool {
         self.names.is_empty() && self.remainder.is_none()
     }
@@ -523,6 +606,23 @@ impl UnaryExpOp {
     pub fn is_connective(self) -> bool {
         self == UnaryExpOp::Negation
     }
+
+    /// Binding strength in the grammar, on the same scale as [`BinaryExpOp::precedence`]
+    pub fn precedence(self) -> u8 {
+        match self {
+            UnaryExpOp::Not | UnaryExpOp::Neg => 10,
+            UnaryExpOp::Negation => 15,
+        }
+    }
+}
+
+/// How a chain of operators of the same precedence groups
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Associativity {
+    /// `a - b - c` is `(a - b) - c`
+    Left,
+    /// `a matches b matches c` is `a matches (b matches c)`
+    Right,
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
@@ -552,6 +652,33 @@ impl BinaryExpOp {
     pub fn is_connective(self) -> bool {
         matches!(self, BinaryExpOp::Conjunction | BinaryExpOp::Disjunction)
     }
+
+    /// Binding strength in the grammar; higher binds tighter. These are the `prec` levels of
+    /// the tree-sitter grammar, so they can be compared with [`UnaryExpOp::precedence`].
+    pub fn precedence(self) -> u8 {
+        match self {
+            BinaryExpOp::Or => 4,
+            BinaryExpOp::And => 5,
+            BinaryExpOp::Matches | BinaryExpOp::Eq | BinaryExpOp::Neq => 6,
+            BinaryExpOp::Lt | BinaryExpOp::Lte | BinaryExpOp::Gt | BinaryExpOp::Gte => 7,
+            BinaryExpOp::Concat | BinaryExpOp::Diff | BinaryExpOp::Add | BinaryExpOp::Sub => 8,
+            BinaryExpOp::Interpolation
+            | BinaryExpOp::Mult
+            | BinaryExpOp::Div
+            | BinaryExpOp::Mod => 9,
+            BinaryExpOp::Disjunction => 13,
+            BinaryExpOp::Conjunction => 14,
+        }
+    }
+
+    /// Only `matches` groups to the right; every other operator groups to the left
+    pub fn associativity(self) -> Associativity {
+        if self == BinaryExpOp::Matches {
+            Associativity::Right
+        } else {
+            Associativity::Left
+        }
+    }
 }
 
 // for-comprehensions
@@ -573,6 +700,13 @@ pub struct Receipt<'ast> {
     pub guard: Option<AnnProc<'ast>>,
 }
 
+impl<'ast> Receipt<'ast> {
+    /// The channels this receipt receives on, one per bind
+    pub fn source_names(&self) -> impl ExactSizeIterator<Item = &Name<'ast>> {
+        self.binds.iter().map(Bind::source_name)
+    }
+}
+
 impl<'ast> std::ops::Deref for Receipt<'ast> {
     type Target = [Bind<'ast>];
 
@@ -606,6 +740,17 @@ pub fn inputs<'a>(receipt: &'a [Bind<'a>]) -> impl DoubleEndedIterator<Item = &'
     receipt.iter().filter_map(|bind| bind.input()).flatten()
 }
 
+/// The arrow of a [`Bind`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ArrowKind {
+    /// `<-`, consumes one message
+    Linear,
+    /// `<=`, consumes every message, like a contract
+    Repeated,
+    /// `<<-`, reads a message without consuming it
+    Peek,
+}
+
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub enum Bind<'ast> {
     Linear { lhs: Names<'ast>, rhs: Source<'ast> },
@@ -646,6 +791,26 @@ impl<'a> Bind<'a> {
     pub fn names_iter(&self) -> std::slice::Iter<'_, Name<'a>> {
         self.names().names.iter()
     }
+
+    pub fn arrow_kind(&self) -> ArrowKind {
+        match self {
+            Bind::Linear { .. } => ArrowKind::Linear,
+            Bind::Repeated { .. } => ArrowKind::Repeated,
+            Bind::Peek { .. } => ArrowKind::Peek,
+        }
+    }
+
+    /// Whether the left-hand side ends with a `...rest` formal collecting the remaining
+    /// message parts
+    pub fn has_continuation(&self) -> bool {
+        self.names().remainder.is_some()
+    }
+
+    /// Pairs each name on the left-hand side with whether it is a binding occurrence.
+    /// See [`Names::binders`].
+    pub fn binders(&'a self) -> impl Iterator<Item = (&'a Name<'a>, bool)> {
+        self.names().binders()
+    }
 }
 
 // source definitions
@@ -825,6 +990,8 @@ impl<'a> LetBinding<'a> {
 pub struct NameDecl<'ast> {
     pub id: Id<'ast>,
     pub uri: Option<Uri<'ast>>,
+    /// The whole declaration, including the URI if there is one
+    pub span: SourceSpan,
 }
 
 impl PartialEq for NameDecl<'_> {
@@ -0,0 +1,163 @@
+//! Building process trees in code, without going through source text.
+
+use std::cell::Cell;
+
+use crate::ast::{
+    AnnProc, BinaryExpOp, Bind, Id, Name, NameDecl, Names, Proc, SendType, Source, Var,
+};
+use crate::parser::ASTBuilder;
+use crate::{SourcePos, SourceSpan};
+
+/// An arena of processes built in code, for tools that generate Rholang.
+///
+/// Every method returns a process that lives as long as the arena, ready to be handed to
+/// anything that takes parsed processes. Identifiers are matched by name, so the `x` of
+/// [`new_scope`](Self::new_scope) binds every `x` built inside its body:
+///
+/// ```
+/// use rholang_parser::{RholangAst, unparse};
+///
+/// let ast = RholangAst::new();
+/// let sum = ast.binary(rholang_parser::ast::BinaryExpOp::Add, ast.int(1), ast.int(2));
+/// let proc = ast.new_scope(&["x"], ast.send(ast.name("x"), &[sum]));
+/// assert_eq!(unparse(&proc), "new x in {\n    x!(1 + 2)\n}");
+/// ```
+///
+/// There is no source text, so each node gets a position of its own on an imaginary first
+/// line, in the order the nodes are built. Later passes tell occurrences of a variable
+/// apart by position, which makes distinct positions necessary.
+pub struct RholangAst<'ast> {
+    builder: ASTBuilder<'ast>,
+    next_col: Cell<usize>,
+}
+
+impl Default for RholangAst<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'ast> RholangAst<'ast> {
+    pub fn new() -> Self {
+        RholangAst {
+            builder: ASTBuilder::new(),
+            next_col: Cell::new(1),
+        }
+    }
+
+    /// The underlying builder, for the processes that have no shorthand here
+    pub fn builder(&self) -> &ASTBuilder<'ast> {
+        &self.builder
+    }
+
+    /// A fresh span, distinct from all others of this arena
+    pub fn span(&self) -> SourceSpan {
+        let col = self.next_col.get();
+        self.next_col.set(col + 1);
+        SourcePos { line: 1, col }.span_of(1)
+    }
+
+    /// Annotate `proc` with a fresh [`span`](Self::span)
+    pub fn ann(&self, proc: &'ast Proc<'ast>) -> AnnProc<'ast> {
+        proc.ann(self.span())
+    }
+
+    /// An identifier at a fresh position
+    pub fn id(&'ast self, name: &str) -> Id<'ast> {
+        Id {
+            name: self.builder.alloc_str(name),
+            pos: self.span().start,
+        }
+    }
+
+    pub fn nil(&'ast self) -> AnnProc<'ast> {
+        self.ann(self.builder.const_nil())
+    }
+
+    pub fn bool(&'ast self, value: bool) -> AnnProc<'ast> {
+        self.ann(if value {
+            self.builder.const_true()
+        } else {
+            self.builder.const_false()
+        })
+    }
+
+    pub fn int(&'ast self, value: i64) -> AnnProc<'ast> {
+        self.ann(self.builder.alloc_long_literal(value))
+    }
+
+    /// A string literal holding `value` as is, with no escape sequences to decode
+    pub fn string(&'ast self, value: &str) -> AnnProc<'ast> {
+        self.ann(self.builder.alloc_decoded_string_literal(value))
+    }
+
+    /// The process variable `name`
+    pub fn var(&'ast self, name: &str) -> AnnProc<'ast> {
+        self.ann(self.builder.alloc_var(self.id(name)))
+    }
+
+    /// The name variable `name`
+    pub fn name(&'ast self, name: &str) -> Name<'ast> {
+        Name::NameVar(Var::Id(self.id(name)))
+    }
+
+    /// The name `@proc`
+    pub fn quote(&self, proc: AnnProc<'ast>) -> Name<'ast> {
+        Name::Quote(proc)
+    }
+
+    pub fn list(&'ast self, elements: &[AnnProc<'ast>]) -> AnnProc<'ast> {
+        self.ann(self.builder.alloc_list(elements))
+    }
+
+    pub fn par(&'ast self, left: AnnProc<'ast>, right: AnnProc<'ast>) -> AnnProc<'ast> {
+        self.ann(self.builder.alloc_par(left, right))
+    }
+
+    pub fn binary(
+        &'ast self,
+        op: BinaryExpOp,
+        left: AnnProc<'ast>,
+        right: AnnProc<'ast>,
+    ) -> AnnProc<'ast> {
+        self.ann(self.builder.alloc_binary_exp(op, left, right))
+    }
+
+    /// `channel!(args)`
+    pub fn send(&'ast self, channel: Name<'ast>, args: &[AnnProc<'ast>]) -> AnnProc<'ast> {
+        self.ann(self.builder.alloc_send(SendType::Single, channel, args))
+    }
+
+    /// `for (patterns <- channel) { body }`
+    pub fn receive(
+        &'ast self,
+        patterns: impl IntoIterator<Item = Name<'ast>>,
+        channel: Name<'ast>,
+        body: AnnProc<'ast>,
+    ) -> AnnProc<'ast> {
+        let bind = Bind::Linear {
+            lhs: Names {
+                names: patterns.into_iter().collect(),
+                remainder: None,
+            },
+            rhs: Source::Simple { name: channel },
+        };
+        self.ann(self.builder.alloc_for([[bind]], body))
+    }
+
+    /// `new names in { body }`
+    pub fn new_scope(&'ast self, names: &[&str], body: AnnProc<'ast>) -> AnnProc<'ast> {
+        let decls = names
+            .iter()
+            .map(|name| {
+                let id = self.id(name);
+                NameDecl {
+                    id,
+                    uri: None,
+                    span: id.pos.span_of(1),
+                }
+            })
+            .collect();
+        self.ann(self.builder.alloc_new(body, decls))
+    }
+}
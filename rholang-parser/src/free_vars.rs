@@ -0,0 +1,276 @@
+//! Free variables of a process tree.
+
+use std::collections::HashSet;
+
+use crate::ast::{AnnProc, Bind, Collection, Id, Name, Names, Proc, Source, SyncSendCont, Var};
+
+/// Returns the variables that occur free in `proc`, each once, in order of first occurrence.
+///
+/// A variable is free unless an enclosing `new`, `for`, `contract`, `let`, `match` case or
+/// `select` branch binds it. Receive sources are evaluated outside the binds of their own
+/// receipt, while later `;`-separated receipts and sequential `let` bindings see the names
+/// bound before them. A `=x` reference in a pattern is a use of `x`, not a binder.
+pub fn free_vars<'a>(proc: &AnnProc<'a>) -> Vec<Id<'a>> {
+    let mut walk = FreeVars::default();
+    walk.proc(proc);
+    walk.free
+}
+
+#[derive(Default)]
+struct FreeVars<'a> {
+    bound: Vec<&'a str>,
+    in_pattern: bool,
+    seen: HashSet<&'a str>,
+    free: Vec<Id<'a>>,
+}
+
+impl<'a> FreeVars<'a> {
+    fn proc(&mut self, proc: &AnnProc<'a>) {
+        match proc.proc {
+            Proc::Nil
+            | Proc::Unit
+            | Proc::BoolLiteral(_)
+            | Proc::LongLiteral(_)
+            | Proc::SignedIntLiteral { .. }
+            | Proc::UnsignedIntLiteral { .. }
+            | Proc::BigIntLiteral(_)
+            | Proc::BigRatLiteral(_)
+            | Proc::FloatLiteral { .. }
+            | Proc::FixedPointLiteral { .. }
+            | Proc::StringLiteral(_)
+            | Proc::UriLiteral(_)
+            | Proc::SimpleType(_)
+            | Proc::Bad => {}
+
+            Proc::Collection(collection) => self.collection(collection),
+            Proc::ProcVar(var) => self.var(*var),
+            Proc::VarRef { var, .. } => self.use_id(*var),
+
+            Proc::Par { left, right } => {
+                self.proc(left);
+                self.proc(right);
+            }
+            Proc::IfThenElse {
+                condition,
+                if_true,
+                if_false,
+            } => {
+                self.proc(condition);
+                self.proc(if_true);
+                if let Some(if_false) = if_false {
+                    self.proc(if_false);
+                }
+            }
+            Proc::Send {
+                channel, inputs, ..
+            } => {
+                self.name(channel);
+                self.procs(inputs);
+            }
+            Proc::SendSync {
+                channel,
+                inputs,
+                cont,
+            } => {
+                self.name(channel);
+                self.procs(inputs);
+                if let SyncSendCont::NonEmpty(cont) = cont {
+                    self.proc(cont);
+                }
+            }
+            Proc::Bundle { proc, .. } => self.proc(proc),
+            Proc::Eval { name } => self.name(name),
+            Proc::Method { receiver, args, .. } => {
+                self.proc(receiver);
+                self.procs(args);
+            }
+            Proc::UnaryExp { arg, .. } => self.proc(arg),
+            Proc::BinaryExp { left, right, .. } => {
+                self.proc(left);
+                self.proc(right);
+            }
+
+            Proc::New { decls, proc } => {
+                let mark = self.bound.len();
+                self.bound.extend(decls.iter().map(|decl| decl.id.name));
+                self.proc(proc);
+                self.bound.truncate(mark);
+            }
+            Proc::ForComprehension { receipts, proc } => {
+                let mark = self.bound.len();
+                for receipt in receipts {
+                    for bind in receipt.iter() {
+                        match bind {
+                            Bind::Linear { rhs, .. } => self.source(rhs),
+                            Bind::Repeated { rhs, .. } | Bind::Peek { rhs, .. } => self.name(rhs),
+                        }
+                    }
+                    for bind in receipt.iter() {
+                        let (Bind::Linear { lhs, .. }
+                        | Bind::Repeated { lhs, .. }
+                        | Bind::Peek { lhs, .. }) = bind;
+                        self.pattern(|walk| walk.names(lhs));
+                    }
+                    if let Some(guard) = &receipt.guard {
+                        self.proc(guard);
+                    }
+                }
+                self.proc(proc);
+                self.bound.truncate(mark);
+            }
+            Proc::Match { expression, cases } => {
+                self.proc(expression);
+                for case in cases {
+                    let mark = self.bound.len();
+                    self.pattern(|walk| walk.proc(&case.pattern));
+                    if let Some(guard) = &case.guard {
+                        self.proc(guard);
+                    }
+                    self.proc(&case.proc);
+                    self.bound.truncate(mark);
+                }
+            }
+            Proc::Select { branches } => {
+                for branch in branches {
+                    let mark = self.bound.len();
+                    for pattern in &branch.patterns {
+                        self.source(&pattern.rhs);
+                    }
+                    for pattern in &branch.patterns {
+                        self.pattern(|walk| walk.names(&pattern.lhs));
+                    }
+                    if let Some(guard) = &branch.guard {
+                        self.proc(guard);
+                    }
+                    self.proc(&branch.proc);
+                    self.bound.truncate(mark);
+                }
+            }
+            Proc::Let {
+                bindings,
+                body,
+                concurrent,
+            } => {
+                let mark = self.bound.len();
+                if *concurrent {
+                    for binding in bindings {
+                        self.procs(&binding.rhs);
+                    }
+                    for binding in bindings {
+                        self.pattern(|walk| walk.names(&binding.lhs));
+                    }
+                } else {
+                    for binding in bindings {
+                        self.procs(&binding.rhs);
+                        self.pattern(|walk| walk.names(&binding.lhs));
+                    }
+                }
+                self.proc(body);
+                self.bound.truncate(mark);
+            }
+            Proc::Contract {
+                name,
+                formals,
+                body,
+            } => {
+                self.name(name);
+                let mark = self.bound.len();
+                self.pattern(|walk| walk.names(formals));
+                self.proc(body);
+                self.bound.truncate(mark);
+            }
+        }
+    }
+
+    fn procs(&mut self, procs: &[AnnProc<'a>]) {
+        for proc in procs {
+            self.proc(proc);
+        }
+    }
+
+    fn collection(&mut self, collection: &'a Collection<'a>) {
+        let remainder = match collection {
+            Collection::List {
+                elements,
+                remainder,
+            }
+            | Collection::Set {
+                elements,
+                remainder,
+            }
+            | Collection::PathMap {
+                elements,
+                remainder,
+            } => {
+                self.procs(elements);
+                remainder
+            }
+            Collection::Tuple(elements) => {
+                self.procs(elements);
+                &None
+            }
+            Collection::Map {
+                elements,
+                remainder,
+            } => {
+                for (key, value) in elements {
+                    self.proc(key);
+                    self.proc(value);
+                }
+                remainder
+            }
+        };
+        if let Some(remainder) = remainder {
+            self.var(*remainder);
+        }
+    }
+
+    fn name(&mut self, name: &Name<'a>) {
+        match name {
+            Name::NameVar(var) => self.var(*var),
+            Name::Quote(proc) => self.proc(proc),
+        }
+    }
+
+    fn names(&mut self, names: &Names<'a>) {
+        for name in &names.names {
+            self.name(name);
+        }
+        if let Some(remainder) = names.remainder {
+            self.var(remainder);
+        }
+    }
+
+    fn source(&mut self, source: &Source<'a>) {
+        match source {
+            Source::Simple { name } | Source::ReceiveSend { name } => self.name(name),
+            Source::SendReceive { name, inputs } => {
+                self.name(name);
+                self.procs(inputs);
+            }
+        }
+    }
+
+    /// Walks a pattern, binding the variables in it for the rest of the current scope
+    fn pattern(&mut self, walk: impl FnOnce(&mut Self)) {
+        let in_pattern = std::mem::replace(&mut self.in_pattern, true);
+        walk(self);
+        self.in_pattern = in_pattern;
+    }
+
+    fn var(&mut self, var: Var<'a>) {
+        if let Var::Id(id) = var {
+            if self.in_pattern {
+                self.bound.push(id.name);
+            } else {
+                self.use_id(id);
+            }
+        }
+    }
+
+    fn use_id(&mut self, id: Id<'a>) {
+        if !self.bound.contains(&id.name) && self.seen.insert(id.name) {
+            self.free.push(id);
+        }
+    }
+}
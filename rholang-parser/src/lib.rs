@@ -5,14 +5,25 @@
 
 use std::fmt::{Debug, Display, Write};
 
+#[cfg(feature = "arbitrary")]
+pub mod arbitrary;
 pub mod ast;
+mod build;
+mod free_vars;
+mod location;
+mod metrics;
 #[cfg(not(target_arch = "wasm32"))]
 pub mod parser;
 #[cfg(target_arch = "wasm32")]
 pub mod parser_wasm;
+mod precedence;
+mod query;
+mod sexp;
+mod structural;
 #[cfg(target_arch = "wasm32")]
 pub use parser_wasm as parser;
 mod traverse;
+mod unparse;
 
 pub use parser::{RholangParser, ASTBuilder};
 
@@ -24,20 +35,34 @@ pub type ParseFailure<'a> = parser::errors::ParsingFailure<'a>;
 pub struct ParseFailure<'a> {
     pub _phantom: core::marker::PhantomData<&'a ()>,
 }
+pub use build::RholangAst;
+pub use free_vars::free_vars;
+pub use location::{LocationIndex, build_location_index};
+pub use metrics::{ProcMetrics, metrics};
+pub use precedence::{PrecedenceViolation, check_precedence};
+pub use query::{ProcFilter, ProcQueryExt};
+pub use sexp::to_sexp;
+pub use structural::{first_difference, structurally_eq};
 pub use traverse::{DfsEvent, DfsEventExt};
+pub use unparse::{BraceStyle, UnparseConfig, unparse, unparse_with};
 
 /// a position in the source code. 1-based
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct SourcePos {
     pub line: usize,
     pub col: usize,
 }
 
 impl SourcePos {
+    /// The span of `chars` columns starting here.
+    ///
+    /// The span is assumed to stay on this line: `line` is never advanced, so text with
+    /// embedded newlines needs a span built from its actual end position instead. The end
+    /// column saturates at `usize::MAX` rather than wrapping around.
     pub fn span_of(self, chars: usize) -> SourceSpan {
         let end = SourcePos {
             line: self.line,
-            col: self.col + chars,
+            col: self.col.saturating_add(chars),
         };
         SourceSpan { start: self, end }
     }
@@ -82,8 +107,8 @@ impl Default for SourcePos {
     }
 }
 
-/// a span in the source code (exclusive)
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// a span in the source code (exclusive). Spans are ordered by `start`, then by `end`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct SourceSpan {
     pub start: SourcePos,
     pub end: SourcePos,
@@ -93,6 +118,39 @@ impl SourceSpan {
     pub fn empty_at(start: SourcePos) -> Self {
         Self { start, end: start }
     }
+
+    /// Checks if `pos` lies within this span. The `end` position is exclusive.
+    pub fn contains(&self, pos: SourcePos) -> bool {
+        self.start <= pos && pos < self.end
+    }
+
+    /// Checks if this span and `other` intersect. Spans that merely touch
+    /// (one ends where the other starts) do not overlap.
+    pub fn overlaps(&self, other: SourceSpan) -> bool {
+        self.start < other.end && other.start < self.end
+    }
+
+    /// Returns the smallest span covering both `self` and `other`
+    /// (including any gap between them).
+    pub fn merge(self, other: SourceSpan) -> SourceSpan {
+        SourceSpan {
+            start: self.start.min(other.start),
+            end: self.end.max(other.end),
+        }
+    }
+
+    /// Renders the span as briefly as possible: `line:col` for an empty span,
+    /// `line:startcol-endcol` for a span on one line, and the `Display` form
+    /// `line:col - line:col` for a span crossing lines.
+    pub fn render_compact(&self) -> String {
+        if self.start == self.end {
+            self.start.to_string()
+        } else if self.start.line == self.end.line {
+            format!("{}:{}-{}", self.start.line, self.start.col, self.end.col)
+        } else {
+            self.to_string()
+        }
+    }
 }
 
 impl Default for SourceSpan {
@@ -0,0 +1,70 @@
+//! Lookup of the innermost process at a source position.
+
+use crate::SourcePos;
+use crate::ast::AnnProc;
+
+/// The source positions of a process tree, cut into segments that are each covered by one
+/// innermost node.
+///
+/// Built once by [`build_location_index`]; every [`LocationIndex::node_at`] is then a binary
+/// search instead of a walk over the tree.
+#[derive(Debug, Clone)]
+pub struct LocationIndex<'a> {
+    /// Segment starts in ascending order; a segment runs until the next one starts
+    segments: Vec<(SourcePos, Option<&'a AnnProc<'a>>)>,
+}
+
+impl<'a> LocationIndex<'a> {
+    /// The innermost node of the tree whose span contains `pos`.
+    ///
+    /// When several nodes share the same span (as desugared processes do), the one visited
+    /// last by [`AnnProc::iter_preorder_dfs`] wins.
+    pub fn node_at(&self, pos: SourcePos) -> Option<&'a AnnProc<'a>> {
+        let after = self.segments.partition_point(|(start, _)| *start <= pos);
+        after.checked_sub(1).and_then(|i| self.segments[i].1)
+    }
+
+    fn start_segment(&mut self, start: SourcePos, node: Option<&'a AnnProc<'a>>) {
+        match self.segments.last_mut() {
+            Some((last, owner)) if *last == start => *owner = node,
+            _ => self.segments.push((start, node)),
+        }
+    }
+
+    /// Pops the `open` nodes ending at or before `pos` (all of them for `None`), handing the
+    /// positions after each back to the node below it
+    fn close_until(&mut self, open: &mut Vec<&'a AnnProc<'a>>, pos: Option<SourcePos>) {
+        while let Some(top) = open.last()
+            && pos.is_none_or(|pos| top.span.end <= pos)
+        {
+            let end = top.span.end;
+            open.pop();
+            self.start_segment(end, open.last().copied());
+        }
+    }
+}
+
+/// Indexes every process of `root` by its span, see [`LocationIndex`].
+pub fn build_location_index<'a>(root: &'a AnnProc<'a>) -> LocationIndex<'a> {
+    let mut nodes: Vec<(usize, &'a AnnProc<'a>)> = root
+        .iter_preorder_dfs()
+        .filter(|node| node.span.start < node.span.end)
+        .enumerate()
+        .collect();
+    // enclosing spans come first, so they are below the nodes they contain on the stack
+    nodes.sort_by(|(a_order, a), (b_order, b)| {
+        (a.span.start, b.span.end, a_order).cmp(&(b.span.start, a.span.end, b_order))
+    });
+
+    let mut index = LocationIndex {
+        segments: Vec::with_capacity(nodes.len() * 2),
+    };
+    let mut open = Vec::new();
+    for (_, node) in nodes {
+        index.close_until(&mut open, Some(node.span.start));
+        index.start_segment(node.span.start, Some(node));
+        open.push(node);
+    }
+    index.close_until(&mut open, None);
+    index
+}
@@ -0,0 +1,54 @@
+//! Size and complexity metrics of a process tree.
+
+use crate::ast::{AnnProc, Proc};
+use crate::traverse::DfsEvent;
+
+/// Counters describing the size and shape of a process.
+///
+/// All counters cover process positions only, as visited by
+/// [`AnnProc::iter_dfs_event`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ProcMetrics {
+    /// Number of process nodes, including the root
+    pub nodes: usize,
+    /// Maximum nesting depth; a lone leaf has depth 1
+    pub max_depth: usize,
+    /// Number of names bound by `new` declarations
+    pub new_names: usize,
+    /// Number of receives: one per bind of a for-comprehension, plus one per contract
+    pub receives: usize,
+    /// Number of sends, synchronous sends included
+    pub sends: usize,
+}
+
+/// Computes [`ProcMetrics`] for `proc` in a single depth-first pass.
+pub fn metrics<'a>(proc: &'a AnnProc<'a>) -> ProcMetrics {
+    let mut metrics = ProcMetrics::default();
+    let mut depth = 0;
+
+    for event in proc.iter_dfs_event() {
+        let node = match event {
+            DfsEvent::Enter(node) => node,
+            DfsEvent::Exit(_) => {
+                depth -= 1;
+                continue;
+            }
+        };
+
+        depth += 1;
+        metrics.nodes += 1;
+        metrics.max_depth = metrics.max_depth.max(depth);
+
+        match node.proc {
+            Proc::New { decls, .. } => metrics.new_names += decls.len(),
+            Proc::ForComprehension { receipts, .. } => {
+                metrics.receives += receipts.iter().map(|receipt| receipt.len()).sum::<usize>()
+            }
+            Proc::Contract { .. } => metrics.receives += 1,
+            Proc::Send { .. } | Proc::SendSync { .. } => metrics.sends += 1,
+            _ => {}
+        }
+    }
+
+    metrics
+}
@@ -51,6 +51,20 @@ impl<'ast> ASTBuilder<'ast> {
         }
     }
 
+    /// Frees every node and string allocated so far.
+    ///
+    /// Taking `&mut self` means no node of the builder can still be borrowed, so nothing can
+    /// observe the freed memory.
+    pub fn reset(&mut self) {
+        self.arena = Arena::with_capacity(64);
+        self.string_arena = Arena::with_capacity(32);
+    }
+
+    /// Number of nodes and strings currently held by the arenas
+    pub fn allocated(&self) -> usize {
+        self.arena.len() + self.string_arena.len()
+    }
+
     pub fn const_nil(&self) -> &Proc<'ast> {
         &self.nil
     }
@@ -88,6 +102,18 @@ impl<'ast> ASTBuilder<'ast> {
             .alloc(Proc::StringLiteral(crate::trim_byte(value, b'"')))
     }
 
+    /// Unlike [`alloc_string_literal`](Self::alloc_string_literal), keeps `value` verbatim
+    /// instead of trimming quotes off it: it is the body of a literal, such as a raw string.
+    pub(crate) fn alloc_string_literal_body(&self, value: &'ast str) -> &Proc<'ast> {
+        self.arena.alloc(Proc::StringLiteral(value))
+    }
+
+    /// Unlike [`alloc_string_literal`](Self::alloc_string_literal), takes the value verbatim:
+    /// it has already been stripped of quotes and had its escape sequences decoded.
+    pub(crate) fn alloc_decoded_string_literal(&'ast self, value: &str) -> &'ast Proc<'ast> {
+        self.arena.alloc(Proc::StringLiteral(self.alloc_str(value)))
+    }
+
     pub fn alloc_long_literal(&self, value: i64) -> &Proc<'ast> {
         match value {
             0 => &self.zero,
@@ -13,11 +13,18 @@ pub enum ParsingError {
     Unexpected(char),
     UnexpectedVar,
     UnexpectedQuote,
+    /// A process that is neither a variable nor a quote where a name is required, such as the
+    /// channel of `42!(1)`.
+    ExpectedName,
     UnexpectedMatchAfter {
         rule: &'static str,
         offender: &'static str,
     },
     NumberOutOfRange,
+    /// String literal contains an escape sequence other than `\n`, `\r`, `\t`, `\'`, `\"`,
+    /// `\\`, an octal escape of one to three octal digits no greater than `\377` (such as `\0`
+    /// or `\101`), or `\u{...}` naming a valid code point. Holds the offending sequence.
+    InvalidEscape(String),
     DuplicateNameDecl {
         first: SourcePos,
         second: SourcePos,
@@ -38,6 +45,15 @@ pub enum ParsingError {
     ///   - Every agent must declare a default.
     ///   - If any `private method` is declared, a `private default` is required.
     MissingAgentDecl { what: &'static str },
+    /// The byte range of a node does not fall on character boundaries of the source, so its
+    /// text cannot be taken as a string slice.
+    InvalidUtf8,
+    /// A string or URI literal is still open at the end of the source. `span` runs from its
+    /// opening delimiter to the end of the source, while the error itself points at the
+    /// delimiter.
+    UnterminatedString {
+        span: SourceSpan,
+    },
 }
 
 impl ParsingError {
@@ -196,6 +212,16 @@ pub(super) fn query_errors(of: &tree_sitter::Node, source: &str, into: &mut Vec<
         }
     }
 
+    // An open literal swallows the rest of the source, so whatever tree-sitter reports from its
+    // opening delimiter on is a consequence of it
+    if let Some(literal) = UnterminatedLiteral::find(source)
+        && of.end_byte() > literal.start
+    {
+        general_errors.retain(|node| node.end_byte() <= literal.start);
+        into.retain(|error| error.byte_range.end <= literal.start);
+        into.push(literal.error(source));
+    }
+
     // Emit only general errors not already claimed by more specific ones
     for node in general_errors {
         let range = node.byte_range();
@@ -203,6 +229,106 @@ pub(super) fn query_errors(of: &tree_sitter::Node, source: &str, into: &mut Vec<
             continue; // already handled by more specific pattern
         }
 
-        into.push(AnnParsingError::from_error(&node, source_bytes));
+        match non_name_channel(&node, source_bytes) {
+            Some(channel) => into.push(AnnParsingError::new(ParsingError::ExpectedName, &channel)),
+            None => into.push(AnnParsingError::from_error(&node, source_bytes)),
+        }
+    }
+}
+
+/// A string or URI literal left open at the end of the source
+pub(super) struct UnterminatedLiteral {
+    /// Byte offset of the opening delimiter
+    pub(super) start: usize,
+    /// The delimiter that would close the literal
+    pub(super) delimiter: &'static str,
+}
+
+impl UnterminatedLiteral {
+    /// Finds the literal `code` ends inside of, if any. Delimiters in comments do not count.
+    pub(super) fn find(code: &str) -> Option<Self> {
+        let bytes = code.as_bytes();
+        let rest = |from: usize| &code[from.min(code.len())..];
+        let mut i = 0;
+        while i < bytes.len() {
+            let (delimiter, body) = match &bytes[i..] {
+                [b'/', b'/', ..] => {
+                    i = rest(i).find('\n').map_or(code.len(), |n| i + n);
+                    continue;
+                }
+                [b'/', b'*', ..] => {
+                    i = rest(i + 2).find("*/").map_or(code.len(), |n| i + 2 + n + 2);
+                    continue;
+                }
+                [b'"', b'"', b'"', ..] => ("\"\"\"", rest(i + 3).find("\"\"\"")),
+                [b'"', ..] => ("\"", closing_quote(rest(i + 1))),
+                [b'`', ..] => ("`", rest(i + 1).find('`')),
+                _ => {
+                    i += 1;
+                    continue;
+                }
+            };
+            match body {
+                Some(len) => i += 2 * delimiter.len() + len,
+                None => {
+                    return Some(UnterminatedLiteral {
+                        start: i,
+                        delimiter,
+                    });
+                }
+            }
+        }
+        None
+    }
+
+    pub(super) fn error(&self, code: &str) -> AnnParsingError {
+        let start = super::end_of(&code[..self.start]);
+        AnnParsingError {
+            error: ParsingError::UnterminatedString {
+                span: SourceSpan {
+                    start,
+                    end: super::end_of(code),
+                },
+            },
+            span: start.span_of(self.delimiter.len()),
+            byte_range: self.start..self.start + self.delimiter.len(),
+        }
+    }
+}
+
+/// Length of the body of a `"` string whose body starts `body`, if it is closed
+fn closing_quote(body: &str) -> Option<usize> {
+    let mut chars = body.char_indices();
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '\\' => {
+                chars.next();
+            }
+            '"' => return Some(i),
+            _ => {}
+        }
+    }
+    None
+}
+
+/// The process a send is attempted on, if `error` is what the grammar makes of a send whose
+/// channel is not a name. Depending on the context, the process is either a sibling in front of
+/// the error (`42!(1)`) or its last child (`{ 42!(1) }`).
+fn non_name_channel<'t>(
+    error: &tree_sitter::Node<'t>,
+    code: &[u8],
+) -> Option<tree_sitter::Node<'t>> {
+    let text = get_text(error, code).trim();
+    let mut channel = if text.starts_with('!') {
+        error.prev_named_sibling()?
+    } else if text.ends_with('!') || text.ends_with("!?") {
+        error.named_child(error.named_child_count().checked_sub(1)?)?
+    } else {
+        return None;
+    };
+    // in `P | 42!(1)` the par is in front of the error, but only its right side is the channel
+    while channel.kind() == "par" {
+        channel = channel.named_child(channel.named_child_count().checked_sub(1)?)?;
     }
+    (!matches!(channel.kind(), "var" | "quote")).then_some(channel)
 }
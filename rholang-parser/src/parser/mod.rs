@@ -6,8 +6,9 @@ use nonempty_collections::NEVec;
 use validated::Validated;
 
 use crate::{
+    SourcePos, SourceSpan,
     ast::AnnProc,
-    parser::errors::{AnnParsingError, ParsingFailure},
+    parser::errors::{AnnParsingError, ParsingError, ParsingFailure, UnterminatedLiteral},
 };
 
 pub use ast_builder::ASTBuilder;
@@ -27,7 +28,33 @@ impl<'a> RholangParser<'a> {
         &'a self,
         code: &'code str,
     ) -> Validated<Vec<AnnProc<'a>>, ParsingFailure<'a>> {
+        self.parse_with_tree(code).1
+    }
+
+    /// Like [`parse`](Self::parse), but also returns the tree-sitter syntax tree the AST was
+    /// built from.
+    ///
+    /// The tree is for tooling working below the AST: running tree-sitter queries over it, or
+    /// applying [`Tree::edit`](tree_sitter::Tree::edit) and handing it back to a
+    /// `tree_sitter::Parser` to reparse a document incrementally. Each named child of its root
+    /// is one top-level process.
+    pub fn parse_with_tree<'code: 'a>(
+        &'a self,
+        code: &'code str,
+    ) -> (
+        tree_sitter::Tree,
+        Validated<Vec<AnnProc<'a>>, ParsingFailure<'a>>,
+    ) {
         let tree = parsing::parse_to_tree(code);
+        let procs = self.tree_to_ast(&tree, code);
+        (tree, procs)
+    }
+
+    fn tree_to_ast<'code: 'a>(
+        &'a self,
+        tree: &tree_sitter::Tree,
+        code: &'code str,
+    ) -> Validated<Vec<AnnProc<'a>>, ParsingFailure<'a>> {
         let root = tree.root_node();
         if root.is_error() {
             let mut errors_inside = Vec::new();
@@ -46,10 +73,144 @@ impl<'a> RholangParser<'a> {
             .collect()
     }
 
+    /// Parses `code` for live editing, always producing some AST.
+    ///
+    /// Where a single token is missing, such as the `}` of `new x in {`, tree-sitter
+    /// inserts it and the process is built as if it were there; each inserted token is
+    /// reported as a [`ParsingError::MissingToken`]. Parts of `code` tree-sitter cannot
+    /// repair that way become `Proc::Bad`, reported with the errors [`parse`](Self::parse)
+    /// gives for them. A string or URI literal left open is first closed at the end of its
+    /// line and reported as a [`ParsingError::UnterminatedString`], if that leaves only
+    /// missing tokens to repair.
+    pub fn parse_lenient<'code: 'a>(
+        &'a self,
+        code: &'code str,
+    ) -> (Vec<AnnProc<'a>>, Vec<AnnParsingError>) {
+        let (procs, errors) = self.parse_best_effort(code);
+        if errors.is_empty() {
+            return (procs, errors);
+        }
+
+        if let Some(literal) = UnterminatedLiteral::find(code) {
+            // the literal takes the rest of its line
+            let body = literal.start + literal.delimiter.len();
+            let line_end = code[body..].find('\n').map_or(code.len(), |n| body + n);
+            let mut patched = code.to_string();
+            patched.insert_str(line_end, literal.delimiter);
+            let patched = self.ast_builder.alloc_str(&patched);
+
+            let (patched_procs, patched_errors) = self.parse_best_effort(patched);
+            if patched_errors
+                .iter()
+                .all(|e| matches!(e.error, ParsingError::MissingToken(_)))
+            {
+                let mut recovered = vec![literal.error(code)];
+                recovered.extend(patched_errors.into_iter().map(|mut missing| {
+                    // inserted tokens are empty, and those past the patch move back by it
+                    let mut at = missing.byte_range.start;
+                    if at > line_end {
+                        at -= literal.delimiter.len();
+                    }
+                    missing.span = SourceSpan::empty_at(end_of(&code[..at]));
+                    missing.byte_range = at..at;
+                    missing
+                }));
+                return (patched_procs, recovered);
+            }
+        }
+
+        (procs, errors)
+    }
+
+    /// Every top-level process of `code` that parses, and the partial tree of
+    /// those that do not, together with all the errors encountered
+    fn parse_best_effort<'code: 'a>(
+        &'a self,
+        code: &'code str,
+    ) -> (Vec<AnnProc<'a>>, Vec<AnnParsingError>) {
+        let tree = parsing::parse_to_tree(code);
+        let root = tree.root_node();
+        let mut procs = Vec::new();
+        let mut errors = Vec::new();
+        if root.is_error() {
+            errors::query_errors(&root, code, &mut errors);
+            if errors.is_empty() {
+                errors.push(AnnParsingError::from_error(&root, code.as_bytes()));
+            }
+            procs.push(AnnProc {
+                proc: self.ast_builder.bad_const(),
+                span: root.range().into(),
+            });
+            return (procs, errors);
+        }
+
+        let mut walker = tree.walk();
+        for node in root.named_children(&mut walker) {
+            match parsing::node_to_ast(&node, &self.ast_builder, code) {
+                Validated::Good(proc) => procs.push(proc),
+                Validated::Fail(failures) => {
+                    for failure in failures {
+                        procs.extend(failure.partial_tree);
+                        errors.extend(failure.errors);
+                    }
+                }
+            }
+        }
+        (procs, errors)
+    }
+
     // Expose AST builder for accessing const_nil
     pub fn ast_builder(&self) -> &ASTBuilder<'a> {
         &self.ast_builder
     }
+
+    /// Frees the AST of every document parsed so far.
+    ///
+    /// Trees returned by [`parse`](Self::parse) borrow the parser for all of `'a`, so once a
+    /// document has been parsed this is no longer callable; use [`scoped`](Self::scoped) to
+    /// parse documents one after another with the same parser.
+    pub fn reset(&mut self) {
+        self.ast_builder.reset();
+    }
+
+    /// Runs `f` with this parser and `code` under a fresh lifetime and frees everything it
+    /// parsed once `f` returns, so one parser can serve many documents without its arena
+    /// growing.
+    ///
+    /// The trees parsed inside `f` cannot outlive it, as `R` cannot mention the fresh lifetime:
+    ///
+    /// ```compile_fail
+    /// use rholang_parser::RholangParser;
+    ///
+    /// let mut parser = RholangParser::new();
+    /// let procs = parser.scoped("Nil", |p, code| p.parse(code).ok().unwrap());
+    /// println!("{:?}", procs[0]);
+    /// ```
+    pub fn scoped<R>(
+        &mut self,
+        code: &str,
+        f: impl for<'p> FnOnce(&'p RholangParser<'p>, &'p str) -> R,
+    ) -> R {
+        self.reset();
+        let fresh = unsafe {
+            // SAFETY: the arenas are empty after the reset, so the builder holds no value of
+            // lifetime `'a`. Whatever `f` allocates under the fresh lifetime cannot escape it and
+            // is freed below, before `self` can be used again.
+            &*(self as *const RholangParser<'a>).cast::<RholangParser<'_>>()
+        };
+        let result = f(fresh, code);
+        self.reset();
+        result
+    }
+}
+
+/// The position just past the last character of `code`
+fn end_of(code: &str) -> SourcePos {
+    let line_start = code.rfind('\n').map_or(0, |i| i + 1);
+    SourcePos {
+        line: code.matches('\n').count() + 1,
+        col: code.len() - line_start + 1,
+    }
 }
 
 impl Default for RholangParser<'_> {
@@ -3,6 +3,7 @@ use bitvec::vec::BitVec;
 use nonempty_collections::NEVec;
 use rholang_tree_sitter_proc_macro::{field, kind};
 use smallvec::{SmallVec, ToSmallVec};
+use std::borrow::Cow;
 use std::fmt::Debug;
 use std::iter::FusedIterator;
 use std::slice::Iter as SliceIter;
@@ -148,11 +149,11 @@ pub(super) fn node_to_ast<'ast>(
                 }
                 kind!("long_literal") => {
                     let lit_value = get_node_value(&node, source);
-                    match lit_value.parse::<i64>() {
-                        Ok(i64_value) => {
+                    match parse_long_literal(lit_value) {
+                        Some(i64_value) => {
                             proc_stack.push(ast_builder.alloc_long_literal(i64_value), span)
                         }
-                        Err(_) => {
+                        None => {
                             // the only possibility is pos/neg overflow
                             errors
                                 .push(AnnParsingError::new(ParsingError::NumberOutOfRange, &node));
@@ -215,13 +216,36 @@ pub(super) fn node_to_ast<'ast>(
                     }
                 }
                 kind!("string_literal") => {
-                    let lit_value = get_node_value(&node, source);
-                    proc_stack.push(ast_builder.alloc_string_literal(lit_value), span);
-                }
-                kind!("uri_literal") => {
-                    let lit_value = get_node_value(&node, source);
-                    proc_stack.push(ast_builder.alloc_uri_literal(lit_value), span);
+                    let decoded = get_node_value_checked(&node, source).and_then(|lit_value| {
+                        if let Some(body) = raw_string_body(lit_value) {
+                            return Ok(Cow::Borrowed(body));
+                        }
+                        decode_string_literal(crate::trim_byte(lit_value, b'"')).map_err(|escape| {
+                            AnnParsingError::new(ParsingError::InvalidEscape(escape), &node)
+                        })
+                    });
+                    match decoded {
+                        Ok(Cow::Borrowed(value)) => {
+                            proc_stack.push(ast_builder.alloc_string_literal_body(value), span)
+                        }
+                        Ok(Cow::Owned(value)) => {
+                            proc_stack.push(ast_builder.alloc_decoded_string_literal(&value), span)
+                        }
+                        Err(err) => {
+                            errors.push(err);
+                            bad = true;
+                        }
+                    }
                 }
+                kind!("uri_literal") => match get_node_value_checked(&node, source) {
+                    Ok(lit_value) => {
+                        proc_stack.push(ast_builder.alloc_uri_literal(lit_value), span)
+                    }
+                    Err(err) => {
+                        errors.push(err);
+                        bad = true;
+                    }
+                },
 
                 kind!("par") => {
                     let (left, right) = get_left_and_right(&node);
@@ -371,7 +395,7 @@ pub(super) fn node_to_ast<'ast>(
                                 field!("value"),
                                 &mut temp_cont_stack,
                             );
-                            if arity == 0 {
+                            if arity == 0 && !has_remainder {
                                 proc_stack.push(ast_builder.const_empty_map(), span);
                             } else {
                                 cont_stack.push(K::ConsumeMap {
@@ -854,7 +878,13 @@ pub(super) fn node_to_ast<'ast>(
                         rhs_arity: usize,
                         lhs_has_cont: bool,
                     ) -> bool {
-                        (lhs_has_cont && lhs_arity > rhs_arity) || lhs_arity != rhs_arity
+                        // `lhs_arity` counts the remainder, which takes whatever the names
+                        // before it leave over
+                        if lhs_has_cont {
+                            lhs_arity - 1 > rhs_arity
+                        } else {
+                            lhs_arity != rhs_arity
+                        }
                     }
 
                     let decls_node = get_field(&node, field!("decls"));
@@ -883,8 +913,17 @@ pub(super) fn node_to_ast<'ast>(
                             temp_cont_stack.push(K::EvalDelayed(lhs));
                             temp_cont_stack.push(K::EvalDelayed(rhs));
                         } else {
+                            // The grammar may split the right-hand side over several
+                            // `procs` nodes, e.g. `1, 2` into `(procs 1) (procs 2)`
+                            let rhs_nodes: SmallVec<[tree_sitter::Node; 2]> = decl_node
+                                .named_children(&mut decl_node.walk())
+                                .skip(1)
+                                .collect();
                             lhs_arity = lhs.named_child_count();
-                            rhs_arity = rhs.named_child_count();
+                            rhs_arity = rhs_nodes
+                                .iter()
+                                .map(|procs| procs.named_child_count())
+                                .sum();
                             lhs_has_cont = lhs.child_by_field_id(field!("cont")).is_some();
 
                             if let_decl_is_malformed(lhs_arity, rhs_arity, lhs_has_cont) {
@@ -897,7 +936,8 @@ pub(super) fn node_to_ast<'ast>(
                                 ));
                             }
                             temp_cont_stack.push(K::EvalList(lhs.walk()));
-                            temp_cont_stack.push(K::EvalList(rhs.walk()));
+                            temp_cont_stack
+                                .extend(rhs_nodes.iter().map(|procs| K::EvalList(procs.walk())));
                         }
 
                         let_decls.push(LetDecl {
@@ -1087,12 +1127,113 @@ fn parse_decls<'a>(from: &tree_sitter::Node, source: &'a str) -> Vec<NameDecl<'a
             .child_by_field_id(field!("uri"))
             .map(|uri_literal| get_node_value(&uri_literal, source).into());
 
-        result.push(NameDecl { id, uri });
+        result.push(NameDecl {
+            id,
+            uri,
+            span: decl_node.range().into(),
+        });
     }
 
     result
 }
 
+/// Parses a decimal literal, or a hex/binary/octal one with a `0x`/`0b`/`0o` prefix, either
+/// optionally negated. Returns `None` if the value does not fit in an `i64`.
+fn parse_long_literal(literal: &str) -> Option<i64> {
+    let (negative, magnitude) = match literal.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, literal),
+    };
+    let (radix, digits) = if let Some(digits) = magnitude.strip_prefix("0x") {
+        (16, digits)
+    } else if let Some(digits) = magnitude.strip_prefix("0b") {
+        (2, digits)
+    } else if let Some(digits) = magnitude.strip_prefix("0o") {
+        (8, digits)
+    } else {
+        return literal.parse().ok();
+    };
+
+    let value = u64::from_str_radix(digits, radix).ok()?;
+    if negative {
+        0i64.checked_sub_unsigned(value)
+    } else {
+        i64::try_from(value).ok()
+    }
+}
+
+/// The body of a triple-quoted raw string literal, taken verbatim, or `None` if `literal` is
+/// an ordinary string literal.
+fn raw_string_body(literal: &str) -> Option<&str> {
+    const DELIMITER: &str = r#"""""#;
+    literal.strip_prefix(DELIMITER)?.strip_suffix(DELIMITER)
+}
+
+/// Interprets the escape sequences in the body of a string literal, borrowing it if there are
+/// none. On failure, returns the first invalid escape sequence.
+fn decode_string_literal(body: &str) -> Result<Cow<'_, str>, String> {
+    if !body.contains('\\') {
+        return Ok(Cow::Borrowed(body));
+    }
+
+    let mut decoded = String::with_capacity(body.len());
+    let mut chars = body.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            decoded.push(c);
+            continue;
+        }
+        let unescaped = match chars.next() {
+            Some('n') => '\n',
+            Some('r') => '\r',
+            Some('t') => '\t',
+            // octal escapes as in Java: up to three digits, at most \377
+            Some(first @ '0'..='7') => {
+                let max_digits = if first <= '3' { 3 } else { 2 };
+                let mut code = first as u8 - b'0';
+                for _ in 1..max_digits {
+                    match chars.clone().next().and_then(|c| c.to_digit(8)) {
+                        Some(digit) => {
+                            code = code * 8 + digit as u8;
+                            chars.next();
+                        }
+                        None => break,
+                    }
+                }
+                char::from(code)
+            }
+            Some(c @ ('\'' | '"' | '\\')) => c,
+            Some('u') => {
+                let rest = chars.as_str();
+                let hex = rest
+                    .strip_prefix('{')
+                    .and_then(|braced| braced.split_once('}'))
+                    .map_or("", |(hex, _)| hex);
+                let code_point = Some(hex)
+                    .filter(|hex| (1..=6).contains(&hex.len()))
+                    .filter(|hex| hex.bytes().all(|b| b.is_ascii_hexdigit()))
+                    .and_then(|hex| u32::from_str_radix(hex, 16).ok())
+                    .and_then(char::from_u32);
+                match code_point {
+                    Some(c) => {
+                        chars = rest[hex.len() + 2..].chars();
+                        c
+                    }
+                    None if rest.starts_with('{') && rest.contains('}') => {
+                        return Err(format!("\\u{{{hex}}}"));
+                    }
+                    None => return Err("\\u".to_string()),
+                }
+            }
+            Some(other) => return Err(format!("\\{other}")),
+            None => return Err("\\".to_string()),
+        };
+        decoded.push(unescaped);
+    }
+
+    Ok(Cow::Owned(decoded))
+}
+
 fn parse_sized_int_literal(literal: &str, suffix: char) -> Option<(&str, u32)> {
     let (value, width) = literal.rsplit_once(suffix)?;
     let bits: u32 = width.parse().ok()?;
@@ -1937,6 +2078,18 @@ fn get_node_value<'a>(node: &tree_sitter::Node, source: &'a str) -> &'a str {
     }
 }
 
+/// Checked counterpart of [`get_node_value`] for nodes whose text is arbitrary user input
+/// (string and URI literals): a byte range that does not fall on character boundaries is
+/// reported as [`ParsingError::InvalidUtf8`] instead of producing an invalid `&str`.
+fn get_node_value_checked<'a>(
+    node: &tree_sitter::Node,
+    source: &'a str,
+) -> Result<&'a str, AnnParsingError> {
+    source
+        .get(node.byte_range())
+        .ok_or_else(|| AnnParsingError::new(ParsingError::InvalidUtf8, node))
+}
+
 fn named_children_of_kind<'a>(
     node: &tree_sitter::Node<'a>,
     kind: u16,
@@ -2282,7 +2435,9 @@ fn into_name(ann_proc: AnnProc, quoted: bool) -> Name {
     } else {
         match ann_proc.proc {
             Proc::ProcVar(var) => Name::NameVar(*var),
-            _ => panic!("invalid proc variant for into_name"),
+            // the grammar only admits variables and quotes as names, a send on anything else
+            // is reported as `ParsingError::ExpectedName` before the AST is built
+            _ => unreachable!("invalid proc variant for into_name"),
         }
     }
 }
@@ -2480,10 +2635,12 @@ fn build_agent_desugaring<'ast>(
         NameDecl {
             id: this_id,
             uri: None,
+            span,
         },
         NameDecl {
             id: private_id,
             uri: None,
+            span,
         },
     ];
     let new_this_in = ann(builder.alloc_new(combined, new_decls), span);
@@ -2693,3 +2850,22 @@ impl<'slice, 'a> DoubleEndedIterator for NamesIter<'slice, 'a> {
 
 impl<'slice, 'a> ExactSizeIterator for NamesIter<'slice, 'a> {}
 impl<'slice, 'a> FusedIterator for NamesIter<'slice, 'a> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn checked_node_value_rejects_ranges_off_char_boundaries() {
+        let code = r#""a""#;
+        let tree = parse_to_tree(code);
+        let literal = tree.root_node().descendant_for_byte_range(0, 3).unwrap();
+        assert_eq!(literal.kind(), "string_literal");
+
+        assert_eq!(get_node_value_checked(&literal, code), Ok(code));
+        // the same byte range over other text ends inside the three bytes of '€'
+        let err = get_node_value_checked(&literal, "a€").unwrap_err();
+        assert_eq!(err.error, ParsingError::InvalidUtf8);
+        assert_eq!(err.byte_range, 0..3);
+    }
+}
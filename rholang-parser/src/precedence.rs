@@ -0,0 +1,84 @@
+//! Verification that expression trees group the way the grammar's precedence table says.
+//!
+//! Parentheses leave no node of their own in the AST, but they do leave a gap in the spans: a
+//! parenthesized left operand starts after its parent expression, and a parenthesized right (or
+//! unary) operand ends before it. An operand without such a gap must bind at least as tightly as
+//! the operator above it, which is what [`check_precedence`] checks using
+//! [`BinaryExpOp::precedence`] and [`BinaryExpOp::associativity`].
+
+use std::cmp::Ordering;
+
+use crate::SourceSpan;
+use crate::ast::{AnnProc, Associativity, BinaryExpOp, Proc};
+
+/// An operand that only ends up under its parent expression if it is parenthesized, but is not
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PrecedenceViolation {
+    /// The expression whose operand is misplaced
+    pub expr: SourceSpan,
+    /// The unparenthesized operand
+    pub operand: SourceSpan,
+}
+
+/// Every operand in `proc` that is grouped differently than the source text would be
+/// parsed. A tree coming straight from the parser always yields none.
+pub fn check_precedence<'a>(proc: &'a AnnProc<'a>) -> Vec<PrecedenceViolation> {
+    let mut violations = Vec::new();
+    let mut report = |expr: &AnnProc, operand: &AnnProc| {
+        violations.push(PrecedenceViolation {
+            expr: expr.span,
+            operand: operand.span,
+        })
+    };
+
+    for node in proc.iter_preorder_dfs() {
+        match node.proc {
+            Proc::BinaryExp { op, left, right } => {
+                if left.span.start == node.span.start && !groups_under(*op, left, Side::Left) {
+                    report(node, left);
+                }
+                if right.span.end == node.span.end && !groups_under(*op, right, Side::Right) {
+                    report(node, right);
+                }
+            }
+            Proc::UnaryExp { op, arg } => {
+                if let Proc::BinaryExp { op: inner, .. } = arg.proc
+                    && arg.span.end == node.span.end
+                    && inner.precedence() < op.precedence()
+                {
+                    report(node, arg);
+                }
+            }
+            _ => {}
+        }
+    }
+    violations
+}
+
+#[derive(Clone, Copy)]
+enum Side {
+    Left,
+    Right,
+}
+
+/// Whether the unparenthesized `operand` is parsed as the `side` operand of `outer`
+fn groups_under(outer: BinaryExpOp, operand: &AnnProc, side: Side) -> bool {
+    let inner = match operand.proc {
+        Proc::BinaryExp { op, .. } => *op,
+        // a prefix operator cannot be split from the right, and on the left it has to bind tighter
+        Proc::UnaryExp { op, .. } => {
+            return matches!(side, Side::Right) || op.precedence() > outer.precedence();
+        }
+        _ => return true,
+    };
+    match inner.precedence().cmp(&outer.precedence()) {
+        Ordering::Greater => true,
+        Ordering::Less => false,
+        // on a tie the operator that is reduced first decides: the left one on the left, the
+        // outer one on the right
+        Ordering::Equal => match side {
+            Side::Left => inner.associativity() == Associativity::Left,
+            Side::Right => outer.associativity() == Associativity::Right,
+        },
+    }
+}
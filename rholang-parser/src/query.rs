@@ -0,0 +1,32 @@
+//! Filters over traversals of a process tree that pick out one kind of node.
+
+use std::iter::Filter;
+
+use crate::ast::{AnnProc, Proc};
+
+/// The iterator returned by the adapters of [`ProcQueryExt`]
+pub type ProcFilter<'a, I> = Filter<I, fn(&&'a AnnProc<'a>) -> bool>;
+
+/// Variant filters for any iterator over processes, such as [`AnnProc::iter_preorder_dfs`].
+///
+/// ```ignore
+/// let sends: Vec<_> = root.iter_preorder_dfs().sends().collect();
+/// ```
+pub trait ProcQueryExt<'a>: Iterator<Item = &'a AnnProc<'a>> + Sized {
+    /// Only the sends `x!(..)` and `x!!(..)`
+    fn sends(self) -> ProcFilter<'a, Self> {
+        self.filter(|node| matches!(node.proc, Proc::Send { .. }))
+    }
+
+    /// Only the for-comprehensions
+    fn receives(self) -> ProcFilter<'a, Self> {
+        self.filter(|node| matches!(node.proc, Proc::ForComprehension { .. }))
+    }
+
+    /// Only the `new` processes
+    fn news(self) -> ProcFilter<'a, Self> {
+        self.filter(|node| matches!(node.proc, Proc::New { .. }))
+    }
+}
+
+impl<'a, I: Iterator<Item = &'a AnnProc<'a>>> ProcQueryExt<'a> for I {}
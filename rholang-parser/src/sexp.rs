@@ -0,0 +1,398 @@
+//! Rendering of an AST as an S-expression.
+//!
+//! Unlike [`unparse`](crate::unparse), which emits Rholang, this shows the structure of the tree
+//! in the style of tree-sitter's `to_sexp`: every node is `(kind children..)`, with leaves
+//! written inline. Source positions are left out, so two trees render the same iff they have the
+//! same shape.
+
+use std::collections::HashMap;
+use std::fmt::Write;
+use std::ops::{Deref, DerefMut, Range};
+
+use crate::ast::{
+    AnnProc, Bind, BundleType, Collection, LetBinding, Name, Names, Proc, Receipt, SendType,
+    Source, SyncSendCont, Var, VarRefKind,
+};
+
+/// Render `proc` as an S-expression such as `(par (send (name x) (long 42)) (nil))`.
+pub fn to_sexp(proc: &AnnProc) -> String {
+    let mut out = Out::default();
+    write_proc(&mut out, proc);
+    out.text
+}
+
+/// Where each process of a tree was rendered by [`to_sexp_with_ranges`], keyed by its address
+pub(crate) type SexpRanges = HashMap<*const (), Range<usize>>;
+
+/// [`to_sexp`], along with the range of the result covered by every process in the tree
+pub(crate) fn to_sexp_with_ranges(proc: &AnnProc) -> (String, SexpRanges) {
+    let mut out = Out {
+        text: String::new(),
+        ranges: Some(HashMap::new()),
+    };
+    write_proc(&mut out, proc);
+    (out.text, out.ranges.unwrap_or_default())
+}
+
+/// The S-expression being written, and where each process went if that is being tracked
+#[derive(Default)]
+struct Out {
+    text: String,
+    ranges: Option<SexpRanges>,
+}
+
+impl Deref for Out {
+    type Target = String;
+
+    fn deref(&self) -> &String {
+        &self.text
+    }
+}
+
+impl DerefMut for Out {
+    fn deref_mut(&mut self) -> &mut String {
+        &mut self.text
+    }
+}
+
+fn write_proc(out: &mut Out, proc: &AnnProc) {
+    let start = out.len();
+    write_proc_node(out, proc);
+    let end = out.len();
+    if let Some(ranges) = &mut out.ranges {
+        ranges.insert(proc as *const AnnProc as *const (), start..end);
+    }
+}
+
+fn write_proc_node(out: &mut Out, proc: &AnnProc) {
+    match proc.proc {
+        Proc::Nil => out.push_str("(nil)"),
+        Proc::Unit => out.push_str("(unit)"),
+        Proc::BoolLiteral(value) => write!(out, "(bool {value})").unwrap(),
+        Proc::LongLiteral(value) => write!(out, "(long {value})").unwrap(),
+        Proc::SignedIntLiteral { value, bits } => {
+            write!(out, "(signed_int {value} {bits})").unwrap()
+        }
+        Proc::UnsignedIntLiteral { value, bits } => {
+            write!(out, "(unsigned_int {value} {bits})").unwrap()
+        }
+        Proc::BigIntLiteral(value) => write!(out, "(big_int {value})").unwrap(),
+        Proc::BigRatLiteral(value) => write!(out, "(big_rat {value})").unwrap(),
+        Proc::FloatLiteral { value, bits } => write!(out, "(float {value} {bits})").unwrap(),
+        Proc::FixedPointLiteral { value, scale } => {
+            write!(out, "(fixed_point {value} {scale})").unwrap()
+        }
+        Proc::StringLiteral(value) => write!(out, "(string {value:?})").unwrap(),
+        Proc::UriLiteral(uri) => write!(out, "(uri {uri})").unwrap(),
+        Proc::SimpleType(simple_type) => write!(out, "(type {simple_type})").unwrap(),
+        Proc::Collection(collection) => write_collection(out, collection),
+        Proc::ProcVar(var) => write_var(out, *var),
+        Proc::Par { left, right } => node(out, "par", |out| {
+            write_proc(out, left);
+            out.push(' ');
+            write_proc(out, right);
+        }),
+        Proc::IfThenElse {
+            condition,
+            if_true,
+            if_false,
+        } => node(out, "if", |out| {
+            write_proc(out, condition);
+            out.push(' ');
+            write_proc(out, if_true);
+            if let Some(if_false) = if_false {
+                out.push(' ');
+                write_proc(out, if_false);
+            }
+        }),
+        Proc::Send {
+            channel,
+            send_type,
+            inputs,
+        } => {
+            let kind = match send_type {
+                SendType::Single => "send",
+                SendType::Multiple => "send_multiple",
+            };
+            node(out, kind, |out| {
+                write_name(out, channel);
+                write_procs(out, inputs);
+            })
+        }
+        Proc::ForComprehension { receipts, proc } => node(out, "for", |out| {
+            for receipt in receipts {
+                write_receipt(out, receipt);
+                out.push(' ');
+            }
+            write_proc(out, proc);
+        }),
+        Proc::Match { expression, cases } => node(out, "match", |out| {
+            write_proc(out, expression);
+            for case in cases {
+                out.push(' ');
+                node(out, "case", |out| {
+                    write_proc(out, &case.pattern);
+                    write_guard(out, case.guard.as_ref());
+                    out.push(' ');
+                    write_proc(out, &case.proc);
+                });
+            }
+        }),
+        Proc::Select { branches } => node(out, "select", |out| {
+            for (i, branch) in branches.iter().enumerate() {
+                if i > 0 {
+                    out.push(' ');
+                }
+                node(out, "branch", |out| {
+                    for (i, pattern) in branch.patterns.iter().enumerate() {
+                        if i > 0 {
+                            out.push(' ');
+                        }
+                        write_bind(out, "pattern", &pattern.lhs, |out| {
+                            write_source(out, &pattern.rhs)
+                        });
+                    }
+                    write_guard(out, branch.guard.as_ref());
+                    out.push(' ');
+                    write_proc(out, &branch.proc);
+                });
+            }
+        }),
+        Proc::Bundle { bundle_type, proc } => {
+            let kind = match bundle_type {
+                BundleType::BundleEquiv => "bundle_equiv",
+                BundleType::BundleWrite => "bundle_write",
+                BundleType::BundleRead => "bundle_read",
+                BundleType::BundleReadWrite => "bundle_read_write",
+            };
+            node(out, kind, |out| write_proc(out, proc))
+        }
+        Proc::Let {
+            bindings,
+            body,
+            concurrent,
+        } => {
+            let kind = if *concurrent { "let_concurrent" } else { "let" };
+            node(out, kind, |out| {
+                for binding in bindings {
+                    write_let_binding(out, binding);
+                    out.push(' ');
+                }
+                write_proc(out, body);
+            })
+        }
+        Proc::New { decls, proc } => node(out, "new", |out| {
+            for decl in decls {
+                match decl.uri {
+                    Some(uri) => write!(out, "(decl {} {uri}) ", decl.id.name).unwrap(),
+                    None => write!(out, "(decl {}) ", decl.id.name).unwrap(),
+                }
+            }
+            write_proc(out, proc);
+        }),
+        Proc::Contract {
+            name,
+            formals,
+            body,
+        } => node(out, "contract", |out| {
+            write_name(out, name);
+            out.push(' ');
+            write_names(out, formals);
+            out.push(' ');
+            write_proc(out, body);
+        }),
+        Proc::SendSync {
+            channel,
+            inputs,
+            cont,
+        } => node(out, "send_sync", |out| {
+            write_name(out, channel);
+            write_procs(out, inputs);
+            if let SyncSendCont::NonEmpty(cont) = cont {
+                out.push(' ');
+                node(out, "cont", |out| write_proc(out, cont));
+            }
+        }),
+        Proc::Eval { name } => node(out, "eval", |out| write_name(out, name)),
+        Proc::Method {
+            receiver,
+            name,
+            args,
+        } => node(out, "method", |out| {
+            write!(out, "{} ", name.name).unwrap();
+            write_proc(out, receiver);
+            write_procs(out, args);
+        }),
+        Proc::UnaryExp { op, arg } => {
+            let kind = format!("{op:?}").to_lowercase();
+            node(out, &kind, |out| write_proc(out, arg))
+        }
+        Proc::BinaryExp { op, left, right } => {
+            let kind = format!("{op:?}").to_lowercase();
+            node(out, &kind, |out| {
+                write_proc(out, left);
+                out.push(' ');
+                write_proc(out, right);
+            })
+        }
+        Proc::VarRef { kind, var } => {
+            let kind = match kind {
+                VarRefKind::Proc => "var_ref",
+                VarRefKind::Name => "var_ref_name",
+            };
+            write!(out, "({kind} {})", var.name).unwrap()
+        }
+        Proc::Bad => out.push_str("(bad)"),
+    }
+}
+
+/// `(kind ` followed by whatever `children` writes and `)`
+fn node(out: &mut Out, kind: &str, children: impl FnOnce(&mut Out)) {
+    out.push('(');
+    out.push_str(kind);
+    out.push(' ');
+    children(out);
+    out.push(')');
+}
+
+/// Each of `procs`, preceded by a space
+fn write_procs(out: &mut Out, procs: &[AnnProc]) {
+    for proc in procs {
+        out.push(' ');
+        write_proc(out, proc);
+    }
+}
+
+fn write_var(out: &mut Out, var: Var) {
+    match var {
+        Var::Wildcard => out.push_str("(wildcard)"),
+        Var::Id(id) => write!(out, "(var {})", id.name).unwrap(),
+    }
+}
+
+fn write_name(out: &mut Out, name: &Name) {
+    match name {
+        Name::NameVar(Var::Wildcard) => out.push_str("(name _)"),
+        Name::NameVar(Var::Id(id)) => write!(out, "(name {})", id.name).unwrap(),
+        Name::Quote(proc) => node(out, "quote", |out| write_proc(out, proc)),
+    }
+}
+
+fn write_names(out: &mut Out, names: &Names) {
+    out.push_str("(names");
+    for name in &names.names {
+        out.push(' ');
+        write_name(out, name);
+    }
+    write_remainder(out, names.remainder);
+    out.push(')');
+}
+
+fn write_remainder(out: &mut Out, remainder: Option<Var>) {
+    if let Some(remainder) = remainder {
+        out.push(' ');
+        node(out, "rest", |out| write_var(out, remainder));
+    }
+}
+
+fn write_collection(out: &mut Out, collection: &Collection) {
+    match collection {
+        Collection::List {
+            elements,
+            remainder,
+        } => {
+            out.push_str("(list");
+            write_procs(out, elements);
+            write_remainder(out, *remainder);
+        }
+        Collection::Tuple(elements) => {
+            out.push_str("(tuple");
+            write_procs(out, elements);
+        }
+        Collection::Set {
+            elements,
+            remainder,
+        } => {
+            out.push_str("(set");
+            write_procs(out, elements);
+            write_remainder(out, *remainder);
+        }
+        Collection::Map {
+            elements,
+            remainder,
+        } => {
+            out.push_str("(map");
+            for (key, value) in elements {
+                out.push(' ');
+                node(out, "pair", |out| {
+                    write_proc(out, key);
+                    out.push(' ');
+                    write_proc(out, value);
+                });
+            }
+            write_remainder(out, *remainder);
+        }
+        Collection::PathMap {
+            elements,
+            remainder,
+        } => {
+            out.push_str("(pathmap");
+            write_procs(out, elements);
+            write_remainder(out, *remainder);
+        }
+    }
+    out.push(')');
+}
+
+fn write_guard(out: &mut Out, guard: Option<&AnnProc>) {
+    if let Some(guard) = guard {
+        out.push(' ');
+        node(out, "where", |out| write_proc(out, guard));
+    }
+}
+
+fn write_receipt(out: &mut Out, receipt: &Receipt) {
+    node(out, "receipt", |out| {
+        for (i, bind) in receipt.iter().enumerate() {
+            if i > 0 {
+                out.push(' ');
+            }
+            match bind {
+                Bind::Linear { lhs, rhs } => {
+                    write_bind(out, "linear", lhs, |out| write_source(out, rhs))
+                }
+                Bind::Repeated { lhs, rhs } => {
+                    write_bind(out, "repeated", lhs, |out| write_name(out, rhs))
+                }
+                Bind::Peek { lhs, rhs } => write_bind(out, "peek", lhs, |out| write_name(out, rhs)),
+            }
+        }
+        write_guard(out, receipt.guard.as_ref());
+    })
+}
+
+fn write_bind(out: &mut Out, kind: &str, lhs: &Names, rhs: impl FnOnce(&mut Out)) {
+    node(out, kind, |out| {
+        write_names(out, lhs);
+        out.push(' ');
+        rhs(out);
+    })
+}
+
+fn write_source(out: &mut Out, source: &Source) {
+    match source {
+        Source::Simple { name } => write_name(out, name),
+        Source::ReceiveSend { name } => node(out, "receive_send", |out| write_name(out, name)),
+        Source::SendReceive { name, inputs } => node(out, "send_receive", |out| {
+            write_name(out, name);
+            write_procs(out, inputs);
+        }),
+    }
+}
+
+fn write_let_binding(out: &mut Out, binding: &LetBinding) {
+    node(out, "binding", |out| {
+        write_names(out, &binding.lhs);
+        write_procs(out, &binding.rhs);
+    })
+}
@@ -0,0 +1,95 @@
+//! Comparison of process trees by shape, ignoring source positions.
+
+use std::mem::discriminant;
+use std::ops::Range;
+
+use crate::ast::AnnProc;
+use crate::sexp::{SexpRanges, to_sexp_with_ranges};
+use crate::to_sexp;
+
+/// Whether `left` and `right` are the same tree apart from their source spans, i.e. whether
+/// they only differ in layout and comments.
+pub fn structurally_eq(left: &AnnProc, right: &AnnProc) -> bool {
+    to_sexp(left) == to_sexp(right)
+}
+
+/// The first pair of corresponding nodes at which `left` and `right` stop being
+/// [structurally equal](structurally_eq), or `None` if they are equal.
+///
+/// Two nodes of the same kind with as many children are looked into, and the first of their
+/// children that differ is reported instead. Otherwise, or if only the nodes themselves (say,
+/// an operator or a channel name) differ, the nodes are the difference.
+pub fn first_difference<'a>(
+    left: &'a AnnProc<'a>,
+    right: &'a AnnProc<'a>,
+) -> Option<(&'a AnnProc<'a>, &'a AnnProc<'a>)> {
+    // Both trees are rendered once, and every node is compared on the part of the rendering
+    // that is its own rather than on a fresh rendering of its whole subtree
+    let (left_sexp, right_sexp) = (Rendered::new(left), Rendered::new(right));
+    first_difference_in(left, right, &left_sexp, &right_sexp)
+}
+
+fn first_difference_in<'a>(
+    left: &'a AnnProc<'a>,
+    right: &'a AnnProc<'a>,
+    left_sexp: &Rendered,
+    right_sexp: &Rendered,
+) -> Option<(&'a AnnProc<'a>, &'a AnnProc<'a>)> {
+    let (left_children, right_children) = (left.children(), right.children());
+    if discriminant(left.proc) != discriminant(right.proc)
+        || left_children.len() != right_children.len()
+    {
+        return Some((left, right));
+    }
+    left_children
+        .iter()
+        .zip(&right_children)
+        .find_map(|(left, right)| first_difference_in(left, right, left_sexp, right_sexp))
+        .or_else(|| {
+            let differs = left_sexp.own_text(left, &left_children)
+                != right_sexp.own_text(right, &right_children);
+            differs.then_some((left, right))
+        })
+}
+
+/// The S-expression of a tree, with the range each of its nodes covers
+struct Rendered {
+    text: String,
+    ranges: SexpRanges,
+}
+
+impl Rendered {
+    fn new(proc: &AnnProc) -> Self {
+        let (text, ranges) = to_sexp_with_ranges(proc);
+        Self { text, ranges }
+    }
+
+    fn range(&self, proc: &AnnProc) -> Option<Range<usize>> {
+        self.ranges
+            .get(&(proc as *const AnnProc as *const ()))
+            .cloned()
+    }
+
+    /// The rendering of `proc` without the renderings of its `children`, in pieces
+    fn own_text(&self, proc: &AnnProc, children: &[&AnnProc]) -> Vec<&str> {
+        let Some(range) = self.range(proc) else {
+            return Vec::new();
+        };
+        let mut child_ranges: Vec<_> = children
+            .iter()
+            .filter_map(|child| self.range(child))
+            .collect();
+        child_ranges.sort_by_key(|child| child.start);
+
+        let mut pieces = Vec::with_capacity(child_ranges.len() + 1);
+        let mut at = range.start;
+        for child in child_ranges {
+            if child.start >= at {
+                pieces.push(&self.text[at..child.start]);
+                at = child.end;
+            }
+        }
+        pieces.push(&self.text[at..range.end]);
+        pieces
+    }
+}
@@ -567,6 +567,88 @@ impl<'a, const S: usize> Iterator for DeepDfsIter<'a, S> {
 
 impl<'a, const S: usize> FusedIterator for DeepDfsIter<'a, S> {}
 
+/// Direct process children of `node`, in the order [`DfsEventIter`] visits them.
+/// See [`AnnProc::children`].
+pub(crate) fn direct_children<'a>(node: &AnnProc<'a>) -> SmallVec<[&'a AnnProc<'a>; 4]> {
+    match node.proc {
+        Proc::Par { left, right } | Proc::BinaryExp { left, right, .. } => smallvec![left, right],
+
+        Proc::ForComprehension { receipts, proc } => iter::once(proc)
+            .chain(
+                receipts
+                    .iter()
+                    .flat_map(|r| inputs(&r.binds).chain(r.guard.as_ref())),
+            )
+            .collect(),
+
+        Proc::Let { bindings, body, .. } => iter::once(body).chain(let_rhss(bindings)).collect(),
+
+        Proc::Contract { name, body, .. } => {
+            let quoted = match name {
+                Name::Quote(q) => Some(q),
+                _ => None,
+            };
+            quoted.into_iter().chain(iter::once(body)).collect()
+        }
+
+        Proc::Match { expression, cases } => {
+            iter::once(expression).chain(match_cases(cases)).collect()
+        }
+
+        Proc::IfThenElse {
+            condition,
+            if_true,
+            if_false,
+        } => iter::once(condition)
+            .chain(iter::once(if_true))
+            .chain(if_false.as_ref())
+            .collect(),
+
+        Proc::New { proc: inner, .. }
+        | Proc::Bundle { proc: inner, .. }
+        | Proc::UnaryExp { arg: inner, .. } => smallvec![inner],
+
+        Proc::Send { inputs, .. } => inputs.iter().collect(),
+        Proc::SendSync { inputs, cont, .. } => {
+            let cont_iter = match cont {
+                SyncSendCont::NonEmpty(p) => Some(p),
+                _ => None,
+            };
+            inputs.iter().chain(cont_iter).collect()
+        }
+
+        Proc::Method { receiver, args, .. } => iter::once(receiver).chain(args).collect(),
+
+        Proc::Collection(collection) => match collection {
+            Collection::List { elements, .. }
+            | Collection::Set { elements, .. }
+            | Collection::PathMap { elements, .. }
+            | Collection::Tuple(elements) => elements.iter().collect(),
+            Collection::Map { elements, .. } => map_elements(elements).collect(),
+        },
+
+        // leaves: no children (`Select` is not supported by the traversals either)
+        Proc::Nil
+        | Proc::Unit
+        | Proc::BoolLiteral(_)
+        | Proc::LongLiteral(_)
+        | Proc::SignedIntLiteral { .. }
+        | Proc::UnsignedIntLiteral { .. }
+        | Proc::BigIntLiteral(_)
+        | Proc::BigRatLiteral(_)
+        | Proc::FloatLiteral { .. }
+        | Proc::FixedPointLiteral { .. }
+        | Proc::StringLiteral(_)
+        | Proc::UriLiteral(_)
+        | Proc::SimpleType(_)
+        | Proc::ProcVar(_)
+        | Proc::Eval { .. }
+        | Proc::VarRef { .. }
+        | Proc::Bad
+        | Proc::Select { .. } => SmallVec::new(),
+    }
+}
+
 /// Helper: extract right-hand sides of let bindings
 fn let_rhss<'a>(
     bindings: &'a [LetBinding<'a>],
@@ -676,6 +758,47 @@ mod tests {
         assert!(exts.next().is_none(), "expected same number of events");
     }
 
+    #[test]
+    fn inline_stack_size_does_not_change_the_traversal() {
+        let depth = 64;
+        let code = format!(
+            "new x in {{ {}x!(1){} }}",
+            "for (y <- x) { ".repeat(depth),
+            " }".repeat(depth)
+        );
+        let parser = crate::RholangParser::new();
+        let procs = parser.parse(&code).unwrap();
+        let root = &procs[0];
+
+        let preorder: Vec<_> = root.iter_preorder_dfs_with_stack::<4>().collect();
+        assert_eq!(
+            preorder,
+            root.iter_preorder_dfs_with_stack::<32>()
+                .collect::<Vec<_>>()
+        );
+        assert!(preorder.len() > depth);
+
+        let events: Vec<_> = root.iter_dfs_event_with_stack::<4>().collect();
+        assert_eq!(
+            events,
+            root.iter_dfs_event_with_stack::<32>().collect::<Vec<_>>()
+        );
+        assert_eq!(events, root.iter_dfs_event().collect::<Vec<_>>());
+
+        let with_names: Vec<_> = root.iter_dfs_event_with_names_with_stack::<4>().collect();
+        assert_eq!(
+            with_names,
+            root.iter_dfs_event_with_names_with_stack::<32>()
+                .collect::<Vec<_>>()
+        );
+        assert_same_events(
+            events,
+            with_names
+                .into_iter()
+                .filter(|ev| !matches!(ev, DfsEventExt::Name(_))),
+        );
+    }
+
     #[test]
     fn single_leaf() {
         let root = Proc::Nil.ann(SourcePos::default().span_of(3));
@@ -1846,4 +1969,69 @@ mod tests {
             ]
         );
     }
+
+    #[test]
+    fn children_are_direct_sub_processes() {
+        // leaf
+        let nil = Proc::Nil.ann(SourcePos::default().span_of(3));
+        assert!(nil.children().is_empty());
+
+        // true | false
+        let left = Proc::BoolLiteral(true).ann(SourcePos::default().span_of(4));
+        let right = Proc::BoolLiteral(false).ann(SourcePos::at_col(8).span_of(5));
+        let par = Proc::Par { left, right };
+        let par_root = par.ann(SourceSpan {
+            start: left.span.start,
+            end: right.span.end,
+        });
+        let children = par_root.children();
+        assert_eq!(children.len(), 2);
+        assert_matches!(children[0].proc, Proc::BoolLiteral(true));
+        assert_matches!(children[1].proc, Proc::BoolLiteral(false));
+
+        // match x { p1 => (y1 | Nil); _ => Nil }
+        let x = Proc::ProcVar(Var::Id(Id {
+            name: "x",
+            pos: SourcePos::at_col(7),
+        }));
+        let p1 = Proc::ProcVar(Var::Id(Id {
+            name: "p1",
+            pos: SourcePos::at_col(11),
+        }));
+        let y1 = Proc::ProcVar(Var::Id(Id {
+            name: "y1",
+            pos: SourcePos::at_col(18),
+        }));
+        let body = Proc::Par {
+            left: y1.ann(SourcePos::at_col(18).span_of(2)),
+            right: Proc::Nil.ann(SourcePos::at_col(23).span_of(3)),
+        };
+        let wildcard = Proc::ProcVar(Var::Wildcard);
+        let match_exp = Proc::Match {
+            expression: x.ann(SourcePos::at_col(7).span_of(1)),
+            cases: vec![
+                Case {
+                    pattern: p1.ann(SourcePos::at_col(11).span_of(2)),
+                    guard: None,
+                    proc: body.ann(SourcePos::at_col(17).span_of(10)),
+                },
+                Case {
+                    pattern: wildcard.ann(SourcePos::at_col(29).span_of(1)),
+                    guard: None,
+                    proc: Proc::Nil.ann(SourcePos::at_col(34).span_of(3)),
+                },
+            ],
+        };
+        let match_root = match_exp.ann(SourcePos::default().span_of(39));
+
+        let children = match_root.children();
+        assert_eq!(children.len(), 5);
+        assert!(children[0].is_ident("x"));
+        assert!(children[1].is_ident("p1"));
+        // the case body is returned as a whole, not expanded
+        assert_matches!(children[2].proc, Proc::Par { .. });
+        assert_matches!(children[3].proc, Proc::ProcVar(Var::Wildcard));
+        assert_matches!(children[4].proc, Proc::Nil);
+        assert_eq!(children[2].children().len(), 2);
+    }
 }
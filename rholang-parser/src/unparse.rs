@@ -0,0 +1,649 @@
+//! Rendering of an AST back into Rholang source.
+//!
+//! The output is meant to be re-parsed rather than read: sub-expressions are fully parenthesized
+//! and process bodies are always wrapped in blocks, so re-parsing the text yields the same tree
+//! (modulo source positions) regardless of operator precedence and associativity.
+
+use std::fmt::Write;
+
+use crate::ast::{
+    AnnProc, BinaryExpOp, Bind, BundleType, Collection, LetBinding, Name, Names, Proc, Receipt,
+    SendType, Source, SyncSendCont, UnaryExpOp, Var, VarRefKind,
+};
+
+/// Where the opening brace of a block goes
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BraceStyle {
+    /// `for (x <- y) {`
+    #[default]
+    SameLine,
+    /// The brace starts a line of its own, at the indentation of the enclosing process
+    NextLine,
+}
+
+/// Layout options for [`unparse_with`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UnparseConfig {
+    /// Spaces per indentation level
+    pub indent: usize,
+    pub brace_style: BraceStyle,
+    /// Lines of `for` receipts longer than this are wrapped, one bind per line
+    pub max_width: usize,
+}
+
+impl Default for UnparseConfig {
+    fn default() -> Self {
+        UnparseConfig {
+            indent: 4,
+            brace_style: BraceStyle::SameLine,
+            max_width: 100,
+        }
+    }
+}
+
+/// Render `proc` as Rholang source code, laid out with [`UnparseConfig::default`].
+///
+/// `Proc::Bad` has no source representation and is rendered as `Nil` preceded by a comment.
+pub fn unparse(proc: &AnnProc) -> String {
+    unparse_with(proc, &UnparseConfig::default())
+}
+
+/// Render `proc` as Rholang source code, laid out according to `config`. The layout never
+/// changes the tree the output parses back into.
+pub fn unparse_with(proc: &AnnProc, config: &UnparseConfig) -> String {
+    let mut printer = Printer {
+        out: String::new(),
+        indent: 0,
+        config: *config,
+    };
+    printer.proc(proc);
+    printer.out
+}
+
+struct Printer {
+    out: String,
+    indent: usize,
+    config: UnparseConfig,
+}
+
+impl Printer {
+    fn newline(&mut self) {
+        self.out.push('\n');
+        let width = self.indent * self.config.indent;
+        self.out.extend(std::iter::repeat_n(' ', width));
+    }
+
+    /// A newline if `wrapped`, a space otherwise
+    fn separator(&mut self, wrapped: bool) {
+        if wrapped {
+            self.newline();
+        } else {
+            self.out.push(' ');
+        }
+    }
+
+    fn open_brace(&mut self) {
+        if self.config.brace_style == BraceStyle::NextLine {
+            self.out.truncate(self.out.trim_end_matches(' ').len());
+            self.newline();
+        }
+        self.out.push('{');
+    }
+
+    /// Process in a position that accepts any process
+    fn proc(&mut self, proc: &AnnProc) {
+        match proc.proc {
+            Proc::Nil => self.out.push_str("Nil"),
+            Proc::Unit => self.out.push_str("()"),
+            Proc::BoolLiteral(value) => write!(self.out, "{value}").unwrap(),
+            Proc::LongLiteral(value) => write!(self.out, "{value}").unwrap(),
+            Proc::SignedIntLiteral { value, bits } => write!(self.out, "{value}i{bits}").unwrap(),
+            Proc::UnsignedIntLiteral { value, bits } => write!(self.out, "{value}u{bits}").unwrap(),
+            Proc::BigIntLiteral(value) => write!(self.out, "{value}n").unwrap(),
+            Proc::BigRatLiteral(value) => write!(self.out, "{value}r").unwrap(),
+            Proc::FloatLiteral { value, bits } => write!(self.out, "{value}f{bits}").unwrap(),
+            Proc::FixedPointLiteral { value, scale } => {
+                write!(self.out, "{value}p{scale}").unwrap()
+            }
+            Proc::StringLiteral(value) => self.string_literal(value),
+            Proc::UriLiteral(uri) => write!(self.out, "{uri}").unwrap(),
+            Proc::SimpleType(simple_type) => write!(self.out, "{simple_type}").unwrap(),
+            Proc::Collection(collection) => self.collection(collection),
+            Proc::ProcVar(var) => self.var(*var),
+
+            Proc::Par { left, right } => {
+                self.proc(left);
+                self.out.push_str(" | ");
+                // `|` is left-associative, so a nested par on the right needs a block
+                if matches!(right.proc, Proc::Par { .. }) {
+                    self.braced(right);
+                } else {
+                    self.proc(right);
+                }
+            }
+
+            Proc::IfThenElse {
+                condition,
+                if_true,
+                if_false,
+            } => {
+                self.out.push_str("if (");
+                self.proc(condition);
+                self.out.push_str(") ");
+                self.block(if_true);
+                if let Some(if_false) = if_false {
+                    self.out.push_str(" else ");
+                    self.block(if_false);
+                }
+            }
+
+            Proc::Send {
+                channel,
+                send_type,
+                inputs,
+            } => {
+                self.name(channel);
+                self.out.push_str(match send_type {
+                    SendType::Single => "!",
+                    SendType::Multiple => "!!",
+                });
+                self.proc_list(inputs);
+            }
+
+            Proc::ForComprehension { receipts, proc } => {
+                let start = self.out.len();
+                self.out.push_str("for (");
+                self.receipts(receipts, false);
+                let line_start = self.out[..start].rfind('\n').map_or(0, |i| i + 1);
+                let header = &self.out[line_start..];
+                let binds: usize = receipts.iter().map(|receipt| receipt.len()).sum();
+                if binds > 1
+                    && !header.contains('\n')
+                    && header.chars().count() + 1 > self.config.max_width
+                {
+                    self.out.truncate(start);
+                    self.out.push_str("for (");
+                    self.receipts(receipts, true);
+                }
+                self.out.push_str(") ");
+                self.block(proc);
+            }
+
+            Proc::Match { expression, cases } => {
+                self.out.push_str("match ");
+                self.atom(expression);
+                self.out.push(' ');
+                self.open_brace();
+                self.indent += 1;
+                for case in cases {
+                    self.newline();
+                    self.atom(&case.pattern);
+                    if let Some(guard) = &case.guard {
+                        self.out.push_str(" where ");
+                        self.atom(guard);
+                    }
+                    self.out.push_str(" => ");
+                    self.block(&case.proc);
+                }
+                self.indent -= 1;
+                self.newline();
+                self.out.push('}');
+            }
+
+            Proc::Select { branches } => {
+                self.out.push_str("select ");
+                self.open_brace();
+                self.indent += 1;
+                for branch in branches {
+                    self.newline();
+                    for (i, pattern) in branch.patterns.iter().enumerate() {
+                        if i > 0 {
+                            self.out.push_str("; ");
+                        }
+                        self.names(&pattern.lhs);
+                        self.out.push_str(" <- ");
+                        self.source(&pattern.rhs);
+                    }
+                    if let Some(guard) = &branch.guard {
+                        self.out.push_str(" where ");
+                        self.atom(guard);
+                    }
+                    self.out.push_str(" => ");
+                    self.block(&branch.proc);
+                }
+                self.indent -= 1;
+                self.newline();
+                self.out.push('}');
+            }
+
+            Proc::Bundle { bundle_type, proc } => {
+                self.out.push_str(match bundle_type {
+                    BundleType::BundleEquiv => "bundle0 ",
+                    BundleType::BundleWrite => "bundle+ ",
+                    BundleType::BundleRead => "bundle- ",
+                    BundleType::BundleReadWrite => "bundle ",
+                });
+                self.block(proc);
+            }
+
+            Proc::Let {
+                bindings,
+                body,
+                concurrent,
+            } => {
+                self.out.push_str("let ");
+                let separator = if *concurrent { " & " } else { "; " };
+                for (i, binding) in bindings.iter().enumerate() {
+                    if i > 0 {
+                        self.out.push_str(separator);
+                    }
+                    self.let_binding(binding);
+                }
+                self.out.push_str(" in ");
+                self.block(body);
+            }
+
+            Proc::New { decls, proc } => {
+                self.out.push_str("new ");
+                for (i, decl) in decls.iter().enumerate() {
+                    if i > 0 {
+                        self.out.push_str(", ");
+                    }
+                    self.out.push_str(decl.id.name);
+                    if let Some(uri) = &decl.uri {
+                        write!(self.out, "({uri})").unwrap();
+                    }
+                }
+                self.out.push_str(" in ");
+                self.block(proc);
+            }
+
+            Proc::Contract {
+                name,
+                formals,
+                body,
+            } => {
+                self.out.push_str("contract ");
+                self.name(name);
+                self.out.push('(');
+                self.names(formals);
+                self.out.push_str(") = ");
+                self.block(body);
+            }
+
+            Proc::SendSync {
+                channel,
+                inputs,
+                cont,
+            } => {
+                self.name(channel);
+                self.out.push_str("!?");
+                self.proc_list(inputs);
+                match cont {
+                    SyncSendCont::Empty => self.out.push('.'),
+                    SyncSendCont::NonEmpty(cont) => {
+                        self.out.push_str("; ");
+                        self.block(cont);
+                    }
+                }
+            }
+
+            Proc::Eval { name } => {
+                self.out.push('*');
+                self.name(name);
+            }
+
+            Proc::Method {
+                receiver,
+                name,
+                args,
+            } => {
+                self.atom(receiver);
+                self.out.push('.');
+                self.out.push_str(name.name);
+                self.proc_list(args);
+            }
+
+            Proc::UnaryExp { op, arg } => {
+                self.out.push_str(match op {
+                    UnaryExpOp::Not => "not ",
+                    UnaryExpOp::Neg => "-",
+                    UnaryExpOp::Negation => "~",
+                });
+                // `-1` would be read back as a negative literal rather than a negation
+                if *op == UnaryExpOp::Neg && is_numeric_literal(arg.proc) {
+                    self.parenthesized(arg);
+                } else {
+                    self.atom(arg);
+                }
+            }
+
+            Proc::BinaryExp { op, left, right } => {
+                self.atom(left);
+                self.out.push(' ');
+                self.out.push_str(binary_op_str(*op));
+                self.out.push(' ');
+                self.atom(right);
+            }
+
+            Proc::VarRef { kind, var } => {
+                self.out.push_str(match kind {
+                    VarRefKind::Proc => "=",
+                    VarRefKind::Name => "=*",
+                });
+                self.out.push_str(var.name);
+            }
+
+            Proc::Bad => self.out.push_str("/* bad */ Nil"),
+        }
+    }
+
+    /// Process in an operand position: printed as is if it cannot be split by the surrounding
+    /// syntax, parenthesized if it is an expression, and wrapped in a block otherwise
+    fn atom(&mut self, proc: &AnnProc) {
+        if is_atomic(proc.proc) {
+            self.proc(proc);
+        } else if is_expression(proc.proc) {
+            self.parenthesized(proc);
+        } else {
+            self.braced(proc);
+        }
+    }
+
+    fn parenthesized(&mut self, proc: &AnnProc) {
+        self.out.push('(');
+        self.proc(proc);
+        self.out.push(')');
+    }
+
+    fn braced(&mut self, proc: &AnnProc) {
+        self.out.push_str("{ ");
+        self.proc(proc);
+        self.out.push_str(" }");
+    }
+
+    fn block(&mut self, proc: &AnnProc) {
+        self.open_brace();
+        self.indent += 1;
+        self.newline();
+        self.proc(proc);
+        self.indent -= 1;
+        self.newline();
+        self.out.push('}');
+    }
+
+    fn proc_list(&mut self, procs: &[AnnProc]) {
+        self.out.push('(');
+        self.comma_separated(procs);
+        self.out.push(')');
+    }
+
+    fn comma_separated(&mut self, procs: &[AnnProc]) {
+        for (i, proc) in procs.iter().enumerate() {
+            if i > 0 {
+                self.out.push_str(", ");
+            }
+            self.proc(proc);
+        }
+    }
+
+    fn var(&mut self, var: Var) {
+        self.out.push_str(var.as_ident());
+    }
+
+    fn string_literal(&mut self, value: &str) {
+        self.out.push('"');
+        for c in value.chars() {
+            match c {
+                '"' => self.out.push_str("\\\""),
+                '\\' => self.out.push_str("\\\\"),
+                '\n' => self.out.push_str("\\n"),
+                '\r' => self.out.push_str("\\r"),
+                '\t' => self.out.push_str("\\t"),
+                '\0' => self.out.push_str("\\0"),
+                c => self.out.push(c),
+            }
+        }
+        self.out.push('"');
+    }
+
+    fn remainder(&mut self, elements_present: bool, remainder: Option<Var>) {
+        if let Some(remainder) = remainder {
+            if elements_present {
+                self.out.push(' ');
+            }
+            self.out.push_str("...");
+            self.var(remainder);
+        }
+    }
+
+    fn collection(&mut self, collection: &Collection) {
+        match collection {
+            Collection::List {
+                elements,
+                remainder,
+            } => {
+                self.out.push('[');
+                self.comma_separated(elements);
+                self.remainder(!elements.is_empty(), *remainder);
+                self.out.push(']');
+            }
+            Collection::Tuple(elements) => {
+                self.out.push('(');
+                self.comma_separated(elements);
+                if elements.len() == 1 {
+                    self.out.push(',');
+                }
+                self.out.push(')');
+            }
+            Collection::Set {
+                elements,
+                remainder,
+            } => {
+                self.out.push_str("Set(");
+                self.comma_separated(elements);
+                self.remainder(!elements.is_empty(), *remainder);
+                self.out.push(')');
+            }
+            Collection::Map {
+                elements,
+                remainder,
+            } => {
+                self.out.push('{');
+                for (i, (key, value)) in elements.iter().enumerate() {
+                    if i > 0 {
+                        self.out.push_str(", ");
+                    }
+                    self.atom(key);
+                    self.out.push_str(": ");
+                    self.proc(value);
+                }
+                self.remainder(!elements.is_empty(), *remainder);
+                self.out.push('}');
+            }
+            Collection::PathMap {
+                elements,
+                remainder,
+            } => {
+                self.out.push_str("{| ");
+                self.comma_separated(elements);
+                self.remainder(!elements.is_empty(), *remainder);
+                self.out.push_str(" |}");
+            }
+        }
+    }
+
+    fn name(&mut self, name: &Name) {
+        match name {
+            Name::NameVar(var) => self.var(*var),
+            Name::Quote(quoted) => {
+                self.out.push('@');
+                if is_atomic(quoted.proc) && !is_numeric_literal(quoted.proc) {
+                    self.proc(quoted);
+                } else {
+                    self.braced(quoted);
+                }
+            }
+        }
+    }
+
+    fn names(&mut self, names: &Names) {
+        for (i, name) in names.names.iter().enumerate() {
+            if i > 0 {
+                self.out.push_str(", ");
+            }
+            self.name(name);
+        }
+        if let Some(remainder) = names.remainder {
+            if !names.names.is_empty() {
+                self.out.push(' ');
+            }
+            self.out.push_str("...@");
+            self.var(remainder);
+        }
+    }
+
+    fn source(&mut self, source: &Source) {
+        match source {
+            Source::Simple { name } => self.name(name),
+            Source::ReceiveSend { name } => {
+                self.name(name);
+                self.out.push_str("?!");
+            }
+            Source::SendReceive { name, inputs } => {
+                self.name(name);
+                self.out.push_str("!?");
+                self.proc_list(inputs);
+            }
+        }
+    }
+
+    /// The receipts of a `for`, each bind on a line of its own if `wrapped`
+    fn receipts(&mut self, receipts: &[Receipt], wrapped: bool) {
+        if wrapped {
+            self.indent += 1;
+            self.newline();
+        }
+        for (i, receipt) in receipts.iter().enumerate() {
+            if i > 0 {
+                self.out.push(';');
+                self.separator(wrapped);
+            }
+            self.receipt(receipt, wrapped);
+        }
+        if wrapped {
+            self.indent -= 1;
+            self.newline();
+        }
+    }
+
+    fn receipt(&mut self, receipt: &Receipt, wrapped: bool) {
+        for (i, bind) in receipt.binds.iter().enumerate() {
+            if i > 0 {
+                self.out.push_str(" &");
+                self.separator(wrapped);
+            }
+            self.names(bind.names());
+            if !bind.names().is_empty() {
+                self.out.push(' ');
+            }
+            match bind {
+                Bind::Linear { rhs, .. } => {
+                    self.out.push_str("<- ");
+                    self.source(rhs);
+                }
+                Bind::Repeated { rhs, .. } => {
+                    self.out.push_str("<= ");
+                    self.name(rhs);
+                }
+                Bind::Peek { rhs, .. } => {
+                    self.out.push_str("<<- ");
+                    self.name(rhs);
+                }
+            }
+        }
+        if let Some(guard) = &receipt.guard {
+            self.out.push_str(" where ");
+            self.atom(guard);
+        }
+    }
+
+    fn let_binding(&mut self, binding: &LetBinding) {
+        self.names(&binding.lhs);
+        self.out.push_str(" <- ");
+        self.comma_separated(&binding.rhs);
+    }
+}
+
+/// Processes that are never split by surrounding operators
+fn is_atomic(proc: &Proc) -> bool {
+    match proc {
+        Proc::Nil
+        | Proc::Unit
+        | Proc::BoolLiteral(_)
+        | Proc::StringLiteral(_)
+        | Proc::UriLiteral(_)
+        | Proc::SimpleType(_)
+        | Proc::Collection(_)
+        | Proc::ProcVar(_) => true,
+        // a leading minus sign could be taken for a binary operator
+        Proc::LongLiteral(value) => *value >= 0,
+        Proc::SignedIntLiteral { value, .. }
+        | Proc::UnsignedIntLiteral { value, .. }
+        | Proc::BigIntLiteral(value)
+        | Proc::BigRatLiteral(value)
+        | Proc::FloatLiteral { value, .. }
+        | Proc::FixedPointLiteral { value, .. } => !value.starts_with('-'),
+        _ => false,
+    }
+}
+
+/// Processes that may appear inside parentheses
+fn is_expression(proc: &Proc) -> bool {
+    is_numeric_literal(proc)
+        || matches!(
+            proc,
+            Proc::Eval { .. }
+                | Proc::Method { .. }
+                | Proc::UnaryExp { .. }
+                | Proc::BinaryExp { .. }
+                | Proc::VarRef { .. }
+        )
+}
+
+fn is_numeric_literal(proc: &Proc) -> bool {
+    matches!(
+        proc,
+        Proc::LongLiteral(_)
+            | Proc::SignedIntLiteral { .. }
+            | Proc::UnsignedIntLiteral { .. }
+            | Proc::BigIntLiteral(_)
+            | Proc::BigRatLiteral(_)
+            | Proc::FloatLiteral { .. }
+            | Proc::FixedPointLiteral { .. }
+    )
+}
+
+fn binary_op_str(op: BinaryExpOp) -> &'static str {
+    match op {
+        BinaryExpOp::Or => "or",
+        BinaryExpOp::And => "and",
+        BinaryExpOp::Matches => "matches",
+        BinaryExpOp::Eq => "==",
+        BinaryExpOp::Neq => "!=",
+        BinaryExpOp::Lt => "<",
+        BinaryExpOp::Lte => "<=",
+        BinaryExpOp::Gt => ">",
+        BinaryExpOp::Gte => ">=",
+        BinaryExpOp::Concat => "++",
+        BinaryExpOp::Diff => "--",
+        BinaryExpOp::Add => "+",
+        BinaryExpOp::Sub => "-",
+        BinaryExpOp::Interpolation => "%%",
+        BinaryExpOp::Mult => "*",
+        BinaryExpOp::Div => "/",
+        BinaryExpOp::Mod => "%",
+        BinaryExpOp::Disjunction => "\\/",
+        BinaryExpOp::Conjunction => "/\\",
+    }
+}
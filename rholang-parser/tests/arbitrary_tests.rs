@@ -0,0 +1,90 @@
+#![cfg(feature = "arbitrary")]
+
+use std::collections::HashSet;
+
+use arbitrary::{Arbitrary, Unstructured};
+use rholang_parser::{
+    RholangParser,
+    arbitrary::{ArbitraryProgram, arbitrary_proc},
+    ast::Proc,
+    unparse,
+};
+use validated::Validated;
+
+/// Deterministic pseudo-random fuzzer input
+fn seed_data(seed: u64, len: usize) -> Vec<u8> {
+    let mut state = seed
+        .wrapping_mul(6364136223846793005)
+        .wrapping_add(1442695040888963407);
+    (0..len)
+        .map(|_| {
+            state = state
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            (state >> 33) as u8
+        })
+        .collect()
+}
+
+fn variant_name(proc: &Proc) -> String {
+    let debug = format!("{proc:?}");
+    debug
+        .split(|c: char| !c.is_alphanumeric())
+        .next()
+        .unwrap_or_default()
+        .to_string()
+}
+
+#[test]
+fn generated_asts_survive_unparse_and_reparse() {
+    let mut variants = HashSet::new();
+
+    for seed in 0..500 {
+        let data = seed_data(seed, 1024);
+        let parser = RholangParser::new();
+        let mut u = Unstructured::new(&data);
+        let generated = arbitrary_proc(parser.ast_builder(), &mut u, 5).unwrap();
+        variants.extend(generated.iter_preorder_dfs().map(|p| variant_name(p.proc)));
+
+        let code = unparse(&generated);
+        let reparser = RholangParser::new();
+        let reparsed = match reparser.parse(&code) {
+            Validated::Good(procs) => procs,
+            Validated::Fail(e) => panic!("seed {seed}: failed to reparse\n{code}\n{e:?}"),
+        };
+        assert_eq!(
+            reparsed.len(),
+            1,
+            "seed {seed}: expected one process\n{code}"
+        );
+        assert_eq!(
+            unparse(&reparsed[0]),
+            code,
+            "seed {seed}: unparse is not stable"
+        );
+    }
+
+    // everything but `Select` and `Bad`
+    assert_eq!(variants.len(), 30, "not every variant was generated");
+}
+
+#[test]
+fn arbitrary_program_parses() {
+    for seed in 0..50 {
+        let data = seed_data(seed, 256);
+        let program = ArbitraryProgram::arbitrary(&mut Unstructured::new(&data)).unwrap();
+        let parser = RholangParser::new();
+        assert!(
+            matches!(parser.parse(&program.0), Validated::Good(_)),
+            "seed {seed}: {}",
+            program.0
+        );
+    }
+}
+
+#[test]
+fn exhausted_input_yields_a_leaf() {
+    let parser = RholangParser::new();
+    let generated = arbitrary_proc(parser.ast_builder(), &mut Unstructured::new(&[]), 5).unwrap();
+    assert_eq!(unparse(&generated), "Nil");
+}
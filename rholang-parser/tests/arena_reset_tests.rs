@@ -0,0 +1,46 @@
+use rholang_parser::{RholangParser, unparse};
+
+#[test]
+fn scoped_parses_are_freed_when_the_scope_ends() {
+    let code = r#"new x in { x!("hello", [1, 2, 3]) | for (@y <- x) { Nil } }"#;
+    let mut parser = RholangParser::new();
+
+    let (first, used) = parser.scoped(code, |p, code| {
+        let procs = p.parse(code).ok().unwrap();
+        (unparse(&procs[0]), p.ast_builder().allocated())
+    });
+    assert!(used > 0);
+    assert_eq!(parser.ast_builder().allocated(), 0);
+
+    let second = parser.scoped(code, |p, code| {
+        let procs = p.parse(code).ok().unwrap();
+        assert_eq!(p.ast_builder().allocated(), used);
+        unparse(&procs[0])
+    });
+    assert_eq!(second, first);
+}
+
+#[test]
+fn many_documents_do_not_accumulate() {
+    let mut parser = RholangParser::new();
+    let mut peak = 0;
+    for i in 0..50 {
+        let code = format!("@{i}!({i} + 1) | for (x <- @{i}) {{ x!(\"doc {i}\") }}");
+        let rendered = parser.scoped(&code, |p, code| {
+            let procs = p.parse(code).ok().unwrap();
+            peak = peak.max(p.ast_builder().allocated());
+            unparse(&procs[0])
+        });
+        assert!(rendered.contains(&format!("doc {i}")), "{rendered}");
+    }
+    assert_eq!(parser.ast_builder().allocated(), 0);
+    assert!(peak < 100, "a single document allocated {peak} values");
+}
+
+#[test]
+fn reset_before_parsing() {
+    let mut parser = RholangParser::new();
+    parser.reset();
+    assert_eq!(parser.ast_builder().allocated(), 0);
+    assert!(parser.parse("Nil").is_good());
+}
@@ -0,0 +1,99 @@
+use rholang_parser::{
+    RholangParser,
+    ast::{ArrowKind, Name, Proc},
+};
+use validated::Validated;
+
+#[test]
+fn quoted_proc_vars_are_binders() {
+    let parser = RholangParser::new();
+    let procs = match parser.parse("for (@x, @y <- ch) { Nil }") {
+        Validated::Good(p) => p,
+        Validated::Fail(e) => panic!("parse failed: {e:?}"),
+    };
+
+    let Proc::ForComprehension { receipts, .. } = procs[0].proc else {
+        panic!("expected ForComprehension, got {:?}", procs[0].proc);
+    };
+    let binders: Vec<_> = receipts[0].binds[0]
+        .binders()
+        .map(|(name, binds)| match name {
+            Name::Quote(quoted) => (rholang_parser::unparse(quoted), binds),
+            Name::NameVar(var) => panic!("expected a quoted pattern, got {var:?}"),
+        })
+        .collect();
+
+    assert_eq!(
+        binders,
+        vec![("x".to_string(), true), ("y".to_string(), true)]
+    );
+}
+
+#[test]
+fn name_vars_are_binders_and_wildcards_are_not() {
+    let parser = RholangParser::new();
+    let procs = match parser.parse("for (x, _, @_ <- ch) { Nil }") {
+        Validated::Good(p) => p,
+        Validated::Fail(e) => panic!("parse failed: {e:?}"),
+    };
+
+    let Proc::ForComprehension { receipts, .. } = procs[0].proc else {
+        panic!("expected ForComprehension, got {:?}", procs[0].proc);
+    };
+    let flags: Vec<bool> = receipts[0].binds[0]
+        .binders()
+        .map(|(_, binds)| binds)
+        .collect();
+
+    assert_eq!(flags, vec![true, false, false]);
+}
+
+#[test]
+fn ground_and_referencing_patterns_are_not_binders() {
+    let parser = RholangParser::new();
+    let procs = match parser.parse("for (@42, @{y!(_)}, @{=*z} <- ch) { Nil }") {
+        Validated::Good(p) => p,
+        Validated::Fail(e) => panic!("parse failed: {e:?}"),
+    };
+
+    let Proc::ForComprehension { receipts, .. } = procs[0].proc else {
+        panic!("expected ForComprehension, got {:?}", procs[0].proc);
+    };
+    let flags: Vec<bool> = receipts[0].binds[0]
+        .names()
+        .binders()
+        .map(|(_, binds)| binds)
+        .collect();
+
+    // `42` is ground, `y!(_)` binds `y`, `=*z` only refers to `z`
+    assert_eq!(flags, vec![false, true, false]);
+}
+
+#[test]
+fn binds_report_arrow_kind_and_continuation() {
+    let parser = RholangParser::new();
+    let procs = match parser
+        .parse("for (x <- a & y, ...@rest <= b & @z <<- c & ...@all <- d!?(1)) { Nil }")
+    {
+        Validated::Good(p) => p,
+        Validated::Fail(e) => panic!("parse failed: {e:?}"),
+    };
+
+    let Proc::ForComprehension { receipts, .. } = procs[0].proc else {
+        panic!("expected ForComprehension, got {:?}", procs[0].proc);
+    };
+    let binds: Vec<_> = receipts[0]
+        .iter()
+        .map(|bind| (bind.arrow_kind(), bind.has_continuation()))
+        .collect();
+
+    assert_eq!(
+        binds,
+        vec![
+            (ArrowKind::Linear, false),
+            (ArrowKind::Repeated, true),
+            (ArrowKind::Peek, false),
+            (ArrowKind::Linear, true),
+        ]
+    );
+}
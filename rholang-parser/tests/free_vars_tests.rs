@@ -0,0 +1,72 @@
+use rholang_parser::{RholangParser, free_vars};
+use validated::Validated;
+
+fn free_vars_of(code: &str) -> Vec<String> {
+    let parser = RholangParser::new();
+    match parser.parse(code) {
+        Validated::Good(procs) => {
+            assert_eq!(procs.len(), 1, "expected a single process in {code}");
+            free_vars(&procs[0])
+                .into_iter()
+                .map(|id| id.name.to_string())
+                .collect()
+        }
+        Validated::Fail(e) => panic!("parse failed for {code}: {e:?}"),
+    }
+}
+
+#[test]
+fn for_binds_its_patterns() {
+    assert_eq!(free_vars_of("for(@x <- c) { x!(y) }"), ["c", "y"]);
+}
+
+#[test]
+fn closed_process_has_no_free_vars() {
+    assert!(free_vars_of("new c in { c!(1) | for(@x <- c) { c!(x) } }").is_empty());
+    assert!(free_vars_of("Nil").is_empty());
+}
+
+#[test]
+fn each_free_var_is_reported_once_in_order() {
+    assert_eq!(free_vars_of("b!(a) | a!(b) | c!(a)"), ["b", "a", "c"]);
+}
+
+#[test]
+fn receive_sources_are_outside_their_binds() {
+    // `x` in the second source is the outer `x`, not the one bound by the first bind
+    assert_eq!(free_vars_of("for(x <- a & y <- x) { Nil }"), ["a", "x"]);
+    // ...but a later receipt sees it
+    assert!(free_vars_of("new a in { for(x <- a; y <- x) { y!(Nil) } }").is_empty());
+}
+
+#[test]
+fn contract_binds_formals_but_not_its_name() {
+    assert_eq!(
+        free_vars_of("contract f(@n, ret) = { ret!(n + k) }"),
+        ["f", "k"]
+    );
+}
+
+#[test]
+fn match_cases_bind_pattern_vars() {
+    assert_eq!(
+        free_vars_of("match e { [h ...t] => h!(t) x where x > lim => x!(Nil) }"),
+        ["e", "lim"]
+    );
+}
+
+#[test]
+fn pattern_var_refs_are_uses() {
+    assert_eq!(free_vars_of("for(@=v <- c) { Nil }"), ["c", "v"]);
+}
+
+#[test]
+fn let_bindings() {
+    assert_eq!(free_vars_of("let x <- 1; y <- x in { y!(z) }"), ["z"]);
+    assert_eq!(free_vars_of("let x <- 1 & y <- x in { y!(Nil) }"), ["x"]);
+}
+
+#[test]
+fn scopes_end_with_their_binder() {
+    assert_eq!(free_vars_of("new x in { x!(1) } | x!(2)"), ["x"]);
+}
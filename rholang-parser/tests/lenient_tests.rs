@@ -0,0 +1,99 @@
+use rholang_parser::{RholangParser, SourcePos, ast::Proc, parser::errors::ParsingError};
+
+#[test]
+fn unclosed_new_yields_partial_new_with_diagnostic() {
+    let parser = RholangParser::new();
+    let (procs, errors) = parser.parse_lenient("new x in {");
+
+    assert_eq!(procs.len(), 1);
+    assert!(matches!(procs[0].proc, Proc::New { .. }));
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].error, ParsingError::MissingToken("}"));
+}
+
+#[test]
+fn missing_tokens_are_reported_where_tree_sitter_inserts_them() {
+    let parser = RholangParser::new();
+    let (procs, errors) = parser.parse_lenient("for (x <- y) { x");
+
+    assert_eq!(procs.len(), 1);
+    assert!(matches!(procs[0].proc, Proc::ForComprehension { .. }));
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].error, ParsingError::MissingToken("}"));
+    assert_eq!(errors[0].span.start, SourcePos { line: 1, col: 17 });
+    assert_eq!(errors[0].byte_range, 16..16);
+}
+
+#[test]
+fn brackets_in_comments_do_not_count() {
+    let parser = RholangParser::new();
+    let (procs, errors) = parser.parse_lenient("new x in { x!(1) // {\n");
+
+    assert_eq!(procs.len(), 1);
+    let Proc::New { proc: body, .. } = procs[0].proc else {
+        panic!("expected new, got {:?}", procs[0].proc);
+    };
+    assert!(matches!(body.proc, Proc::Send { .. }));
+    let found: Vec<_> = errors.iter().map(|e| e.error.clone()).collect();
+    assert_eq!(found, vec![ParsingError::MissingToken("}")]);
+}
+
+#[test]
+fn unrepairable_parts_become_bad() {
+    let parser = RholangParser::new();
+
+    let (procs, errors) = parser.parse_lenient("new x in { x!(");
+    assert_eq!(procs.len(), 1);
+    assert_eq!(procs[0].proc, &Proc::Bad);
+    assert!(matches!(errors[..], [ref e] if matches!(e.error, ParsingError::SyntaxError { .. })));
+
+    let (procs, errors) = parser.parse_lenient("1 + ");
+    let Proc::BinaryExp { right, .. } = procs[0].proc else {
+        panic!("expected binary expression, got {:?}", procs[0].proc);
+    };
+    assert_eq!(right.proc, &Proc::Bad);
+    assert_eq!(errors[0].error, ParsingError::MissingToken("var"));
+}
+
+#[test]
+fn well_formed_code_has_no_diagnostics() {
+    let parser = RholangParser::new();
+    let (procs, errors) = parser.parse_lenient("new x in { x!(1) }");
+
+    assert_eq!(procs.len(), 1);
+    assert!(errors.is_empty());
+}
+
+#[test]
+fn unrecoverable_code_still_yields_an_ast() {
+    let parser = RholangParser::new();
+    let (procs, errors) = parser.parse_lenient("} 1 +");
+
+    assert!(!procs.is_empty());
+    assert!(!errors.is_empty());
+}
+
+#[test]
+fn unterminated_string_takes_the_rest_of_its_line() {
+    let parser = RholangParser::new();
+    let (procs, errors) = parser.parse_lenient("stdout!(\"oops");
+
+    assert_eq!(procs.len(), 1);
+    let Proc::Send { inputs, .. } = procs[0].proc else {
+        panic!("expected send, got {:?}", procs[0].proc);
+    };
+    assert_eq!(inputs[0].proc, &Proc::StringLiteral("oops"));
+
+    let found: Vec<_> = errors.iter().map(|e| &e.error).collect();
+    assert!(
+        matches!(
+            found[..],
+            [
+                ParsingError::UnterminatedString { .. },
+                ParsingError::MissingToken(")")
+            ]
+        ),
+        "{found:?}"
+    );
+    assert_eq!(errors[0].span.start, SourcePos { line: 1, col: 9 });
+}
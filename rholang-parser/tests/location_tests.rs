@@ -0,0 +1,82 @@
+use rholang_parser::ast::AnnProc;
+use rholang_parser::{RholangParser, SourcePos, build_location_index};
+use validated::Validated;
+
+/// The innermost node containing `pos`, found by walking the whole tree
+fn node_at_linear<'a>(root: &'a AnnProc<'a>, pos: SourcePos) -> Option<&'a AnnProc<'a>> {
+    root.iter_preorder_dfs()
+        .filter(|node| node.span.contains(pos))
+        .fold(None, |best: Option<&AnnProc>, node| match best {
+            Some(best)
+                if !(best.span.start <= node.span.start && node.span.end <= best.span.end) =>
+            {
+                Some(best)
+            }
+            _ => Some(node),
+        })
+}
+
+fn large_program(copies: usize) -> String {
+    let block = r#"new x, stdout(`rho:io:stdout`) in {
+  x!(1 + 2 * 3, [4, 5], {"k": (6, 7)}) |
+  for (@a, @b <- x; @c <= x) {
+    if (a > b) { stdout!(a - b) } else { stdout!(not c) }
+  } |
+  match [1, 2] { [h ...t] => stdout!(h) _ => Nil }
+}"#;
+    vec![block; copies].join(" |\n")
+}
+
+#[test]
+fn node_at_matches_a_linear_scan() {
+    let code = large_program(20);
+    let parser = RholangParser::new();
+    let Validated::Good(procs) = parser.parse(&code) else {
+        panic!("failed to parse the program");
+    };
+    let root = &procs[0];
+    let index = build_location_index(root);
+
+    let positions: Vec<SourcePos> = code
+        .lines()
+        .enumerate()
+        .flat_map(|(line, text)| {
+            (0..=text.chars().count() + 1).map(move |col| SourcePos {
+                line: line + 1,
+                col: col + 1,
+            })
+        })
+        .chain([SourcePos {
+            line: 10_000,
+            col: 1,
+        }])
+        .collect();
+
+    for &pos in &positions {
+        assert_eq!(
+            index.node_at(pos).map(|node| node as *const AnnProc),
+            node_at_linear(root, pos).map(|node| node as *const AnnProc),
+            "at {pos}"
+        );
+    }
+}
+
+#[test]
+fn node_at_finds_the_innermost_node() {
+    let code = "x!(1 + 2)";
+    let parser = RholangParser::new();
+    let Validated::Good(procs) = parser.parse(code) else {
+        panic!("failed to parse {code}");
+    };
+    let index = build_location_index(&procs[0]);
+
+    let at = |col| {
+        index
+            .node_at(SourcePos { line: 1, col })
+            .map(|node| node.span)
+    };
+    assert_eq!(at(1), Some(procs[0].span));
+    assert_eq!(at(4), Some(SourcePos { line: 1, col: 4 }.span_of(1)));
+    assert_eq!(at(6), Some(SourcePos { line: 1, col: 4 }.span_of(5)));
+    assert_eq!(at(10), None);
+}
@@ -0,0 +1,44 @@
+use rholang_parser::{ProcMetrics, RholangParser, metrics};
+use validated::Validated;
+
+fn metrics_of(code: &str) -> ProcMetrics {
+    let parser = RholangParser::new();
+    match parser.parse(code) {
+        Validated::Good(procs) => {
+            assert_eq!(procs.len(), 1, "expected a single process in {code}");
+            metrics(&procs[0])
+        }
+        Validated::Fail(e) => panic!("parse failed for {code}: {e:?}"),
+    }
+}
+
+#[test]
+fn metrics_of_leaf() {
+    assert_eq!(
+        metrics_of("Nil"),
+        ProcMetrics {
+            nodes: 1,
+            max_depth: 1,
+            ..ProcMetrics::default()
+        }
+    );
+}
+
+#[test]
+fn metrics_of_program() {
+    let code = r#"
+        new a, b, stdout(`rho:io:stdout`) in {
+            a!(1) |
+            for (@x <- a & @y <- b) { stdout!(x + y) } |
+            contract b(@n) = { b!(n - 1) }
+        }"#;
+
+    let m = metrics_of(code);
+    assert_eq!(m.new_names, 3);
+    assert_eq!(m.sends, 3);
+    assert_eq!(m.receives, 3);
+    // new > par > for > send > x + y > x
+    assert_eq!(m.max_depth, 7);
+    // new, 2 pars, send + 1, for + send + (x + y) + x + y, contract + send + (n - 1) + n + 1
+    assert_eq!(m.nodes, 15);
+}
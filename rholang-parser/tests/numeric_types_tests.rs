@@ -89,3 +89,41 @@ fn rejects_non_power_of_two_sized_int_literals() {
             .all(|error| matches!(error.error, ParsingError::NumberOutOfRange))
     );
 }
+
+#[test]
+fn parses_radix_prefixed_long_literals() {
+    let parser = RholangParser::new();
+    let parsed = parser.parse("0x1F\n0b1010\n0o17\n-0x10\n-42\n-0x8000000000000000");
+
+    let procs = match parsed {
+        Validated::Good(procs) => procs,
+        Validated::Fail(err) => panic!("expected successful parse, got errors: {err:?}"),
+    };
+
+    let values: Vec<i64> = procs
+        .iter()
+        .map(|p| match p.proc {
+            Proc::LongLiteral(value) => *value,
+            other => panic!("expected a long literal, got {other:?}"),
+        })
+        .collect();
+    assert_eq!(values, vec![31, 10, 15, -16, -42, i64::MIN]);
+}
+
+#[test]
+fn rejects_out_of_range_hex_literals() {
+    let parser = RholangParser::new();
+    let parsed = parser.parse("0x8000000000000000");
+
+    let failures = match parsed {
+        Validated::Good(_) => panic!("expected parse failure"),
+        Validated::Fail(errs) => errs,
+    };
+
+    assert!(
+        failures
+            .iter()
+            .flat_map(|failure| failure.errors.iter())
+            .all(|error| matches!(error.error, ParsingError::NumberOutOfRange))
+    );
+}
@@ -0,0 +1,99 @@
+use rholang_parser::ast::{AnnProc, Associativity, BinaryExpOp, Id, UnaryExpOp};
+use rholang_parser::{PrecedenceViolation, RholangParser, SourcePos, check_precedence, unparse};
+use rstest::rstest;
+use validated::Validated;
+
+/// The fully parenthesized form of `code`, checked to be grouped as the precedence table says
+fn grouping(code: &str) -> String {
+    let parser = RholangParser::new();
+    match parser.parse(code) {
+        Validated::Good(procs) => {
+            assert_eq!(procs.len(), 1, "expected a single process in {code}");
+            assert_eq!(check_precedence(&procs[0]), vec![], "in {code}");
+            unparse(&procs[0])
+        }
+        Validated::Fail(e) => panic!("parse failed for {code}: {e:?}"),
+    }
+}
+
+#[rstest]
+// same precedence chains to the left
+#[case("a - b - c", "(a - b) - c")]
+#[case("a + b - c + d", "((a + b) - c) + d")]
+#[case("a / b * c", "(a / b) * c")]
+#[case("a * b / c % d", "((a * b) / c) % d")]
+#[case("a or b or c", "(a or b) or c")]
+#[case("a == b != c", "(a == b) != c")]
+#[case("a < b > c", "(a < b) > c")]
+#[case("a ++ b -- c", "(a ++ b) -- c")]
+#[case("{|1|} ++ {|2|} -- {|3|}", "({| 1 |} ++ {| 2 |}) -- {| 3 |}")]
+#[case("a %% b * c", "(a %% b) * c")]
+// ... except matches, which chains to the right
+#[case("a matches b matches c", "a matches (b matches c)")]
+// mixed precedence
+#[case("a + b * c", "a + (b * c)")]
+#[case("a * b + c", "(a * b) + c")]
+#[case("a or b and c", "a or (b and c)")]
+#[case("a and b or c", "(a and b) or c")]
+#[case("a == b < c", "a == (b < c)")]
+#[case("a < b + c", "a < (b + c)")]
+#[case("a + b ++ c", "(a + b) ++ c")]
+#[case("a == b matches c", "(a == b) matches c")]
+#[case("a matches b == c", "a matches (b == c)")]
+#[case("a \\/ b /\\ c", "a \\/ (b /\\ c)")]
+#[case("a /\\ b \\/ c", "(a /\\ b) \\/ c")]
+// prefix operators
+#[case("-a - b", "(-a) - b")]
+#[case("not a and b", "(not a) and b")]
+#[case("~a /\\ b", "(~a) /\\ b")]
+// explicit parentheses win
+#[case("a - (b - c)", "a - (b - c)")]
+#[case("(a or b) and c", "(a or b) and c")]
+#[case("-(a - b)", "-(a - b)")]
+fn operators_group_by_precedence(#[case] code: &str, #[case] expected: &str) {
+    assert_eq!(grouping(code), expected);
+}
+
+#[test]
+fn precedence_table_is_consistent() {
+    use BinaryExpOp::*;
+    assert!(Or.precedence() < And.precedence());
+    assert!(And.precedence() < Eq.precedence());
+    assert!(Eq.precedence() < Lt.precedence());
+    assert!(Lt.precedence() < Add.precedence());
+    assert!(Add.precedence() < Mult.precedence());
+    assert!(Mult.precedence() < UnaryExpOp::Neg.precedence());
+    assert_eq!(Concat.precedence(), Add.precedence());
+    assert_eq!(Interpolation.precedence(), Mult.precedence());
+    assert!(Disjunction.precedence() < Conjunction.precedence());
+    assert!(Conjunction.precedence() < UnaryExpOp::Negation.precedence());
+    assert_eq!(Matches.associativity(), Associativity::Right);
+    assert_eq!(Sub.associativity(), Associativity::Left);
+}
+
+#[test]
+fn misgrouped_tree_is_reported() {
+    // `a - b - c` with the tree of `a - (b - c)`
+    let parser = RholangParser::new();
+    let builder = parser.ast_builder();
+    let var = |name, col| {
+        let pos = SourcePos { line: 1, col };
+        builder.alloc_var(Id { name, pos }).ann(pos.span_of(1))
+    };
+    let start = SourcePos { line: 1, col: 1 };
+    let right_start = SourcePos { line: 1, col: 5 };
+    let right = builder
+        .alloc_binary_exp(BinaryExpOp::Sub, var("b", 5), var("c", 9))
+        .ann(right_start.span_of(5));
+    let whole: AnnProc = builder
+        .alloc_binary_exp(BinaryExpOp::Sub, var("a", 1), right)
+        .ann(start.span_of(9));
+
+    assert_eq!(
+        check_precedence(&whole),
+        vec![PrecedenceViolation {
+            expr: whole.span,
+            operand: right.span,
+        }]
+    );
+}
@@ -0,0 +1,26 @@
+use rholang_parser::ast::Proc;
+use rholang_parser::{ProcQueryExt, RholangParser};
+use validated::Validated;
+
+#[test]
+fn sends_yields_every_send() {
+    let code = r#"new x, y in { x!(1) | for (@v <- y) { y!(v + 1) } | Nil }"#;
+    let parser = RholangParser::new();
+    let Validated::Good(procs) = parser.parse(code) else {
+        panic!("{code} should parse");
+    };
+    let root = &procs[0];
+
+    let sends: Vec<_> = root.iter_preorder_dfs().sends().collect();
+    assert_eq!(sends.len(), 2);
+    assert!(
+        sends
+            .iter()
+            .all(|node| matches!(node.proc, Proc::Send { .. }))
+    );
+    let columns: Vec<_> = sends.iter().map(|node| node.span.start.col).collect();
+    assert_eq!(columns, [15, 39]);
+
+    assert_eq!(root.iter_preorder_dfs().receives().count(), 1);
+    assert_eq!(root.iter_preorder_dfs().news().count(), 1);
+}
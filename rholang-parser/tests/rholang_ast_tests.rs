@@ -0,0 +1,31 @@
+use rholang_parser::{RholangAst, ast::BinaryExpOp, free_vars, unparse};
+
+#[test]
+fn built_processes_unparse_to_source() {
+    let ast = RholangAst::new();
+    let sum = ast.binary(BinaryExpOp::Add, ast.int(1), ast.int(2));
+    let send = ast.send(
+        ast.name("x"),
+        &[sum, ast.string("two"), ast.list(&[ast.bool(true)])],
+    );
+    let receive = ast.receive([ast.quote(ast.var("v"))], ast.name("x"), ast.var("v"));
+    let proc = ast.new_scope(&["x"], ast.par(send, receive));
+
+    let source = unparse(&proc);
+    let parser = rholang_parser::RholangParser::new();
+    let reparsed = parser
+        .parse(&source)
+        .ok()
+        .expect("unparsed source should parse");
+    assert!(rholang_parser::structurally_eq(&reparsed[0], &proc));
+    assert!(free_vars(&proc).is_empty());
+}
+
+#[test]
+fn built_nodes_have_distinct_positions() {
+    let ast = RholangAst::new();
+    let (a, b) = (ast.id("x"), ast.id("x"));
+    assert_eq!(a, b);
+    assert_ne!(a.pos, b.pos);
+    assert_ne!(ast.nil().span, ast.nil().span);
+}
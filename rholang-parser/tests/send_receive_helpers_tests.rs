@@ -0,0 +1,83 @@
+use rholang_parser::{
+    RholangParser,
+    ast::{AnnProc, Name, Var},
+};
+use validated::Validated;
+
+fn parse_one<'a>(parser: &'a RholangParser<'a>, code: &'a str) -> AnnProc<'a> {
+    match parser.parse(code) {
+        Validated::Good(procs) => {
+            assert_eq!(procs.len(), 1, "expected a single process in {code}");
+            procs[0]
+        }
+        Validated::Fail(e) => panic!("parse failed for {code}: {e:?}"),
+    }
+}
+
+fn channel_name(name: &Name) -> String {
+    match name {
+        Name::NameVar(Var::Id(id)) => id.name.to_string(),
+        Name::NameVar(Var::Wildcard) => "_".to_string(),
+        Name::Quote(quoted) => format!("@{}", rholang_parser::unparse(quoted)),
+    }
+}
+
+#[test]
+fn send_helpers() {
+    let parser = RholangParser::new();
+
+    let send = parse_one(&parser, "ch!(1, 2, 3)");
+    assert_eq!(send.proc.send_arity(), Some(3));
+    assert_eq!(
+        send.proc.send_channel().map(channel_name),
+        Some("ch".to_string())
+    );
+
+    let persistent = parse_one(&parser, "@\"out\"!!()");
+    assert_eq!(persistent.proc.send_arity(), Some(0));
+    assert_eq!(
+        persistent.proc.send_channel().map(channel_name),
+        Some("@\"out\"".to_string())
+    );
+
+    let sync = parse_one(&parser, "ch!?(1, 2); Nil");
+    assert_eq!(sync.proc.send_arity(), Some(2));
+    assert_eq!(
+        sync.proc.send_channel().map(channel_name),
+        Some("ch".to_string())
+    );
+}
+
+#[test]
+fn receive_helpers() {
+    let parser = RholangParser::new();
+
+    let receive = parse_one(&parser, "for (x <- a & y <= b; z <<- @1) { Nil }");
+    let channels: Vec<_> = receive
+        .proc
+        .receive_channels()
+        .expect("a for-comprehension has receive channels")
+        .map(channel_name)
+        .collect();
+    assert_eq!(channels, ["a", "b", "@1"]);
+}
+
+#[test]
+fn helpers_are_none_on_other_processes() {
+    let parser = RholangParser::new();
+
+    for code in [
+        "Nil",
+        "x + 1",
+        "contract c(x) = { x!(1) }",
+        "new c in { c!(1) }",
+    ] {
+        let proc = parse_one(&parser, code);
+        assert_eq!(proc.proc.send_arity(), None, "{code}");
+        assert!(proc.proc.send_channel().is_none(), "{code}");
+        assert!(proc.proc.receive_channels().is_none(), "{code}");
+    }
+
+    let send = parse_one(&parser, "ch!(1)");
+    assert!(send.proc.receive_channels().is_none());
+}
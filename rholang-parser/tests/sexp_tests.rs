@@ -0,0 +1,62 @@
+use rholang_parser::{RholangParser, to_sexp};
+use rstest::rstest;
+use validated::Validated;
+
+fn sexp(code: &str) -> String {
+    let parser = RholangParser::new();
+    match parser.parse(code) {
+        Validated::Good(procs) => procs.iter().map(to_sexp).collect::<Vec<_>>().join("\n"),
+        Validated::Fail(e) => panic!("parse failed for {code}: {e:?}"),
+    }
+}
+
+#[test]
+fn small_program() {
+    assert_eq!(
+        sexp("x!(42) | Nil"),
+        "(par (send (name x) (long 42)) (nil))"
+    );
+}
+
+#[rstest]
+#[case("1 + 2 * 3", "(add (long 1) (mult (long 2) (long 3)))")]
+#[case("not true", "(not (bool true))")]
+#[case(
+    r#"[1, "a" ...rest]"#,
+    r#"(list (long 1) (string "a") (rest (var rest)))"#
+)]
+#[case("{1: ()}", "(map (pair (long 1) (unit)))")]
+#[case(
+    "new x, out(`rho:io:stdout`) in { out!(*x) }",
+    "(new (decl x) (decl out `rho:io:stdout`) (send (name out) (eval (name x))))"
+)]
+#[case(
+    "for (@y, _ <- x & z <<- @1) { y }",
+    "(for (receipt (linear (names (quote (var y)) (name _)) (name x)) \
+     (peek (names (name z)) (quote (long 1)))) (var y))"
+)]
+#[case(
+    "match 1 { 1 => Nil _ => 2 }",
+    "(match (long 1) (case (long 1) (nil)) (case (wildcard) (long 2)))"
+)]
+#[case(
+    "contract f(x, ...@rest) = { x!!(1) }",
+    "(contract (name f) (names (name x) (rest (var rest))) (send_multiple (name x) (long 1)))"
+)]
+#[case(
+    "let x, y <- 1, 2; z, ...@rest <- 3, 4, 5 in { Nil }",
+    "(let (binding (names (name x) (name y)) (long 1) (long 2)) \
+     (binding (names (name z) (rest (var rest))) (long 3) (long 4) (long 5)) (nil))"
+)]
+#[case(
+    "if (a) { b } else { c.len() }",
+    "(if (var a) (var b) (method len (var c)))"
+)]
+fn renders_structure(#[case] code: &str, #[case] expected: &str) {
+    assert_eq!(sexp(code), expected);
+}
+
+#[test]
+fn positions_are_not_rendered() {
+    assert_eq!(sexp("x!(1)"), sexp("x  !  (\n 1\n)"));
+}
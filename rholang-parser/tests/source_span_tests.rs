@@ -0,0 +1,139 @@
+use std::collections::{BTreeSet, HashSet};
+
+use rholang_parser::{RholangParser, SourcePos, SourceSpan, ast::Proc};
+
+fn pos(line: usize, col: usize) -> SourcePos {
+    SourcePos { line, col }
+}
+
+fn span(start: SourcePos, end: SourcePos) -> SourceSpan {
+    SourceSpan { start, end }
+}
+
+#[test]
+fn contains_is_inclusive_at_start_and_exclusive_at_end() {
+    let s = span(pos(1, 5), pos(1, 10));
+
+    assert!(s.contains(pos(1, 5)));
+    assert!(s.contains(pos(1, 9)));
+    assert!(!s.contains(pos(1, 10)));
+    assert!(!s.contains(pos(1, 4)));
+}
+
+#[test]
+fn spans_order_by_start_then_end() {
+    let spans = [
+        span(pos(2, 1), pos(2, 4)),
+        span(pos(1, 5), pos(3, 1)),
+        span(pos(1, 5), pos(1, 9)),
+        span(pos(1, 1), pos(4, 1)),
+        span(pos(1, 5), pos(1, 9)),
+    ];
+
+    let set: BTreeSet<_> = spans.iter().copied().collect();
+    let sorted: Vec<_> = set.into_iter().collect();
+    assert_eq!(
+        sorted,
+        [
+            span(pos(1, 1), pos(4, 1)),
+            span(pos(1, 5), pos(1, 9)),
+            span(pos(1, 5), pos(3, 1)),
+            span(pos(2, 1), pos(2, 4)),
+        ]
+    );
+    assert_eq!(spans.iter().collect::<HashSet<_>>().len(), 4);
+}
+
+#[test]
+fn span_of_saturates_instead_of_wrapping() {
+    let s = pos(3, usize::MAX - 2).span_of(10);
+    assert_eq!(s.end, pos(3, usize::MAX));
+    assert!(s.start < s.end);
+
+    assert_eq!(pos(1, 4).span_of(3).end, pos(1, 7));
+}
+
+#[test]
+fn contains_across_lines() {
+    let s = span(pos(2, 8), pos(4, 3));
+
+    assert!(s.contains(pos(3, 1)));
+    assert!(s.contains(pos(2, 100)));
+    assert!(s.contains(pos(4, 2)));
+    assert!(!s.contains(pos(2, 7)));
+    assert!(!s.contains(pos(4, 3)));
+}
+
+#[test]
+fn empty_span_contains_nothing() {
+    let s = SourceSpan::empty_at(pos(1, 5));
+    assert!(!s.contains(pos(1, 5)));
+}
+
+#[test]
+fn overlaps_respects_exclusive_end() {
+    let a = span(pos(1, 1), pos(1, 5));
+    let touching = span(pos(1, 5), pos(1, 8));
+    let crossing = span(pos(1, 4), pos(1, 8));
+    let inner = span(pos(1, 2), pos(1, 3));
+
+    assert!(!a.overlaps(touching));
+    assert!(!touching.overlaps(a));
+    assert!(a.overlaps(crossing));
+    assert!(crossing.overlaps(a));
+    assert!(a.overlaps(inner));
+    assert!(inner.overlaps(a));
+}
+
+#[test]
+fn merge_disjoint_spans_covers_the_gap() {
+    let a = span(pos(1, 1), pos(1, 5));
+    let b = span(pos(3, 2), pos(3, 9));
+
+    let expected = span(pos(1, 1), pos(3, 9));
+    assert_eq!(a.merge(b), expected);
+    assert_eq!(b.merge(a), expected);
+}
+
+#[test]
+fn merge_nested_span_is_outer_span() {
+    let outer = span(pos(1, 1), pos(5, 1));
+    let inner = span(pos(2, 3), pos(2, 7));
+
+    assert_eq!(outer.merge(inner), outer);
+    assert_eq!(inner.merge(outer), outer);
+}
+
+#[test]
+fn render_compact_empty_span_is_a_position() {
+    let s = SourceSpan::empty_at(pos(3, 7));
+    assert_eq!(s.render_compact(), "3:7");
+}
+
+#[test]
+fn render_compact_single_line_span_shares_the_line() {
+    let s = span(pos(2, 5), pos(2, 12));
+    assert_eq!(s.render_compact(), "2:5-12");
+}
+
+#[test]
+fn render_compact_multi_line_span_is_the_full_form() {
+    let s = span(pos(1, 4), pos(3, 2));
+    assert_eq!(s.render_compact(), "1:4 - 3:2");
+    assert_eq!(s.render_compact(), s.to_string());
+}
+
+#[test]
+fn name_decl_span_covers_the_uri() {
+    let parser = RholangParser::new();
+    let procs = parser
+        .parse("new x(`rho:io:stdout`), y in { Nil }")
+        .unwrap();
+    let Proc::New { decls, .. } = procs[0].proc else {
+        panic!("expected new, got {:?}", procs[0].proc);
+    };
+
+    assert_eq!(decls[0].span, span(pos(1, 5), pos(1, 23)));
+    assert_eq!(decls[0].id.pos, pos(1, 5));
+    assert_eq!(decls[1].span, span(pos(1, 25), pos(1, 26)));
+}
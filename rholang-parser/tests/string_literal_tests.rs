@@ -0,0 +1,155 @@
+use rholang_parser::{RholangParser, ast::Proc, parser::errors::ParsingError};
+use rstest::rstest;
+use validated::Validated;
+
+fn parse_string(code: &str) -> String {
+    let parser = RholangParser::new();
+    match parser.parse(code) {
+        Validated::Good(procs) => match procs[0].proc {
+            Proc::StringLiteral(value) => value.to_string(),
+            other => panic!("expected a string literal, got {other:?}"),
+        },
+        Validated::Fail(e) => panic!("parse failed for {code}: {e:?}"),
+    }
+}
+
+#[rstest]
+#[case(r#""plain""#, "plain")]
+#[case(r#""a\nb""#, "a\nb")]
+#[case(r#""a\tb""#, "a\tb")]
+#[case(r#""a\rb""#, "a\rb")]
+#[case(r#""a\0b""#, "a\0b")]
+#[case(r#""\"quoted\"""#, "\"quoted\"")]
+#[case(r#""it\'s""#, "it's")]
+#[case(r#""back\\slash""#, "back\\slash")]
+#[case(r#""\u{1F980}""#, "🦀")]
+#[case(r#""\u{41}\u{42}c""#, "ABc")]
+fn decodes_escape_sequences(#[case] code: &str, #[case] expected: &str) {
+    assert_eq!(parse_string(code), expected);
+}
+
+#[rstest]
+#[case(r#""\1""#, "\u{1}")]
+#[case(r#""a\7b""#, "a\u{7}b")]
+#[case(r#""\012""#, "\n")]
+#[case(r#""\101\102""#, "AB")]
+#[case(r#""\377""#, "\u{ff}")]
+#[case(r#""\400""#, " 0")]
+#[case(r#""\08""#, "\08")]
+#[case(r#""\0123""#, "\n3")]
+fn decodes_digit_escapes_as_octal(#[case] code: &str, #[case] expected: &str) {
+    assert_eq!(parse_string(code), expected);
+}
+
+#[rstest]
+#[case(r#""""""""#, "")]
+#[case(r#""""plain""""#, "plain")]
+#[case(r#""""say "hi" and ""bye"" ok""""#, r#"say "hi" and ""bye"" ok"#)]
+#[case("\"\"\"line 1\n  \"line 2\"\n\"\"\"", "line 1\n  \"line 2\"\n")]
+#[case(r#""""no \n or \u{41} escapes""""#, r"no \n or \u{41} escapes")]
+#[case(r#"""""hi" he said""""#, r#""hi" he said"#)]
+fn keeps_raw_strings_verbatim(#[case] code: &str, #[case] expected: &str) {
+    assert_eq!(parse_string(code), expected);
+}
+
+#[test]
+fn raw_strings_between_other_tokens() {
+    let code = "x!(\"\"\"a \"quoted\"\nword\"\"\", \"\") | y!(\"b\")";
+    let parser = RholangParser::new();
+    let procs = parser.parse(code).unwrap();
+    let rendered = rholang_parser::unparse(&procs[0]);
+    assert_eq!(rendered, r#"x!("a \"quoted\"\nword", "") | y!("b")"#);
+}
+
+#[rstest]
+#[case(r#""\q""#, r"\q")]
+#[case(r#""ok\u{110000}""#, r"\u{110000}")]
+#[case(r#""\u{zz}""#, r"\u{zz}")]
+#[case(r#""\u0041""#, r"\u")]
+#[case(r#""\8\101""#, r"\8")]
+#[case(r#""\9""#, r"\9")]
+fn rejects_invalid_escape_sequences(#[case] code: &str, #[case] escape: &str) {
+    let parser = RholangParser::new();
+    let failures = match parser.parse(code) {
+        Validated::Good(procs) => panic!("expected parse failure, got {procs:?}"),
+        Validated::Fail(errs) => errs,
+    };
+
+    let errors: Vec<_> = failures
+        .iter()
+        .flat_map(|failure| failure.errors.iter())
+        .collect();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(
+        errors[0].error,
+        ParsingError::InvalidEscape(escape.to_string())
+    );
+    assert_eq!(errors[0].byte_range, 0..code.len());
+}
+
+#[test]
+fn multi_byte_text_near_literal_boundaries_never_panics() {
+    let parses = |code: &str| RholangParser::new().parse(code).is_good();
+    for c in ["é", "中", "🦀", "e\u{301}"] {
+        for prefix in ["", "a", "ab", "abc"] {
+            let text = format!("{prefix}{c}");
+            assert_eq!(parse_string(&format!("\"{text}\"")), text);
+            assert_eq!(
+                parse_string(&format!("\"{c}{text}{c}\"")),
+                format!("{c}{text}{c}")
+            );
+
+            let uri = format!("new x(`rho:{text}`) in {{ x!({c:?}) }}");
+            assert!(parses(&uri), "{uri}");
+
+            // malformed input around the same characters must fail cleanly
+            for code in [
+                format!("\"{text}"),
+                format!("{text}\""),
+                format!("x!(\"{text})"),
+                format!("`{text}"),
+                format!("x!({c}{text})"),
+            ] {
+                assert!(!parses(&code), "{code}");
+            }
+        }
+    }
+}
+
+#[rstest]
+#[case("stdout!(\"oops", 9, 1)]
+#[case("new x in {\n  x!(\"a\") | x!(\"b) }", 16, 1)]
+#[case("x!(`rho:io", 4, 1)]
+#[case("Nil\n\"\"\"abc", 1, 3)]
+fn reports_unterminated_literals_at_the_opening_delimiter(
+    #[case] code: &str,
+    #[case] col: usize,
+    #[case] delimiter_len: usize,
+) {
+    let parser = RholangParser::new();
+    let failures = match parser.parse(code) {
+        Validated::Good(procs) => panic!("expected parse failure, got {procs:?}"),
+        Validated::Fail(errs) => errs,
+    };
+
+    let errors: Vec<_> = failures
+        .iter()
+        .flat_map(|failure| failure.errors.iter())
+        .collect();
+    assert_eq!(errors.len(), 1, "{errors:?}");
+    let quote = errors[0].span.start;
+    assert_eq!(quote.col, col);
+    assert_eq!(quote.line, code.lines().count());
+    assert_eq!(errors[0].byte_range.len(), delimiter_len);
+    let ParsingError::UnterminatedString { span } = errors[0].error else {
+        panic!("expected an unterminated string, got {:?}", errors[0].error);
+    };
+    assert_eq!(span.start, quote);
+}
+
+#[test]
+fn quotes_in_comments_do_not_open_literals() {
+    let parser = RholangParser::new();
+    assert!(parser.parse("// it's \"quoted\nNil /* ` */").is_good());
+    assert!(parser.parse("\"http://a\" | `rho:io:stdout`").is_good());
+}
@@ -0,0 +1,60 @@
+use rholang_parser::{RholangParser, first_difference, structurally_eq};
+use validated::Validated;
+
+#[test]
+fn layout_does_not_matter() {
+    let parser = RholangParser::new();
+    let (Validated::Good(left), Validated::Good(right)) = (
+        parser.parse("new x in { x!(1 + 2) | Nil }"),
+        parser.parse("new x in {\n  x!(1+2)\n  | Nil\n}"),
+    ) else {
+        panic!("both should parse");
+    };
+    assert!(structurally_eq(&left[0], &right[0]));
+    assert_eq!(first_difference(&left[0], &right[0]), None);
+}
+
+#[test]
+fn first_difference_is_the_innermost_differing_node() {
+    let parser = RholangParser::new();
+    let (Validated::Good(left), Validated::Good(right)) = (
+        parser.parse("new x in { x!(1, 2) | x!(3) }"),
+        parser.parse("new x in { x!(1, 5) | x!(4) }"),
+    ) else {
+        panic!("both should parse");
+    };
+    let (l, r) = first_difference(&left[0], &right[0]).expect("trees differ");
+    assert_eq!(l.span.render_compact(), "1:18-19");
+    assert_eq!(r.span.render_compact(), "1:18-19");
+
+    // a differing operator is reported at the expression itself
+    let (Validated::Good(left), Validated::Good(right)) =
+        (parser.parse("x!(1 + 2)"), parser.parse("x!(1 - 2)"))
+    else {
+        panic!("both should parse");
+    };
+    let (l, _) = first_difference(&left[0], &right[0]).expect("trees differ");
+    assert_eq!(l.span.render_compact(), "1:4-9");
+}
+
+#[test]
+fn differences_outside_the_children_are_reported_at_the_node() {
+    for (left, right, at) in [
+        ("x!(1) | y!(2)", "x!(1) | z!(2)", "1:9-14"),
+        (
+            "for (@a <- x) { Nil } | Nil",
+            "for (@b <- x) { Nil } | Nil",
+            "1:1-22",
+        ),
+    ] {
+        let parser = RholangParser::new();
+        let (Validated::Good(left), Validated::Good(right)) =
+            (parser.parse(left), parser.parse(right))
+        else {
+            panic!("both should parse");
+        };
+        let (l, r) = first_difference(&left[0], &right[0]).expect("trees differ");
+        assert_eq!(l.span.render_compact(), at);
+        assert_eq!(r.span.render_compact(), at);
+    }
+}
@@ -0,0 +1,28 @@
+use rholang_parser::RholangParser;
+
+#[test]
+fn tree_root_has_one_named_child_per_top_level_process() {
+    let parser = RholangParser::new();
+    let code = "new x in { x!(1) }\nfor (y <- @\"ch\") { Nil }\n1 + 2";
+    let (tree, procs) = parser.parse_with_tree(code);
+
+    let procs = procs.ok().unwrap();
+    let root = tree.root_node();
+    assert_eq!(procs.len(), 3);
+    assert_eq!(root.named_child_count(), procs.len());
+    assert_eq!(parser.parse(code).ok().unwrap(), procs);
+
+    for (i, proc) in procs.iter().enumerate() {
+        let node = root.named_child(i).unwrap();
+        assert_eq!(node.start_position().row + 1, proc.span.start.line);
+    }
+}
+
+#[test]
+fn tree_is_returned_for_invalid_code() {
+    let parser = RholangParser::new();
+    let (tree, procs) = parser.parse_with_tree("new x in {");
+
+    assert!(procs.is_fail());
+    assert!(tree.root_node().has_error());
+}
@@ -0,0 +1,125 @@
+use rholang_parser::{BraceStyle, RholangParser, UnparseConfig, to_sexp, unparse, unparse_with};
+use rstest::rstest;
+use validated::Validated;
+
+fn parse_and_unparse(code: &str) -> String {
+    let parser = RholangParser::new();
+    match parser.parse(code) {
+        Validated::Good(procs) => {
+            assert_eq!(procs.len(), 1, "expected a single process in {code}");
+            unparse(&procs[0])
+        }
+        Validated::Fail(e) => panic!("parse failed for {code}: {e:?}"),
+    }
+}
+
+/// `code` unparsed with `config`, and the S-expression of what that parses back into
+fn unparse_with_config(code: &str, config: &UnparseConfig) -> (String, String) {
+    let parser = RholangParser::new();
+    let procs = parser.parse(code).unwrap();
+    let unparsed = unparse_with(&procs[0], config);
+    let reparsed = parser.parse(&unparsed).unwrap();
+    (unparsed.clone(), to_sexp(&reparsed[0]))
+}
+
+#[rstest]
+#[case("Nil")]
+#[case("x!(1, -2, \"three\") | y!!(4u8, 5i16, 6n, 7r, 8.5f64, 1.25p2)")]
+#[case("a - b - c | a - (b - c) | a / b * c | -(1) | - x | not true or ~_")]
+#[case("a | (b | c)")]
+#[case("new x, stdout(`rho:io:stdout`) in { x!(Set(1, 2 ...rest)) | stdout!([*x, =y, =*z]) }")]
+#[case(
+    "for (@a, b <- x & c <= @{1 + 2} where a > 1; ...@rest <<- y; <- z!?(1) & d <- w?!) { Nil }"
+)]
+#[case("match [x, y] { [a ...r] where a == 1 => { a } -5 => Nil {|1, 2|} => {} }")]
+#[case("contract @\"c\"(ret, ...@tail) = { ret!?(1). | ret!?(2); Nil }")]
+#[case("let x <- 1; @y <- 2 in { x } | let a <- 1 & b <- 2 in { Nil }")]
+#[case("bundle+ { x } | bundle- { x } | bundle0 { x } | bundle { x }")]
+#[case("if (x matches Int /\\ ~String \\/ Bool) { x.toString() } else { (1,).nth(0) }")]
+#[case("{\"a\": 1, (): (1, 2) ...m} ++ \"%%\" %% {} | *@{ x!(1) }")]
+#[case("{...m} | [...l] | Set(...s) | {| ...p |}")]
+#[case(r#"x!("tab\there", "quote \" and \\", "\u{1F980}\n")"#)]
+fn unparse_reparses_to_the_same_tree(#[case] code: &str) {
+    let unparsed = parse_and_unparse(code);
+    assert_eq!(
+        parse_and_unparse(&unparsed),
+        unparsed,
+        "unparse is not stable for {code}"
+    );
+}
+
+#[test]
+fn unparse_parenthesizes_operands() {
+    assert_eq!(parse_and_unparse("a - b - c"), "(a - b) - c");
+    assert_eq!(parse_and_unparse("a - (b - c)"), "a - (b - c)");
+    assert_eq!(parse_and_unparse("-(1)"), "-(1)");
+}
+
+#[test]
+fn unparse_indents_blocks() {
+    assert_eq!(
+        parse_and_unparse("new x in { for (y <- x) { Nil } }"),
+        "new x in {\n    for (y <- x) {\n        Nil\n    }\n}"
+    );
+}
+
+#[test]
+fn unparse_indent_width_only_changes_leading_whitespace() {
+    let code = "new x in { for (y <- x) { match y { 1 => Nil _ => contract z(w) = { Nil } } } }";
+    let (two, two_tree) = unparse_with_config(
+        code,
+        &UnparseConfig {
+            indent: 2,
+            ..UnparseConfig::default()
+        },
+    );
+    let (four, four_tree) = unparse_with_config(
+        code,
+        &UnparseConfig {
+            indent: 4,
+            ..UnparseConfig::default()
+        },
+    );
+
+    assert_ne!(two, four);
+    assert!(
+        two.lines()
+            .map(str::trim_start)
+            .eq(four.lines().map(str::trim_start))
+    );
+    assert_eq!(two_tree, four_tree);
+    assert!(two.contains("\n  for (y <- x) {\n    match y {"), "{two}");
+}
+
+#[test]
+fn unparse_braces_on_their_own_line() {
+    let code = "new x in { for (y <- x) { Nil } }";
+    let config = UnparseConfig {
+        brace_style: BraceStyle::NextLine,
+        ..UnparseConfig::default()
+    };
+    let (unparsed, tree) = unparse_with_config(code, &config);
+
+    assert_eq!(
+        unparsed,
+        "new x in\n{\n    for (y <- x)\n    {\n        Nil\n    }\n}"
+    );
+    assert_eq!(tree, unparse_with_config(code, &UnparseConfig::default()).1);
+}
+
+#[test]
+fn unparse_wraps_long_receipts() {
+    let code = "for (first <- channel_one & second <- channel_two; third <- channel_three) { Nil }";
+    let config = UnparseConfig {
+        max_width: 40,
+        ..UnparseConfig::default()
+    };
+    let (unparsed, tree) = unparse_with_config(code, &config);
+
+    assert_eq!(
+        unparsed,
+        "for (\n    first <- channel_one &\n    second <- channel_two;\n    third <- channel_three\n) {\n    Nil\n}"
+    );
+    assert_eq!(tree, unparse_with_config(code, &UnparseConfig::default()).1);
+    assert!(!parse_and_unparse(code).contains("for (\n"));
+}
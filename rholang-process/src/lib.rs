@@ -15,6 +15,7 @@
 
 mod parameter;
 mod process;
+mod serialize;
 
 pub use parameter::Parameter;
 pub use process::{Process, ProcessEvent, ProcessEventHandler};
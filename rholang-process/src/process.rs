@@ -1,7 +1,7 @@
 use crate::parameter::Parameter;
 use rholang_bytecode::core::instructions::Instruction as CoreInst;
 use rholang_rspace::{ExecError, ProcessHolder, ProcessState, Value};
-use rholang_vm::{StepResult, VM};
+use rholang_vm::{ChannelEvent, StepResult, VM};
 use std::any::Any;
 use std::fmt;
 use std::sync::Arc;
@@ -19,12 +19,33 @@ pub struct Process {
     pub state: ProcessState,
     /// Named parameter bindings that must be solved before execution
     pub parameters: Vec<Parameter>,
+    /// The receive this process is waiting at: its program counter and channel
+    blocked: Option<(usize, String)>,
 }
 
 #[derive(Clone, Debug, PartialEq)]
 pub enum ProcessEvent {
     Value(String),
     Error(String),
+    /// The process sent `value` on `channel`
+    Send {
+        channel: String,
+        value: Value,
+    },
+    /// The process received (or peeked at) `value` on `channel`
+    Receive {
+        channel: String,
+        value: Value,
+    },
+}
+
+impl From<ChannelEvent> for ProcessEvent {
+    fn from(event: ChannelEvent) -> Self {
+        match event {
+            ChannelEvent::Send { channel, value } => ProcessEvent::Send { channel, value },
+            ChannelEvent::Receive { channel, value } => ProcessEvent::Receive { channel, value },
+        }
+    }
 }
 
 pub type ProcessEventHandler = Arc<dyn Fn(ProcessEvent) + Send + Sync>;
@@ -40,6 +61,7 @@ impl Process {
             vm: VM::new(),
             state: ProcessState::Ready,
             parameters: Vec::new(),
+            blocked: None,
         }
     }
 
@@ -54,6 +76,7 @@ impl Process {
             vm,
             state: ProcessState::Ready,
             parameters: Vec::new(),
+            blocked: None,
         }
     }
 
@@ -89,6 +112,12 @@ impl Process {
         self.parameters.iter().all(|p| p.is_solved(rspace.as_ref()))
     }
 
+    /// The channel this process is waiting on after `execute` returned
+    /// [`ExecError::Blocked`], if any.
+    pub fn blocked_on(&self) -> Option<&str> {
+        self.blocked.as_ref().map(|(_, channel)| channel.as_str())
+    }
+
     /// Box this process into a ProcessHolder trait object
     pub fn boxed(self) -> Box<dyn ProcessHolder> {
         Box::new(self)
@@ -119,6 +148,43 @@ impl Process {
         }
     }
 
+    /// Execute the single instruction at `pc` and return the next program
+    /// counter, or `None` once the process has halted (HALT or end of code).
+    /// A receive on an empty channel fails with [`ExecError::Blocked`] and
+    /// leaves the stack untouched, so stepping `pc` again retries it.
+    ///
+    /// This is the building block of `execute`; it does not check the process
+    /// state or parameters, so callers driving a process instruction by
+    /// instruction (e.g. a debugger) are responsible for resetting the stack
+    /// before the first step.
+    pub fn step_at(&mut self, pc: usize) -> Result<Option<usize>, ExecError> {
+        let Some(&inst) = self.code.get(pc) else {
+            return Ok(None);
+        };
+        match self
+            .vm
+            .execute(&mut self.locals, &self.names, &self.constants, inst)?
+        {
+            StepResult::Next => Ok(Some(pc + 1)),
+            StepResult::Stop => Ok(None),
+            StepResult::Jump(target) => Ok(Some(target)),
+            StepResult::Block(channel) => Err(ExecError::Blocked { channel }),
+            StepResult::Eval(target) => {
+                // Handle EVAL: execute Par values or pass through others.
+                // Only a receive of this process itself can be resumed
+                let eval_result = Self::evaluate_value(target).map_err(|err| match err {
+                    ExecError::Blocked { channel } => ExecError::OpcodeParamError {
+                        opcode: "EVAL",
+                        message: format!("evaluated process blocked on channel {}", channel),
+                    },
+                    other => other,
+                })?;
+                self.vm.stack.push(eval_result);
+                Ok(Some(pc + 1))
+            }
+        }
+    }
+
     pub fn execute(&mut self) -> Result<Value, ExecError> {
         self.execute_with_event(None)
     }
@@ -136,7 +202,9 @@ impl Process {
                     message: format!("cannot re-execute process in error state: {}", msg),
                 })
             }
-            ProcessState::Wait => {
+            // A blocked receive picks up where it stopped
+            ProcessState::Wait | ProcessState::WaitOn(_) if self.blocked.is_some() => {}
+            ProcessState::Wait | ProcessState::WaitOn(_) => {
                 return Err(ExecError::OpcodeParamError {
                     opcode: "EXECUTE",
                     message: "cannot execute process in wait state".to_string(),
@@ -153,39 +221,37 @@ impl Process {
             });
         }
 
-        self.vm.reset_stack();
-
-        let mut pc = 0usize;
-        let code = self.code.clone();
-        let result = loop {
-            if pc >= code.len() {
-                break Ok(self.vm.stack.last().cloned().unwrap_or(Value::Nil));
+        let mut pc = match self.blocked.take() {
+            Some((pc, _)) => pc,
+            None => {
+                self.vm.reset_stack();
+                0
             }
-
-            let inst = code[pc];
-            match self.vm.execute(&mut self.locals, &self.names, &self.constants, inst) {
-                Ok(StepResult::Next) => {
-                    pc += 1;
-                }
-                Ok(StepResult::Stop) => {
-                    break Ok(self.vm.stack.last().cloned().unwrap_or(Value::Nil));
-                }
-                Ok(StepResult::Jump(target)) => {
-                    pc = target;
-                }
-                Ok(StepResult::Eval(target)) => {
-                    // Handle EVAL: execute Par values or pass through others
-                    let eval_result = Self::evaluate_value(target)?;
-                    self.vm.stack.push(eval_result);
-                    pc += 1;
-                }
-                Err(err) => {
-                    break Err(err);
+        };
+        // Sends and receives are only worth recording for a handler
+        self.vm.trace_channels(handler.is_some());
+        let result = loop {
+            let step = self.step_at(pc);
+            if let (Ok(_), Some(handler)) = (&step, handler) {
+                if let Some(event) = self.vm.take_channel_event() {
+                    handler(event.into());
                 }
             }
+            match step {
+                Ok(Some(next)) => pc = next,
+                Ok(None) => break Ok(self.vm.stack.last().cloned().unwrap_or(Value::Nil)),
+                Err(err) => break Err(err),
+            }
         };
+        self.vm.trace_channels(false);
 
         match result {
+            Err(ExecError::Blocked { channel }) => {
+                // Not terminal: execute again once a message was sent on the channel
+                self.state = ProcessState::WaitOn(vec![channel.clone()]);
+                self.blocked = Some((pc, channel.clone()));
+                Err(ExecError::Blocked { channel })
+            }
             Ok(val) => {
                 self.state = ProcessState::Value(val.clone());
                 if let Some(handler) = handler {
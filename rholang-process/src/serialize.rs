@@ -0,0 +1,304 @@
+// Binary serialization of compiled processes
+// Lets a compiled program be cached as bytes and executed later without
+// re-parsing. Only what the compiler produces is stored: the instruction
+// stream, the string and constant pools, and the parameters. A loaded process
+// gets a fresh VM, empty locals and the Ready state.
+//
+// Layout (all integers little-endian):
+//   magic "RHOP", format version (u8)
+//   source_ref (str)
+//   code (u32 count, then 4 bytes per instruction)
+//   names, constants (u32 count, then tagged values)
+//   parameters (u32 count, then str per parameter name)
+// where a str is a u32 byte length followed by UTF-8 bytes.
+
+use crate::parameter::Parameter;
+use crate::process::Process;
+use anyhow::{anyhow, bail, Result};
+use num_bigint::BigInt;
+use num_rational::BigRational;
+use rholang_bytecode::core::instructions::Instruction as CoreInst;
+use rholang_rspace::Value;
+
+const MAGIC: &[u8; 4] = b"RHOP";
+const FORMAT_VERSION: u8 = 1;
+
+const TAG_NIL: u8 = 0;
+const TAG_INT: u8 = 1;
+const TAG_FLOAT: u8 = 2;
+const TAG_BIG_INT: u8 = 3;
+const TAG_BIG_RAT: u8 = 4;
+const TAG_FIXED_POINT: u8 = 5;
+const TAG_BOOL: u8 = 6;
+const TAG_STR: u8 = 7;
+const TAG_NAME: u8 = 8;
+const TAG_LIST: u8 = 9;
+const TAG_TUPLE: u8 = 10;
+const TAG_MAP: u8 = 11;
+const TAG_PATH_MAP: u8 = 12;
+const TAG_SET: u8 = 13;
+
+impl Process {
+    /// Serialize the instruction stream, string and constant pools, and
+    /// parameters of this process.
+    ///
+    /// Execution state (VM, locals, process state) is not included, so a
+    /// process restored with [`Process::from_bytes`] starts out Ready.
+    ///
+    /// Fails if a pool contains a `Value::Par`, which the compiler never emits and
+    /// the format has no representation for.
+    pub fn to_bytes(&self) -> Result<Vec<u8>> {
+        let mut out = Vec::with_capacity(16 + self.code.len() * 4);
+        out.extend_from_slice(MAGIC);
+        out.push(FORMAT_VERSION);
+        write_str(&mut out, &self.source_ref);
+
+        write_len(&mut out, self.code.len());
+        for inst in &self.code {
+            out.extend_from_slice(&inst.to_bytes());
+        }
+
+        write_values(&mut out, &self.names)?;
+        write_values(&mut out, &self.constants)?;
+
+        write_len(&mut out, self.parameters.len());
+        for param in &self.parameters {
+            write_str(&mut out, param.name());
+        }
+        Ok(out)
+    }
+
+    /// Restore a process serialized with [`Process::to_bytes`].
+    ///
+    /// Fails on a bad header, truncated or trailing data, unknown value tags,
+    /// and instructions with an invalid opcode.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Process> {
+        let mut reader = Reader { bytes, pos: 0 };
+        if reader.take(MAGIC.len())? != MAGIC {
+            bail!("not a serialized process (bad magic)");
+        }
+        let version = reader.u8()?;
+        if version != FORMAT_VERSION {
+            bail!("unsupported process format version {}", version);
+        }
+        let source_ref = reader.str()?;
+
+        let code_len = reader.len()?;
+        let mut code = Vec::with_capacity(code_len.min(reader.remaining() / 4));
+        for offset in 0..code_len {
+            let raw = reader.take(4)?;
+            let inst = CoreInst::from_bytes([raw[0], raw[1], raw[2], raw[3]]);
+            inst.opcode()
+                .map_err(|e| anyhow!("invalid instruction at {}: {}", offset, e))?;
+            code.push(inst);
+        }
+
+        let names = reader.values()?;
+        let constants = reader.values()?;
+
+        let param_len = reader.len()?;
+        let mut parameters = Vec::with_capacity(param_len.min(reader.remaining()));
+        for _ in 0..param_len {
+            parameters.push(Parameter::new(reader.str()?));
+        }
+
+        if reader.remaining() != 0 {
+            bail!(
+                "{} trailing byte(s) after serialized process",
+                reader.remaining()
+            );
+        }
+
+        let mut process = Process::new(code, source_ref).with_parameters(parameters);
+        process.names = names;
+        process.constants = constants;
+        Ok(process)
+    }
+}
+
+fn write_len(out: &mut Vec<u8>, len: usize) {
+    let len = u32::try_from(len).expect("length exceeds u32::MAX");
+    out.extend_from_slice(&len.to_le_bytes());
+}
+
+fn write_bytes(out: &mut Vec<u8>, bytes: &[u8]) {
+    write_len(out, bytes.len());
+    out.extend_from_slice(bytes);
+}
+
+fn write_str(out: &mut Vec<u8>, s: &str) {
+    write_bytes(out, s.as_bytes());
+}
+
+fn write_values(out: &mut Vec<u8>, values: &[Value]) -> Result<()> {
+    write_len(out, values.len());
+    for value in values {
+        write_value(out, value)?;
+    }
+    Ok(())
+}
+
+fn write_value(out: &mut Vec<u8>, value: &Value) -> Result<()> {
+    match value {
+        Value::Nil => out.push(TAG_NIL),
+        Value::Int(n) => {
+            out.push(TAG_INT);
+            out.extend_from_slice(&n.to_le_bytes());
+        }
+        Value::Float(f) => {
+            out.push(TAG_FLOAT);
+            out.extend_from_slice(&f.to_bits().to_le_bytes());
+        }
+        Value::BigInt(n) => {
+            out.push(TAG_BIG_INT);
+            write_bytes(out, &n.to_signed_bytes_le());
+        }
+        Value::BigRat(r) => {
+            out.push(TAG_BIG_RAT);
+            write_bytes(out, &r.numer().to_signed_bytes_le());
+            write_bytes(out, &r.denom().to_signed_bytes_le());
+        }
+        Value::FixedPoint { unscaled, scale } => {
+            out.push(TAG_FIXED_POINT);
+            write_bytes(out, &unscaled.to_signed_bytes_le());
+            out.extend_from_slice(&scale.to_le_bytes());
+        }
+        Value::Bool(b) => {
+            out.push(TAG_BOOL);
+            out.push(u8::from(*b));
+        }
+        Value::Str(s) => {
+            out.push(TAG_STR);
+            write_str(out, s);
+        }
+        Value::Name(s) => {
+            out.push(TAG_NAME);
+            write_str(out, s);
+        }
+        Value::List(items) => {
+            out.push(TAG_LIST);
+            write_values(out, items)?;
+        }
+        Value::Tuple(items) => {
+            out.push(TAG_TUPLE);
+            write_values(out, items)?;
+        }
+        Value::Map(entries) => {
+            out.push(TAG_MAP);
+            write_len(out, entries.len());
+            for (k, v) in entries {
+                write_value(out, k)?;
+                write_value(out, v)?;
+            }
+        }
+        Value::Set(items) => {
+            out.push(TAG_SET);
+            write_values(out, items)?;
+        }
+        Value::PathMap(paths) => {
+            out.push(TAG_PATH_MAP);
+            write_values(out, paths)?;
+        }
+        Value::Par(_) => bail!("unsupported value in a process pool: Par"),
+    }
+    Ok(())
+}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn remaining(&self) -> usize {
+        self.bytes.len() - self.pos
+    }
+
+    fn take(&mut self, n: usize) -> Result<&'a [u8]> {
+        if self.remaining() < n {
+            bail!("serialized process truncated at byte {}", self.pos);
+        }
+        let slice = &self.bytes[self.pos..self.pos + n];
+        self.pos += n;
+        Ok(slice)
+    }
+
+    fn array<const N: usize>(&mut self) -> Result<[u8; N]> {
+        let mut buf = [0u8; N];
+        buf.copy_from_slice(self.take(N)?);
+        Ok(buf)
+    }
+
+    fn u8(&mut self) -> Result<u8> {
+        Ok(self.take(1)?[0])
+    }
+
+    fn len(&mut self) -> Result<usize> {
+        Ok(u32::from_le_bytes(self.array()?) as usize)
+    }
+
+    fn bytes(&mut self) -> Result<&'a [u8]> {
+        let len = self.len()?;
+        self.take(len)
+    }
+
+    fn str(&mut self) -> Result<String> {
+        let bytes = self.bytes()?;
+        String::from_utf8(bytes.to_vec()).map_err(|e| anyhow!("invalid UTF-8 string: {}", e))
+    }
+
+    fn big_int(&mut self) -> Result<BigInt> {
+        Ok(BigInt::from_signed_bytes_le(self.bytes()?))
+    }
+
+    fn values(&mut self) -> Result<Vec<Value>> {
+        let len = self.len()?;
+        // every value takes at least one byte, so don't trust `len` beyond that
+        let mut values = Vec::with_capacity(len.min(self.remaining()));
+        for _ in 0..len {
+            values.push(self.value()?);
+        }
+        Ok(values)
+    }
+
+    fn value(&mut self) -> Result<Value> {
+        let value = match self.u8()? {
+            TAG_NIL => Value::Nil,
+            TAG_INT => Value::Int(i64::from_le_bytes(self.array()?)),
+            TAG_FLOAT => Value::Float(f64::from_bits(u64::from_le_bytes(self.array()?))),
+            TAG_BIG_INT => Value::BigInt(self.big_int()?),
+            TAG_BIG_RAT => {
+                let numer = self.big_int()?;
+                let denom = self.big_int()?;
+                if denom == BigInt::from(0) {
+                    bail!("BigRat with zero denominator");
+                }
+                Value::BigRat(BigRational::new(numer, denom))
+            }
+            TAG_FIXED_POINT => {
+                let unscaled = self.big_int()?;
+                let scale = u32::from_le_bytes(self.array()?);
+                Value::FixedPoint { unscaled, scale }
+            }
+            TAG_BOOL => Value::Bool(self.u8()? != 0),
+            TAG_STR => Value::Str(self.str()?),
+            TAG_NAME => Value::Name(self.str()?),
+            TAG_LIST => Value::List(self.values()?),
+            TAG_TUPLE => Value::Tuple(self.values()?),
+            TAG_MAP => {
+                let len = self.len()?;
+                let mut entries = Vec::with_capacity(len.min(self.remaining() / 2));
+                for _ in 0..len {
+                    let key = self.value()?;
+                    let value = self.value()?;
+                    entries.push((key, value));
+                }
+                Value::Map(entries)
+            }
+            TAG_PATH_MAP => Value::PathMap(self.values()?),
+            TAG_SET => Value::Set(self.values()?),
+            tag => bail!("unknown value tag {} at byte {}", tag, self.pos - 1),
+        };
+        Ok(value)
+    }
+}
@@ -0,0 +1,96 @@
+// Tests for Process serialization
+// A process serialized with to_bytes and restored with from_bytes keeps its
+// instruction stream, pools and parameters, and starts out Ready.
+
+use num_bigint::BigInt;
+use num_rational::BigRational;
+use rholang_bytecode::core::instructions::Instruction;
+use rholang_bytecode::core::Opcode;
+use rholang_process::{Parameter, Process, ProcessState, Value};
+
+fn sample_process() -> Process {
+    let code = vec![
+        Instruction::unary(Opcode::PUSH_CONST, 0),
+        Instruction::unary(Opcode::PUSH_CONST, 1),
+        Instruction::nullary(Opcode::ADD),
+        Instruction::nullary(Opcode::HALT),
+    ];
+    let mut process = Process::new(code, "proc_0").with_parameters(vec![Parameter::new("input")]);
+    process.names = vec![Value::Str("hello".to_string()), Value::Str(String::new())];
+    process.constants = vec![
+        Value::Int(40),
+        Value::Int(2),
+        Value::Float(1.5),
+        Value::BigInt(BigInt::from(-12345678901234567890i128)),
+        Value::BigRat(BigRational::new(BigInt::from(-3), BigInt::from(4))),
+        Value::FixedPoint {
+            unscaled: BigInt::from(125),
+            scale: 2,
+        },
+        Value::List(vec![Value::Bool(true), Value::Nil]),
+        Value::Tuple(vec![Value::Name("chan".to_string())]),
+        Value::Map(vec![(Value::Str("k".to_string()), Value::Int(1))]),
+        Value::PathMap(vec![Value::List(vec![Value::Int(1), Value::Int(2)])]),
+    ];
+    process
+}
+
+#[test]
+fn test_round_trip_preserves_program() {
+    let process = sample_process();
+    let loaded = Process::from_bytes(&process.to_bytes().unwrap()).unwrap();
+
+    assert_eq!(loaded.code, process.code);
+    assert_eq!(loaded.source_ref, process.source_ref);
+    assert_eq!(loaded.names, process.names);
+    assert_eq!(loaded.constants, process.constants);
+    assert_eq!(loaded.parameters(), process.parameters());
+}
+
+#[test]
+fn test_loaded_process_is_ready() {
+    let mut process = sample_process().with_parameters(Vec::new());
+    process.execute().unwrap();
+
+    let mut loaded = Process::from_bytes(&process.to_bytes().unwrap()).unwrap();
+    assert_eq!(loaded.state, ProcessState::Ready);
+    assert_eq!(loaded.execute().unwrap(), Value::Int(42));
+}
+
+#[test]
+fn test_rejects_bad_magic() {
+    let mut bytes = sample_process().to_bytes().unwrap();
+    bytes[0] = b'X';
+    assert!(Process::from_bytes(&bytes).is_err());
+}
+
+#[test]
+fn test_rejects_truncated_and_trailing_data() {
+    let bytes = sample_process().to_bytes().unwrap();
+    for len in [0, 5, bytes.len() / 2, bytes.len() - 1] {
+        assert!(Process::from_bytes(&bytes[..len]).is_err(), "len {len}");
+    }
+
+    let mut trailing = bytes;
+    trailing.push(0);
+    assert!(Process::from_bytes(&trailing).is_err());
+}
+
+#[test]
+fn test_rejects_invalid_opcode() {
+    let process = Process::new(vec![Instruction::nullary(Opcode::HALT)], "p");
+    let mut bytes = process.to_bytes().unwrap();
+    // header (5) + source_ref (4 + 1) + code length (4) puts the opcode at 14
+    bytes[14] = 0xFF;
+    assert!(Process::from_bytes(&bytes).is_err());
+}
+
+#[test]
+fn test_refuses_par_values() {
+    let mut process = sample_process();
+    process
+        .constants
+        .push(Value::List(vec![Value::Par(Vec::new())]));
+    let err = process.to_bytes().unwrap_err();
+    assert!(err.to_string().contains("Par"), "{err}");
+}
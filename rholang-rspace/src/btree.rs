@@ -0,0 +1,272 @@
+//! BTreeMap-based RSpace implementation with ordered iteration.
+
+use crate::entry::Entry;
+use crate::rspace::RSpace;
+use crate::stats::{ChannelStats, StatsRecorder};
+use crate::value::{ProcessState, Value};
+use anyhow::{bail, Result};
+use std::collections::{BTreeMap, HashMap};
+
+/// RSpace backed by a `BTreeMap`, keeping entries sorted by name.
+///
+/// Lookups are O(log n) instead of the O(1) of `InMemoryRSpace`, in exchange for
+/// [`keys`](BTreeRSpace::keys) and [`iter_entries`](BTreeRSpace::iter_entries)
+/// yielding names in lexicographic order. This makes dumps reproducible across
+/// runs, which is handy for snapshot tests.
+///
+/// # Feature Flag
+///
+/// Available with feature `btree-impl`.
+///
+/// # Example
+///
+/// ```
+/// use rholang_rspace::{BTreeRSpace, RSpace, Value};
+///
+/// let mut rspace = BTreeRSpace::new();
+/// rspace.tell("b", Value::Int(2)).unwrap();
+/// rspace.tell("a", Value::Int(1)).unwrap();
+/// assert_eq!(rspace.keys().collect::<Vec<_>>(), vec!["a", "b"]);
+/// ```
+#[derive(Default)]
+pub struct BTreeRSpace {
+    store: BTreeMap<String, Entry>,
+    stats: StatsRecorder,
+}
+
+impl BTreeRSpace {
+    /// Create a new empty BTreeRSpace.
+    pub fn new() -> Self {
+        Self {
+            store: BTreeMap::new(),
+            stats: StatsRecorder::default(),
+        }
+    }
+
+    /// Names of all entries, in lexicographic order.
+    pub fn keys(&self) -> impl Iterator<Item = &str> {
+        self.store.keys().map(String::as_str)
+    }
+
+    /// All entries with their names, in lexicographic order of the names.
+    pub fn iter_entries(&self) -> impl Iterator<Item = (&str, &Entry)> {
+        self.store
+            .iter()
+            .map(|(name, entry)| (name.as_str(), entry))
+    }
+}
+
+impl RSpace for BTreeRSpace {
+    fn get_entry(&self, name: &str) -> Option<Entry> {
+        self.store.get(name).cloned()
+    }
+
+    fn tell(&mut self, name: &str, data: Value) -> Result<()> {
+        let depth = match self.store.get_mut(name) {
+            Some(Entry::Channel(queue)) => {
+                queue.push(data);
+                queue.len()
+            }
+            Some(_) => {
+                bail!("entry '{}' exists but is not a channel", name)
+            }
+            None => {
+                self.store
+                    .insert(name.to_string(), Entry::Channel(vec![data]));
+                1
+            }
+        };
+        self.stats.record_tell(name, depth);
+        Ok(())
+    }
+
+    fn ask(&mut self, name: &str) -> Result<Option<Value>> {
+        let value = match self.store.get_mut(name) {
+            Some(Entry::Channel(queue)) => {
+                if queue.is_empty() {
+                    None
+                } else {
+                    Some(queue.remove(0))
+                }
+            }
+            Some(_) => {
+                bail!("entry '{}' exists but is not a channel", name)
+            }
+            None => None,
+        };
+        self.stats.record_ask(name);
+        Ok(value)
+    }
+
+    fn peek(&self, name: &str) -> Result<Option<Value>> {
+        let value = match self.store.get(name) {
+            Some(Entry::Channel(queue)) => queue.first().cloned(),
+            Some(_) => {
+                bail!("entry '{}' exists but is not a channel", name)
+            }
+            None => None,
+        };
+        self.stats.record_peek(name);
+        Ok(value)
+    }
+
+    fn peek_all(&self, name: &str) -> Result<Vec<Value>> {
+        let values = match self.store.get(name) {
+            Some(Entry::Channel(queue)) => queue.clone(),
+            Some(_) => {
+                bail!("entry '{}' exists but is not a channel", name)
+            }
+            None => Vec::new(),
+        };
+        self.stats.record_peek(name);
+        Ok(values)
+    }
+
+    fn register_process(&mut self, name: &str, state: ProcessState) -> Result<()> {
+        if self.store.contains_key(name) {
+            bail!("entry '{}' already exists", name)
+        }
+        self.store
+            .insert(name.to_string(), Entry::Process { state });
+        Ok(())
+    }
+
+    fn update_process(&mut self, name: &str, state: ProcessState) -> Result<()> {
+        match self.store.get_mut(name) {
+            Some(Entry::Process { state: s }) => {
+                *s = state;
+                Ok(())
+            }
+            Some(_) => {
+                bail!("entry '{}' exists but is not a process", name)
+            }
+            None => {
+                bail!("no process registered with name '{}'", name)
+            }
+        }
+    }
+
+    fn get_process_state(&self, name: &str) -> Option<ProcessState> {
+        match self.store.get(name) {
+            Some(Entry::Process { state }) => Some(state.clone()),
+            _ => None,
+        }
+    }
+
+    fn set_value(&mut self, name: &str, value: Value) -> Result<()> {
+        if self.store.contains_key(name) {
+            bail!("entry '{}' already exists", name)
+        }
+        self.store.insert(name.to_string(), Entry::Value(value));
+        Ok(())
+    }
+
+    fn get_value(&self, name: &str) -> Option<Value> {
+        match self.store.get(name) {
+            Some(Entry::Value(val)) => Some(val.clone()),
+            _ => None,
+        }
+    }
+
+    fn reset(&mut self) {
+        self.store.clear();
+    }
+
+    fn entries(&self) -> Vec<(String, Entry)> {
+        self.iter_entries()
+            .map(|(name, entry)| (name.to_string(), entry.clone()))
+            .collect()
+    }
+
+    fn remove_entry(&mut self, name: &str) -> Option<Entry> {
+        self.store.remove(name)
+    }
+
+    fn enable_stats(&mut self) {
+        self.stats.enable();
+    }
+
+    fn stats(&self) -> HashMap<String, ChannelStats> {
+        self.stats.snapshot()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_keys_sorted() -> Result<()> {
+        let mut rspace = BTreeRSpace::new();
+
+        rspace.tell("c", Value::Int(3))?;
+        rspace.tell("a", Value::Int(1))?;
+        rspace.tell("b", Value::Int(2))?;
+
+        assert_eq!(rspace.keys().collect::<Vec<_>>(), vec!["a", "b", "c"]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_iter_entries_sorted() -> Result<()> {
+        let mut rspace = BTreeRSpace::new();
+
+        rspace.set_value("config/timeout", Value::Int(30))?;
+        rspace.register_process("@0:worker", ProcessState::Ready)?;
+        rspace.tell("inbox", Value::Int(1))?;
+
+        let entries: Vec<_> = rspace.iter_entries().collect();
+        assert_eq!(
+            entries,
+            vec![
+                (
+                    "@0:worker",
+                    &Entry::Process {
+                        state: ProcessState::Ready
+                    }
+                ),
+                ("config/timeout", &Entry::Value(Value::Int(30))),
+                ("inbox", &Entry::Channel(vec![Value::Int(1)])),
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_channel_peek_all() -> Result<()> {
+        let mut rspace = BTreeRSpace::new();
+
+        rspace.tell("queue", Value::Int(1))?;
+        rspace.tell("queue", Value::Int(2))?;
+        rspace.tell("queue", Value::Int(3))?;
+
+        assert_eq!(
+            rspace.peek_all("queue")?,
+            vec![Value::Int(1), Value::Int(2), Value::Int(3)]
+        );
+        assert!(matches!(
+            rspace.get_entry("queue"),
+            Some(Entry::Channel(queue)) if queue.len() == 3
+        ));
+        assert_eq!(rspace.peek_all("missing")?, Vec::new());
+
+        rspace.set_value("config", Value::Nil)?;
+        assert!(rspace.peek_all("config").is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_reset() -> Result<()> {
+        let mut rspace = BTreeRSpace::new();
+
+        rspace.tell("channel", Value::Int(1))?;
+        rspace.reset();
+
+        assert_eq!(rspace.keys().count(), 0);
+
+        Ok(())
+    }
+}
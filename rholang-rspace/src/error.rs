@@ -4,13 +4,41 @@ use std::error::Error;
 use std::fmt;
 
 /// Execution error that can occur during process execution.
-#[derive(Debug)]
+///
+/// The dedicated variants cover the failures embedders commonly want to tell apart;
+/// `OpcodeParamError` remains the catch-all for malformed bytecode and other
+/// opcode-specific problems.
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ExecError {
     /// Error related to opcode parameter validation or execution.
     OpcodeParamError {
         opcode: &'static str,
         message: String,
     },
+    /// An operand had a different type than the opcode accepts. For binary operators
+    /// `expected` is the type of the left operand.
+    TypeMismatch {
+        op: &'static str,
+        expected: String,
+        got: String,
+    },
+    /// Integer, big-number or fixed-point division (or modulus) by zero.
+    DivisionByZero { op: &'static str },
+    /// A method that the VM does not provide was invoked.
+    UnknownMethod(String),
+    /// An opcode needed more operands than the stack holds.
+    ArityMismatch {
+        op: &'static str,
+        expected: usize,
+        got: usize,
+    },
+    /// A channel referenced by the bytecode is not bound in the process' name table.
+    UnboundChannel(String),
+    /// An opcode popped from an empty stack.
+    StackUnderflow { op: &'static str },
+    /// A receive found no message on `channel`. The process is left waiting and can be
+    /// resumed once something is sent there.
+    Blocked { channel: String },
 }
 
 impl fmt::Display for ExecError {
@@ -19,6 +47,19 @@ impl fmt::Display for ExecError {
             ExecError::OpcodeParamError { opcode, message } => {
                 write!(f, "{} parameter error: {}", opcode, message)
             }
+            ExecError::TypeMismatch { op, expected, got } => {
+                write!(f, "{} type mismatch: expects {}, got {}", op, expected, got)
+            }
+            ExecError::DivisionByZero { op } => write!(f, "{}: division by zero", op),
+            ExecError::UnknownMethod(method) => write!(f, "unknown method '{}'", method),
+            ExecError::ArityMismatch { op, expected, got } => write!(
+                f,
+                "{} arity mismatch: expects {} operands, got {}",
+                op, expected, got
+            ),
+            ExecError::UnboundChannel(channel) => write!(f, "unbound channel {}", channel),
+            ExecError::StackUnderflow { op } => write!(f, "{}: stack underflow", op),
+            ExecError::Blocked { channel } => write!(f, "blocked waiting on channel {}", channel),
         }
     }
 }
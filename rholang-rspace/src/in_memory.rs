@@ -6,6 +6,7 @@
 
 use crate::entry::Entry;
 use crate::rspace::RSpace;
+use crate::stats::{ChannelStats, StatsRecorder};
 use crate::value::{ProcessState, Value};
 use anyhow::{bail, Result};
 use std::collections::HashMap;
@@ -34,6 +35,7 @@ use std::collections::HashMap;
 #[derive(Default)]
 pub struct InMemoryRSpace {
     store: HashMap<String, Entry>,
+    stats: StatsRecorder,
 }
 
 impl InMemoryRSpace {
@@ -41,6 +43,7 @@ impl InMemoryRSpace {
     pub fn new() -> Self {
         Self {
             store: HashMap::new(),
+            stats: StatsRecorder::default(),
         }
     }
 }
@@ -55,10 +58,10 @@ impl RSpace for InMemoryRSpace {
     // === Channel operations ===
 
     fn tell(&mut self, name: &str, data: Value) -> Result<()> {
-        match self.store.get_mut(name) {
+        let depth = match self.store.get_mut(name) {
             Some(Entry::Channel(queue)) => {
                 queue.push(data);
-                Ok(())
+                queue.len()
             }
             Some(_) => {
                 bail!("entry '{}' exists but is not a channel", name)
@@ -66,35 +69,53 @@ impl RSpace for InMemoryRSpace {
             None => {
                 self.store
                     .insert(name.to_string(), Entry::Channel(vec![data]));
-                Ok(())
+                1
             }
-        }
+        };
+        self.stats.record_tell(name, depth);
+        Ok(())
     }
 
     fn ask(&mut self, name: &str) -> Result<Option<Value>> {
-        match self.store.get_mut(name) {
+        let value = match self.store.get_mut(name) {
             Some(Entry::Channel(queue)) => {
                 if queue.is_empty() {
-                    Ok(None)
+                    None
                 } else {
-                    Ok(Some(queue.remove(0)))
+                    Some(queue.remove(0))
                 }
             }
             Some(_) => {
                 bail!("entry '{}' exists but is not a channel", name)
             }
-            None => Ok(None),
-        }
+            None => None,
+        };
+        self.stats.record_ask(name);
+        Ok(value)
     }
 
     fn peek(&self, name: &str) -> Result<Option<Value>> {
-        match self.store.get(name) {
-            Some(Entry::Channel(queue)) => Ok(queue.first().cloned()),
+        let value = match self.store.get(name) {
+            Some(Entry::Channel(queue)) => queue.first().cloned(),
             Some(_) => {
                 bail!("entry '{}' exists but is not a channel", name)
             }
-            None => Ok(None),
-        }
+            None => None,
+        };
+        self.stats.record_peek(name);
+        Ok(value)
+    }
+
+    fn peek_all(&self, name: &str) -> Result<Vec<Value>> {
+        let values = match self.store.get(name) {
+            Some(Entry::Channel(queue)) => queue.clone(),
+            Some(_) => {
+                bail!("entry '{}' exists but is not a channel", name)
+            }
+            None => Vec::new(),
+        };
+        self.stats.record_peek(name);
+        Ok(values)
     }
 
     // === Process operations ===
@@ -152,6 +173,30 @@ impl RSpace for InMemoryRSpace {
     fn reset(&mut self) {
         self.store.clear();
     }
+
+    fn entries(&self) -> Vec<(String, Entry)> {
+        let mut entries: Vec<_> = self
+            .store
+            .iter()
+            .map(|(name, entry)| (name.clone(), entry.clone()))
+            .collect();
+        entries.sort_by(|(a, _), (b, _)| a.cmp(b));
+        entries
+    }
+
+    fn remove_entry(&mut self, name: &str) -> Option<Entry> {
+        self.store.remove(name)
+    }
+
+    // === Profiling ===
+
+    fn enable_stats(&mut self) {
+        self.stats.enable();
+    }
+
+    fn stats(&self) -> HashMap<String, ChannelStats> {
+        self.stats.snapshot()
+    }
 }
 
 #[cfg(test)]
@@ -190,6 +235,27 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_channel_peek_all() -> Result<()> {
+        let mut rspace = InMemoryRSpace::new();
+
+        rspace.tell("queue", Value::Int(1))?;
+        rspace.tell("queue", Value::Int(2))?;
+        rspace.tell("queue", Value::Int(3))?;
+
+        assert_eq!(
+            rspace.peek_all("queue")?,
+            vec![Value::Int(1), Value::Int(2), Value::Int(3)]
+        );
+        assert!(matches!(
+            rspace.get_entry("queue"),
+            Some(Entry::Channel(queue)) if queue.len() == 3
+        ));
+        assert_eq!(rspace.peek_all("missing")?, Vec::new());
+
+        Ok(())
+    }
+
     #[test]
     fn test_channel_is_solved() -> Result<()> {
         let mut rspace = InMemoryRSpace::new();
@@ -0,0 +1,282 @@
+//! Conversion between [`Value`] and JSON, for handing results to external services.
+//!
+//! | Value                            | JSON                       |
+//! |----------------------------------|----------------------------|
+//! | `Nil`                            | `null`                     |
+//! | `Bool`                           | boolean                    |
+//! | `Int`, `Float`                   | number                     |
+//! | `Str`                            | string                     |
+//! | `List`, `Tuple`                  | array                      |
+//! | `Map`                            | object                     |
+//! | `Name`                           | `{"#name": "<name>"}`      |
+//! | `BigInt`, `BigRat`, `FixedPoint` | `{"#number": "<literal>"}` |
+//! | `Set`                            | `{"#set": [..]}`           |
+//! | `PathMap`                        | `{"#pathmap": [..]}`       |
+//!
+//! Big numbers are written as their [`Display`](std::fmt::Display) literal, e.g. `"12n"` or
+//! `"1.50p2"`, since JSON numbers cannot hold them exactly. A tuple comes back as a list.
+//!
+//! JSON object keys are strings, so map keys are coerced: a `Str` key is used as is, and
+//! `Int` and `Bool` keys are written in decimal and as `true`/`false`. Any other key, a key
+//! that collides with another after coercion, a non-finite float and a `Par` cannot be
+//! represented and are errors. Objects read back always have `Str` keys.
+//!
+//! A key starting with `#` is written with a second `#` in front, so that a map such as
+//! `{"#name": "x"}` is not read back as a tag; reading drops one `#` from keys starting
+//! with `##`.
+
+use crate::Value;
+use anyhow::{anyhow, bail, Result};
+use num_bigint::BigInt;
+use serde_json::{Map, Number, Value as Json};
+
+const TAG_PREFIX: char = '#';
+const TAG_NAME: &str = "#name";
+const TAG_NUMBER: &str = "#number";
+const TAG_SET: &str = "#set";
+const TAG_PATHMAP: &str = "#pathmap";
+
+/// Convert `value` to JSON as described in the [module docs](self).
+pub fn to_json(value: &Value) -> Result<Json> {
+    Ok(match value {
+        Value::Nil => Json::Null,
+        Value::Bool(b) => Json::Bool(*b),
+        Value::Int(n) => Json::from(*n),
+        Value::Float(v) => Number::from_f64(*v)
+            .map(Json::Number)
+            .ok_or_else(|| anyhow!("{v} has no JSON representation"))?,
+        Value::Str(s) => Json::String(s.clone()),
+        Value::List(items) | Value::Tuple(items) => to_json_array(items)?,
+        Value::Map(entries) => {
+            let mut object = Map::with_capacity(entries.len());
+            for (key, value) in entries {
+                let key = match key {
+                    Value::Str(s) => s.clone(),
+                    Value::Int(n) => n.to_string(),
+                    Value::Bool(b) => b.to_string(),
+                    other => bail!("map key {other} cannot be a JSON object key"),
+                };
+                let key = escape_key(key);
+                if object.insert(key.clone(), to_json(value)?).is_some() {
+                    bail!("map key {key:?} occurs twice after conversion to a string");
+                }
+            }
+            Json::Object(object)
+        }
+        Value::Name(name) => tagged(TAG_NAME, Json::String(name.clone())),
+        Value::BigInt(_) | Value::BigRat(_) | Value::FixedPoint { .. } => {
+            tagged(TAG_NUMBER, Json::String(value.to_string()))
+        }
+        Value::Set(items) => tagged(TAG_SET, to_json_array(items)?),
+        Value::PathMap(paths) => tagged(TAG_PATHMAP, to_json_array(paths)?),
+        Value::Par(_) => bail!("a process has no JSON representation"),
+    })
+}
+
+/// Convert JSON written by [`to_json`], or any other JSON document, to a [`Value`].
+///
+/// An object whose only key is one of the tags of the [module docs](self) is decoded as
+/// that kind of value; every other object becomes a `Map`. Integers beyond `i64` become
+/// `BigInt`s.
+pub fn from_json(json: &Json) -> Result<Value> {
+    Ok(match json {
+        Json::Null => Value::Nil,
+        Json::Bool(b) => Value::Bool(*b),
+        Json::Number(n) => match (n.as_i64(), n.as_u64(), n.as_f64()) {
+            (Some(i), _, _) => Value::Int(i),
+            (None, Some(u), _) => Value::BigInt(BigInt::from(u)),
+            (None, None, Some(f)) => Value::Float(f),
+            _ => bail!("JSON number {n} is out of range"),
+        },
+        Json::String(s) => Value::Str(s.clone()),
+        Json::Array(items) => Value::List(from_json_all(items)?),
+        Json::Object(object) => match untag(object) {
+            Some((TAG_NAME, Json::String(name))) => Value::Name(name.clone()),
+            Some((TAG_NUMBER, Json::String(literal))) => match literal.parse::<Value>() {
+                Ok(number @ (Value::BigInt(_) | Value::BigRat(_) | Value::FixedPoint { .. })) => {
+                    number
+                }
+                _ => bail!("{literal:?} is not a BigInt, BigRat or FixedPoint literal"),
+            },
+            Some((TAG_SET, Json::Array(items))) => Value::new_set(from_json_all(items)?),
+            Some((TAG_PATHMAP, Json::Array(paths))) => Value::PathMap(from_json_all(paths)?),
+            Some((tag, _)) => bail!("malformed {tag:?} object"),
+            None => Value::Map(
+                object
+                    .iter()
+                    .map(|(key, value)| Ok((Value::Str(unescape_key(key)), from_json(value)?)))
+                    .collect::<Result<_>>()?,
+            ),
+        },
+    })
+}
+
+fn to_json_array(items: &[Value]) -> Result<Json> {
+    items
+        .iter()
+        .map(to_json)
+        .collect::<Result<_>>()
+        .map(Json::Array)
+}
+
+fn from_json_all(items: &[Json]) -> Result<Vec<Value>> {
+    items.iter().map(from_json).collect()
+}
+
+/// Object key of a map key, kept apart from the tags
+fn escape_key(key: String) -> String {
+    if key.starts_with(TAG_PREFIX) {
+        format!("{TAG_PREFIX}{key}")
+    } else {
+        key
+    }
+}
+
+/// Map key of an object key, the inverse of [`escape_key`]
+fn unescape_key(key: &str) -> String {
+    match key.strip_prefix(TAG_PREFIX) {
+        Some(rest) if rest.starts_with(TAG_PREFIX) => rest.to_string(),
+        _ => key.to_string(),
+    }
+}
+
+fn tagged(tag: &str, content: Json) -> Json {
+    Json::Object(Map::from_iter([(tag.to_string(), content)]))
+}
+
+/// The tag and content of a single-key object whose key is one of ours
+fn untag(object: &Map<String, Json>) -> Option<(&'static str, &Json)> {
+    if object.len() != 1 {
+        return None;
+    }
+    let (key, content) = object.iter().next()?;
+    [TAG_NAME, TAG_NUMBER, TAG_SET, TAG_PATHMAP]
+        .into_iter()
+        .find(|tag| key == tag)
+        .map(|tag| (tag, content))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use num_rational::BigRational;
+    use serde_json::json;
+
+    fn round_trip(value: Value, expected: Json) {
+        let json = to_json(&value).unwrap();
+        assert_eq!(json, expected);
+        assert_eq!(from_json(&json).unwrap(), value);
+    }
+
+    #[test]
+    fn scalars() {
+        round_trip(Value::Nil, json!(null));
+        round_trip(Value::Bool(true), json!(true));
+        round_trip(Value::Int(-7), json!(-7));
+        round_trip(Value::Float(1.5), json!(1.5));
+        round_trip(Value::Str("hi".to_string()), json!("hi"));
+        round_trip(Value::Name("ch".to_string()), json!({"#name": "ch"}));
+    }
+
+    #[test]
+    fn big_numbers() {
+        round_trip(Value::BigInt(BigInt::from(12)), json!({"#number": "12n"}));
+        round_trip(
+            Value::BigRat(BigRational::new(1.into(), 3.into())),
+            json!({"#number": "1r/3r"}),
+        );
+        round_trip(
+            Value::FixedPoint {
+                unscaled: BigInt::from(150),
+                scale: 2,
+            },
+            json!({"#number": "1.50p2"}),
+        );
+        assert_eq!(
+            from_json(&json!(u64::MAX)).unwrap(),
+            Value::BigInt(BigInt::from(u64::MAX))
+        );
+        assert!(from_json(&json!({"#number": "12"})).is_err());
+    }
+
+    #[test]
+    fn collections() {
+        round_trip(
+            Value::List(vec![Value::Int(1), Value::List(vec![Value::Nil])]),
+            json!([1, [null]]),
+        );
+        round_trip(
+            Value::new_set(vec![Value::Int(1), Value::Int(2)]),
+            json!({"#set": [1, 2]}),
+        );
+        round_trip(
+            Value::PathMap(vec![Value::Str("a".to_string())]),
+            json!({"#pathmap": ["a"]}),
+        );
+        round_trip(
+            Value::Map(vec![(Value::Str("k".to_string()), Value::Bool(false))]),
+            json!({"k": false}),
+        );
+
+        // A tuple comes back as a list
+        let tuple = Value::Tuple(vec![Value::Int(1), Value::Str("x".to_string())]);
+        assert_eq!(to_json(&tuple).unwrap(), json!([1, "x"]));
+        assert_eq!(
+            from_json(&json!([1, "x"])).unwrap(),
+            Value::List(vec![Value::Int(1), Value::Str("x".to_string())])
+        );
+    }
+
+    #[test]
+    fn map_keys_are_coerced_to_strings() {
+        let map = Value::Map(vec![
+            (Value::Int(1), Value::Nil),
+            (Value::Bool(true), Value::Nil),
+        ]);
+        assert_eq!(to_json(&map).unwrap(), json!({"1": null, "true": null}));
+
+        let list_key = Value::Map(vec![(Value::List(vec![]), Value::Nil)]);
+        assert!(to_json(&list_key).is_err());
+
+        let collision = Value::Map(vec![
+            (Value::Int(1), Value::Nil),
+            (Value::Str("1".to_string()), Value::Nil),
+        ]);
+        assert!(to_json(&collision).is_err());
+    }
+
+    #[test]
+    fn keys_that_look_like_tags_stay_map_keys() {
+        for tag in [TAG_NAME, TAG_NUMBER, TAG_SET, TAG_PATHMAP] {
+            let map = Value::Map(vec![(
+                Value::Str(tag.to_string()),
+                Value::Str("x".to_string()),
+            )]);
+            round_trip(map, json!({ format!("#{tag}"): "x" }));
+        }
+        round_trip(
+            Value::Map(vec![
+                (Value::Str("##a".to_string()), Value::Nil),
+                (Value::Str("a#".to_string()), Value::Nil),
+            ]),
+            json!({"###a": null, "a#": null}),
+        );
+    }
+
+    #[test]
+    fn unrepresentable_values() {
+        assert!(to_json(&Value::Float(f64::NAN)).is_err());
+        assert!(to_json(&Value::Par(vec![])).is_err());
+        assert!(from_json(&json!({"#name": 1})).is_err());
+
+        // An unknown tag, or extra keys, leave the object a plain map
+        assert_eq!(
+            from_json(&json!({"#other": 1})).unwrap(),
+            Value::Map(vec![(Value::Str("#other".to_string()), Value::Int(1))])
+        );
+        assert!(matches!(
+            from_json(&json!({"#name": "x", "y": 1})).unwrap(),
+            Value::Map(_)
+        ));
+    }
+}
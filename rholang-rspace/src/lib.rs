@@ -36,6 +36,9 @@
 //!
 //! # Use InMemory (testing)
 //! rholang-rspace = { path = "../rholang-rspace", default-features = false, features = ["inmemory-impl"] }
+//!
+//! # Also build BTreeRSpace (sorted keys, for snapshot tests)
+//! rholang-rspace = { path = "../rholang-rspace", features = ["btree-impl"] }
 //! ```
 //!
 //! ## Runtime Injection
@@ -95,6 +98,7 @@
 //! │  │  • peek(name) → Option  • get_process_state                            │ │
 //! │  │                                                                        │ │
 //! │  │  Utility: get_entry, is_solved, reset                                  │ │
+//! │  │  Profiling: enable_stats, stats                                        │ │
 //! │  └────────────────────────────────────────────────────────────────────────┘ │
 //! │                              ▲                                              │
 //! │               ┌──────────────┴──────────────┐                               │
@@ -113,7 +117,7 @@
 //! │  │                         Core Types                                     │ │
 //! │  │                                                                        │ │
 //! │  │  Entry:         Value:              ProcessState:                      │ │
-//! │  │  • Channel      • Int, Bool, Str    • Wait                             │ │
+//! │  │  • Channel      • Int, Bool, Str    • Wait, WaitOn(Vec<String>)        │ │
 //! │  │  • Process      • Name, List        • Ready                            │ │
 //! │  │  • Value        • Tuple, Map        • Value(Value)                     │ │
 //! │  │                 • Par, Nil          • Error(String)                    │ │
@@ -148,12 +152,15 @@
 //!     fn tell(&mut self, name: &str, data: Value) -> Result<()> { /* ... */ }
 //!     fn ask(&mut self, name: &str) -> Result<Option<Value>> { /* ... */ }
 //!     fn peek(&self, name: &str) -> Result<Option<Value>> { /* ... */ }
+//!     fn peek_all(&self, name: &str) -> Result<Vec<Value>> { /* ... */ }
 //!     fn register_process(&mut self, name: &str, state: ProcessState) -> Result<()> { /* ... */ }
 //!     fn update_process(&mut self, name: &str, state: ProcessState) -> Result<()> { /* ... */ }
 //!     fn get_process_state(&self, name: &str) -> Option<ProcessState> { /* ... */ }
 //!     fn set_value(&mut self, name: &str, value: Value) -> Result<()> { /* ... */ }
 //!     fn get_value(&self, name: &str) -> Option<Value> { /* ... */ }
 //!     fn reset(&mut self) { /* ... */ }
+//!     fn entries(&self) -> Vec<(String, Entry)> { /* ... */ }
+//!     fn remove_entry(&mut self, name: &str) -> Option<Entry> { /* ... */ }
 //! }
 //! ```
 //!
@@ -179,6 +186,7 @@
 //! #[cfg(test)]
 //! mod tests {
 //!     rspace_interface_tests!(MyRSpace, my_rspace_tests);
+//!     rspace_concurrency_tests!(MyRSpace, my_rspace_concurrency_tests);
 //! }
 //! ```
 //!
@@ -215,13 +223,22 @@
 mod entry;
 mod error;
 mod in_memory;
+mod literal;
+mod name;
 mod rspace;
+mod stats;
 mod value;
 
 #[cfg(feature = "pathmap-impl")]
 mod path_map;
 
-use std::sync::{Arc, Mutex, OnceLock};
+#[cfg(feature = "btree-impl")]
+mod btree;
+
+#[cfg(feature = "json")]
+mod json;
+
+use std::sync::{Arc, Mutex, MutexGuard, OnceLock, PoisonError};
 
 // ============================================================================
 // Public API - Core Types
@@ -229,8 +246,14 @@ use std::sync::{Arc, Mutex, OnceLock};
 
 pub use entry::Entry;
 pub use error::ExecError;
+pub use name::{fresh_name, quoted_name, quoted_name_text, system_name, system_uri};
 pub use rspace::RSpace;
-pub use value::{ProcessHolder, ProcessState, Value};
+pub use stats::ChannelStats;
+pub use value::{NameRenderStyle, ProcessHolder, ProcessState, Value, ELLIPSIS};
+
+/// JSON conversion of values, enabled with feature `json`.
+#[cfg(feature = "json")]
+pub use json::{from_json, to_json};
 
 // ============================================================================
 // Public API - Implementations
@@ -253,6 +276,17 @@ pub use path_map::PathMapRSpace;
 /// Always available regardless of features.
 pub use in_memory::InMemoryRSpace;
 
+/// BTreeRSpace - ORDERED ITERATION.
+///
+/// BTreeMap-based implementation whose `keys()` and `iter_entries()` yield
+/// names in sorted order, for snapshot tests and reproducible dumps.
+///
+/// # Feature Flag
+///
+/// Enabled with feature `btree-impl`.
+#[cfg(feature = "btree-impl")]
+pub use btree::BTreeRSpace;
+
 // ============================================================================
 // Public API - Type Aliases
 // ============================================================================
@@ -440,7 +474,7 @@ pub fn global_rspace() -> Option<&'static SharedRSpace> {
 ///
 /// # Panics
 ///
-/// Panics if the mutex is poisoned.
+/// Panics if the mutex is poisoned. Use the `try_` variant to recover instead.
 ///
 /// # Example
 ///
@@ -472,7 +506,7 @@ where
 ///
 /// # Panics
 ///
-/// Panics if the mutex is poisoned.
+/// Panics if the mutex is poisoned. Use the `try_` variant to recover instead.
 ///
 /// # Example
 ///
@@ -493,6 +527,84 @@ where
     f(guard.as_mut())
 }
 
+/// Execute a closure with read access to the global RSpace, recovering from a
+/// poisoned mutex instead of panicking.
+///
+/// If a previous closure panicked while holding the lock, the RSpace is still
+/// handed to `f` and the poison flag is cleared, so later calls (including
+/// [`with_global_rspace`]) succeed again. In that case the result of `f` is
+/// returned wrapped in a [`PoisonError`] to signal that the RSpace may have been
+/// left mid-update; use [`PoisonError::into_inner`] to accept it anyway.
+///
+/// Automatically initializes the global RSpace if not already initialized.
+///
+/// # Example
+///
+/// ```
+/// use rholang_rspace::try_with_global_rspace;
+/// use std::sync::PoisonError;
+///
+/// let readable = try_with_global_rspace(|rspace| rspace.peek("test").is_ok())
+///     .unwrap_or_else(PoisonError::into_inner);
+/// assert!(readable);
+/// ```
+pub fn try_with_global_rspace<F, R>(f: F) -> Result<R, PoisonError<R>>
+where
+    F: FnOnce(&dyn RSpace) -> R,
+{
+    let (guard, poisoned) = lock_global_rspace();
+    let result = f(guard.as_ref());
+    if poisoned {
+        Err(PoisonError::new(result))
+    } else {
+        Ok(result)
+    }
+}
+
+/// Execute a closure with mutable access to the global RSpace, recovering from a
+/// poisoned mutex instead of panicking.
+///
+/// Recovery semantics are the same as for [`try_with_global_rspace`]: `f` always
+/// runs, the poison flag is cleared, and the result is wrapped in a
+/// [`PoisonError`] if the lock had been poisoned.
+///
+/// # Example
+///
+/// ```
+/// use rholang_rspace::{try_with_global_rspace_mut, Value};
+///
+/// try_with_global_rspace_mut(|rspace| {
+///     rspace.tell("channel", Value::Int(42)).unwrap();
+/// })
+/// .expect("global RSpace was poisoned");
+/// ```
+pub fn try_with_global_rspace_mut<F, R>(f: F) -> Result<R, PoisonError<R>>
+where
+    F: FnOnce(&mut dyn RSpace) -> R,
+{
+    let (mut guard, poisoned) = lock_global_rspace();
+    let result = f(guard.as_mut());
+    if poisoned {
+        Err(PoisonError::new(result))
+    } else {
+        Ok(result)
+    }
+}
+
+/// Lock the global RSpace, taking over the guard of a poisoned mutex.
+/// Returns the guard and whether the mutex had been poisoned.
+fn lock_global_rspace() -> (MutexGuard<'static, BoxedRSpace>, bool) {
+    init_global_rspace();
+    let rspace = GLOBAL_RSPACE.get().expect("global RSpace not initialized");
+    match rspace.lock() {
+        Ok(guard) => (guard, false),
+        Err(poisoned) => {
+            rspace.clear_poison();
+            (poisoned.into_inner(), true)
+        }
+    }
+}
+
 /// Reset the global RSpace (clears all data but keeps the instance).
 ///
 /// # Example
@@ -686,6 +798,30 @@ mod tests {
         assert!(rspace.get_entry("test").is_none());
     }
 
+    #[test]
+    fn test_entries_and_remove_entry() {
+        let mut rspace = new_rspace();
+
+        rspace.tell("inbox", Value::Int(1)).unwrap();
+        rspace.set_value("config", Value::Bool(true)).unwrap();
+
+        assert_eq!(
+            rspace.entries(),
+            vec![
+                ("config".to_string(), Entry::Value(Value::Bool(true))),
+                ("inbox".to_string(), Entry::Channel(vec![Value::Int(1)])),
+            ]
+        );
+
+        assert_eq!(
+            rspace.remove_entry("inbox"),
+            Some(Entry::Channel(vec![Value::Int(1)]))
+        );
+        assert_eq!(rspace.remove_entry("inbox"), None);
+        assert!(rspace.get_entry("inbox").is_none());
+        assert_eq!(rspace.entries().len(), 1);
+    }
+
     #[test]
     fn test_concurrent_access() {
         use std::thread;
@@ -0,0 +1,383 @@
+//! Parsing [`Value`] literals from text.
+//!
+//! Accepts the forms written by the [`Display`](std::fmt::Display) impl of
+//! [`Value`], so `value.to_string().parse::<Value>()` gives the value back
+//! (strings and names are not escaped on display, so a `"` inside them does not
+//! survive the round trip). Unforgeable names and system channels have no
+//! literal form. This is only for literal data such as test fixtures
+//! or a seed file for an RSpace, not a parser for Rholang processes.
+
+use crate::{quoted_name, Value};
+use num_bigint::BigInt;
+use num_rational::BigRational;
+use num_traits::Zero;
+use std::str::FromStr;
+
+impl FromStr for Value {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parser = LiteralParser {
+            src: s,
+            pos: 0,
+            depth: 0,
+        };
+        let value = parser.value()?;
+        parser.skip_whitespace();
+        if parser.pos != s.len() {
+            return Err(parser.error("unexpected trailing input"));
+        }
+        Ok(value)
+    }
+}
+
+/// Deepest nesting of collections accepted, so that parsing cannot overflow the stack
+const MAX_DEPTH: usize = 256;
+
+/// Largest fixed-point scale accepted, so that `1p4294967295` does not allocate
+/// gigabytes of padding
+const MAX_SCALE: u32 = 1024;
+
+/// Characters that end a bare token such as a number or keyword
+fn is_delimiter(c: char) -> bool {
+    c.is_whitespace() || matches!(c, ',' | ':' | '[' | ']' | '(' | ')' | '{' | '}' | '|' | '"')
+}
+
+struct LiteralParser<'s> {
+    src: &'s str,
+    pos: usize,
+    /// Number of collections the parser is inside of
+    depth: usize,
+}
+
+impl<'s> LiteralParser<'s> {
+    fn rest(&self) -> &'s str {
+        &self.src[self.pos..]
+    }
+
+    fn error(&self, message: &str) -> String {
+        format!("{message} at byte {}", self.pos)
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = self.rest();
+        self.pos += rest.len() - rest.trim_start().len();
+    }
+
+    /// Consumes `token` after optional whitespace, if it is next
+    fn eat(&mut self, token: &str) -> bool {
+        self.skip_whitespace();
+        if self.rest().starts_with(token) {
+            self.pos += token.len();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, token: &str) -> Result<(), String> {
+        if self.eat(token) {
+            Ok(())
+        } else {
+            Err(self.error(&format!("expected '{token}'")))
+        }
+    }
+
+    fn value(&mut self) -> Result<Value, String> {
+        if self.depth == MAX_DEPTH {
+            return Err(self.error("values nested too deeply"));
+        }
+        self.depth += 1;
+        let value = self.unnested_value();
+        self.depth -= 1;
+        value
+    }
+
+    fn unnested_value(&mut self) -> Result<Value, String> {
+        self.skip_whitespace();
+        let rest = self.rest();
+        match rest.chars().next() {
+            None => Err(self.error("expected a value")),
+            Some('"') => self.string().map(Value::Str),
+            Some('@') => {
+                self.pos += 1;
+                if !self.rest().starts_with('"') {
+                    return Err(self.error("expected a quoted name after '@'"));
+                }
+                self.string().map(|text| Value::Name(quoted_name(&text)))
+            }
+            Some('[') => {
+                self.pos += 1;
+                self.sequence("]").map(Value::List)
+            }
+            Some('(') => {
+                self.pos += 1;
+                self.sequence(")").map(Value::Tuple)
+            }
+            Some('{') if rest.starts_with("{|") => {
+                self.pos += 2;
+                self.sequence("|}").map(Value::PathMap)
+            }
+            Some('{') => {
+                self.pos += 1;
+                self.map()
+            }
+            Some(_) if rest.starts_with("Set(") => {
+                self.pos += 4;
+                self.sequence(")").map(Value::new_set)
+            }
+            Some(_) => self.scalar(),
+        }
+    }
+
+    /// A double-quoted string, with `\"`, `\\`, `\n` and `\t` escapes
+    fn string(&mut self) -> Result<String, String> {
+        let start = self.pos;
+        let mut out = String::new();
+        let mut chars = self.rest().char_indices().skip(1);
+        while let Some((i, c)) = chars.next() {
+            match c {
+                '"' => {
+                    self.pos += i + 1;
+                    return Ok(out);
+                }
+                '\\' => match chars.next() {
+                    Some((_, '"')) => out.push('"'),
+                    Some((_, '\\')) => out.push('\\'),
+                    Some((_, 'n')) => out.push('\n'),
+                    Some((_, 't')) => out.push('\t'),
+                    Some((j, other)) => {
+                        self.pos += j;
+                        return Err(self.error(&format!("unknown escape '\\{other}'")));
+                    }
+                    None => break,
+                },
+                _ => out.push(c),
+            }
+        }
+        self.pos = start;
+        Err(self.error("unterminated string"))
+    }
+
+    /// Comma-separated values up to and including `close`
+    fn sequence(&mut self, close: &str) -> Result<Vec<Value>, String> {
+        let mut items = Vec::new();
+        if self.eat(close) {
+            return Ok(items);
+        }
+        loop {
+            items.push(self.value()?);
+            if self.eat(close) {
+                return Ok(items);
+            }
+            if !self.eat(",") {
+                return Err(self.error(&format!("expected ',' or '{close}'")));
+            }
+        }
+    }
+
+    /// Comma-separated `key: value` entries up to and including `}`
+    fn map(&mut self) -> Result<Value, String> {
+        let mut entries = Vec::new();
+        if self.eat("}") {
+            return Ok(Value::Map(entries));
+        }
+        loop {
+            let key = self.value()?;
+            self.expect(":")?;
+            let value = self.value()?;
+            entries.push((key, value));
+            if self.eat("}") {
+                return Ok(Value::Map(entries));
+            }
+            if !self.eat(",") {
+                return Err(self.error("expected ',' or '}'"));
+            }
+        }
+    }
+
+    /// `Nil`, a boolean or a number, told apart by its suffix
+    fn scalar(&mut self) -> Result<Value, String> {
+        let rest = self.rest();
+        let len = rest.find(is_delimiter).unwrap_or(rest.len());
+        let token = &rest[..len];
+        let value = match token {
+            "Nil" => Some(Value::Nil),
+            "true" => Some(Value::Bool(true)),
+            "false" => Some(Value::Bool(false)),
+            _ => number(token),
+        };
+        match value {
+            Some(value) => {
+                self.pos += len;
+                Ok(value)
+            }
+            None if token.is_empty() => Err(self.error("expected a value")),
+            None => Err(self.error(&format!("invalid literal '{token}'"))),
+        }
+    }
+}
+
+/// Parses `42`, `1.5f64`, `42n`, `1r/2r`, `3r` and `1.50p2`
+fn number(token: &str) -> Option<Value> {
+    if let Some(float) = token.strip_suffix("f64") {
+        return float.parse().ok().map(Value::Float);
+    }
+    if let Some(big) = token.strip_suffix('n') {
+        return big_int(big).map(Value::BigInt);
+    }
+    if let Some(rat) = token.strip_suffix('r') {
+        let (numer, denom) = match rat.split_once("r/") {
+            Some((numer, denom)) => (big_int(numer)?, big_int(denom)?),
+            None => (big_int(rat)?, BigInt::from(1)),
+        };
+        if denom.is_zero() {
+            return None;
+        }
+        return Some(Value::BigRat(BigRational::new(numer, denom)));
+    }
+    if let Some((decimal, scale)) = token.rsplit_once('p') {
+        return fixed_point(decimal, scale.parse().ok()?);
+    }
+    token.parse().ok().map(Value::Int)
+}
+
+fn big_int(digits: &str) -> Option<BigInt> {
+    let unsigned = digits.strip_prefix('-').unwrap_or(digits);
+    if unsigned.is_empty() || !unsigned.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    digits.parse().ok()
+}
+
+/// `decimal` may have at most `scale` fractional digits; missing ones are zero
+fn fixed_point(decimal: &str, scale: u32) -> Option<Value> {
+    if scale > MAX_SCALE {
+        return None;
+    }
+    let (integer, frac) = decimal.split_once('.').unwrap_or((decimal, ""));
+    let padding = (scale as usize).checked_sub(frac.len())?;
+    if !frac.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    let unscaled = big_int(&format!("{integer}{frac}{}", "0".repeat(padding)))?;
+    Some(Value::FixedPoint { unscaled, scale })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn round_trip(value: Value) {
+        let text = value.to_string();
+        assert_eq!(text.parse::<Value>(), Ok(value), "round trip of {text}");
+    }
+
+    #[test]
+    fn scalars_round_trip() {
+        round_trip(Value::Nil);
+        round_trip(Value::Int(42));
+        round_trip(Value::Int(-7));
+        round_trip(Value::Float(1.5));
+        round_trip(Value::Float(-2.0));
+        round_trip(Value::BigInt(BigInt::from(123_456_789_012_345_678_i128)));
+        round_trip(Value::BigRat(BigRational::new(1.into(), 3.into())));
+        round_trip(Value::BigRat(BigRational::from_integer(5.into())));
+        round_trip(Value::FixedPoint {
+            unscaled: BigInt::from(150),
+            scale: 2,
+        });
+        round_trip(Value::FixedPoint {
+            unscaled: BigInt::from(-5),
+            scale: 2,
+        });
+        round_trip(Value::FixedPoint {
+            unscaled: BigInt::from(7),
+            scale: 0,
+        });
+        round_trip(Value::Bool(true));
+        round_trip(Value::Bool(false));
+        round_trip(Value::Str("hello, world".to_string()));
+        round_trip(Value::Name("inbox".to_string()));
+        round_trip(Value::Name(quoted_name("@3:1")));
+    }
+
+    #[test]
+    fn collections_round_trip() {
+        round_trip(Value::List(vec![
+            Value::Int(1),
+            Value::Int(2),
+            Value::Int(3),
+        ]));
+        round_trip(Value::List(vec![]));
+        round_trip(Value::Tuple(vec![
+            Value::Int(1),
+            Value::Str("a".to_string()),
+        ]));
+        round_trip(Value::Tuple(vec![]));
+        round_trip(Value::Map(vec![
+            (Value::Int(1), Value::Str("a".to_string())),
+            (Value::Str("k".to_string()), Value::List(vec![Value::Nil])),
+        ]));
+        round_trip(Value::Map(vec![]));
+        round_trip(Value::new_set(vec![Value::Int(1), Value::Int(2)]));
+        round_trip(Value::PathMap(vec![Value::List(vec![Value::Int(1)])]));
+    }
+
+    #[test]
+    fn accepts_whitespace_and_escapes() {
+        assert_eq!(
+            " [ 1 ,2 ] ".parse::<Value>(),
+            Ok(Value::List(vec![Value::Int(1), Value::Int(2)]))
+        );
+        assert_eq!(
+            r#""a\"b\\c\n""#.parse::<Value>(),
+            Ok(Value::Str("a\"b\\c\n".to_string()))
+        );
+        assert_eq!(
+            "Set(1, 1, 2)".parse::<Value>(),
+            Ok(Value::Set(vec![Value::Int(1), Value::Int(2)]))
+        );
+    }
+
+    #[test]
+    fn rejects_malformed_input() {
+        for input in [
+            "",
+            "[1, 2",
+            "[1 2]",
+            "{1 \"a\"}",
+            "\"open",
+            "@name",
+            "12abc",
+            "1r/0r",
+            "1.234p2",
+            "1p4294967295",
+            "99999999999999999999",
+            "<Par>",
+            "1 2",
+        ] {
+            assert!(
+                input.parse::<Value>().is_err(),
+                "{input:?} should not parse"
+            );
+        }
+        assert_eq!(
+            "[1, 2".parse::<Value>(),
+            Err("expected ',' or ']' at byte 5".to_string())
+        );
+    }
+
+    #[test]
+    fn limits_scale_and_nesting() {
+        assert!(format!("1p{MAX_SCALE}").parse::<Value>().is_ok());
+        assert!(format!("1p{}", MAX_SCALE + 1).parse::<Value>().is_err());
+
+        let nested = |depth: usize| format!("{}{}", "[".repeat(depth), "]".repeat(depth));
+        assert!(nested(MAX_DEPTH).parse::<Value>().is_ok());
+        assert_eq!(
+            nested(100_000).parse::<Value>(),
+            Err(format!("values nested too deeply at byte {MAX_DEPTH}"))
+        );
+    }
+}
@@ -0,0 +1,90 @@
+//! Channel keys of the different kinds of names.
+//!
+//! Every name is stored as a [`Value::Name`](crate::Value::Name) string key, so the kinds
+//! share one namespace and are kept apart by their first characters:
+//!
+//! - a quoted name `@"text"` uses `text` itself, escaped with a second `@` when it
+//!   already starts with one;
+//! - an unforgeable name created by `new` is `@<kind>:<id>`;
+//! - a system channel bound by `new x(`rho:io:stdout`)` is `@rho:io:stdout`.
+//!
+//! A key starting with a single `@` can therefore never come from a quoted string.
+
+use std::sync::atomic::{AtomicU64, Ordering};
+
+const RESERVED: char = '@';
+
+/// Ids of unforgeable names, shared by every VM in the process so that names created
+/// by different processes or REPL runs never collide.
+static NEXT_NAME_ID: AtomicU64 = AtomicU64::new(1);
+
+/// Key of the quoted name `@"text"`.
+pub fn quoted_name(text: &str) -> String {
+    if text.starts_with(RESERVED) {
+        format!("{RESERVED}{text}")
+    } else {
+        text.to_string()
+    }
+}
+
+/// Key of a fresh unforgeable name of the given kind.
+pub fn fresh_name(kind: u16) -> String {
+    let id = NEXT_NAME_ID.fetch_add(1, Ordering::Relaxed);
+    format!("{RESERVED}{kind}:{id}")
+}
+
+/// Key of the system channel bound to `uri`, a known `rho:` URI.
+pub fn system_name(uri: &str) -> String {
+    format!("{RESERVED}{uri}")
+}
+
+/// The URI of a system channel key, or `None` for every other name.
+pub fn system_uri(key: &str) -> Option<&str> {
+    key.strip_prefix(RESERVED)
+        .filter(|rest| !rest.starts_with(|c: char| c == RESERVED || c.is_ascii_digit()))
+}
+
+/// The text of a quoted name key, or `None` for unforgeable names and system channels.
+pub fn quoted_name_text(key: &str) -> Option<&str> {
+    match key.strip_prefix(RESERVED) {
+        None => Some(key),
+        Some(rest) if rest.starts_with(RESERVED) => Some(rest),
+        Some(_) => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_quoted_names_round_trip() {
+        for text in ["", "ch", "@", "@3:1", "@@x", "@rho:io:stdout"] {
+            assert_eq!(quoted_name_text(&quoted_name(text)), Some(text), "{text:?}");
+        }
+    }
+
+    #[test]
+    fn test_reserved_keys_cannot_be_quoted() {
+        let fresh = fresh_name(3);
+        assert!(fresh.starts_with("@3:"), "{fresh}");
+        assert_eq!(quoted_name_text(&fresh), None);
+        assert_ne!(quoted_name(&fresh), fresh);
+
+        let stdout = system_name("rho:io:stdout");
+        assert_eq!(system_uri(&stdout), Some("rho:io:stdout"));
+        assert_eq!(system_uri(&fresh), None);
+        assert_eq!(system_uri(&quoted_name("@rho:io:stdout")), None);
+        assert_eq!(system_uri("rho:io:stdout"), None);
+        assert_eq!(quoted_name_text(&stdout), None);
+        assert_ne!(quoted_name("rho:io:stdout"), stdout);
+        assert_ne!(quoted_name("@rho:io:stdout"), stdout);
+    }
+
+    #[test]
+    fn test_fresh_names_are_unique() {
+        let a = fresh_name(3);
+        let b = fresh_name(3);
+        assert_ne!(a, b);
+    }
+}
@@ -2,9 +2,11 @@
 
 use crate::entry::Entry;
 use crate::rspace::RSpace;
+use crate::stats::{ChannelStats, StatsRecorder};
 use crate::value::{ProcessState, Value};
 use anyhow::{bail, Result};
 use pathmap::PathMap;
+use std::collections::HashMap;
 
 /// PathMap-based RSpace - THE DEFAULT PRODUCTION IMPLEMENTATION.
 ///
@@ -50,6 +52,7 @@ use pathmap::PathMap;
 /// ```
 pub struct PathMapRSpace {
     store: PathMap<Entry>,
+    stats: StatsRecorder,
 }
 
 impl PathMapRSpace {
@@ -57,6 +60,7 @@ impl PathMapRSpace {
     pub fn new() -> Self {
         Self {
             store: PathMap::new(),
+            stats: StatsRecorder::default(),
         }
     }
 }
@@ -73,45 +77,63 @@ impl RSpace for PathMapRSpace {
     }
 
     fn tell(&mut self, name: &str, data: Value) -> Result<()> {
-        match self.store.get_mut(name) {
+        let depth = match self.store.get_mut(name) {
             Some(Entry::Channel(queue)) => {
                 queue.push(data);
-                Ok(())
+                queue.len()
             }
             Some(_) => {
                 bail!("entry '{}' exists but is not a channel", name)
             }
             None => {
                 self.store.insert(name, Entry::Channel(vec![data]));
-                Ok(())
+                1
             }
-        }
+        };
+        self.stats.record_tell(name, depth);
+        Ok(())
     }
 
     fn ask(&mut self, name: &str) -> Result<Option<Value>> {
-        match self.store.get_mut(name) {
+        let value = match self.store.get_mut(name) {
             Some(Entry::Channel(queue)) => {
                 if queue.is_empty() {
-                    Ok(None)
+                    None
                 } else {
-                    Ok(Some(queue.remove(0)))
+                    Some(queue.remove(0))
                 }
             }
             Some(_) => {
                 bail!("entry '{}' exists but is not a channel", name)
             }
-            None => Ok(None),
-        }
+            None => None,
+        };
+        self.stats.record_ask(name);
+        Ok(value)
     }
 
     fn peek(&self, name: &str) -> Result<Option<Value>> {
-        match self.store.get(name) {
-            Some(Entry::Channel(queue)) => Ok(queue.first().cloned()),
+        let value = match self.store.get(name) {
+            Some(Entry::Channel(queue)) => queue.first().cloned(),
             Some(_) => {
                 bail!("entry '{}' exists but is not a channel", name)
             }
-            None => Ok(None),
-        }
+            None => None,
+        };
+        self.stats.record_peek(name);
+        Ok(value)
+    }
+
+    fn peek_all(&self, name: &str) -> Result<Vec<Value>> {
+        let values = match self.store.get(name) {
+            Some(Entry::Channel(queue)) => queue.clone(),
+            Some(_) => {
+                bail!("entry '{}' exists but is not a channel", name)
+            }
+            None => Vec::new(),
+        };
+        self.stats.record_peek(name);
+        Ok(values)
     }
 
     fn register_process(&mut self, name: &str, state: ProcessState) -> Result<()> {
@@ -162,6 +184,28 @@ impl RSpace for PathMapRSpace {
     fn reset(&mut self) {
         self.store = PathMap::new();
     }
+
+    fn entries(&self) -> Vec<(String, Entry)> {
+        let mut entries: Vec<_> = self
+            .store
+            .iter()
+            .map(|(path, entry)| (String::from_utf8_lossy(&path).into_owned(), entry.clone()))
+            .collect();
+        entries.sort_by(|(a, _), (b, _)| a.cmp(b));
+        entries
+    }
+
+    fn remove_entry(&mut self, name: &str) -> Option<Entry> {
+        self.store.remove(name)
+    }
+
+    fn enable_stats(&mut self) {
+        self.stats.enable();
+    }
+
+    fn stats(&self) -> HashMap<String, ChannelStats> {
+        self.stats.snapshot()
+    }
 }
 
 #[cfg(test)]
@@ -195,6 +239,27 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_channel_peek_all() -> Result<()> {
+        let mut rspace = PathMapRSpace::new();
+
+        rspace.tell("queue", Value::Int(1))?;
+        rspace.tell("queue", Value::Int(2))?;
+        rspace.tell("queue", Value::Int(3))?;
+
+        assert_eq!(
+            rspace.peek_all("queue")?,
+            vec![Value::Int(1), Value::Int(2), Value::Int(3)]
+        );
+        assert_eq!(rspace.ask("queue")?, Some(Value::Int(1)));
+        assert_eq!(rspace.peek_all("missing")?, Vec::new());
+
+        rspace.set_value("config", Value::Nil)?;
+        assert!(rspace.peek_all("config").is_err());
+
+        Ok(())
+    }
+
     #[test]
     fn test_hierarchical_paths() -> Result<()> {
         let mut rspace = PathMapRSpace::new();
@@ -9,8 +9,10 @@
 //! - **Dependency Inversion**: Consumers depend on this abstraction, not concrete implementations
 
 use crate::entry::Entry;
+use crate::stats::ChannelStats;
 use crate::value::{ProcessState, Value};
-use anyhow::Result;
+use anyhow::{bail, Result};
+use std::collections::HashMap;
 
 /// Unified storage interface for channels, processes, and values.
 ///
@@ -94,6 +96,21 @@ pub trait RSpace: Send + Sync {
     /// Returns error if entry exists but is not a channel.
     fn peek(&self, name: &str) -> Result<Option<Value>>;
 
+    /// Non-destructive snapshot: return a copy of every queued value, oldest first.
+    ///
+    /// Returns an empty vector if channel is empty or doesn't exist.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if entry exists but is not a channel.
+    fn peek_all(&self, name: &str) -> Result<Vec<Value>> {
+        match self.get_entry(name) {
+            Some(Entry::Channel(queue)) => Ok(queue),
+            Some(_) => bail!("entry '{}' exists but is not a channel", name),
+            None => Ok(Vec::new()),
+        }
+    }
+
     // =========================================================================
     // Process operations (for Entry::Process)
     // =========================================================================
@@ -117,6 +134,23 @@ pub trait RSpace: Send + Sync {
     /// Returns `None` if entry doesn't exist or is not a process.
     fn get_process_state(&self, name: &str) -> Option<ProcessState>;
 
+    /// Names of the registered processes in `ProcessState::WaitOn` state that list
+    /// `channel`, sorted by name.
+    ///
+    /// These are the processes a scheduler should wake after a `tell` on `channel`.
+    fn waiting_on(&self, channel: &str) -> Vec<String> {
+        self.entries()
+            .into_iter()
+            .filter(|(_, entry)| {
+                matches!(
+                    entry.as_process_state(),
+                    Some(ProcessState::WaitOn(channels)) if channels.iter().any(|c| c == channel)
+                )
+            })
+            .map(|(name, _)| name)
+            .collect()
+    }
+
     // =========================================================================
     // Value operations (for Entry::Value)
     // =========================================================================
@@ -139,4 +173,36 @@ pub trait RSpace: Send + Sync {
 
     /// Reset all storage, clearing all entries.
     fn reset(&mut self);
+
+    /// All entries with their names, sorted by name.
+    ///
+    /// Implementations that cannot enumerate their entries return an empty vector,
+    /// which also leaves [`waiting_on`](RSpace::waiting_on) empty.
+    fn entries(&self) -> Vec<(String, Entry)> {
+        Vec::new()
+    }
+
+    /// Remove the entry stored under `name`, returning it if there was one.
+    ///
+    /// Implementations that cannot remove single entries keep them and return `None`.
+    fn remove_entry(&mut self, _name: &str) -> Option<Entry> {
+        None
+    }
+
+    // =========================================================================
+    // Profiling
+    // =========================================================================
+
+    /// Start counting tell/ask/peek operations per channel.
+    ///
+    /// Counting is off by default and costs nothing until enabled. Counters are
+    /// kept across `reset`. Implementations without statistics ignore this call.
+    fn enable_stats(&mut self) {}
+
+    /// Per-channel operation counts collected since `enable_stats`.
+    ///
+    /// Empty if stats were never enabled or the implementation does not collect them.
+    fn stats(&self) -> HashMap<String, ChannelStats> {
+        HashMap::new()
+    }
 }
@@ -0,0 +1,78 @@
+//! Opt-in per-channel operation statistics for profiling.
+
+use std::collections::HashMap;
+use std::sync::{Mutex, PoisonError};
+
+/// Operation counts for a single channel.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ChannelStats {
+    pub tells: u64,
+    pub asks: u64,
+    pub peeks: u64,
+    /// Largest number of values queued on the channel at any one time.
+    pub max_depth: usize,
+}
+
+/// Per-channel counters shared by the built-in RSpace implementations.
+///
+/// Disabled by default, in which case recording is a single branch. The counters
+/// sit behind a mutex because `peek` only gets `&self`.
+#[derive(Default)]
+pub(crate) struct StatsRecorder {
+    channels: Option<Mutex<HashMap<String, ChannelStats>>>,
+}
+
+impl StatsRecorder {
+    /// Start recording. Counts collected so far are kept.
+    pub(crate) fn enable(&mut self) {
+        self.channels.get_or_insert_with(Mutex::default);
+    }
+
+    /// Copy of the counters, empty if recording was never enabled.
+    pub(crate) fn snapshot(&self) -> HashMap<String, ChannelStats> {
+        self.channels
+            .as_ref()
+            .map(|channels| {
+                channels
+                    .lock()
+                    .unwrap_or_else(PoisonError::into_inner)
+                    .clone()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Record a tell that left `depth` values queued on `name`.
+    #[inline]
+    pub(crate) fn record_tell(&self, name: &str, depth: usize) {
+        self.update(name, |stats| {
+            stats.tells += 1;
+            stats.max_depth = stats.max_depth.max(depth);
+        });
+    }
+
+    #[inline]
+    pub(crate) fn record_ask(&self, name: &str) {
+        self.update(name, |stats| stats.asks += 1);
+    }
+
+    #[inline]
+    pub(crate) fn record_peek(&self, name: &str) {
+        self.update(name, |stats| stats.peeks += 1);
+    }
+
+    #[inline]
+    fn update(&self, name: &str, f: impl FnOnce(&mut ChannelStats)) {
+        let Some(channels) = &self.channels else {
+            return;
+        };
+        let mut channels = channels.lock().unwrap_or_else(PoisonError::into_inner);
+        match channels.get_mut(name) {
+            Some(stats) => f(stats),
+            None => {
+                let mut stats = ChannelStats::default();
+                f(&mut stats);
+                channels.insert(name.to_string(), stats);
+            }
+        }
+    }
+}
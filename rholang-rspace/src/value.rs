@@ -1,7 +1,8 @@
 //! Core value types for RSpace storage.
 
+use crate::name::quoted_name_text;
 use crate::ExecError;
-use num_bigint::BigInt;
+use num_bigint::{BigInt, BigUint, Sign};
 use num_rational::BigRational;
 use num_traits::Zero;
 use std::any::Any;
@@ -15,6 +16,9 @@ use std::fmt;
 pub enum ProcessState {
     /// Process is blocked, must not be executed.
     Wait,
+    /// Process is blocked until a message arrives on one of these channels, must not be
+    /// executed. See [`RSpace::waiting_on`](crate::RSpace::waiting_on).
+    WaitOn(Vec<String>),
     /// Process is eligible for execution.
     Ready,
     /// Process finished successfully with a final value (terminal state).
@@ -85,6 +89,21 @@ impl PartialEq for Box<dyn ProcessHolder> {
 /// - `FixedPoint` — shifted bigints with fixed decimal scale (suffix `p<digits>`)
 ///
 /// No implicit coercion between types. All binary ops require matching types.
+///
+/// # Ordering
+///
+/// Values of different types are ordered by type, in this order:
+///
+/// ```text
+/// Nil < Bool < Int < BigInt < BigRat < FixedPoint < Float
+///     < Str < Name < Tuple < List < Set < Map < PathMap < Par
+/// ```
+///
+/// Values of the same type compare naturally; strings and names by their
+/// bytes, and tuples, lists, maps and path maps lexicographically. Sets are
+/// compared as their sorted elements. [`Value::total_cmp`] orders any two
+/// values. `PartialOrd` follows it, except that two numbers of different types
+/// (or fixed points of different scales) do not compare, nor does NaN.
 #[derive(Clone, Debug)]
 pub enum Value {
     /// 64-bit signed integer.
@@ -108,14 +127,22 @@ pub enum Value {
     Name(String),
     /// Ordered list of values.
     List(Vec<Value>),
-    /// Fixed-size tuple of values.
+    /// Fixed-size tuple of values. The empty tuple is the unit value `()`,
+    /// which is distinct from `Nil`.
     Tuple(Vec<Value>),
     /// Key-value map (preserves insertion order).
     Map(Vec<(Value, Value)>),
+    /// Set of values (preserves insertion order, no duplicates).
+    /// Build with [`Value::new_set`]; equality ignores the order.
+    Set(Vec<Value>),
+    /// Set of paths (preserves insertion order, no duplicates).
+    /// A `List` element is a multi-segment path; any other value is a
+    /// single-segment path.
+    PathMap(Vec<Value>),
     /// Parallel composition of processes.
     /// Use rholang-process utilities to work with these.
     Par(Vec<Box<dyn ProcessHolder>>),
-    /// Null/unit value.
+    /// The stopped process `Nil`.
     Nil,
 }
 
@@ -143,6 +170,10 @@ impl PartialEq for Value {
             (Value::List(a), Value::List(b)) => a == b,
             (Value::Tuple(a), Value::Tuple(b)) => a == b,
             (Value::Map(a), Value::Map(b)) => a == b,
+            (Value::Set(a), Value::Set(b)) => {
+                a.len() == b.len() && a.iter().all(|item| b.contains(item))
+            }
+            (Value::PathMap(a), Value::PathMap(b)) => a == b,
             (Value::Par(a), Value::Par(b)) => a == b,
             (Value::Nil, Value::Nil) => true,
             _ => false,
@@ -152,33 +183,101 @@ impl PartialEq for Value {
 
 impl PartialOrd for Value {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        if self.is_number() && other.is_number() && self.type_rank() != other.type_rank() {
+            return None; // no implicit coercion between numeric types
+        }
         match (self, other) {
-            (Value::Int(a), Value::Int(b)) => Some(a.cmp(b)),
             (Value::Float(a), Value::Float(b)) => a.partial_cmp(b), // NaN → None
-            (Value::BigInt(a), Value::BigInt(b)) => Some(a.cmp(b)),
-            (Value::BigRat(a), Value::BigRat(b)) => Some(a.cmp(b)),
-            (
-                Value::FixedPoint {
-                    unscaled: ua,
-                    scale: sa,
-                },
-                Value::FixedPoint {
-                    unscaled: ub,
-                    scale: sb,
-                },
-            ) => {
-                if sa != sb {
-                    None // cross-scale comparison not defined
+            // cross-scale comparison not defined
+            (Value::FixedPoint { scale: sa, .. }, Value::FixedPoint { scale: sb, .. })
+                if sa != sb =>
+            {
+                None
+            }
+            (Value::List(a), Value::List(b))
+            | (Value::Tuple(a), Value::Tuple(b))
+            | (Value::PathMap(a), Value::PathMap(b)) => a.iter().partial_cmp(b.iter()),
+            (Value::Map(a), Value::Map(b)) => a.iter().partial_cmp(b.iter()),
+            (Value::Set(a), Value::Set(b)) => {
+                if self == other {
+                    Some(Ordering::Equal)
                 } else {
-                    Some(ua.cmp(ub))
+                    sorted(a).partial_cmp(&sorted(b))
                 }
             }
-            (Value::Str(a), Value::Str(b)) => Some(a.cmp(b)),
-            _ => None,
+            (Value::Par(_), Value::Par(_)) => (self == other).then_some(Ordering::Equal),
+            _ => Some(self.total_cmp(other)),
         }
     }
 }
 
+/// References to `values`, sorted by [`Value::total_cmp`].
+fn sorted(values: &[Value]) -> Vec<&Value> {
+    let mut sorted: Vec<&Value> = values.iter().collect();
+    sorted.sort_by(|a, b| a.total_cmp(b));
+    sorted
+}
+
+/// References to the entries of a map, sorted by key, then value, with [`Value::total_cmp`].
+fn sorted_entries(entries: &[(Value, Value)]) -> Vec<&(Value, Value)> {
+    let mut sorted: Vec<&(Value, Value)> = entries.iter().collect();
+    sorted.sort_by(|(ka, va), (kb, vb)| ka.total_cmp(kb).then_with(|| va.total_cmp(vb)));
+    sorted
+}
+
+/// Lexicographic [`Value::total_cmp`] of two sequences.
+fn total_cmp_all<'a>(
+    a: impl IntoIterator<Item = &'a Value>,
+    b: impl IntoIterator<Item = &'a Value>,
+) -> Ordering {
+    let mut b = b.into_iter();
+    for x in a {
+        let Some(y) = b.next() else {
+            return Ordering::Greater;
+        };
+        match x.total_cmp(y) {
+            Ordering::Equal => {}
+            ordering => return ordering,
+        }
+    }
+    if b.next().is_some() {
+        Ordering::Less
+    } else {
+        Ordering::Equal
+    }
+}
+
+/// Compare `ua / 10^sa` with `ub / 10^sb` by value.
+///
+/// Operands are only rescaled once their leading digits are known to line up, so
+/// the power of ten involved is never larger than the operands themselves.
+fn fixed_point_cmp(ua: &BigInt, sa: u32, ub: &BigInt, sb: u32) -> Ordering {
+    if sa == sb {
+        return ua.cmp(ub);
+    }
+    let sign = ua.sign().cmp(&ub.sign());
+    if sign != Ordering::Equal || ua.is_zero() {
+        return sign;
+    }
+
+    // Position of the leading digit relative to the decimal point
+    let exponent = |u: &BigInt, s: u32| u.magnitude().to_string().len() as i64 - i64::from(s);
+    let magnitude = match exponent(ua, sa).cmp(&exponent(ub, sb)) {
+        Ordering::Equal if sa < sb => {
+            (ua.magnitude() * BigUint::from(10u8).pow(sb - sa)).cmp(ub.magnitude())
+        }
+        Ordering::Equal => ua
+            .magnitude()
+            .cmp(&(ub.magnitude() * BigUint::from(10u8).pow(sa - sb))),
+        ordering => ordering,
+    };
+    if ua.sign() == Sign::Minus {
+        magnitude.reverse()
+    } else {
+        magnitude
+    }
+}
+
 impl Value {
     /// Try to extract an integer value.
     pub fn as_int(&self) -> Option<i64> {
@@ -252,27 +351,114 @@ impl Value {
             Value::BigRat(_) => "BigRat",
             Value::FixedPoint { .. } => "FixedPoint",
             Value::Bool(_) => "Bool",
-            Value::Str(_) => "Str",
+            Value::Str(_) => "String",
             Value::Name(_) => "Name",
             Value::List(_) => "List",
             Value::Tuple(_) => "Tuple",
             Value::Map(_) => "Map",
+            Value::Set(_) => "Set",
+            Value::PathMap(_) => "PathMap",
             Value::Par(_) => "Par",
             Value::Nil => "Nil",
         }
     }
 
+    /// A total order over all values, as described under [Ordering](Value#ordering).
+    ///
+    /// Use this to sort values of mixed types. `Value` is not `Ord` because
+    /// `==` follows IEEE 754 for floats; here floats are ordered by
+    /// [`f64::total_cmp`], so NaN equals itself and `-0.0 < 0.0`. Fixed points
+    /// are ordered by value, then by scale. Processes in a `Par` are only
+    /// counted, so two different `Par` values of the same size compare equal.
+    pub fn total_cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Value::Int(a), Value::Int(b)) => a.cmp(b),
+            (Value::Float(a), Value::Float(b)) => a.total_cmp(b),
+            (Value::BigInt(a), Value::BigInt(b)) => a.cmp(b),
+            (Value::BigRat(a), Value::BigRat(b)) => a.cmp(b),
+            (
+                Value::FixedPoint {
+                    unscaled: ua,
+                    scale: sa,
+                },
+                Value::FixedPoint {
+                    unscaled: ub,
+                    scale: sb,
+                },
+            ) => fixed_point_cmp(ua, *sa, ub, *sb).then(sa.cmp(sb)),
+            (Value::Bool(a), Value::Bool(b)) => a.cmp(b),
+            (Value::Str(a), Value::Str(b)) | (Value::Name(a), Value::Name(b)) => a.cmp(b),
+            (Value::List(a), Value::List(b))
+            | (Value::Tuple(a), Value::Tuple(b))
+            | (Value::PathMap(a), Value::PathMap(b)) => total_cmp_all(a, b),
+            (Value::Set(a), Value::Set(b)) => total_cmp_all(sorted(a), sorted(b)),
+            (Value::Map(a), Value::Map(b)) => total_cmp_all(
+                a.iter().flat_map(|(k, v)| [k, v]),
+                b.iter().flat_map(|(k, v)| [k, v]),
+            ),
+            (Value::Par(a), Value::Par(b)) => a.len().cmp(&b.len()),
+            (Value::Nil, Value::Nil) => Ordering::Equal,
+            _ => self.type_rank().cmp(&other.type_rank()),
+        }
+    }
+
+    /// Position of this value's type in the cross-type order.
+    fn type_rank(&self) -> u8 {
+        match self {
+            Value::Nil => 0,
+            Value::Bool(_) => 1,
+            Value::Int(_) => 2,
+            Value::BigInt(_) => 3,
+            Value::BigRat(_) => 4,
+            Value::FixedPoint { .. } => 5,
+            Value::Float(_) => 6,
+            Value::Str(_) => 7,
+            Value::Name(_) => 8,
+            Value::Tuple(_) => 9,
+            Value::List(_) => 10,
+            Value::Set(_) => 11,
+            Value::Map(_) => 12,
+            Value::PathMap(_) => 13,
+            Value::Par(_) => 14,
+        }
+    }
+
+    /// Whether this is one of the numeric types.
+    fn is_number(&self) -> bool {
+        matches!(
+            self,
+            Value::Int(_)
+                | Value::Float(_)
+                | Value::BigInt(_)
+                | Value::BigRat(_)
+                | Value::FixedPoint { .. }
+        )
+    }
+
     /// Create a BigRat value, returning zero for 0r.
     pub fn new_bigrat(r: BigRational) -> Value {
         Value::BigRat(r)
     }
 
+    /// Create a Set value, dropping duplicate items (the first one is kept).
+    pub fn new_set(items: impl IntoIterator<Item = Value>) -> Value {
+        let mut set = Vec::new();
+        for item in items {
+            if !set.contains(&item) {
+                set.push(item);
+            }
+        }
+        Value::Set(set)
+    }
+
     /// Create a BigRat zero.
     pub fn bigrat_zero() -> Value {
         Value::BigRat(BigRational::zero())
     }
 }
 
+/// Map entries and set items are written in [`Value::total_cmp`] order rather than storage
+/// order, so maps and sets built in a different order render the same.
 impl fmt::Display for Value {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
@@ -306,7 +492,10 @@ impl fmt::Display for Value {
             }
             Value::Bool(b) => write!(f, "{b}"),
             Value::Str(s) => write!(f, "\"{s}\""),
-            Value::Name(n) => write!(f, "@\"{n}\""),
+            Value::Name(n) => match quoted_name_text(n) {
+                Some(text) => write!(f, "@\"{text}\""),
+                None => f.write_str(n),
+            },
             Value::List(items) => {
                 let inner: Vec<String> = items.iter().map(|v| v.to_string()).collect();
                 write!(f, "[{}]", inner.join(", "))
@@ -316,21 +505,212 @@ impl fmt::Display for Value {
                 write!(f, "({})", inner.join(", "))
             }
             Value::Map(entries) => {
-                let inner: Vec<String> = entries
-                    .iter()
+                let inner: Vec<String> = sorted_entries(entries)
+                    .into_iter()
                     .map(|(k, v)| format!("{k}: {v}"))
                     .collect();
                 write!(f, "{{{}}}", inner.join(", "))
             }
+            Value::Set(items) => {
+                let inner: Vec<String> = sorted(items).iter().map(|v| v.to_string()).collect();
+                write!(f, "Set({})", inner.join(", "))
+            }
+            Value::PathMap(paths) => {
+                let inner: Vec<String> = paths.iter().map(|v| v.to_string()).collect();
+                write!(f, "{{| {} |}}", inner.join(", "))
+            }
             Value::Par(_) => write!(f, "<Par>"),
             Value::Nil => write!(f, "Nil"),
         }
     }
 }
 
+/// Marker written where [`Value::to_string_truncated`] cuts a value short.
+pub const ELLIPSIS: &str = "…";
+
+/// How a [`Value::Name`] is written by [`Value::to_string_truncated_with`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum NameRenderStyle {
+    /// `@"n"`, the [`Display`](fmt::Display) rendering.
+    #[default]
+    Quoted,
+    /// `@` followed by the 64-bit FNV-1a hash of the name in hex.
+    Hash,
+    /// The quoted process itself, without `@` and quotes.
+    Unquoted,
+    /// `@#i`, numbering distinct names in the order they appear in the value.
+    Index,
+}
+
+impl std::str::FromStr for NameRenderStyle {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "quoted" => Ok(NameRenderStyle::Quoted),
+            "hash" => Ok(NameRenderStyle::Hash),
+            "unquoted" => Ok(NameRenderStyle::Unquoted),
+            "index" => Ok(NameRenderStyle::Index),
+            other => Err(format!(
+                "unknown name style '{other}' (expected quoted, hash, unquoted or index)"
+            )),
+        }
+    }
+}
+
+/// Per-rendering state: the style and, for [`NameRenderStyle::Index`], the names seen so far.
+struct NameRenderer {
+    style: NameRenderStyle,
+    seen: Vec<String>,
+}
+
+impl NameRenderer {
+    fn write(&mut self, out: &mut impl fmt::Write, name: &str) -> fmt::Result {
+        match self.style {
+            NameRenderStyle::Quoted => match quoted_name_text(name) {
+                Some(text) => write!(out, "@\"{text}\""),
+                None => out.write_str(name),
+            },
+            NameRenderStyle::Hash => write!(out, "@{:016x}", fnv1a(name.as_bytes())),
+            NameRenderStyle::Unquoted => out.write_str(quoted_name_text(name).unwrap_or(name)),
+            NameRenderStyle::Index => {
+                let index = match self.seen.iter().position(|seen| *seen == name) {
+                    Some(index) => index,
+                    None => {
+                        self.seen.push(name.to_string());
+                        self.seen.len() - 1
+                    }
+                };
+                write!(out, "@#{index}")
+            }
+        }
+    }
+}
+
+/// 64-bit FNV-1a, chosen over `DefaultHasher` for output that is stable across Rust releases.
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf29ce484222325, |hash, &b| {
+        (hash ^ u64::from(b)).wrapping_mul(0x100000001b3)
+    })
+}
+
+impl Value {
+    /// Render like [`Display`](fmt::Display), but stop once the output reaches
+    /// `max_bytes`: remaining collection elements are summarized as `… (N more)`
+    /// and a scalar longer than `max_bytes` is cut with `…`.
+    ///
+    /// Elements past the budget are never formatted, so rendering a huge value
+    /// costs roughly `max_bytes`, not the size of the full rendering. The element
+    /// that crosses the budget, closing brackets and the summaries may take the
+    /// output past `max_bytes`, by at most about `max_bytes` again.
+    pub fn to_string_truncated(&self, max_bytes: usize) -> String {
+        self.to_string_truncated_with(max_bytes, NameRenderStyle::default())
+    }
+
+    /// Like [`to_string_truncated`](Value::to_string_truncated), writing names
+    /// in the given style.
+    pub fn to_string_truncated_with(&self, max_bytes: usize, style: NameRenderStyle) -> String {
+        let mut out = String::new();
+        let mut names = NameRenderer {
+            style,
+            seen: Vec::new(),
+        };
+        self.write_truncated(&mut out, max_bytes, &mut names);
+        out
+    }
+
+    fn write_truncated(&self, out: &mut String, max: usize, names: &mut NameRenderer) {
+        match self {
+            Value::List(items) => write_seq_truncated(out, max, "[", "]", items, |out, v| {
+                v.write_truncated(out, max, names)
+            }),
+            Value::Tuple(items) => write_seq_truncated(out, max, "(", ")", items, |out, v| {
+                v.write_truncated(out, max, names)
+            }),
+            Value::Map(entries) => {
+                let entries = sorted_entries(entries);
+                write_seq_truncated(out, max, "{", "}", &entries, |out, (k, v)| {
+                    k.write_truncated(out, max, names);
+                    out.push_str(": ");
+                    v.write_truncated(out, max, names);
+                })
+            }
+            Value::Set(items) => {
+                let items = sorted(items);
+                write_seq_truncated(out, max, "Set(", ")", &items, |out, v| {
+                    v.write_truncated(out, max, names)
+                })
+            }
+            Value::PathMap(paths) => {
+                write_seq_truncated(out, max, "{| ", " |}", paths, |out, v| {
+                    v.write_truncated(out, max, names)
+                })
+            }
+            scalar => {
+                use fmt::Write;
+                // a scalar is only cut if it alone exceeds the budget
+                let max = out.len() + max;
+                let mut bounded = BoundedWriter { out, max };
+                let written = match scalar {
+                    Value::Name(name) => names.write(&mut bounded, name),
+                    _ => write!(bounded, "{scalar}"),
+                };
+                if written.is_err() {
+                    out.push_str(ELLIPSIS);
+                }
+            }
+        }
+    }
+}
+
+fn write_seq_truncated<T>(
+    out: &mut String,
+    max: usize,
+    open: &str,
+    close: &str,
+    items: &[T],
+    mut write_item: impl FnMut(&mut String, &T),
+) {
+    out.push_str(open);
+    for (i, item) in items.iter().enumerate() {
+        if i > 0 {
+            out.push_str(", ");
+        }
+        if out.len() >= max {
+            out.push_str(&format!("{ELLIPSIS} ({} more)", items.len() - i));
+            break;
+        }
+        write_item(out, item);
+    }
+    out.push_str(close);
+}
+
+/// Appends to `out` until it holds `max` bytes, then fails.
+struct BoundedWriter<'a> {
+    out: &'a mut String,
+    max: usize,
+}
+
+impl fmt::Write for BoundedWriter<'_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let room = self.max.saturating_sub(self.out.len());
+        if s.len() <= room {
+            self.out.push_str(s);
+            return Ok(());
+        }
+        let mut end = room;
+        while !s.is_char_boundary(end) {
+            end -= 1;
+        }
+        self.out.push_str(&s[..end]);
+        Err(fmt::Error)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::quoted_name;
     use num_bigint::BigInt;
     use num_rational::BigRational;
 
@@ -396,6 +776,22 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_new_set_drops_duplicates() {
+        let set = Value::new_set([Value::Int(1), Value::Int(1), Value::Int(2)]);
+        assert_eq!(set, Value::Set(vec![Value::Int(1), Value::Int(2)]));
+        assert_eq!(set.to_string(), "Set(1, 2)");
+    }
+
+    #[test]
+    fn test_set_equality_ignores_order() {
+        let a = Value::new_set([Value::Int(1), Value::Int(2), Value::Int(3)]);
+        let b = Value::new_set([Value::Int(3), Value::Int(1), Value::Int(2)]);
+        assert_eq!(a, b);
+        assert_ne!(a, Value::new_set([Value::Int(1), Value::Int(2)]));
+        assert_ne!(a, Value::List(vec![Value::Int(1), Value::Int(2), Value::Int(3)]));
+    }
+
     #[test]
     fn test_float_equality() {
         assert_eq!(Value::Float(1.0), Value::Float(1.0));
@@ -515,12 +911,195 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_strings_and_collections_ordering() {
+        let str = |s: &str| Value::Str(s.to_string());
+        assert!(str("a") < str("b"));
+        assert!(Value::List(vec![Value::Int(1)]) < Value::List(vec![Value::Int(2)]));
+        assert!(Value::List(vec![Value::Int(1)]) < Value::List(vec![Value::Int(1), Value::Nil]));
+        assert!(Value::Tuple(vec![str("a"), Value::Int(9)]) < Value::Tuple(vec![str("b")]));
+        assert!(Value::Bool(false) < Value::Bool(true));
+        // sets compare as their sorted elements
+        let set = |items: &[i64]| Value::Set(items.iter().copied().map(Value::Int).collect());
+        assert_eq!(
+            set(&[2, 1]).partial_cmp(&set(&[1, 2])),
+            Some(Ordering::Equal)
+        );
+        assert!(set(&[2, 1]) < set(&[3, 1]));
+        // an element that does not compare makes the collection not compare
+        assert_eq!(
+            Value::List(vec![Value::Int(1)]).partial_cmp(&Value::List(vec![Value::Float(1.0)])),
+            None
+        );
+    }
+
+    #[test]
+    fn test_cross_type_ordering() {
+        assert!(Value::Int(100) < Value::Str("a".to_string()));
+        assert!(Value::Nil < Value::Bool(false));
+        assert!(Value::Str("z".to_string()) < Value::List(vec![]));
+        assert!(Value::Tuple(vec![]) < Value::List(vec![]));
+
+        let mut values = [
+            Value::List(vec![Value::Int(1)]),
+            Value::Str("b".to_string()),
+            Value::Float(f64::NAN),
+            Value::Int(2),
+            Value::Float(-1.0),
+            Value::Str("a".to_string()),
+            Value::Nil,
+            Value::Int(-5),
+        ];
+        values.sort_by(Value::total_cmp);
+        assert_eq!(
+            values.iter().map(Value::to_string).collect::<Vec<_>>(),
+            ["Nil", "-5", "2", "-1f64", "NaNf64", "\"a\"", "\"b\"", "[1]"]
+        );
+    }
+
+    #[test]
+    fn test_total_cmp_within_numeric_types() {
+        assert_eq!(
+            Value::Float(f64::NAN).total_cmp(&Value::Float(f64::NAN)),
+            Ordering::Equal
+        );
+        assert_eq!(
+            Value::Float(-0.0).total_cmp(&Value::Float(0.0)),
+            Ordering::Less
+        );
+        // 1.0p1 vs 0.10p2: by value first
+        let one = Value::FixedPoint {
+            unscaled: BigInt::from(10),
+            scale: 1,
+        };
+        let tenth = Value::FixedPoint {
+            unscaled: BigInt::from(10),
+            scale: 2,
+        };
+        assert_eq!(one.total_cmp(&tenth), Ordering::Greater);
+        // ...then by scale
+        let one_p2 = Value::FixedPoint {
+            unscaled: BigInt::from(100),
+            scale: 2,
+        };
+        assert_eq!(one.total_cmp(&one_p2), Ordering::Less);
+    }
+
+    #[test]
+    fn test_fixed_point_total_cmp_with_distant_scales() {
+        let fixed = |unscaled: i64, scale: u32| Value::FixedPoint {
+            unscaled: BigInt::from(unscaled),
+            scale,
+        };
+
+        // the leading digits settle these without computing 10^4294967295
+        assert_eq!(
+            fixed(1, 0).total_cmp(&fixed(1, u32::MAX)),
+            Ordering::Greater
+        );
+        assert_eq!(fixed(-1, 0).total_cmp(&fixed(-1, u32::MAX)), Ordering::Less);
+        assert_eq!(fixed(-1, 0).total_cmp(&fixed(1, u32::MAX)), Ordering::Less);
+        assert_eq!(fixed(0, 0).total_cmp(&fixed(0, u32::MAX)), Ordering::Less);
+        assert_eq!(
+            fixed(5, u32::MAX).total_cmp(&fixed(7, u32::MAX)),
+            Ordering::Less
+        );
+
+        // equal leading positions fall back to rescaling
+        assert_eq!(fixed(15, 1).total_cmp(&fixed(149, 2)), Ordering::Greater);
+        assert_eq!(fixed(-15, 1).total_cmp(&fixed(-151, 2)), Ordering::Greater);
+        assert_eq!(fixed(15, 1).total_cmp(&fixed(150, 2)), Ordering::Less);
+    }
+
+    #[test]
+    fn test_to_string_truncated() {
+        let small = Value::List(vec![Value::Int(1), Value::Str("a".to_string())]);
+        assert_eq!(small.to_string_truncated(100), small.to_string());
+
+        let big = Value::List((0..10_000).map(Value::Int).collect());
+        let rendered = big.to_string_truncated(64);
+        assert!(rendered.len() < 128, "{rendered}");
+        assert!(rendered.starts_with("[0, 1, 2, "), "{rendered}");
+
+        // shown elements plus the elided count cover the whole list
+        let (shown, rest) = rendered.split_once(ELLIPSIS).unwrap();
+        let shown = shown.trim_end_matches(", ").split(", ").count();
+        let elided: usize = rest
+            .trim_start_matches(" (")
+            .trim_end_matches(" more)]")
+            .parse()
+            .unwrap();
+        assert_eq!(shown + elided, 10_000);
+
+        let long = Value::Str("x".repeat(1_000));
+        assert_eq!(long.to_string_truncated(5), format!("\"xxxx{ELLIPSIS}"));
+
+        let nested = Value::Map(vec![(
+            Value::Str("k".to_string()),
+            Value::Tuple((0..100).map(Value::Int).collect()),
+        )]);
+        assert!(nested.to_string_truncated(20).ends_with("more))}"));
+    }
+
+    #[test]
+    fn test_maps_and_sets_render_in_canonical_order() {
+        let str = |s: &str| Value::Str(s.to_string());
+        let a = Value::Map(vec![(Value::Int(2), str("b")), (Value::Int(1), str("a"))]);
+        let b = Value::Map(vec![(Value::Int(1), str("a")), (Value::Int(2), str("b"))]);
+        assert_eq!(a.to_string(), "{1: \"a\", 2: \"b\"}");
+        assert_eq!(a.to_string(), b.to_string());
+        assert_eq!(a.to_string_truncated(100), b.to_string_truncated(100));
+
+        let set = Value::new_set([Value::Int(3), Value::Int(1), Value::Int(2)]);
+        assert_eq!(set.to_string(), "Set(1, 2, 3)");
+        assert_eq!(set.to_string_truncated(100), "Set(1, 2, 3)");
+        // storage order is left alone
+        assert_eq!(
+            a,
+            Value::Map(vec![(Value::Int(2), str("b")), (Value::Int(1), str("a"))])
+        );
+    }
+
+    #[test]
+    fn test_name_render_styles() {
+        let name = Value::Name("@0:ch".to_string());
+        let render = |style| name.to_string_truncated_with(100, style);
+
+        assert_eq!(render(NameRenderStyle::Quoted), "@0:ch");
+        assert_eq!(render(NameRenderStyle::Quoted), name.to_string());
+        assert_eq!(render(NameRenderStyle::Unquoted), "@0:ch");
+        assert_eq!(render(NameRenderStyle::Index), "@#0");
+
+        let hashed = render(NameRenderStyle::Hash);
+        assert_eq!(hashed.len(), 17, "{hashed}");
+        assert!(hashed.starts_with('@'));
+        assert!(hashed[1..].chars().all(|c| c.is_ascii_hexdigit()));
+        assert_eq!(
+            Value::Name("".to_string()).to_string_truncated_with(100, NameRenderStyle::Hash),
+            "@cbf29ce484222325"
+        );
+
+        let names = Value::List(vec![
+            Value::Name("a".to_string()),
+            Value::Name("b".to_string()),
+            Value::Name("a".to_string()),
+        ]);
+        assert_eq!(
+            names.to_string_truncated_with(100, NameRenderStyle::Index),
+            "[@#0, @#1, @#0]"
+        );
+
+        assert_eq!("hash".parse(), Ok(NameRenderStyle::Hash));
+        assert!("bogus".parse::<NameRenderStyle>().is_err());
+    }
+
     #[test]
     fn test_type_name() {
         assert_eq!(Value::Int(1).type_name(), "Int");
         assert_eq!(Value::Float(1.0).type_name(), "Float");
         assert_eq!(Value::BigInt(BigInt::from(1)).type_name(), "BigInt");
         assert_eq!(Value::Nil.type_name(), "Nil");
+        assert_eq!(Value::Str("s".to_string()).type_name(), "String");
     }
 
     #[test]
@@ -586,8 +1165,18 @@ mod tests {
         assert_eq!(Value::Bool(true).to_string(), "true");
         assert_eq!(Value::Str("hello".into()).to_string(), "\"hello\"");
         assert_eq!(Value::Name("ch".into()).to_string(), "@\"ch\"");
+        assert_eq!(Value::Name(quoted_name("@3:1")).to_string(), "@\"@3:1\"");
+        assert_eq!(Value::Name("@3:1".into()).to_string(), "@3:1");
         assert_eq!(Value::Nil.to_string(), "Nil");
         assert_eq!(Value::List(vec![Value::Int(1), Value::Int(2)]).to_string(), "[1, 2]");
         assert_eq!(Value::Tuple(vec![Value::Int(1), Value::Bool(true)]).to_string(), "(1, true)");
+        assert_eq!(
+            Value::PathMap(vec![
+                Value::Str("a".into()),
+                Value::List(vec![Value::Str("b".into()), Value::Str("c".into())]),
+            ])
+            .to_string(),
+            "{| \"a\", [\"b\", \"c\"] |}"
+        );
     }
 }
@@ -0,0 +1,48 @@
+// Tests for recovering the global RSpace after a closure panicked while holding it.
+// Kept in their own test binary: poisoning the global would otherwise interfere
+// with other tests sharing the process.
+
+use rholang_rspace::{
+    try_with_global_rspace, try_with_global_rspace_mut, with_global_rspace, with_global_rspace_mut,
+    Value,
+};
+use std::panic;
+
+#[test]
+fn try_variants_recover_from_poisoned_global() {
+    with_global_rspace_mut(|rspace| {
+        rspace.tell("before_panic", Value::Int(1)).unwrap();
+    });
+
+    // Panic while holding the global lock, poisoning the mutex
+    let outcome = panic::catch_unwind(|| {
+        with_global_rspace_mut(|rspace| {
+            rspace.tell("during_panic", Value::Int(2)).unwrap();
+            panic!("closure failed mid-update");
+        })
+    });
+    assert!(outcome.is_err());
+
+    // The first access after the panic reports the poisoning but still runs
+    let recovered = try_with_global_rspace_mut(|rspace| {
+        rspace.tell("after_panic", Value::Int(3)).unwrap();
+        rspace.peek("before_panic").unwrap()
+    });
+    let value = recovered.expect_err("expected poisoned lock to be reported");
+    assert_eq!(value.into_inner(), Some(Value::Int(1)));
+
+    // Poison was cleared: both try_ and regular variants work again
+    let peeked = try_with_global_rspace(|rspace| {
+        (
+            rspace.peek("during_panic").unwrap(),
+            rspace.peek("after_panic").unwrap(),
+        )
+    });
+    assert_eq!(
+        peeked.ok(),
+        Some((Some(Value::Int(2)), Some(Value::Int(3))))
+    );
+    with_global_rspace(|rspace| {
+        assert_eq!(rspace.peek("after_panic").unwrap(), Some(Value::Int(3)));
+    });
+}
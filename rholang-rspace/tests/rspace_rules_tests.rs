@@ -2,12 +2,14 @@
 //!
 //! This test module verifies:
 //! - RSpace Interface (tell, ask, peek, reset)
+//! - Per-channel operation statistics
 //! - Entry types (Channel, Process, Value)
 //! - Stored Values (all Value variants)
 //! - Process Storage (Value::Par)
 //! - Process States (wait, ready, value, error)
 //! - Execution Flow with RSpace
-//! - Both RSpace Implementations (InMemoryRSpace, PathMapRSpace)
+//! - Concurrent tell/ask through `SharedRSpace` (no lost or duplicated values)
+//! - All RSpace Implementations (InMemoryRSpace, PathMapRSpace, BTreeRSpace with `btree-impl`)
 //! - FIFO ordering
 
 use anyhow::Result;
@@ -15,10 +17,13 @@ use rholang_bytecode::core::instructions::Instruction;
 use rholang_bytecode::core::Opcode;
 use rholang_process::{Process, ProcessEvent};
 use rholang_rspace::{
-    Entry, InMemoryRSpace, PathMapRSpace, ProcessHolder, ProcessState, RSpace, Value,
+    ChannelStats, Entry, InMemoryRSpace, PathMapRSpace, ProcessHolder, ProcessState, RSpace, Value,
 };
 use std::sync::Arc;
 
+#[cfg(feature = "btree-impl")]
+use rholang_rspace::BTreeRSpace;
+
 // =============================================================================
 // RSpace Interface Tests (tell, ask, peek, reset)
 // =============================================================================
@@ -392,6 +397,52 @@ macro_rules! rspace_interface_tests {
                 let rspace = make_rspace();
                 assert!(!rspace.is_solved("missing"));
             }
+
+            #[test]
+            fn test_stats_count_operations() -> Result<()> {
+                let mut rspace = make_rspace();
+                rspace.enable_stats();
+
+                rspace.tell("hot", Value::Int(1))?;
+                rspace.tell("hot", Value::Int(2))?;
+                rspace.tell("hot", Value::Int(3))?;
+                rspace.peek("hot")?;
+                rspace.ask("hot")?;
+                rspace.ask("hot")?;
+                rspace.tell("hot", Value::Int(4))?;
+                rspace.ask("cold")?;
+
+                let stats = rspace.stats();
+                assert_eq!(
+                    stats.get("hot"),
+                    Some(&ChannelStats {
+                        tells: 4,
+                        asks: 2,
+                        peeks: 1,
+                        max_depth: 3,
+                    })
+                );
+                assert_eq!(
+                    stats.get("cold"),
+                    Some(&ChannelStats {
+                        asks: 1,
+                        ..ChannelStats::default()
+                    })
+                );
+                assert_eq!(stats.len(), 2);
+                Ok(())
+            }
+
+            #[test]
+            fn test_stats_disabled_by_default() -> Result<()> {
+                let mut rspace = make_rspace();
+
+                rspace.tell("test", Value::Int(1))?;
+                rspace.ask("test")?;
+
+                assert!(rspace.stats().is_empty());
+                Ok(())
+            }
         }
     };
 }
@@ -399,6 +450,8 @@ macro_rules! rspace_interface_tests {
 // Generate tests for both implementations
 rspace_interface_tests!(InMemoryRSpace, in_memory_rspace_tests);
 rspace_interface_tests!(PathMapRSpace, path_map_rspace_tests);
+#[cfg(feature = "btree-impl")]
+rspace_interface_tests!(BTreeRSpace, btree_rspace_tests);
 
 // =============================================================================
 // Process State Tests (wait, ready, value, error)
@@ -537,6 +590,28 @@ mod process_registration_tests {
         let rspace: Box<dyn RSpace> = Box::new(InMemoryRSpace::new());
         assert!(rspace.get_process_state("missing").is_none());
     }
+
+    #[test]
+    fn test_waiting_on_lists_processes_blocked_on_channel() -> Result<()> {
+        let mut rspace: Box<dyn RSpace> = Box::new(InMemoryRSpace::new());
+
+        rspace.register_process(
+            "reader",
+            ProcessState::WaitOn(vec!["inbox".to_string(), "control".to_string()]),
+        )?;
+        rspace.register_process("logger", ProcessState::WaitOn(vec!["inbox".to_string()]))?;
+        rspace.register_process("idle", ProcessState::Wait)?;
+        rspace.tell("inbox", Value::Int(1))?;
+
+        assert_eq!(rspace.waiting_on("inbox"), ["logger", "reader"]);
+        assert_eq!(rspace.waiting_on("control"), ["reader"]);
+        assert!(rspace.waiting_on("other").is_empty());
+
+        // Woken processes no longer wait
+        rspace.update_process("logger", ProcessState::Ready)?;
+        assert_eq!(rspace.waiting_on("inbox"), ["reader"]);
+        Ok(())
+    }
 }
 
 // =============================================================================
@@ -932,6 +1007,128 @@ mod concurrency_tests {
     }
 }
 
+// =============================================================================
+// Concurrency Invariant Tests (per implementation)
+// =============================================================================
+
+macro_rules! rspace_concurrency_tests {
+    ($rspace_type:ty, $mod_name:ident) => {
+        mod $mod_name {
+            use super::*;
+            use rholang_rspace::shared_rspace;
+            use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+            use std::thread;
+
+            const PRODUCERS: i64 = 4;
+            const CONSUMERS: usize = 4;
+            const PER_PRODUCER: i64 = 500;
+            const CHANNELS: i64 = 3;
+            const TOTAL: usize = (PRODUCERS * PER_PRODUCER) as usize;
+
+            fn channel(producer: i64) -> String {
+                format!("work/{}", producer % CHANNELS)
+            }
+
+            #[test]
+            fn test_concurrent_tell_ask_loses_and_duplicates_nothing() -> Result<()> {
+                let rspace = shared_rspace(<$rspace_type>::new());
+                let received = Arc::new(AtomicUsize::new(0));
+                let produced = Arc::new(AtomicBool::new(false));
+
+                let producers: Vec<_> = (0..PRODUCERS)
+                    .map(|producer| {
+                        let rspace = rspace.clone();
+                        thread::spawn(move || {
+                            for i in 0..PER_PRODUCER {
+                                let value = Value::Int(producer * PER_PRODUCER + i);
+                                rspace
+                                    .lock()
+                                    .unwrap()
+                                    .tell(&channel(producer), value)
+                                    .unwrap();
+                            }
+                        })
+                    })
+                    .collect();
+
+                let consumers: Vec<_> = (0..CONSUMERS)
+                    .map(|consumer| {
+                        let rspace = rspace.clone();
+                        let received = received.clone();
+                        let produced = produced.clone();
+                        thread::spawn(move || {
+                            let mut taken = Vec::new();
+                            let mut next = consumer as i64;
+                            // Stop once everything was received or, should values get
+                            // lost, once all producers are done and every channel is empty
+                            let mut empty_in_a_row = 0;
+                            while received.load(Ordering::SeqCst) < TOTAL
+                                && !(produced.load(Ordering::SeqCst) && empty_in_a_row > CHANNELS)
+                            {
+                                let asked = rspace.lock().unwrap().ask(&channel(next)).unwrap();
+                                next += 1;
+                                match asked {
+                                    Some(Value::Int(n)) => {
+                                        received.fetch_add(1, Ordering::SeqCst);
+                                        taken.push(n);
+                                        empty_in_a_row = 0;
+                                    }
+                                    Some(other) => panic!("unexpected value {other:?}"),
+                                    None => {
+                                        empty_in_a_row += 1;
+                                        thread::yield_now();
+                                    }
+                                }
+                            }
+                            taken
+                        })
+                    })
+                    .collect();
+
+                for producer in producers {
+                    producer.join().unwrap();
+                }
+                produced.store(true, Ordering::SeqCst);
+                let taken: Vec<Vec<i64>> = consumers
+                    .into_iter()
+                    .map(|consumer| consumer.join().unwrap())
+                    .collect();
+
+                // Each channel is FIFO, so every consumer sees a producer's values in order
+                for values in &taken {
+                    for producer in 0..PRODUCERS {
+                        let from_producer: Vec<i64> = values
+                            .iter()
+                            .copied()
+                            .filter(|n| n / PER_PRODUCER == producer)
+                            .collect();
+                        assert!(
+                            from_producer.windows(2).all(|pair| pair[0] < pair[1]),
+                            "values of producer {producer} out of order: {from_producer:?}"
+                        );
+                    }
+                }
+
+                let mut all: Vec<i64> = taken.into_iter().flatten().collect();
+                all.sort_unstable();
+                let expected: Vec<i64> = (0..PRODUCERS * PER_PRODUCER).collect();
+                assert_eq!(all, expected, "values were lost or duplicated");
+
+                let mut guard = rspace.lock().unwrap();
+                for producer in 0..CHANNELS {
+                    assert_eq!(guard.ask(&channel(producer))?, None);
+                }
+                Ok(())
+            }
+        }
+    };
+}
+
+rspace_concurrency_tests!(InMemoryRSpace, in_memory_concurrency_tests);
+rspace_concurrency_tests!(PathMapRSpace, path_map_concurrency_tests);
+#[cfg(feature = "btree-impl")]
+rspace_concurrency_tests!(BTreeRSpace, btree_concurrency_tests);
+
 // =============================================================================
 // Entry Solved State Tests
 // =============================================================================
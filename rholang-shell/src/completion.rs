@@ -0,0 +1,122 @@
+//! Completion candidates for the identifier being typed in the shell.
+//!
+//! Keywords are always offered. When the code parses, possibly after closing the
+//! brackets left open by a partially typed process, names bound by the scopes
+//! enclosing the cursor are offered as well, ahead of the keywords.
+//!
+//! `rustyline_async` has no hook for a completer, so the interactive shell
+//! exposes this through the `.complete` command rather than the Tab key.
+
+use librho::sem::{FactPass, ResolverPass, SemanticDb};
+use rholang_parser::{RholangParser, SourcePos};
+use validated::Validated;
+
+/// Rholang keywords offered for completion
+pub const KEYWORDS: &[&str] = &[
+    "new", "for", "contract", "match", "if", "else", "select", "bundle", "Nil", "true", "false",
+];
+
+/// Keywords starting with `prefix`, in the order of [`KEYWORDS`]
+pub fn complete_keyword(prefix: &str) -> Vec<&'static str> {
+    KEYWORDS
+        .iter()
+        .copied()
+        .filter(|keyword| keyword.starts_with(prefix))
+        .collect()
+}
+
+/// Candidates for the identifier that ends at byte offset `cursor` of `code`:
+/// the in-scope names starting with it, sorted, followed by the matching keywords
+pub fn complete(code: &str, cursor: usize) -> Vec<String> {
+    let prefix = identifier_before(code, cursor);
+    let mut candidates = names_in_scope(code, cursor);
+    candidates.retain(|name| name.starts_with(prefix));
+    candidates.sort();
+    candidates.dedup();
+    candidates.extend(
+        complete_keyword(prefix)
+            .into_iter()
+            .filter(|keyword| !candidates.iter().any(|name| name == keyword))
+            .map(str::to_string)
+            .collect::<Vec<_>>(),
+    );
+    candidates
+}
+
+/// The identifier characters immediately preceding `cursor`
+fn identifier_before(code: &str, cursor: usize) -> &str {
+    let head = &code[..cursor];
+    let start = head
+        .char_indices()
+        .rev()
+        .find(|&(_, c)| !(c.is_alphanumeric() || c == '_' || c == '\''))
+        .map_or(0, |(i, c)| i + c.len_utf8());
+    &head[start..]
+}
+
+/// Names bound by every scope whose span contains `cursor`, or none if `code`
+/// does not parse even with its open brackets closed
+fn names_in_scope(code: &str, cursor: usize) -> Vec<String> {
+    let parser = RholangParser::new();
+    let closed = format!("{code}{}", closing_brackets(code));
+    let procs = match parser.parse(code) {
+        Validated::Good(procs) => procs,
+        Validated::Fail(_) => match parser.parse(&closed) {
+            Validated::Good(procs) => procs,
+            Validated::Fail(_) => return Vec::new(),
+        },
+    };
+
+    let mut db = SemanticDb::new();
+    for proc in procs.iter() {
+        let root = db.build_index(proc);
+        ResolverPass::new(root).run(&mut db);
+    }
+
+    let pos = source_pos(code, cursor);
+    db.scopes_full()
+        .filter(|(pid, _)| db.get(*pid).is_some_and(|proc| proc.span.contains(pos)))
+        .flat_map(|(_, scope)| db.binders(scope))
+        .filter_map(|binder| db.resolve_symbol_owned(binder.name))
+        .collect()
+}
+
+/// The 1-based line and byte column of offset `cursor`
+fn source_pos(code: &str, cursor: usize) -> SourcePos {
+    let head = &code[..cursor];
+    let line_start = head.rfind('\n').map_or(0, |i| i + 1);
+    SourcePos {
+        line: head.matches('\n').count() + 1,
+        col: cursor - line_start + 1,
+    }
+}
+
+/// The brackets that close those left open in `code`, innermost first.
+/// Brackets inside string literals are ignored.
+fn closing_brackets(code: &str) -> String {
+    let mut open = Vec::new();
+    let mut chars = code.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '(' => open.push(')'),
+            '[' => open.push(']'),
+            '{' => open.push('}'),
+            ')' | ']' | '}' => {
+                open.pop();
+            }
+            '"' => {
+                while let Some(c) = chars.next() {
+                    match c {
+                        '\\' => {
+                            chars.next();
+                        }
+                        '"' => break,
+                        _ => {}
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+    open.iter().rev().collect()
+}
@@ -0,0 +1,102 @@
+//! Stepwise execution support for the `.step` / `.continue` shell commands.
+
+use anyhow::Result;
+use rholang_compiler::Process;
+use rholang_vm::api::Value as VmValue;
+
+/// Outcome of advancing a [`StepSession`]
+#[derive(Debug, Clone, PartialEq)]
+pub enum StepOutcome {
+    /// One instruction was executed; carries the rendered trace line
+    Stepped(String),
+    /// The process halted with the given value
+    Finished(VmValue),
+}
+
+/// A compiled process being executed one VM instruction at a time
+pub struct StepSession {
+    process: Process,
+    /// Program counter of the next instruction, `None` once halted
+    pc: Option<usize>,
+    /// Number of instructions executed so far
+    steps: usize,
+}
+
+impl StepSession {
+    /// Start a session positioned before the first instruction of `process`
+    pub fn new(mut process: Process) -> Self {
+        process.vm.reset_stack();
+        StepSession {
+            process,
+            pc: Some(0),
+            steps: 0,
+        }
+    }
+
+    /// Total number of instructions in the process being stepped
+    pub fn len(&self) -> usize {
+        self.process.code.len()
+    }
+
+    /// Returns true if the process has no instructions
+    pub fn is_empty(&self) -> bool {
+        self.process.code.is_empty()
+    }
+
+    /// Returns true once the process has halted
+    pub fn is_finished(&self) -> bool {
+        self.pc.is_none_or(|pc| pc >= self.process.code.len())
+    }
+
+    /// Execute the next instruction.
+    ///
+    /// Returns the trace line for the executed instruction (opcode and resulting
+    /// stack), or the final value if the process had already reached its end.
+    pub fn step(&mut self) -> Result<StepOutcome> {
+        let Some(pc) = self.pc.filter(|pc| *pc < self.process.code.len()) else {
+            self.pc = None;
+            return Ok(StepOutcome::Finished(self.result()));
+        };
+
+        let inst = self.process.code[pc];
+        self.pc = self.process.step_at(pc)?;
+        self.steps += 1;
+
+        Ok(StepOutcome::Stepped(format!(
+            "[step {}] {:04}: {:?} | stack: {}",
+            self.steps,
+            pc,
+            inst,
+            self.render_stack()
+        )))
+    }
+
+    /// Run the remaining instructions and return the final value
+    pub fn finish(&mut self) -> Result<VmValue> {
+        while !self.is_finished() {
+            self.step()?;
+        }
+        self.pc = None;
+        Ok(self.result())
+    }
+
+    fn result(&self) -> VmValue {
+        self.process
+            .vm
+            .stack
+            .last()
+            .cloned()
+            .unwrap_or(VmValue::Nil)
+    }
+
+    fn render_stack(&self) -> String {
+        let items: Vec<String> = self
+            .process
+            .vm
+            .stack
+            .iter()
+            .map(|v| v.to_string())
+            .collect();
+        format!("[{}]", items.join(", "))
+    }
+}
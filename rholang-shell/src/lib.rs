@@ -1,9 +1,14 @@
+pub mod completion;
+pub mod debugger;
 pub mod providers;
+pub mod types;
 
 use anyhow::Result;
 use bracket_parser::{BracketParser, BracketState};
 use clap::Parser;
+use debugger::{StepOutcome, StepSession};
 use providers::{InterpretationResult, InterpreterProvider};
+use rholang_vm::api::{Entry, Value};
 use rustyline_async::{Readline, ReadlineEvent};
 use std::io::Write;
 
@@ -29,6 +34,36 @@ pub struct Args {
     /// Show both disassembly and execution result (use with -e or -f)
     #[arg(short = 'b', long = "both")]
     pub both: bool,
+
+    /// Re-run the file given with -f whenever it changes on disk, until Ctrl+C
+    #[arg(short = 'w', long = "watch", requires = "file")]
+    pub watch: bool,
+
+    /// Truncate rendered results longer than this many bytes
+    #[arg(
+        long = "max-output",
+        value_name = "BYTES",
+        default_value_t = providers::DEFAULT_MAX_OUTPUT
+    )]
+    pub max_output: usize,
+
+    /// How names are written in results: quoted, hash, unquoted or index
+    #[arg(long = "name-style", value_name = "STYLE", default_value = "quoted")]
+    pub name_style: rholang_vm::api::NameRenderStyle,
+
+    /// Run every top-level process even after one fails, printing each result or
+    /// error on its own line
+    #[arg(long = "keep-going")]
+    pub keep_going: bool,
+
+    /// Treat semantic warnings as errors: `.validate` fails on any warning, and
+    /// -e or -f validate the code first and stop if that fails
+    #[arg(long = "deny-warnings")]
+    pub deny_warnings: bool,
+
+    /// Validate every .rho file under the directory, print a summary and exit
+    #[arg(long = "lint-dir", value_name = "PATH")]
+    pub lint_dir: Option<std::path::PathBuf>,
 }
 
 pub fn help_message() -> String {
@@ -39,10 +74,21 @@ pub fn help_message() -> String {
         + "\n  .reset or Ctrl+C - Interrupt current input (clear buffer)"
         + "\n  .load <file> - Load code from file into the buffer"
         + "\n  .dia - Disassemble bytecode for the code in the buffer"
-        + "\n  .validate - Validate code in buffer with all rholang-lib validators"
+        + "\n  .save-bytecode <file> - Compile the buffer and write its bytecode to a .rhbc file"
+        + "\n  .load-bytecode <file> - Execute a precompiled .rhbc file and show the result"
+        + "\n  .diff <file1> <file2> - Compare the ASTs of two files, ignoring layout"
+        + "\n  .type [<expr>] - Show the static type of an expression or the buffer"
+        + "\n  .complete <prefix> - List keywords and in-scope names completing the buffer"
+        + "\n  .step - Execute the buffer one VM instruction at a time (Enter advances)"
+        + "\n  .continue - Finish the current .step session"
+        + "\n  .validate [--deny-warnings] - Validate code in buffer with all rholang-lib validators"
         + "\n  .validate-unused - Validate only unused-variable diagnostics"
         + "\n  .validate-elab - Validate only elaboration diagnostics (types/joins/consumption/patterns)"
         + "\n  .validate-resolver - Run resolver and show its diagnostics only"
+        + "\n  .set ast-format pretty|compact - Print ASTs and results over several lines or on one"
+        + "\n  .env - List the channels and entries kept in the RSpace between runs"
+        + "\n  .env set <channel> <value> - Send a literal value on a channel"
+        + "\n  .env clear <channel> - Remove a channel or entry from the RSpace"
         + "\n  .ps - List all running processes"
         + "\n  .kill <index> - Kill a running process by index"
         + "\n  .quit - Exit the rholang-shell"
@@ -51,11 +97,115 @@ pub fn help_message() -> String {
         + "\n  --file, -f <FILE>     Execute code loaded from the file and exit"
         + "\n  --disassemble, -d     Show disassembly instead of executing (use with -e or -f)"
         + "\n  --both, -b            Show both disassembly and execution result"
+        + "\n  --watch, -w           Re-run the file given with -f whenever it changes (Ctrl+C exits)"
+        + "\n  --max-output <BYTES>  Truncate results longer than BYTES (default 65536)"
+        + "\n  --name-style <STYLE>  Render names as quoted, hash, unquoted or index (default quoted)"
+        + "\n  --deny-warnings       Fail validation on warnings; -e and -f validate before running"
+        + "\n  --lint-dir <PATH>     Validate every .rho file under PATH and exit (non-zero on errors)"
         + "\n  If stdin is piped (non-TTY), the shell reads all input and processes it"
 }
 
 const DEFAULT_PROMPT: &str = ">>> ";
 
+/// How ASTs and results are printed, chosen with `.set ast-format`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AstFormat {
+    /// The indented `{:#?}` form, over several lines
+    #[default]
+    Pretty,
+    /// The `{:?}` form, on a single line
+    Compact,
+}
+
+impl AstFormat {
+    /// Render `output`, which uses the pretty `{:#?}` layout, in this format
+    pub fn render(self, output: &str) -> String {
+        match self {
+            AstFormat::Pretty => output.to_string(),
+            AstFormat::Compact => compact_debug(output),
+        }
+    }
+}
+
+impl std::str::FromStr for AstFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s {
+            "pretty" => Ok(AstFormat::Pretty),
+            "compact" => Ok(AstFormat::Compact),
+            other => Err(format!(
+                "unknown ast-format '{other}', expected pretty or compact"
+            )),
+        }
+    }
+}
+
+impl std::fmt::Display for AstFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            AstFormat::Pretty => "pretty",
+            AstFormat::Compact => "compact",
+        })
+    }
+}
+
+/// Joins the lines of a `{:#?}` rendering back into the `{:?}` form. Output that is
+/// not laid out that way (no line opens a bracket) is returned as is.
+fn compact_debug(pretty: &str) -> String {
+    if !pretty.lines().any(|line| line.ends_with(['{', '(', '['])) {
+        return pretty.to_string();
+    }
+    let mut out = String::with_capacity(pretty.len());
+    for line in pretty
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+    {
+        let closes = line.starts_with(['}', ')', ']']);
+        if closes && out.ends_with(',') {
+            out.pop();
+        }
+        let glued = out.ends_with(['(', '[']) || (closes && !line.starts_with('}'));
+        if !out.is_empty() && !glued {
+            out.push(' ');
+        }
+        out.push_str(line);
+    }
+    out
+}
+
+/// State the interactive shell keeps between commands
+#[derive(Default)]
+pub struct ShellSession {
+    /// Debugger state for `.step`
+    pub step: Option<StepSession>,
+    /// How ASTs and results are printed
+    pub ast_format: AstFormat,
+    /// Whether the `.validate` commands fail on warnings, as with `--deny-warnings`
+    pub deny_warnings: bool,
+}
+
+fn handle_set_command<W: Write>(
+    arg: &str,
+    stdout: &mut W,
+    session: &mut ShellSession,
+) -> Result<()> {
+    let (setting, value) = arg.split_once(' ').unwrap_or((arg, ""));
+    match (setting, value.trim()) {
+        ("ast-format", "") => writeln!(stdout, "ast-format is {}", session.ast_format)?,
+        ("ast-format", value) => match value.parse() {
+            Ok(format) => {
+                session.ast_format = format;
+                writeln!(stdout, "ast-format set to {format}")?;
+            }
+            Err(e) => writeln!(stdout, "{} {e}", label_err_out("Error:"))?,
+        },
+        _ => writeln!(stdout, "Usage: .set ast-format pretty|compact")?,
+    }
+    Ok(())
+}
+
 // ANSI color helpers (enabled only when writing to a TTY)
 fn is_tty_stdout() -> bool {
     atty::is(atty::Stream::Stdout)
@@ -79,7 +229,6 @@ fn label_info(s: &str) -> String {
 fn label_ok(s: &str) -> String {
     colorize(s, "32", is_tty_stdout())
 } // green
-#[allow(dead_code)]
 fn label_warn(s: &str) -> String {
     colorize(s, "33", is_tty_stdout())
 } // yellow
@@ -228,6 +377,79 @@ fn handle_kill_command<W: Write, I: InterpreterProvider>(
     Ok(())
 }
 
+fn handle_env_command<W: Write, I: InterpreterProvider>(
+    arg: &str,
+    stdout: &mut W,
+    interpreter: &I,
+) -> Result<()> {
+    let Some(rspace) = interpreter.rspace() else {
+        writeln!(stdout, "This interpreter keeps no RSpace between runs")?;
+        return Ok(());
+    };
+    let mut rspace = match rspace.lock() {
+        Ok(guard) => guard,
+        Err(e) => {
+            writeln!(stdout, "{} {}", label_err_out("RSpace error:"), e)?;
+            return Ok(());
+        }
+    };
+
+    let (action, rest) = arg.split_once(' ').unwrap_or((arg, ""));
+    match (action, rest.trim()) {
+        ("", _) => {
+            let entries = rspace.entries();
+            if entries.is_empty() {
+                writeln!(stdout, "RSpace is empty")?;
+            } else {
+                let tree = render_env_entries(&entries);
+                let rendered = if is_tty_stdout() {
+                    colorize_ast_tree(&tree, true)
+                } else {
+                    tree
+                };
+                write!(stdout, "{rendered}")?;
+            }
+        }
+        ("set", rest) if !rest.is_empty() => {
+            let (channel, literal) = rest.split_once(' ').unwrap_or((rest, ""));
+            match literal.parse::<Value>() {
+                Ok(value) => match rspace.tell(channel, value.clone()) {
+                    Ok(()) => writeln!(stdout, "Sent {value} on {channel}")?,
+                    Err(e) => writeln!(stdout, "{} {}", label_err_out("RSpace error:"), e)?,
+                },
+                Err(e) => writeln!(stdout, "Invalid value '{}': {}", literal.trim(), e)?,
+            }
+        }
+        ("clear", channel) if !channel.is_empty() => match rspace.remove_entry(channel) {
+            Some(_) => writeln!(stdout, "Cleared {channel}")?,
+            None => writeln!(stdout, "No entry named {channel}")?,
+        },
+        _ => writeln!(
+            stdout,
+            "Usage: .env [set <channel> <value> | clear <channel>]"
+        )?,
+    }
+    Ok(())
+}
+
+/// One line per entry naming its kind, followed by its contents indented below it
+fn render_env_entries(entries: &[(String, Entry)]) -> String {
+    let mut out = String::new();
+    for (name, entry) in entries {
+        match entry {
+            Entry::Channel(messages) => {
+                out.push_str(&format!("{name}: Channel\n"));
+                for message in messages {
+                    out.push_str(&format!("  {message}\n"));
+                }
+            }
+            Entry::Process { state } => out.push_str(&format!("{name}: Process\n  {state:?}\n")),
+            Entry::Value(value) => out.push_str(&format!("{name}: Value\n  {value}\n")),
+        }
+    }
+    out
+}
+
 fn print_processes<W: Write, I: InterpreterProvider>(
     stdout: &mut W,
     interpreter: &I,
@@ -278,14 +500,181 @@ fn load_file_into_buffer<W: Write>(
     Ok(())
 }
 
+fn save_bytecode<W: Write, I: InterpreterProvider>(
+    path: &str,
+    code: &str,
+    stdout: &mut W,
+    interpreter: &I,
+) -> Result<()> {
+    let bytes = match interpreter.compile_to_bytes(code) {
+        Ok(bytes) => bytes,
+        Err(e) => {
+            writeln!(stdout, "{} {}", label_err_out("Compilation error:"), e)?;
+            return Ok(());
+        }
+    };
+    match std::fs::write(path, &bytes) {
+        Ok(()) => writeln!(
+            stdout,
+            "Wrote {} bytes of bytecode to: {}",
+            bytes.len(),
+            path
+        )?,
+        Err(e) => writeln!(stdout, "Error writing file '{}': {}", path, e)?,
+    }
+    Ok(())
+}
+
+fn load_bytecode<W: Write, I: InterpreterProvider>(
+    path: &str,
+    stdout: &mut W,
+    interpreter: &I,
+) -> Result<()> {
+    let bytes = match std::fs::read(path) {
+        Ok(bytes) => bytes,
+        Err(e) => {
+            writeln!(stdout, "Error loading file '{}': {}", path, e)?;
+            return Ok(());
+        }
+    };
+    match interpreter.execute_bytes(&bytes) {
+        Ok(result) => writeln!(stdout, "{result}")?,
+        Err(e) => writeln!(stdout, "{} {}", label_err_out("Bytecode error:"), e)?,
+    }
+    Ok(())
+}
+
+/// Split command arguments at whitespace, except inside double quotes, so that paths with
+/// spaces can be given as `"my file.rho"`. Returns `None` if a quote is left open
+fn split_args(arg: &str) -> Option<Vec<String>> {
+    let mut args = Vec::new();
+    let mut current: Option<String> = None;
+    let mut quoted = false;
+    for c in arg.chars() {
+        match c {
+            '"' => {
+                quoted = !quoted;
+                current.get_or_insert_with(String::new);
+            }
+            c if c.is_whitespace() && !quoted => args.extend(current.take()),
+            c => current.get_or_insert_with(String::new).push(c),
+        }
+    }
+    args.extend(current);
+    (!quoted).then_some(args)
+}
+
+/// Report whether the programs in two files have the same AST, ignoring spans, and where
+/// they first differ if not
+fn diff_files<W: Write>(left_path: &str, right_path: &str, stdout: &mut W) -> Result<()> {
+    use rholang_parser::{first_difference, unparse, RholangParser};
+
+    let mut sources = Vec::with_capacity(2);
+    for path in [left_path, right_path] {
+        match std::fs::read_to_string(path) {
+            Ok(contents) => sources.push(contents),
+            Err(e) => {
+                writeln!(stdout, "Error loading file '{}': {}", path, e)?;
+                return Ok(());
+            }
+        }
+    }
+
+    let parser = RholangParser::new();
+    let mut trees = Vec::with_capacity(2);
+    for (path, source) in [left_path, right_path].into_iter().zip(&sources) {
+        match parser.parse(source) {
+            validated::Validated::Good(procs) => trees.push(procs),
+            validated::Validated::Fail(_err) => {
+                writeln!(
+                    stdout,
+                    "{} unable to build AST for '{}'",
+                    label_err_out("Parsing failed:"),
+                    path
+                )?;
+                return Ok(());
+            }
+        }
+    }
+    let (left, right) = (&trees[0], &trees[1]);
+
+    if left.len() != right.len() {
+        writeln!(
+            stdout,
+            "{} '{}' has {} top-level processes, '{}' has {}",
+            label_warn("Different:"),
+            left_path,
+            left.len(),
+            right_path,
+            right.len()
+        )?;
+        return Ok(());
+    }
+    match left
+        .iter()
+        .zip(right)
+        .find_map(|(left, right)| first_difference(left, right))
+    {
+        None => writeln!(
+            stdout,
+            "{} '{}' and '{}' have the same structure",
+            label_ok("Equivalent:"),
+            left_path,
+            right_path
+        )?,
+        Some((left, right)) => {
+            writeln!(stdout, "{} first difference at", label_warn("Different:"))?;
+            writeln!(
+                stdout,
+                "  {}:{}: {}",
+                left_path,
+                left.span.render_compact(),
+                unparse(left)
+            )?;
+            writeln!(
+                stdout,
+                "  {}:{}: {}",
+                right_path,
+                right.span.render_compact(),
+                unparse(right)
+            )?;
+        }
+    }
+    Ok(())
+}
+
 /// Process a special command (starting with '.')
 /// Returns true if the command was processed, false otherwise
+///
+/// No debugger state is kept between calls, so `.step` only shows the first
+/// instruction; use [`process_special_command_with_session`] to keep stepping.
 pub fn process_special_command<W: Write, I: InterpreterProvider>(
     command: &str,
     buffer: &mut Vec<String>,
     stdout: &mut W,
     update_prompt: impl FnOnce(&str) -> Result<()>,
     interpreter: &I,
+) -> Result<bool> {
+    process_special_command_with_session(
+        command,
+        buffer,
+        stdout,
+        update_prompt,
+        interpreter,
+        &mut ShellSession::default(),
+    )
+}
+
+/// Process a special command (starting with '.'), keeping the `.step` debugger
+/// state and the `.set` settings in `session` so they survive across calls
+/// Returns true if the command was processed, false otherwise
+pub fn process_special_command_with_session<W: Write, I: InterpreterProvider>(
+    command: &str,
+    buffer: &mut Vec<String>,
+    stdout: &mut W,
+    update_prompt: impl FnOnce(&str) -> Result<()>,
+    interpreter: &I,
+    session: &mut ShellSession,
 ) -> Result<bool> {
     let trimmed = command.trim();
     if !trimmed.starts_with('.') {
@@ -332,6 +721,9 @@ pub fn process_special_command<W: Write, I: InterpreterProvider>(
         ".kill" => {
             handle_kill_command(arg, stdout, interpreter)?;
         }
+        ".env" => {
+            handle_env_command(arg, stdout, interpreter)?;
+        }
         ".load" => {
             let path = arg.trim();
             if path.is_empty() {
@@ -355,36 +747,103 @@ pub fn process_special_command<W: Write, I: InterpreterProvider>(
                 }
             }
         }
-        ".validate" => {
+        ".save-bytecode" => {
             let code = buffer.join("\n");
-            if code.trim().is_empty() {
-                writeln!(stdout, "Buffer is empty, nothing to validate")?;
+            if arg.is_empty() {
+                writeln!(stdout, "Usage: .save-bytecode <file>")?;
+            } else if code.trim().is_empty() {
+                writeln!(stdout, "Buffer is empty, nothing to compile")?;
             } else {
-                run_all_validators_on_code(&code, stdout)?;
+                save_bytecode(arg, &code, stdout, interpreter)?;
             }
         }
-        ".validate-unused" => {
-            let code = buffer.join("\n");
+        ".load-bytecode" => {
+            if arg.is_empty() {
+                writeln!(stdout, "Usage: .load-bytecode <file>")?;
+            } else {
+                load_bytecode(arg, stdout, interpreter)?;
+            }
+        }
+        ".diff" => match split_args(arg).as_deref() {
+            Some([left, right]) => diff_files(left, right, stdout)?,
+            _ => writeln!(
+                stdout,
+                "Usage: .diff <file1> <file2> (quote paths that contain spaces)"
+            )?,
+        },
+        ".set" => {
+            handle_set_command(arg, stdout, session)?;
+        }
+        ".type" => {
+            let code = if arg.is_empty() {
+                buffer.join("\n")
+            } else {
+                arg.to_string()
+            };
             if code.trim().is_empty() {
-                writeln!(stdout, "Buffer is empty, nothing to validate")?;
+                writeln!(stdout, "Usage: .type <expr> (or fill the buffer first)")?;
             } else {
-                run_validation_subset(&code, stdout, ValidationMode::UnusedOnly)?;
+                print_types(&code, stdout)?;
             }
         }
-        ".validate-elab" => {
+        ".complete" => {
+            let mut code = buffer.join("\n");
+            if !code.is_empty() {
+                code.push('\n');
+            }
+            code.push_str(arg);
+            let candidates = completion::complete(&code, code.len());
+            if candidates.is_empty() {
+                writeln!(stdout, "No completions for '{arg}'")?;
+            } else {
+                writeln!(stdout, "{}", candidates.join("  "))?;
+            }
+        }
+        ".step" => {
             let code = buffer.join("\n");
             if code.trim().is_empty() {
-                writeln!(stdout, "Buffer is empty, nothing to validate")?;
+                writeln!(stdout, "Buffer is empty, nothing to step through")?;
             } else {
-                run_validation_subset(&code, stdout, ValidationMode::ElabOnly)?;
+                match interpreter.step_session(&code) {
+                    Ok(new_session) => {
+                        writeln!(
+                            stdout,
+                            "{} {} instruction(s); press Enter to step, .continue to finish",
+                            label_info("Stepping:"),
+                            new_session.len()
+                        )?;
+                        session.step = Some(new_session);
+                        advance_step_session(&mut session.step, stdout)?;
+                    }
+                    Err(e) => {
+                        session.step = None;
+                        writeln!(stdout, "{} {}", label_err_out("Step error:"), e)?;
+                    }
+                }
             }
         }
-        ".validate-resolver" => {
+        ".continue" => match session.step.take() {
+            Some(mut active) => match active.finish() {
+                Ok(value) => writeln!(stdout, "{} {}", label_ok("Finished:"), value)?,
+                Err(e) => writeln!(stdout, "{} {}", label_err_out("Execution error:"), e)?,
+            },
+            None => {
+                writeln!(stdout, "No active step session, use .step to start one")?;
+            }
+        },
+        ".validate" | ".validate-unused" | ".validate-elab" | ".validate-resolver" => {
+            let mode = match cmd {
+                ".validate-unused" => ValidationMode::UnusedOnly,
+                ".validate-elab" => ValidationMode::ElabOnly,
+                ".validate-resolver" => ValidationMode::ResolverOnly,
+                _ => ValidationMode::All,
+            };
+            let deny_warnings = session.deny_warnings || arg == "--deny-warnings";
             let code = buffer.join("\n");
             if code.trim().is_empty() {
                 writeln!(stdout, "Buffer is empty, nothing to validate")?;
             } else {
-                run_validation_subset(&code, stdout, ValidationMode::ResolverOnly)?;
+                run_validation_subset(&code, stdout, mode, deny_warnings)?;
             }
         }
         _ => {
@@ -394,8 +853,66 @@ pub fn process_special_command<W: Write, I: InterpreterProvider>(
     Ok(false) // Don't exit
 }
 
+/// Print the static type of each top-level process in `code`, one per line.
+fn print_types<W: Write>(code: &str, stdout: &mut W) -> Result<()> {
+    match types::classify_code(code) {
+        Ok(labels) => {
+            for label in labels {
+                writeln!(stdout, "{label}")?;
+            }
+        }
+        Err(e) => writeln!(stdout, "{} {}", label_err_out("Type error:"), e)?,
+    }
+    Ok(())
+}
+
+/// Execute one instruction of the active `.step` session and print the trace.
+/// The session is dropped once the process halts or fails.
+pub fn advance_step_session<W: Write>(
+    session: &mut Option<StepSession>,
+    stdout: &mut W,
+) -> Result<()> {
+    let Some(active) = session.as_mut() else {
+        writeln!(stdout, "No active step session, use .step to start one")?;
+        return Ok(());
+    };
+    match active.step() {
+        Ok(StepOutcome::Stepped(trace)) => {
+            writeln!(stdout, "{trace}")?;
+            if active.is_finished() {
+                let value = active.finish()?;
+                writeln!(stdout, "{} {}", label_ok("Finished:"), value)?;
+                *session = None;
+            }
+        }
+        Ok(StepOutcome::Finished(value)) => {
+            writeln!(stdout, "{} {}", label_ok("Finished:"), value)?;
+            *session = None;
+        }
+        Err(e) => {
+            writeln!(stdout, "{} {}", label_err_out("Execution error:"), e)?;
+            *session = None;
+        }
+    }
+    Ok(())
+}
+
 // ... existing code ...
 
+/// Check whether `code` is complete enough to be submitted, using the same bracket
+/// heuristic as the shell's multiline mode.
+///
+/// Returns false only while some `(`, `[` or `{` is still open; over-closed input
+/// such as `}}` counts as complete since more lines cannot fix it, and is left for
+/// the parser to report. If the bracket parser cannot be created, every input is
+/// considered complete.
+pub fn input_is_complete(code: &str) -> bool {
+    match BracketParser::new() {
+        Ok(mut parser) => parser.get_final_state(code) != BracketState::Inside,
+        Err(_) => true,
+    }
+}
+
 /// Process a line of input in multiline mode
 /// Returns Some(command) if a command is ready to be executed, None otherwise
 pub fn process_multiline_input(
@@ -419,27 +936,7 @@ pub fn process_multiline_input(
         return Ok(None);
     }
 
-    // Empty line with non-empty buffer: only execute on DOUBLE empty lines when brackets are balanced
-    let mut bracket_parser = match BracketParser::new() {
-        Ok(parser) => parser,
-        Err(_) => {
-            // Fallback: if parser cannot be created, keep previous behavior and execute on double empty
-            if buffer.last().map(|s| s.is_empty()).unwrap_or(false) {
-                // Second empty: execute (exclude the marker empty)
-                let _ = buffer.pop();
-                let command = buffer.join("\n");
-                // Keep buffer after execution for future processing
-                update_prompt(DEFAULT_PROMPT)?;
-                return Ok(Some(command));
-            } else {
-                // First empty: remember it and wait for another empty
-                buffer.push(String::new());
-                update_prompt("... ")?;
-                return Ok(None);
-            }
-        }
-    };
-
+    // Empty line with non-empty buffer: only execute on DOUBLE empty lines when brackets are balanced.
     // Build the joined input without any trailing empty marker for bracket parsing
     let joined_no_trailing_empty = if buffer.last().map(|s| s.is_empty()).unwrap_or(false) {
         // There is already a pending empty marker; don't include it in parsing
@@ -448,9 +945,7 @@ pub fn process_multiline_input(
         buffer.join("\n")
     };
 
-    let state = bracket_parser.get_final_state(&joined_no_trailing_empty);
-
-    if state == BracketState::Inside {
+    if !input_is_complete(&joined_no_trailing_empty) {
         // Brackets are still open; stay in multiline mode and do not execute
         update_prompt("... ")?;
         return Ok(None);
@@ -497,6 +992,48 @@ pub fn handle_interrupt<W: Write, I: InterpreterProvider>(
     Ok(())
 }
 
+/// Owns an output handle and flushes it when dropped, so output still pending in it is
+/// written however the owning scope is left: normally, through `?` or by a panic
+pub struct FlushGuard<T, F: FnMut(&mut T)> {
+    inner: T,
+    flush: F,
+}
+
+impl<T, F: FnMut(&mut T)> FlushGuard<T, F> {
+    pub fn new(inner: T, flush: F) -> Self {
+        Self { inner, flush }
+    }
+}
+
+impl<W: Write> FlushGuard<W, fn(&mut W)> {
+    /// Guard a writer. Flush errors are ignored, as there is no one left to report them to
+    pub fn writer(inner: W) -> Self {
+        Self::new(inner, |w| {
+            let _ = w.flush();
+        })
+    }
+}
+
+impl<T, F: FnMut(&mut T)> std::ops::Deref for FlushGuard<T, F> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.inner
+    }
+}
+
+impl<T, F: FnMut(&mut T)> std::ops::DerefMut for FlushGuard<T, F> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.inner
+    }
+}
+
+impl<T, F: FnMut(&mut T)> Drop for FlushGuard<T, F> {
+    fn drop(&mut self) {
+        (self.flush)(&mut self.inner);
+    }
+}
+
 /// Run code in non-interactive mode with optional disassembly
 async fn run_non_interactive<I: InterpreterProvider>(
     code: &str,
@@ -505,6 +1042,14 @@ async fn run_non_interactive<I: InterpreterProvider>(
 ) -> Result<()> {
     let show_disasm = args.disassemble || args.both;
     let show_exec = !args.disassemble || args.both;
+    let _flush = FlushGuard::writer(std::io::stdout());
+
+    if args.deny_warnings {
+        let mut stdout = std::io::stdout();
+        if !run_validation_subset(code, &mut stdout, ValidationMode::All, true)? {
+            anyhow::bail!("validation failed with --deny-warnings");
+        }
+    }
 
     // Show disassembly if requested
     if show_disasm {
@@ -540,14 +1085,97 @@ async fn run_non_interactive<I: InterpreterProvider>(
     Ok(())
 }
 
+/// How often `--watch` checks the watched file for modifications
+const WATCH_POLL_INTERVAL: std::time::Duration = std::time::Duration::from_millis(300);
+
+fn modified_time(path: &std::path::Path) -> Option<std::time::SystemTime> {
+    std::fs::metadata(path).and_then(|m| m.modified()).ok()
+}
+
+/// Run the file, then run it again every time its modification time changes.
+/// Each run starts from an empty RSpace, so nothing a previous run left behind
+/// is visible to the next one. Returns when Ctrl+C is pressed, including in the
+/// middle of a run.
+async fn run_watch<I: InterpreterProvider>(
+    path: &std::path::Path,
+    args: &Args,
+    interpreter: &I,
+) -> Result<()> {
+    let ctrl_c = tokio::signal::ctrl_c();
+    tokio::pin!(ctrl_c);
+
+    let mut last_modified = modified_time(path);
+    loop {
+        if is_tty_stdout() {
+            // Clear the screen and move the cursor home
+            print!("\x1b[2J\x1b[H");
+        }
+
+        if let Some(rspace) = interpreter.rspace() {
+            rspace
+                .lock()
+                .map_err(|e| anyhow::anyhow!("RSpace lock error: {}", e))?
+                .reset();
+        }
+
+        match std::fs::read_to_string(path) {
+            Ok(code) => {
+                tokio::select! {
+                    _ = &mut ctrl_c => return Ok(()),
+                    res = run_non_interactive(&code, args, interpreter) => res?,
+                }
+            }
+            Err(e) => eprintln!("Error reading {}: {}", path.display(), e),
+        }
+        println!(
+            "{}",
+            label_info(&format!(
+                "Watching {} for changes (Ctrl+C to exit)",
+                path.display()
+            ))
+        );
+        std::io::stdout().flush()?;
+
+        loop {
+            tokio::select! {
+                _ = &mut ctrl_c => return Ok(()),
+                _ = tokio::time::sleep(WATCH_POLL_INTERVAL) => {}
+            }
+            let modified = modified_time(path);
+            if modified != last_modified {
+                last_modified = modified;
+                break;
+            }
+        }
+    }
+}
+
 /// Run the rholang-shell with the provided interpreter provider
 pub async fn run_shell<I: InterpreterProvider>(args: Args, interpreter: I) -> Result<()> {
+    interpreter.set_max_output(args.max_output)?;
+    interpreter.set_name_style(args.name_style)?;
+    interpreter.set_execution_mode(if args.keep_going {
+        providers::ExecutionMode::Independent
+    } else {
+        providers::ExecutionMode::FailFast
+    })?;
+
+    if let Some(dir) = args.lint_dir.as_ref() {
+        if !lint_directory(dir, &mut std::io::stdout(), args.deny_warnings)? {
+            anyhow::bail!("validation failed for files under {}", dir.display());
+        }
+        return Ok(());
+    }
+
     // Highest-priority non-interactive: explicit --exec or --file flags
     if let Some(code) = args.exec.as_ref() {
         return run_non_interactive(code, &args, &interpreter).await;
     }
 
     if let Some(file_path) = args.file.as_ref() {
+        if args.watch {
+            return run_watch(file_path, &args, &interpreter).await;
+        }
         let code = std::fs::read_to_string(file_path)?;
         return run_non_interactive(&code, &args, &interpreter).await;
     }
@@ -568,8 +1196,17 @@ pub async fn run_shell<I: InterpreterProvider>(args: Args, interpreter: I) -> Re
 
     let prompt = ">>> ".to_string();
 
-    let (mut rl, mut stdout) = Readline::new(prompt.clone())?;
+    let (rl, mut stdout) = Readline::new(prompt.clone())?;
+    // Output written through `stdout` is queued until `rl` prints it, so flush it on every way out
+    let mut rl = FlushGuard::new(rl, |rl: &mut Readline| {
+        let _ = rl.flush();
+    });
     let mut buffer: Vec<String> = Vec::new();
+    // Debugger state for `.step` and settings, kept across readline iterations
+    let mut session = ShellSession {
+        deny_warnings: args.deny_warnings,
+        ..ShellSession::default()
+    };
 
     rl.should_print_line_on(true, false);
 
@@ -587,13 +1224,20 @@ pub async fn run_shell<I: InterpreterProvider>(args: Args, interpreter: I) -> Re
                 Ok(ReadlineEvent::Line(line)) => {
                     let line = line.trim().to_string();
 
+                    // While stepping, a bare Enter advances the VM by one instruction
+                    if line.is_empty() && session.step.is_some() {
+                        advance_step_session(&mut session.step, &mut stdout)?;
+                        continue;
+                    }
+
                     // Process special commands
-                    let should_exit = process_special_command(
+                    let should_exit = process_special_command_with_session(
                         &line,
                         &mut buffer,
                         &mut stdout,
                         |prompt| Ok(rl.update_prompt(prompt)?),
                         &interpreter,
+                        &mut session,
                     )?;
 
                     if should_exit {
@@ -619,6 +1263,7 @@ pub async fn run_shell<I: InterpreterProvider>(args: Args, interpreter: I) -> Re
                         let result = interpreter.interpret(&command).await;
                         match result {
                             InterpretationResult::Success(output) => {
+                                let output = session.ast_format.render(&output);
                                 let rendered = if is_tty_stdout() { colorize_ast_tree(&output, true) } else { output };
                                 writeln!(stdout, "{} {}", label_ok("Output:"), rendered)?
                             }
@@ -645,7 +1290,6 @@ pub async fn run_shell<I: InterpreterProvider>(args: Args, interpreter: I) -> Re
             }
         }
     }
-    rl.flush()?;
     Ok(())
 }
 
@@ -691,10 +1335,17 @@ fn print_diagnostics<W: Write>(
     Ok(())
 }
 
-fn run_validation_subset<W: Write>(code: &str, stdout: &mut W, mode: ValidationMode) -> Result<()> {
+/// Runs the validators of `mode` and prints their diagnostics. Returns whether the code
+/// passed: it parsed and produced no errors, nor any warnings if `deny_warnings` is set.
+fn run_validation_subset<W: Write>(
+    code: &str,
+    stdout: &mut W,
+    mode: ValidationMode,
+    deny_warnings: bool,
+) -> Result<bool> {
     use librho::sem::{
-        diagnostics::UnusedVarsPass, DiagnosticPass, FactPass, ForCompElaborationPass,
-        ResolverPass, SemanticDb,
+        diagnostics::UnusedVarsPass, DiagnosticFilter, DiagnosticPass, FactPass,
+        ForCompElaborationPass, ResolverPass, SemanticDb,
     };
     use rholang_parser::RholangParser;
 
@@ -709,16 +1360,20 @@ fn run_validation_subset<W: Write>(code: &str, stdout: &mut W, mode: ValidationM
                 stdout,
                 "Parsing failed: unable to build AST. Please fix syntax errors and try again."
             )?;
-            return Ok(());
+            return Ok(false);
         }
     };
 
     if ast_vec.is_empty() {
         writeln!(stdout, "No code to validate (empty AST)")?;
-        return Ok(());
+        return Ok(true);
     }
 
     let mut db = SemanticDb::new();
+    db.set_diagnostic_filter(DiagnosticFilter {
+        deny_warnings,
+        ..DiagnosticFilter::default()
+    });
 
     // Index and validate each top-level proc independently
     for proc in ast_vec.iter() {
@@ -757,11 +1412,71 @@ fn run_validation_subset<W: Write>(code: &str, stdout: &mut W, mode: ValidationM
         ValidationMode::ResolverOnly => "Resolver validation produced",
     };
 
-    print_diagnostics(stdout, db.diagnostics(), header)
+    print_diagnostics(stdout, db.diagnostics(), header)?;
+    if deny_warnings && db.has_errors() && db.errors().next().is_none() {
+        writeln!(
+            stdout,
+            "{} warnings are denied",
+            label_err_out("Validation failed:")
+        )?;
+    }
+    Ok(!db.has_errors())
 }
 
-// (Disassembler functionality moved into InterpreterProvider::disassemble)
+/// Collects the .rho files under `dir`, recursively and in path order. Symlinks to directories
+/// are not followed, so a link back up the tree cannot send this round in circles
+fn collect_rho_files(dir: &std::path::Path, files: &mut Vec<std::path::PathBuf>) -> Result<()> {
+    let mut entries = std::fs::read_dir(dir)?
+        .map(|entry| entry.map(|entry| entry.path()))
+        .collect::<std::io::Result<Vec<_>>>()?;
+    entries.sort();
+    for path in entries {
+        if std::fs::symlink_metadata(&path)?.is_dir() {
+            collect_rho_files(&path, files)?;
+        } else if path.extension().is_some_and(|ext| ext == "rho") {
+            files.push(path);
+        }
+    }
+    Ok(())
+}
 
-fn run_all_validators_on_code<W: Write>(code: &str, stdout: &mut W) -> Result<()> {
-    run_validation_subset(code, stdout, ValidationMode::All)
+/// Runs all validators on every .rho file under `dir`, printing the diagnostics of each
+/// file followed by a final count. Returns whether every file passed.
+pub fn lint_directory<W: Write>(
+    dir: &std::path::Path,
+    stdout: &mut W,
+    deny_warnings: bool,
+) -> Result<bool> {
+    let mut files = Vec::new();
+    collect_rho_files(dir, &mut files)?;
+
+    let mut failed = 0;
+    for path in &files {
+        writeln!(stdout, "{}", label_info(&format!("== {}", path.display())))?;
+        let passed = match std::fs::read_to_string(path) {
+            Ok(code) => run_validation_subset(&code, stdout, ValidationMode::All, deny_warnings)?,
+            Err(e) => {
+                writeln!(stdout, "{} {e}", label_err_out("Error reading file:"))?;
+                false
+            }
+        };
+        if !passed {
+            failed += 1;
+        }
+    }
+
+    let summary = format!(
+        "Linted {} file(s): {} passed, {} failed",
+        files.len(),
+        files.len() - failed,
+        failed
+    );
+    if failed == 0 {
+        writeln!(stdout, "{}", label_ok(&summary))?;
+    } else {
+        writeln!(stdout, "{}", label_err_out(&summary))?;
+    }
+    Ok(failed == 0)
 }
+
+// (Disassembler functionality moved into InterpreterProvider::disassemble)
@@ -1,20 +1,31 @@
 use anyhow::{anyhow, Result};
 use async_trait::async_trait;
+use futures_core::Stream;
 use rholang_parser::RholangParser;
 use std::collections::HashMap;
 use std::fmt;
+use std::io::Write;
+use std::pin::Pin;
+use std::sync::mpsc::RecvTimeoutError;
 use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll};
 use std::time::Duration;
-use tokio::sync::oneshot;
+use tokio::sync::{mpsc, oneshot};
 use tokio::task;
 use tokio::time::timeout;
 // Compiler/VM imports
+use crate::debugger::StepSession;
 use librho::sem::{
     pipeline::Pipeline, DiagnosticKind, EnclosureAnalysisPass, ErrorKind, ForCompElaborationPass,
     ResolverPass, SemanticDb,
 };
-use rholang_compiler::Compiler;
-use rholang_vm::api::Value as VmValue;
+use rholang_compiler::{Compiler, Process};
+use rholang_interpreter::{is_failure, render_exec_error};
+pub use rholang_interpreter::{ExecutionMode, DEFAULT_MAX_OUTPUT};
+use rholang_vm::api::{
+    InMemoryRSpace, NameRenderStyle, OutputSink, RSpace, SharedRSpace, Value as VmValue, VM,
+};
+use rholang_vm::ExecError;
 
 /// Remove source position/span information from a pretty-printed AST/debug output
 fn strip_sourcepos(input: &str) -> String {
@@ -171,6 +182,9 @@ impl InterpretationResult {
     }
 }
 
+/// How long [`RholangCompilerInterpreterProvider`] lets code run before abandoning it
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(30);
+
 /// Trait for interpreter providers
 /// This trait defines the interface for interpreters that can be used with the rholang-shell
 #[async_trait]
@@ -184,6 +198,54 @@ pub trait InterpreterProvider {
         Err(anyhow!("Disassembly is not supported by this provider"))
     }
 
+    /// Compile the provided code into a session that executes it one VM instruction at a time
+    /// Default providers may return an error if unsupported
+    fn step_session(&self, _code: &str) -> Result<StepSession> {
+        Err(anyhow!("Stepping is not supported by this provider"))
+    }
+
+    /// Compile the provided code, a single top-level process, and serialize the result, see
+    /// `.save-bytecode`
+    /// Default providers may return an error if unsupported
+    fn compile_to_bytes(&self, _code: &str) -> Result<Vec<u8>> {
+        Err(anyhow!(
+            "Bytecode compilation is not supported by this provider"
+        ))
+    }
+
+    /// Execute a process serialized by [`InterpreterProvider::compile_to_bytes`] and render
+    /// its result
+    /// Default providers may return an error if unsupported
+    fn execute_bytes(&self, _bytes: &[u8]) -> Result<String> {
+        Err(anyhow!(
+            "Bytecode execution is not supported by this provider"
+        ))
+    }
+
+    /// Limit rendered results to about `max_bytes`; larger values are truncated with an ellipsis
+    /// Default providers ignore the limit
+    fn set_max_output(&self, _max_bytes: usize) -> Result<()> {
+        Ok(())
+    }
+
+    /// Select how names in rendered results are written
+    /// Default providers ignore the style
+    fn set_name_style(&self, _style: NameRenderStyle) -> Result<()> {
+        Ok(())
+    }
+
+    /// Select how runtime errors in one of several top-level processes are handled
+    /// Default providers ignore the mode
+    fn set_execution_mode(&self, _mode: ExecutionMode) -> Result<()> {
+        Ok(())
+    }
+
+    /// The tuple space that persists across `interpret` calls, if any
+    /// Default providers keep no state between runs
+    fn rspace(&self) -> Option<SharedRSpace> {
+        None
+    }
+
     /// List all running processes
     /// Returns a vector of tuples containing the process ID and the code being executed
     fn list_processes(&self) -> Result<Vec<(usize, String)>>;
@@ -197,6 +259,36 @@ pub trait InterpreterProvider {
     fn kill_all_processes(&self) -> Result<usize>;
 }
 
+/// One item of an [`InterpretStream`]
+#[derive(Debug, Clone)]
+pub enum InterpretOutput {
+    /// A message sent on `rho:io:stdout`, as it would be printed but without the newline
+    Stdout(String),
+    /// The result of the whole run; always the last item of the stream
+    Finished(InterpretationResult),
+}
+
+/// Output of a running program, see [`StreamingInterpreterProvider::interpret_stream`]
+pub struct InterpretStream {
+    receiver: mpsc::UnboundedReceiver<InterpretOutput>,
+}
+
+impl Stream for InterpretStream {
+    type Item = InterpretOutput;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.receiver.poll_recv(cx)
+    }
+}
+
+/// Interpreters that report what a program prints while it is still running
+pub trait StreamingInterpreterProvider: InterpreterProvider {
+    /// Start interpreting `code` and return a stream of its `rho:io:stdout` messages, ending
+    /// with an [`InterpretOutput::Finished`] item
+    /// Must be called from within a Tokio runtime
+    fn interpret_stream(&self, code: &str) -> InterpretStream;
+}
+
 /// A fake interpreter provider that simply returns the input code
 /// This is used for testing and as a placeholder
 pub struct FakeInterpreterProvider;
@@ -456,6 +548,134 @@ impl InterpreterProvider for RholangParserInterpreterProvider {
     }
 }
 
+/// Parse, analyse and compile the first top-level process of `code`.
+/// Returns `Ok(None)` for an empty AST and a human-readable message on failure.
+fn compile_first_process(code: &str) -> std::result::Result<Option<Process>, String> {
+    compile_process(code, false)
+}
+
+/// Like [`compile_first_process`], but fails if `code` has more than one top-level process
+/// rather than leaving the others out
+fn compile_only_process(code: &str) -> std::result::Result<Option<Process>, String> {
+    compile_process(code, true)
+}
+
+fn compile_process(code: &str, only: bool) -> std::result::Result<Option<Process>, String> {
+    // Parse
+    let parser = RholangParser::new();
+    let validated = parser.parse(code);
+
+    let ast_vec = match validated {
+        validated::Validated::Good(ast) => ast,
+        validated::Validated::Fail(_err) => {
+            return Err(
+                "Parsing failed: unable to build AST. Please fix syntax errors and try again."
+                    .to_string(),
+            );
+        }
+    };
+
+    if ast_vec.is_empty() {
+        return Ok(None);
+    }
+    if only && ast_vec.len() > 1 {
+        return Err(format!(
+            "Expected a single top-level process, found {}; join them with `|`",
+            ast_vec.len()
+        ));
+    }
+
+    // Build semantic DB and run essential passes (resolver + elaborations)
+    let mut db = SemanticDb::new();
+    let first = &ast_vec[0];
+    let root = db.build_index(first);
+
+    // Run the pipeline using a lightweight runtime local to this thread
+    if let Ok(rt) = tokio::runtime::Builder::new_current_thread()
+        .enable_time()
+        .build()
+    {
+        let pipeline = Pipeline::new()
+            .add_fact(ResolverPass::new(root))
+            .add_fact(ForCompElaborationPass::new(root))
+            .add_fact(EnclosureAnalysisPass::new(root));
+        rt.block_on(pipeline.run(&mut db));
+    } else {
+        return Err("Failed to initialize runtime for semantic pipeline".to_string());
+    }
+
+    // Filter out NameInProcPosition errors (handled by compiler emitting EVAL)
+    let real_errors: Vec<_> = db
+        .errors()
+        .filter(|diag| {
+            !matches!(
+                diag.kind,
+                DiagnosticKind::Error(ErrorKind::NameInProcPosition(_, _))
+            )
+        })
+        .collect();
+
+    if !real_errors.is_empty() {
+        return Err(format!("Semantic errors: {:?}", real_errors));
+    }
+
+    // Compile first top-level process
+    let compiler = Compiler::new(&db);
+    compiler
+        .compile_single(first)
+        .map(Some)
+        .map_err(|e| format!("Compilation error: {}", e))
+}
+
+/// Run `work` on the current thread, or on a dedicated OS thread if we're inside a
+/// Tokio runtime, to avoid nested-runtime and blocking issues.
+/// Returns `None` if the worker thread panicked.
+fn run_off_runtime<T, F>(work: F) -> Option<T>
+where
+    T: Send + 'static,
+    F: FnOnce() -> T + Send + 'static,
+{
+    if tokio::runtime::Handle::try_current().is_ok() {
+        std::thread::spawn(work).join().ok()
+    } else {
+        Some(work())
+    }
+}
+
+/// Output sink that forwards every complete line to an [`InterpretStream`].
+/// Flushing forwards the unterminated rest as a line of its own.
+struct StreamSink {
+    sender: mpsc::UnboundedSender<InterpretOutput>,
+    pending: Vec<u8>,
+}
+
+impl StreamSink {
+    fn send_line(&self, line: &[u8]) {
+        let text = String::from_utf8_lossy(line).into_owned();
+        // a dropped stream just means nobody is listening any more
+        let _ = self.sender.send(InterpretOutput::Stdout(text));
+    }
+}
+
+impl Write for StreamSink {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.pending.extend_from_slice(buf);
+        while let Some(end) = self.pending.iter().position(|&b| b == b'\n') {
+            let line: Vec<u8> = self.pending.drain(..=end).collect();
+            self.send_line(&line[..end]);
+        }
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        if !self.pending.is_empty() {
+            let rest = std::mem::take(&mut self.pending);
+            self.send_line(&rest);
+        }
+        Ok(())
+    }
+}
+
 /// Provider backed by the rholang-compiler and rholang-vm
 /// Parses, compiles to bytecode, executes in the VM, and returns the resulting value.
 #[derive(Clone)]
@@ -466,14 +686,30 @@ pub struct RholangCompilerInterpreterProvider {
     next_pid: Arc<Mutex<usize>>,
     /// Optional artificial delay (ms) for testing/demo
     delay_ms: Arc<Mutex<u64>>,
+    /// Size limit (in bytes) for rendered results
+    max_output: Arc<Mutex<usize>>,
+    /// How names in rendered results are written
+    name_style: Arc<Mutex<NameRenderStyle>>,
+    /// How a runtime error in one top-level process affects the others
+    execution_mode: Arc<Mutex<ExecutionMode>>,
+    /// How long a run may take before it is abandoned
+    timeout: Arc<Mutex<Duration>>,
+    /// Tuple space shared by every process this provider runs
+    rspace: SharedRSpace,
 }
 
 impl RholangCompilerInterpreterProvider {
     pub fn new() -> Result<Self> {
+        let rspace: Box<dyn RSpace> = Box::new(InMemoryRSpace::new());
         Ok(RholangCompilerInterpreterProvider {
             processes: Arc::new(Mutex::new(HashMap::new())),
             next_pid: Arc::new(Mutex::new(1)),
             delay_ms: Arc::new(Mutex::new(0)),
+            max_output: Arc::new(Mutex::new(DEFAULT_MAX_OUTPUT)),
+            name_style: Arc::new(Mutex::new(NameRenderStyle::default())),
+            execution_mode: Arc::new(Mutex::new(ExecutionMode::default())),
+            timeout: Arc::new(Mutex::new(DEFAULT_TIMEOUT)),
+            rspace: Arc::new(Mutex::new(rspace)),
         })
     }
 
@@ -487,7 +723,16 @@ impl RholangCompilerInterpreterProvider {
         Ok(self)
     }
 
-    fn render_value(v: &VmValue) -> String {
+    pub fn set_timeout(&self, limit: Duration) -> Result<&Self> {
+        let mut timeout = self
+            .timeout
+            .lock()
+            .map_err(|e| anyhow!("Failed to lock timeout: {}", e))?;
+        *timeout = limit;
+        Ok(self)
+    }
+
+    fn render_value(v: &VmValue, max_output: usize, name_style: NameRenderStyle) -> String {
         match v {
             VmValue::Par(procs) => {
                 let inner: Vec<String> = procs
@@ -496,14 +741,13 @@ impl RholangCompilerInterpreterProvider {
                     .collect();
                 inner.join(" | ")
             }
-            other => other.to_string(),
+            other => other.to_string_truncated_with(max_output, name_style),
         }
     }
-}
 
-#[async_trait]
-impl InterpreterProvider for RholangCompilerInterpreterProvider {
-    async fn interpret(&self, code: &str) -> InterpretationResult {
+    /// Run `code` to completion, sending what it prints on `rho:io:stdout` to `stdout` if
+    /// given and to the process's own stdout otherwise
+    async fn run(&self, code: &str, stdout: Option<OutputSink>) -> InterpretationResult {
         // Clone inputs and shared state
         let code_clone = code.to_string();
         let code_for_task = code.to_string();
@@ -559,6 +803,48 @@ impl InterpreterProvider for RholangCompilerInterpreterProvider {
             }
         };
 
+        let max_output = match self.max_output.lock() {
+            Ok(guard) => *guard,
+            Err(e) => {
+                return InterpretationResult::Error(InterpreterError::other_error(format!(
+                    "Failed to lock max_output: {}",
+                    e
+                )))
+            }
+        };
+
+        let name_style = match self.name_style.lock() {
+            Ok(guard) => *guard,
+            Err(e) => {
+                return InterpretationResult::Error(InterpreterError::other_error(format!(
+                    "Failed to lock name_style: {}",
+                    e
+                )))
+            }
+        };
+
+        let mode = match self.execution_mode.lock() {
+            Ok(guard) => *guard,
+            Err(e) => {
+                return InterpretationResult::Error(InterpreterError::other_error(format!(
+                    "Failed to lock execution_mode: {}",
+                    e
+                )))
+            }
+        };
+
+        let limit = match self.timeout.lock() {
+            Ok(guard) => *guard,
+            Err(e) => {
+                return InterpretationResult::Error(InterpreterError::other_error(format!(
+                    "Failed to lock timeout: {}",
+                    e
+                )))
+            }
+        };
+
+        let rspace = Arc::clone(&self.rspace);
+
         // cancellation future
         let mut cancel_future = cancel_receiver;
 
@@ -587,19 +873,19 @@ impl InterpreterProvider for RholangCompilerInterpreterProvider {
                     };
 
                     if ast_vec.is_empty() {
-                        return InterpretationResult::Success("".to_string());
+                        return InterpretationResult::Success("Nil".to_string());
                     }
 
                     let mut db = SemanticDb::new();
-                    // For now, execute the first top-level process
-                    let first = &ast_vec[0];
-                    let root = db.build_index(first);
-
-                    // Run essential semantic passes before compilation using a dedicated runtime
-                    let pipeline = Pipeline::new()
-                        .add_fact(ResolverPass::new(root))
-                        .add_fact(ForCompElaborationPass::new(root))
-                        .add_fact(EnclosureAnalysisPass::new(root));
+                    let mut pipeline = Pipeline::new();
+                    for proc in &ast_vec {
+                        let root = db.build_index(proc);
+                        // Run essential semantic passes before compilation
+                        pipeline = pipeline
+                            .add_fact(ResolverPass::new(root))
+                            .add_fact(ForCompElaborationPass::new(root))
+                            .add_fact(EnclosureAnalysisPass::new(root));
+                    }
 
                     // Create a minimal runtime to block_on the async pipeline
                     if let Ok(rt) = tokio::runtime::Builder::new_current_thread()
@@ -631,8 +917,12 @@ impl InterpreterProvider for RholangCompilerInterpreterProvider {
                     }
 
                     let compiler = Compiler::new(&db);
-                    let mut process = match compiler.compile_single(first) {
-                        Ok(p) => p,
+                    let processes = match ast_vec
+                        .iter()
+                        .map(|proc| compiler.compile_single(proc))
+                        .collect::<Result<Vec<_>>>()
+                    {
+                        Ok(processes) => processes,
                         Err(e) => {
                             return InterpretationResult::Error(InterpreterError::other_error(
                                 format!("Compilation error: {}", e),
@@ -640,18 +930,48 @@ impl InterpreterProvider for RholangCompilerInterpreterProvider {
                         }
                     };
 
-                    // Execute the process (VM is initialized by default)
-                    let value = match process.execute() {
-                        Ok(v) => v,
-                        Err(e) => {
-                            return InterpretationResult::Error(InterpreterError::other_error(
-                                format!("Execution error: {}", e),
-                            ))
+                    // Execute the processes against the provider's tuple space so that
+                    // messages left behind are visible to later runs and to `.env`
+                    let mut outcomes = Vec::with_capacity(processes.len());
+                    for mut process in processes {
+                        process.vm = VM::with_shared_rspace(Arc::clone(&rspace));
+                        if let Some(sink) = &stdout {
+                            process.vm = process.vm.with_stdout(Arc::clone(sink));
                         }
-                    };
+                        let outcome = process.execute();
+                        let failed = is_failure(&outcome);
+                        outcomes.push(outcome);
+                        if failed && mode == ExecutionMode::FailFast {
+                            break;
+                        }
+                    }
 
-                    let rendered = Self::render_value(&value);
-                    InterpretationResult::Success(rendered)
+                    // Fail-fast reports the last process, or the one that failed;
+                    // independent mode reports every process on its own line
+                    match mode {
+                        ExecutionMode::FailFast => match outcomes.pop() {
+                            Some(Ok(value)) => InterpretationResult::Success(Self::render_value(
+                                &value, max_output, name_style,
+                            )),
+                            Some(Err(e @ ExecError::Blocked { .. })) => {
+                                InterpretationResult::Success(render_exec_error(&e))
+                            }
+                            Some(Err(e)) => InterpretationResult::Error(
+                                InterpreterError::other_error(format!("Execution error: {}", e)),
+                            ),
+                            None => InterpretationResult::Success("Nil".to_string()),
+                        },
+                        ExecutionMode::Independent => InterpretationResult::Success(
+                            outcomes
+                                .iter()
+                                .map(|outcome| match outcome {
+                                    Ok(value) => Self::render_value(value, max_output, name_style),
+                                    Err(e) => render_exec_error(e),
+                                })
+                                .collect::<Vec<_>>()
+                                .join("\n"),
+                        ),
+                    }
                 })
                 .await
                 .unwrap_or_else(|e| {
@@ -664,11 +984,11 @@ impl InterpreterProvider for RholangCompilerInterpreterProvider {
             res
         };
 
-        let timeout_future = timeout(Duration::from_secs(30), fut);
+        let timeout_future = timeout(limit, fut);
 
         let result = tokio::select! {
             result = timeout_future => {
-                result.unwrap_or_else(|_| InterpretationResult::Error(InterpreterError::timeout_error("Execution timed out after 30 seconds")))
+                result.unwrap_or_else(|_| InterpretationResult::Error(InterpreterError::timeout_error(format!("Execution timed out after {:?}", limit))))
             }
             _ = &mut cancel_future => {
                 InterpretationResult::Error(InterpreterError::cancellation_error("Execution was cancelled"))
@@ -689,86 +1009,111 @@ impl InterpreterProvider for RholangCompilerInterpreterProvider {
 
         result
     }
+}
 
-    fn disassemble(&self, code: &str) -> Result<String> {
-        // Helper that does the entire pipeline on the current thread
-        fn do_disassemble(code: &str) -> String {
-            // Parse
-            let parser = RholangParser::new();
-            let validated = parser.parse(code);
-
-            let ast_vec = match validated {
-                validated::Validated::Good(ast) => ast,
-                validated::Validated::Fail(_err) => {
-                    return "Parsing failed: unable to build AST. Please fix syntax errors and try again.".to_string();
-                }
-            };
+#[async_trait]
+impl InterpreterProvider for RholangCompilerInterpreterProvider {
+    async fn interpret(&self, code: &str) -> InterpretationResult {
+        self.run(code, None).await
+    }
 
-            if ast_vec.is_empty() {
-                return "No code to disassemble (empty AST)".to_string();
+    fn disassemble(&self, code: &str) -> Result<String> {
+        let code_owned = code.to_string();
+        let output = run_off_runtime(move || match compile_first_process(&code_owned) {
+            Ok(Some(process)) => {
+                // Disassemble in verbose format by default
+                use rholang_compiler::{Disassembler, DisassemblyFormat};
+                let disasm = Disassembler::with_format(DisassemblyFormat::Verbose);
+                disasm.disassemble(&process)
             }
+            Ok(None) => "No code to disassemble (empty AST)".to_string(),
+            Err(message) => message,
+        });
+        Ok(output.unwrap_or_else(|| "Disassembly failed due to thread panic".to_string()))
+    }
 
-            // Build semantic DB and run essential passes (resolver + elaborations)
-            let mut db = SemanticDb::new();
-            let first = &ast_vec[0];
-            let root = db.build_index(first);
-
-            // Run the pipeline using a lightweight runtime local to this thread
-            if let Ok(rt) = tokio::runtime::Builder::new_current_thread()
-                .enable_time()
-                .build()
-            {
-                let pipeline = Pipeline::new()
-                    .add_fact(ResolverPass::new(root))
-                    .add_fact(ForCompElaborationPass::new(root))
-                    .add_fact(EnclosureAnalysisPass::new(root));
-                rt.block_on(pipeline.run(&mut db));
-            } else {
-                return "Failed to initialize runtime for semantic pipeline".to_string();
-            }
+    fn step_session(&self, code: &str) -> Result<StepSession> {
+        let code_owned = code.to_string();
+        match run_off_runtime(move || compile_first_process(&code_owned)) {
+            Some(Ok(Some(process))) => Ok(StepSession::new(process)),
+            Some(Ok(None)) => Err(anyhow!("No code to step through (empty AST)")),
+            Some(Err(message)) => Err(anyhow!(message)),
+            None => Err(anyhow!("Compilation failed due to thread panic")),
+        }
+    }
 
-            // Filter out NameInProcPosition errors (handled by compiler emitting EVAL)
-            let real_errors: Vec<_> = db
-                .errors()
-                .filter(|diag| {
-                    !matches!(
-                        diag.kind,
-                        DiagnosticKind::Error(ErrorKind::NameInProcPosition(_, _))
-                    )
-                })
-                .collect();
+    fn compile_to_bytes(&self, code: &str) -> Result<Vec<u8>> {
+        let code_owned = code.to_string();
+        match run_off_runtime(move || compile_only_process(&code_owned)) {
+            Some(Ok(Some(process))) => process.to_bytes(),
+            Some(Ok(None)) => Err(anyhow!("No code to compile (empty AST)")),
+            Some(Err(message)) => Err(anyhow!(message)),
+            None => Err(anyhow!("Compilation failed due to thread panic")),
+        }
+    }
+
+    fn execute_bytes(&self, bytes: &[u8]) -> Result<String> {
+        let mut process = Process::from_bytes(bytes)?;
+        process.vm = VM::with_shared_rspace(Arc::clone(&self.rspace));
+        let limit = *self
+            .timeout
+            .lock()
+            .map_err(|e| anyhow!("Failed to lock timeout: {}", e))?;
 
-            if !real_errors.is_empty() {
-                return format!("Semantic errors: {:?}", real_errors);
+        // Like `run`, a process that does not finish in time is abandoned to its thread
+        let (sender, receiver) = std::sync::mpsc::channel();
+        std::thread::spawn(move || {
+            let _ = sender.send(process.execute());
+        });
+        let value = match receiver.recv_timeout(limit) {
+            Ok(outcome) => outcome.map_err(|e| anyhow!("Execution error: {}", e))?,
+            Err(RecvTimeoutError::Timeout) => {
+                return Err(anyhow!("Execution timed out after {:?}", limit))
+            }
+            Err(RecvTimeoutError::Disconnected) => {
+                return Err(anyhow!("Execution failed due to thread panic"))
             }
+        };
+        let max_output = *self
+            .max_output
+            .lock()
+            .map_err(|e| anyhow!("Failed to lock max_output: {}", e))?;
+        let name_style = *self
+            .name_style
+            .lock()
+            .map_err(|e| anyhow!("Failed to lock name_style: {}", e))?;
+        Ok(Self::render_value(&value, max_output, name_style))
+    }
 
-            // Compile first top-level process
-            let compiler = Compiler::new(&db);
-            let process = match compiler.compile_single(first) {
-                Ok(p) => p,
-                Err(e) => {
-                    return format!("Compilation error: {}", e);
-                }
-            };
+    fn set_max_output(&self, max_bytes: usize) -> Result<()> {
+        let mut max_output = self
+            .max_output
+            .lock()
+            .map_err(|e| anyhow!("Failed to lock max_output: {}", e))?;
+        *max_output = max_bytes;
+        Ok(())
+    }
 
-            // Disassemble in verbose format by default
-            use rholang_compiler::{Disassembler, DisassemblyFormat};
-            let disasm = Disassembler::with_format(DisassemblyFormat::Verbose);
-            disasm.disassemble(&process)
-        }
+    fn set_name_style(&self, style: NameRenderStyle) -> Result<()> {
+        let mut name_style = self
+            .name_style
+            .lock()
+            .map_err(|e| anyhow!("Failed to lock name_style: {}", e))?;
+        *name_style = style;
+        Ok(())
+    }
 
-        // If we're inside a Tokio runtime, offload the entire work to a dedicated OS thread
-        // to avoid nested-runtime and blocking issues. Otherwise, run directly.
-        if tokio::runtime::Handle::try_current().is_ok() {
-            let code_owned = code.to_string();
-            let join = std::thread::spawn(move || do_disassemble(&code_owned));
-            match join.join() {
-                Ok(s) => Ok(s),
-                Err(_e) => Ok("Disassembly failed due to thread panic".to_string()),
-            }
-        } else {
-            Ok(do_disassemble(code))
-        }
+    fn set_execution_mode(&self, mode: ExecutionMode) -> Result<()> {
+        let mut execution_mode = self
+            .execution_mode
+            .lock()
+            .map_err(|e| anyhow!("Failed to lock execution_mode: {}", e))?;
+        *execution_mode = mode;
+        Ok(())
+    }
+
+    fn rspace(&self) -> Option<SharedRSpace> {
+        Some(Arc::clone(&self.rspace))
     }
 
     fn list_processes(&self) -> Result<Vec<(usize, String)>> {
@@ -812,3 +1157,24 @@ impl InterpreterProvider for RholangCompilerInterpreterProvider {
         Ok(count)
     }
 }
+
+impl StreamingInterpreterProvider for RholangCompilerInterpreterProvider {
+    fn interpret_stream(&self, code: &str) -> InterpretStream {
+        let (sender, receiver) = mpsc::unbounded_channel();
+        let sink: OutputSink = Arc::new(Mutex::new(StreamSink {
+            sender: sender.clone(),
+            pending: Vec::new(),
+        }));
+        let provider = self.clone();
+        let code = code.to_string();
+        task::spawn(async move {
+            let result = provider.run(&code, Some(sink.clone())).await;
+            // Output that did not end with a newline still comes before the result
+            if let Ok(mut sink) = sink.lock() {
+                let _ = sink.flush();
+            }
+            let _ = sender.send(InterpretOutput::Finished(result));
+        });
+        InterpretStream { receiver }
+    }
+}
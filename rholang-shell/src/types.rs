@@ -0,0 +1,232 @@
+//! Lightweight static classification of expressions for the `.type` shell command.
+//!
+//! This is not type inference: each expression is labelled from its syntactic shape
+//! alone, and anything that would need knowledge of bound variables (apart from
+//! names introduced by an enclosing `new` and not shadowed by a `let`) is reported
+//! as unknown.
+
+use std::fmt;
+
+use anyhow::{anyhow, Result};
+use rholang_parser::ast::{
+    AnnProc, BinaryExpOp, Collection, LetBinding, Name, Proc, UnaryExpOp, Var,
+};
+use rholang_parser::RholangParser;
+
+/// The kind of value an expression evaluates to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StaticType {
+    Nil,
+    Bool,
+    Int,
+    BigInt,
+    BigRat,
+    Float,
+    FixedPoint,
+    String,
+    Uri,
+    Type,
+    Unit,
+    List,
+    Tuple,
+    Set,
+    Map,
+    PathMap,
+    Name,
+    Process,
+    Unknown,
+}
+
+impl StaticType {
+    fn is_numeric(self) -> bool {
+        matches!(
+            self,
+            StaticType::Int
+                | StaticType::BigInt
+                | StaticType::BigRat
+                | StaticType::Float
+                | StaticType::FixedPoint
+        )
+    }
+
+    /// The common type of two operands: a known type wins over `Unknown`, and
+    /// operands of different known types give `Unknown`.
+    fn unify(self, other: StaticType) -> StaticType {
+        match (self, other) {
+            (StaticType::Unknown, t) | (t, StaticType::Unknown) => t,
+            (a, b) if a == b => a,
+            _ => StaticType::Unknown,
+        }
+    }
+}
+
+impl fmt::Display for StaticType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let label = match self {
+            StaticType::Nil => "Nil",
+            StaticType::Bool => "Bool",
+            StaticType::Int => "Int",
+            StaticType::BigInt => "BigInt",
+            StaticType::BigRat => "BigRat",
+            StaticType::Float => "Float",
+            StaticType::FixedPoint => "FixedPoint",
+            StaticType::String => "String",
+            StaticType::Uri => "Uri",
+            StaticType::Type => "Type",
+            StaticType::Unit => "Unit",
+            StaticType::List => "List",
+            StaticType::Tuple => "Tuple",
+            StaticType::Set => "Set",
+            StaticType::Map => "Map",
+            StaticType::PathMap => "PathMap",
+            StaticType::Name => "Name",
+            StaticType::Process => "Process",
+            StaticType::Unknown => "unknown",
+        };
+        f.write_str(label)
+    }
+}
+
+/// Classify a single process without running it
+pub fn classify(proc: &AnnProc) -> StaticType {
+    classify_in(proc, &mut Vec::new())
+}
+
+/// Parse `code` and classify each top-level process, one label per process
+pub fn classify_code(code: &str) -> Result<Vec<StaticType>> {
+    let parser = RholangParser::new();
+    match parser.parse(code) {
+        validated::Validated::Good(procs) => Ok(procs.iter().map(classify).collect()),
+        validated::Validated::Fail(_) => Err(anyhow!("unable to parse expression")),
+    }
+}
+
+/// The identifiers bound by a `let` binding, which shadow any enclosing `new` names
+fn let_bound<'a>(binding: &'a LetBinding<'a>) -> impl Iterator<Item = &'a str> {
+    let names = binding.lhs.names.iter().flat_map(|name| match name {
+        Name::NameVar(var) => vec![*var],
+        Name::Quote(quoted) => quoted.iter_vars().collect(),
+    });
+    names
+        .chain(binding.lhs.remainder)
+        .filter_map(|var| match var {
+            Var::Id(id) => Some(id.name),
+            Var::Wildcard => None,
+        })
+}
+
+/// `names` holds the identifiers bound in enclosing scopes, innermost last, each paired
+/// with whether it was declared by `new`
+fn classify_in<'a>(proc: &AnnProc<'a>, names: &mut Vec<(&'a str, bool)>) -> StaticType {
+    match proc.proc {
+        Proc::Nil => StaticType::Nil,
+        Proc::Unit => StaticType::Unit,
+        Proc::BoolLiteral(_) => StaticType::Bool,
+        Proc::LongLiteral(_) | Proc::SignedIntLiteral { .. } | Proc::UnsignedIntLiteral { .. } => {
+            StaticType::Int
+        }
+        Proc::BigIntLiteral(_) => StaticType::BigInt,
+        Proc::BigRatLiteral(_) => StaticType::BigRat,
+        Proc::FloatLiteral { .. } => StaticType::Float,
+        Proc::FixedPointLiteral { .. } => StaticType::FixedPoint,
+        Proc::StringLiteral(_) => StaticType::String,
+        Proc::UriLiteral(_) => StaticType::Uri,
+        Proc::SimpleType(_) => StaticType::Type,
+        Proc::Collection(collection) => match collection {
+            Collection::List { .. } => StaticType::List,
+            Collection::Tuple(_) => StaticType::Tuple,
+            Collection::Set { .. } => StaticType::Set,
+            Collection::Map { .. } => StaticType::Map,
+            Collection::PathMap { .. } => StaticType::PathMap,
+        },
+        Proc::ProcVar(Var::Id(id))
+            if names
+                .iter()
+                .rev()
+                .find(|(name, _)| *name == id.name)
+                .is_some_and(|(_, is_new)| *is_new) =>
+        {
+            StaticType::Name
+        }
+        Proc::Eval {
+            name: Name::Quote(quoted),
+        } => classify_in(quoted, names),
+        Proc::IfThenElse {
+            if_true, if_false, ..
+        } => {
+            let then_type = classify_in(if_true, names);
+            let else_type = if_false
+                .as_ref()
+                .map_or(StaticType::Nil, |p| classify_in(p, names));
+            if then_type == else_type {
+                then_type
+            } else {
+                StaticType::Unknown
+            }
+        }
+        Proc::Let { bindings, body, .. } => {
+            let depth = names.len();
+            names.extend(
+                bindings
+                    .iter()
+                    .flat_map(let_bound)
+                    .map(|name| (name, false)),
+            );
+            let body = classify_in(body, names);
+            names.truncate(depth);
+            body
+        }
+        Proc::New { decls, proc } => {
+            let depth = names.len();
+            names.extend(decls.iter().map(|decl| (decl.id.name, true)));
+            let body = classify_in(proc, names);
+            names.truncate(depth);
+            body
+        }
+        Proc::Par { .. }
+        | Proc::Send { .. }
+        | Proc::SendSync { .. }
+        | Proc::ForComprehension { .. }
+        | Proc::Match { .. }
+        | Proc::Select { .. }
+        | Proc::Bundle { .. }
+        | Proc::Contract { .. } => StaticType::Process,
+        Proc::UnaryExp { op, arg } => match op {
+            UnaryExpOp::Not => StaticType::Bool,
+            UnaryExpOp::Neg => match classify_in(arg, names) {
+                t if t.is_numeric() => t,
+                _ => StaticType::Unknown,
+            },
+            UnaryExpOp::Negation => StaticType::Unknown,
+        },
+        Proc::BinaryExp { op, left, right } => {
+            let (l, r) = (classify_in(left, names), classify_in(right, names));
+            match op {
+                BinaryExpOp::Or
+                | BinaryExpOp::And
+                | BinaryExpOp::Matches
+                | BinaryExpOp::Eq
+                | BinaryExpOp::Neq
+                | BinaryExpOp::Lt
+                | BinaryExpOp::Lte
+                | BinaryExpOp::Gt
+                | BinaryExpOp::Gte => StaticType::Bool,
+                BinaryExpOp::Add
+                | BinaryExpOp::Sub
+                | BinaryExpOp::Mult
+                | BinaryExpOp::Div
+                | BinaryExpOp::Mod => match l.unify(r) {
+                    t if t.is_numeric() => t,
+                    _ => StaticType::Unknown,
+                },
+                BinaryExpOp::Concat | BinaryExpOp::Diff => l.unify(r),
+                BinaryExpOp::Interpolation => StaticType::String,
+                BinaryExpOp::Disjunction | BinaryExpOp::Conjunction => StaticType::Unknown,
+            }
+        }
+        Proc::ProcVar(_) | Proc::Eval { .. } | Proc::Method { .. } | Proc::VarRef { .. } => {
+            StaticType::Unknown
+        }
+        Proc::Bad => StaticType::Unknown,
+    }
+}
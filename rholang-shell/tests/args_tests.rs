@@ -27,3 +27,34 @@ fn test_args_parse_with_load_short() {
         "tests/data/sample.rho"
     );
 }
+
+#[test]
+fn test_args_parse_watch_with_file() {
+    let args = Args::parse_from(["rhosh", "--watch", "-f", "prog.rho"]);
+    assert!(args.watch);
+    assert_eq!(args.file.unwrap().to_string_lossy(), "prog.rho");
+}
+
+#[test]
+fn test_args_watch_requires_file() {
+    assert!(Args::try_parse_from(["rhosh", "-w"]).is_err());
+}
+
+#[test]
+fn test_args_parse_name_style() {
+    use rholang_vm::api::NameRenderStyle;
+
+    assert_eq!(
+        Args::parse_from(["rhosh"]).name_style,
+        NameRenderStyle::Quoted
+    );
+    let args = Args::parse_from(["rhosh", "--name-style", "index"]);
+    assert_eq!(args.name_style, NameRenderStyle::Index);
+    assert!(Args::try_parse_from(["rhosh", "--name-style", "bogus"]).is_err());
+}
+
+#[test]
+fn test_args_parse_keep_going() {
+    assert!(!Args::parse_from(["rhosh"]).keep_going);
+    assert!(Args::parse_from(["rhosh", "--keep-going"]).keep_going);
+}
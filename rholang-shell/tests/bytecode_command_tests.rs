@@ -0,0 +1,139 @@
+mod common;
+
+use anyhow::Result;
+use std::time::Duration;
+
+use common::TempFile;
+use rholang_shell::{
+    process_special_command,
+    providers::{FakeInterpreterProvider, InterpreterProvider, RholangCompilerInterpreterProvider},
+};
+
+fn run<I: InterpreterProvider>(command: &str, buffer: &[&str], interpreter: &I) -> Result<String> {
+    let mut buffer = buffer.iter().map(|line| line.to_string()).collect();
+    let mut stdout = Vec::new();
+    process_special_command(command, &mut buffer, &mut stdout, |_| Ok(()), interpreter)?;
+    Ok(String::from_utf8(stdout)?)
+}
+
+#[test]
+fn bytecode_round_trips_through_a_file() -> Result<()> {
+    let interpreter = RholangCompilerInterpreterProvider::new()?;
+    let file = TempFile::new("round-trip.rhbc");
+
+    let saved = run(
+        &format!(".save-bytecode {}", file.path()),
+        &["[1 + 2,", "\"three\"]"],
+        &interpreter,
+    )?;
+    assert!(saved.starts_with("Wrote "), "{saved}");
+    assert!(saved.contains(file.path()), "{saved}");
+
+    let loaded = run(
+        &format!(".load-bytecode {}", file.path()),
+        &[],
+        &interpreter,
+    )?;
+    assert_eq!(loaded, "[3, \"three\"]\n");
+    Ok(())
+}
+
+#[test]
+fn save_bytecode_reports_compile_errors() -> Result<()> {
+    let interpreter = RholangCompilerInterpreterProvider::new()?;
+    let file = TempFile::new("parse-error.rhbc");
+
+    let output = run(
+        &format!(".save-bytecode {}", file.path()),
+        &["("],
+        &interpreter,
+    )?;
+    assert!(output.contains("Compilation error:"), "{output}");
+    assert!(!file.0.exists());
+    Ok(())
+}
+
+#[test]
+fn save_bytecode_refuses_to_drop_processes() -> Result<()> {
+    let interpreter = RholangCompilerInterpreterProvider::new()?;
+    let file = TempFile::new("two-processes.rhbc");
+
+    let output = run(
+        &format!(".save-bytecode {}", file.path()),
+        &["1 + 2", "\"three\""],
+        &interpreter,
+    )?;
+    assert!(output.contains("Compilation error:"), "{output}");
+    assert!(
+        output.contains("Expected a single top-level process, found 2"),
+        "{output}"
+    );
+    assert!(!file.0.exists());
+    Ok(())
+}
+
+#[test]
+fn load_bytecode_gives_up_on_processes_that_never_finish() -> Result<()> {
+    let interpreter = RholangCompilerInterpreterProvider::new()?;
+    interpreter.set_timeout(Duration::from_millis(200))?;
+    let file = TempFile::new("endless.rhbc");
+
+    run(
+        &format!(".save-bytecode {}", file.path()),
+        &["new loop in { contract loop(@n) = { loop!(n + 1) } | loop!(0) }"],
+        &interpreter,
+    )?;
+    let output = run(
+        &format!(".load-bytecode {}", file.path()),
+        &[],
+        &interpreter,
+    )?;
+    assert!(
+        output.contains("Execution timed out after 200ms"),
+        "{output}"
+    );
+    Ok(())
+}
+
+#[test]
+fn load_bytecode_rejects_other_files() -> Result<()> {
+    let interpreter = RholangCompilerInterpreterProvider::new()?;
+    let file = TempFile::new("not-bytecode.rhbc");
+    std::fs::write(&file.0, "1 + 2")?;
+
+    let output = run(
+        &format!(".load-bytecode {}", file.path()),
+        &[],
+        &interpreter,
+    )?;
+    assert!(output.contains("Bytecode error:"), "{output}");
+    assert!(output.contains("bad magic"), "{output}");
+
+    let missing = run(".load-bytecode /nonexistent/file.rhbc", &[], &interpreter)?;
+    assert!(missing.starts_with("Error loading file"), "{missing}");
+    Ok(())
+}
+
+#[test]
+fn bytecode_usage_and_unsupported_providers() -> Result<()> {
+    let interpreter = RholangCompilerInterpreterProvider::new()?;
+    assert_eq!(
+        run(".save-bytecode", &["1"], &interpreter)?,
+        "Usage: .save-bytecode <file>\n"
+    );
+    assert_eq!(
+        run(".save-bytecode out.rhbc", &[" "], &interpreter)?,
+        "Buffer is empty, nothing to compile\n"
+    );
+    assert_eq!(
+        run(".load-bytecode", &[], &interpreter)?,
+        "Usage: .load-bytecode <file>\n"
+    );
+
+    let output = run(".save-bytecode out.rhbc", &["1"], &FakeInterpreterProvider)?;
+    assert!(
+        output.contains("not supported by this provider"),
+        "{output}"
+    );
+    Ok(())
+}
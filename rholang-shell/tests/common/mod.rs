@@ -0,0 +1,51 @@
+//! Helpers shared by the shell's integration tests
+
+/// A file in the temp dir, named after this test process, removed again when dropped
+pub struct TempFile(pub std::path::PathBuf);
+
+impl TempFile {
+    /// A path for `name` that nothing has been written to yet
+    #[allow(dead_code)]
+    pub fn new(name: &str) -> Self {
+        let file_name = format!("rhosh-{}-{name}", std::process::id());
+        TempFile(std::env::temp_dir().join(file_name))
+    }
+
+    /// A file called `name` holding `contents`
+    #[allow(dead_code)]
+    pub fn with_contents(name: &str, contents: &str) -> Self {
+        let file = Self::new(name);
+        std::fs::write(&file.0, contents).unwrap();
+        file
+    }
+
+    #[allow(dead_code)]
+    pub fn path(&self) -> &str {
+        self.0.to_str().unwrap()
+    }
+}
+
+impl Drop for TempFile {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.0);
+    }
+}
+
+/// A directory in the temp dir, named after this test process, removed with everything in it
+/// when dropped
+pub struct TempDir(pub std::path::PathBuf);
+
+impl TempDir {
+    #[allow(dead_code)]
+    pub fn new(name: &str) -> Self {
+        let dir = std::env::temp_dir().join(format!("rhosh-{}-{name}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        TempDir(dir)
+    }
+}
+
+impl Drop for TempDir {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.0);
+    }
+}
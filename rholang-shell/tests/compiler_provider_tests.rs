@@ -1,6 +1,8 @@
 use anyhow::Result;
+use futures::StreamExt;
 use rholang_shell::providers::{
-    InterpretationResult, InterpreterProvider, RholangCompilerInterpreterProvider,
+    ExecutionMode, InterpretOutput, InterpretationResult, InterpreterProvider,
+    RholangCompilerInterpreterProvider, StreamingInterpreterProvider,
 };
 
 // Use Tokio tests for async provider methods
@@ -15,6 +17,18 @@ async fn interpret_success_nil() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn interpret_empty_programs_yield_nil() -> Result<()> {
+    let provider = RholangCompilerInterpreterProvider::new()?;
+    for code in ["", "   "] {
+        match provider.interpret(code).await {
+            InterpretationResult::Success(s) => assert_eq!(s, "Nil"),
+            other => panic!("Expected Success, got: {:?}", other),
+        }
+    }
+    Ok(())
+}
+
 #[tokio::test]
 async fn interpret_parse_error() -> Result<()> {
     let provider = RholangCompilerInterpreterProvider::new()?;
@@ -61,3 +75,87 @@ async fn process_management_and_cancellation() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn large_results_are_truncated() -> Result<()> {
+    let provider = RholangCompilerInterpreterProvider::new()?;
+    provider.set_max_output(100)?;
+
+    let elements: Vec<String> = (0..10_000).map(|i| i.to_string()).collect();
+    let code = format!("[{}]", elements.join(", "));
+    match provider.interpret(&code).await {
+        InterpretationResult::Success(s) => {
+            assert!(s.len() < 200, "not truncated: {} bytes", s.len());
+            assert!(s.starts_with("[0, 1, 2, "), "{s}");
+            assert!(s.ends_with(" more)]"), "{s}");
+        }
+        other => panic!("Expected Success, got: {:?}", other),
+    }
+    Ok(())
+}
+
+#[tokio::test]
+async fn interpret_stream_yields_stdout_before_the_result() -> Result<()> {
+    let provider = RholangCompilerInterpreterProvider::new()?;
+    let code = r#"new out(`rho:io:stdout`) in { out!("one") | out!("two") | out!("three") }"#;
+    let items: Vec<InterpretOutput> = provider.interpret_stream(code).collect().await;
+
+    assert_eq!(items.len(), 4, "{items:?}");
+    let mut printed: Vec<&str> = items[..3]
+        .iter()
+        .map(|item| match item {
+            InterpretOutput::Stdout(line) => line.as_str(),
+            other => panic!("Expected Stdout, got: {:?}", other),
+        })
+        .collect();
+    printed.sort();
+    assert_eq!(printed, ["one", "three", "two"]);
+    match &items[3] {
+        InterpretOutput::Finished(result) => assert!(result.is_success(), "{result:?}"),
+        other => panic!("Expected Finished, got: {:?}", other),
+    }
+    Ok(())
+}
+
+#[tokio::test]
+async fn interpret_stream_ends_with_the_error() -> Result<()> {
+    let provider = RholangCompilerInterpreterProvider::new()?;
+    let items: Vec<InterpretOutput> = provider.interpret_stream("(").collect().await;
+
+    assert!(
+        matches!(
+            items.as_slice(),
+            [InterpretOutput::Finished(InterpretationResult::Error(_))]
+        ),
+        "{items:?}"
+    );
+    Ok(())
+}
+
+const BATCH: &str = "1 + 2\n10 / 0\n\"ok\"";
+
+#[tokio::test]
+async fn fail_fast_stops_at_the_first_failing_process() -> Result<()> {
+    let provider = RholangCompilerInterpreterProvider::new()?;
+
+    match provider.interpret(BATCH).await {
+        InterpretationResult::Error(e) => assert!(e.message.contains("Execution error"), "{e}"),
+        other => panic!("Expected Error, got: {:?}", other),
+    }
+    assert_eq!(provider.interpret("1\n2").await.unwrap(), "2");
+    Ok(())
+}
+
+#[tokio::test]
+async fn keep_going_reports_every_process() -> Result<()> {
+    let provider = RholangCompilerInterpreterProvider::new()?;
+    provider.set_execution_mode(ExecutionMode::Independent)?;
+
+    let rendered = provider.interpret(BATCH).await.unwrap();
+    let lines: Vec<_> = rendered.lines().collect();
+    assert_eq!(lines.len(), 3, "{rendered}");
+    assert_eq!(lines[0], "3");
+    assert!(lines[1].starts_with("Execution error:"), "{}", lines[1]);
+    assert_eq!(lines[2], "\"ok\"");
+    Ok(())
+}
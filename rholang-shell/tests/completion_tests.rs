@@ -0,0 +1,65 @@
+use anyhow::Result;
+use std::io::Cursor;
+
+use rholang_shell::{
+    completion::{complete, complete_keyword, KEYWORDS},
+    process_special_command,
+    providers::FakeInterpreterProvider,
+};
+
+fn complete_at_end(code: &str) -> Vec<String> {
+    complete(code, code.len())
+}
+
+#[test]
+fn keyword_prefix() {
+    assert_eq!(complete_keyword("con"), vec!["contract"]);
+    assert_eq!(complete_keyword("s"), vec!["select"]);
+    assert_eq!(complete_keyword(""), KEYWORDS);
+    assert!(complete_keyword("xyz").is_empty());
+    assert_eq!(complete_at_end("con"), vec!["contract"]);
+}
+
+#[test]
+fn names_in_scope_at_cursor() {
+    assert_eq!(
+        complete_at_end("new stdout, stderr, counter in { st"),
+        vec!["stderr", "stdout"]
+    );
+    assert_eq!(
+        complete_at_end("new counter in { contract c(x) = { co"),
+        vec!["counter", "contract"]
+    );
+}
+
+#[test]
+fn names_out_of_scope_are_not_offered() {
+    assert_eq!(
+        complete_at_end("new stdout in { Nil } | st"),
+        Vec::<String>::new()
+    );
+}
+
+#[test]
+fn multi_byte_separator_before_prefix() {
+    assert_eq!(complete_at_end("x·con"), vec!["contract"]);
+}
+
+#[test]
+fn complete_command_uses_buffer() -> Result<()> {
+    let interpreter = FakeInterpreterProvider;
+    let mut buffer = vec!["new counter, ack in {".to_string()];
+    let mut stdout = Cursor::new(Vec::new());
+
+    process_special_command(
+        ".complete c",
+        &mut buffer,
+        &mut stdout,
+        |_| Ok(()),
+        &interpreter,
+    )?;
+
+    let output = String::from_utf8(stdout.into_inner())?;
+    assert_eq!(output, "counter  contract\n");
+    Ok(())
+}
@@ -0,0 +1,84 @@
+mod common;
+
+use anyhow::Result;
+
+use common::TempFile;
+use rholang_shell::{process_special_command, providers::FakeInterpreterProvider};
+
+fn run(command: &str) -> Result<String> {
+    let mut buffer = Vec::new();
+    let mut stdout = Vec::new();
+    process_special_command(
+        command,
+        &mut buffer,
+        &mut stdout,
+        |_| Ok(()),
+        &FakeInterpreterProvider,
+    )?;
+    Ok(String::from_utf8(stdout)?)
+}
+
+fn diff(left: &str, right: &str) -> Result<String> {
+    run(&format!(".diff {left} {right}"))
+}
+
+#[test]
+fn whitespace_changes_are_equivalent() -> Result<()> {
+    let compact = TempFile::with_contents("diff-compact.rho", "new x in { x!(1 + 2) | Nil }");
+    let spread =
+        TempFile::with_contents("diff-spread.rho", "new x in {\n    x!(1+2)\n  | Nil\n}\n");
+
+    let output = diff(compact.path(), spread.path())?;
+    assert!(output.starts_with("Equivalent:"), "{output}");
+    Ok(())
+}
+
+#[test]
+fn changed_literal_is_reported_with_its_location() -> Result<()> {
+    let before = TempFile::with_contents("diff-before.rho", "new x in { x!(\"a\", 1) }");
+    let after = TempFile::with_contents("diff-after.rho", "new x in {\n  x!(\"a\", 2)\n}");
+
+    let output = diff(before.path(), after.path())?;
+    assert!(output.starts_with("Different:"), "{output}");
+    assert!(
+        output.contains(&format!("{}:1:20-21: 1", before.path())),
+        "{output}"
+    );
+    assert!(
+        output.contains(&format!("{}:2:11-12: 2", after.path())),
+        "{output}"
+    );
+    Ok(())
+}
+
+#[test]
+fn parse_failures_and_usage() -> Result<()> {
+    let good = TempFile::with_contents("diff-good.rho", "Nil");
+    let bad = TempFile::with_contents("diff-bad.rho", "new x in {");
+
+    let output = diff(good.path(), bad.path())?;
+    assert!(output.starts_with("Parsing failed:"), "{output}");
+    assert!(output.contains(bad.path()), "{output}");
+
+    let missing = diff(good.path(), "/nonexistent/file.rho")?;
+    assert!(missing.starts_with("Error loading file"), "{missing}");
+
+    let usage = "Usage: .diff <file1> <file2> (quote paths that contain spaces)\n";
+    assert_eq!(run(".diff one.rho")?, usage);
+    assert_eq!(run(".diff one.rho \"two.rho")?, usage);
+    Ok(())
+}
+
+#[test]
+fn quoted_paths_may_contain_spaces() -> Result<()> {
+    let left = TempFile::with_contents("diff left side.rho", "x!(1)");
+    let right = TempFile::with_contents("diff right side.rho", "x!( 1 )");
+
+    let output = diff(
+        &format!("\"{}\"", left.path()),
+        &format!("\"{}\"", right.path()),
+    )?;
+    assert!(output.starts_with("Equivalent:"), "{output}");
+    assert!(output.contains(left.path()), "{output}");
+    Ok(())
+}
@@ -0,0 +1,139 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use std::sync::{Arc, Mutex};
+
+use rholang_shell::{
+    process_special_command,
+    providers::{FakeInterpreterProvider, InterpretationResult, InterpreterProvider},
+};
+use rholang_vm::api::{Entry, InMemoryRSpace, RSpace, SharedRSpace, Value};
+
+/// Interpreter that runs nothing but keeps an RSpace between calls
+struct MockInterpreter {
+    rspace: SharedRSpace,
+}
+
+impl MockInterpreter {
+    fn new() -> Self {
+        let rspace: Box<dyn RSpace> = Box::new(InMemoryRSpace::new());
+        MockInterpreter {
+            rspace: Arc::new(Mutex::new(rspace)),
+        }
+    }
+}
+
+#[async_trait]
+impl InterpreterProvider for MockInterpreter {
+    async fn interpret(&self, code: &str) -> InterpretationResult {
+        InterpretationResult::Success(code.to_string())
+    }
+
+    fn rspace(&self) -> Option<SharedRSpace> {
+        Some(Arc::clone(&self.rspace))
+    }
+
+    fn list_processes(&self) -> Result<Vec<(usize, String)>> {
+        Ok(Vec::new())
+    }
+
+    fn kill_process(&self, _pid: usize) -> Result<bool> {
+        Ok(false)
+    }
+
+    fn kill_all_processes(&self) -> Result<usize> {
+        Ok(0)
+    }
+}
+
+fn run<I: InterpreterProvider>(command: &str, interpreter: &I) -> Result<String> {
+    let mut buffer = Vec::new();
+    let mut stdout = Vec::new();
+    process_special_command(command, &mut buffer, &mut stdout, |_| Ok(()), interpreter)?;
+    Ok(String::from_utf8(stdout)?)
+}
+
+#[test]
+fn env_lists_entries_as_a_tree() -> Result<()> {
+    let interpreter = MockInterpreter::new();
+    {
+        let mut rspace = interpreter.rspace.lock().unwrap();
+        rspace.tell("inbox", Value::Int(1))?;
+        rspace.tell("inbox", Value::Str("two".to_string()))?;
+        rspace.set_value("config", Value::Bool(true))?;
+    }
+
+    assert_eq!(
+        run(".env", &interpreter)?,
+        "config: Value\n  true\ninbox: Channel\n  1\n  \"two\"\n"
+    );
+    Ok(())
+}
+
+#[test]
+fn env_on_an_empty_rspace() -> Result<()> {
+    let interpreter = MockInterpreter::new();
+    assert_eq!(run(".env", &interpreter)?, "RSpace is empty\n");
+    Ok(())
+}
+
+#[test]
+fn env_set_parses_and_sends_the_value() -> Result<()> {
+    let interpreter = MockInterpreter::new();
+
+    assert_eq!(
+        run(".env set inbox [1, 2]", &interpreter)?,
+        "Sent [1, 2] on inbox\n"
+    );
+    run(".env set inbox {1: \"a\"}", &interpreter)?;
+
+    let rspace = interpreter.rspace.lock().unwrap();
+    assert_eq!(
+        rspace.get_entry("inbox"),
+        Some(Entry::Channel(vec![
+            Value::List(vec![Value::Int(1), Value::Int(2)]),
+            Value::Map(vec![(Value::Int(1), Value::Str("a".to_string()))]),
+        ]))
+    );
+    Ok(())
+}
+
+#[test]
+fn env_set_rejects_malformed_values() -> Result<()> {
+    let interpreter = MockInterpreter::new();
+
+    let output = run(".env set inbox [1, 2", &interpreter)?;
+    assert!(output.starts_with("Invalid value '[1, 2'"), "{output}");
+    assert!(interpreter.rspace.lock().unwrap().entries().is_empty());
+    Ok(())
+}
+
+#[test]
+fn env_clear_removes_the_entry() -> Result<()> {
+    let interpreter = MockInterpreter::new();
+    run(".env set inbox 42", &interpreter)?;
+
+    assert_eq!(run(".env clear inbox", &interpreter)?, "Cleared inbox\n");
+    assert_eq!(
+        run(".env clear inbox", &interpreter)?,
+        "No entry named inbox\n"
+    );
+    assert!(interpreter
+        .rspace
+        .lock()
+        .unwrap()
+        .get_entry("inbox")
+        .is_none());
+    Ok(())
+}
+
+#[test]
+fn env_usage_and_stateless_interpreters() -> Result<()> {
+    let usage = "Usage: .env [set <channel> <value> | clear <channel>]\n";
+    assert_eq!(run(".env set", &MockInterpreter::new())?, usage);
+    assert_eq!(run(".env drop inbox", &MockInterpreter::new())?, usage);
+    assert_eq!(
+        run(".env", &FakeInterpreterProvider)?,
+        "This interpreter keeps no RSpace between runs\n"
+    );
+    Ok(())
+}
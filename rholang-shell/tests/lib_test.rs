@@ -445,6 +445,31 @@ fn test_validate_invalid_buffer_reports_diagnostics() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_validate_deny_warnings() -> Result<()> {
+    let interpreter = MockInterpreterProvider::new();
+    let validate = |command: &str| -> Result<String> {
+        let mut buffer = vec!["new x in { Nil }".to_string()];
+        let mut stdout = Cursor::new(Vec::new());
+        process_special_command(command, &mut buffer, &mut stdout, |_| Ok(()), &interpreter)?;
+        Ok(String::from_utf8(stdout.into_inner())?)
+    };
+
+    // the unused variable is only a warning by default...
+    let lenient = validate(".validate")?;
+    assert!(lenient.contains("UnusedVariable"), "{lenient}");
+    assert!(!lenient.contains("Validation failed"), "{lenient}");
+
+    // ...but fails validation when warnings are denied
+    let strict = validate(".validate --deny-warnings")?;
+    assert!(strict.contains("UnusedVariable"), "{strict}");
+    assert!(
+        strict.contains("Validation failed: warnings are denied"),
+        "{strict}"
+    );
+    Ok(())
+}
+
 #[test]
 fn test_validate_unused_command() -> Result<()> {
     let mut buffer = vec!["new ch in { for(@x <- ch) { Nil } }".to_string()];
@@ -1,9 +1,10 @@
 use anyhow::Result;
-use std::io::Cursor;
+use std::io::{BufWriter, Cursor, Write};
+use std::sync::{Arc, Mutex};
 
 use rholang_shell::{
     handle_interrupt, process_multiline_input, process_special_command,
-    providers::FakeInterpreterProvider,
+    providers::FakeInterpreterProvider, FlushGuard,
 };
 
 // Helper function to create a fake interpreter provider
@@ -524,3 +525,62 @@ async fn test_process_multiline_input_mixed_brackets_all_types() -> Result<()> {
 
     Ok(())
 }
+
+/// Writer into a shared buffer, so what reached it can be checked after its owner is dropped
+#[derive(Clone, Default)]
+struct Sink(Arc<Mutex<Vec<u8>>>);
+
+impl Sink {
+    fn contents(&self) -> String {
+        String::from_utf8(self.0.lock().unwrap().clone()).unwrap()
+    }
+}
+
+impl Write for Sink {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0.lock().unwrap().extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+#[test]
+fn test_flush_guard_flushes_on_error_return() {
+    let sink = Sink::default();
+    let run = || -> Result<()> {
+        let mut out = FlushGuard::writer(BufWriter::new(sink.clone()));
+        write!(out, "partial output")?;
+        anyhow::bail!("command failed")
+    };
+
+    assert!(run().is_err());
+    assert_eq!(sink.contents(), "partial output");
+}
+
+#[test]
+fn test_flush_guard_flushes_on_panic() {
+    let sink = Sink::default();
+    let run = || {
+        let mut out = FlushGuard::writer(BufWriter::new(sink.clone()));
+        write!(out, "partial output").unwrap();
+        panic!("interpreter panicked");
+    };
+
+    assert!(std::panic::catch_unwind(run).is_err());
+    assert_eq!(sink.contents(), "partial output");
+}
+
+#[test]
+fn test_flush_guard_runs_custom_flush_on_drop() {
+    // The interactive loop guards `Readline` this way: its output waits in a queue until flushed
+    let mut printed = Vec::new();
+    {
+        let mut queue = FlushGuard::new(Vec::new(), |queue: &mut Vec<&str>| printed.append(queue));
+        queue.push("Output: 3");
+        assert_eq!(queue.len(), 1);
+    }
+    assert_eq!(printed, vec!["Output: 3"]);
+}
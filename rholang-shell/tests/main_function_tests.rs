@@ -1,6 +1,12 @@
+mod common;
+
 use anyhow::Result;
 use clap::Parser;
-use rholang_shell::{providers::RholangParserInterpreterProvider, run_shell, Args};
+use common::{TempDir, TempFile};
+use rholang_shell::{
+    providers::{FakeInterpreterProvider, RholangParserInterpreterProvider},
+    run_shell, Args,
+};
 use std::time::Duration;
 use tokio::time::timeout;
 
@@ -30,3 +36,107 @@ async fn test_main_function_code_path() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn test_deny_warnings_fails_non_interactive_run() -> Result<()> {
+    let code = "new x in { Nil }";
+    let lenient = Args::parse_from(["program_name", "-e", code]);
+    assert!(run_shell(lenient, FakeInterpreterProvider).await.is_ok());
+
+    let strict = Args::parse_from(["program_name", "--deny-warnings", "-e", code]);
+    let err = run_shell(strict, FakeInterpreterProvider)
+        .await
+        .expect_err("an unused variable should fail under --deny-warnings");
+    assert!(err.to_string().contains("--deny-warnings"), "{err}");
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_lint_dir_summarizes_and_fails_on_errors() -> Result<()> {
+    let temp = TempDir::new("lint");
+    let dir = &temp.0;
+    std::fs::create_dir_all(dir.join("nested"))?;
+    std::fs::write(dir.join("clean.rho"), "new x in { x!(1) }")?;
+    std::fs::write(dir.join("nested").join("broken.rho"), "y!(1)")?;
+    std::fs::write(dir.join("notes.txt"), "not rholang")?;
+
+    let mut stdout = Vec::new();
+    let passed = rholang_shell::lint_directory(dir, &mut stdout, false)?;
+    let output = String::from_utf8(stdout)?;
+    assert!(!passed, "{output}");
+    assert!(output.contains("clean.rho"), "{output}");
+    assert!(output.contains("broken.rho"), "{output}");
+    assert!(!output.contains("notes.txt"), "{output}");
+    assert!(
+        output.ends_with("Linted 2 file(s): 1 passed, 1 failed\n"),
+        "{output}"
+    );
+
+    let args = Args::parse_from(["program_name", "--lint-dir", dir.to_str().unwrap()]);
+    let result = run_shell(args, FakeInterpreterProvider).await;
+    assert!(result.is_err(), "an unresolved name should fail the lint");
+
+    std::fs::remove_file(dir.join("nested").join("broken.rho"))?;
+    let args = Args::parse_from(["program_name", "--lint-dir", dir.to_str().unwrap()]);
+    let result = run_shell(args, FakeInterpreterProvider).await;
+    assert!(result.is_ok(), "{result:?}");
+    Ok(())
+}
+
+#[cfg(unix)]
+#[test]
+fn test_lint_dir_does_not_follow_directory_symlinks() -> Result<()> {
+    let temp = TempDir::new("lint-symlinks");
+    let dir = &temp.0;
+    std::fs::create_dir_all(dir.join("nested"))?;
+    std::fs::write(dir.join("nested").join("clean.rho"), "new x in { x!(1) }")?;
+    // a link back to the root would be walked forever if it were followed
+    std::os::unix::fs::symlink(dir, dir.join("nested").join("loop"))?;
+
+    let mut stdout = Vec::new();
+    let passed = rholang_shell::lint_directory(dir, &mut stdout, false)?;
+    let output = String::from_utf8(stdout)?;
+    assert!(passed, "{output}");
+    assert!(
+        output.ends_with("Linted 1 file(s): 1 passed, 0 failed\n"),
+        "{output}"
+    );
+    Ok(())
+}
+
+#[cfg(unix)]
+#[test]
+fn test_watch_runs_start_from_an_empty_rspace() -> Result<()> {
+    use std::io::{BufRead, BufReader};
+    use std::process::{Command, Stdio};
+
+    let file = TempFile::with_contents("watch.rho", "@\"c\"!(1)");
+    let mut child = Command::new(env!("CARGO_BIN_EXE_rhosh"))
+        .args(["--watch", "-f", file.path()])
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn()?;
+    let stdout = BufReader::new(child.stdout.take().expect("stdout is piped"));
+
+    // The second version would receive the message the first one sent, had it been kept
+    let mut output = Vec::new();
+    let mut runs = 0;
+    for line in stdout.lines() {
+        let line = line?;
+        if line.starts_with("Watching") {
+            runs += 1;
+            if runs == 2 {
+                break;
+            }
+            std::fs::write(&file.0, "for (@x <- @\"c\") { x }")?;
+        } else {
+            output.push(line);
+        }
+    }
+    child.kill()?;
+    child.wait()?;
+
+    assert_eq!(runs, 2, "output: {output:?}");
+    assert!(!output.contains(&"1".to_string()), "output: {output:?}");
+    Ok(())
+}
@@ -10,6 +10,12 @@ fn test_args_creation_and_parse() {
         file: None,
         disassemble: false,
         both: false,
+        watch: false,
+        max_output: rholang_shell::providers::DEFAULT_MAX_OUTPUT,
+        name_style: Default::default(),
+        keep_going: false,
+        deny_warnings: false,
+        lint_dir: None,
     };
     let _parsed = Args::try_parse_from(["program_name"]).expect("Failed to parse args");
 }
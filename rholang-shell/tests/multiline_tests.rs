@@ -67,3 +67,16 @@ async fn test_multiline_commands_joined_correctly(
 
     Ok(())
 }
+
+#[rstest]
+#[case("new x in {", false)]
+#[case("new x in {\n  x!(1) | for (@y <- x) {", false)]
+#[case("contract c(x) = {", false)]
+#[case("new x in { x!(1) }", true)]
+#[case("new x in {\n  x!(1) | for (@y <- x) { Nil }\n}", true)]
+#[case("}}", true)]
+#[case("new x in { Nil }}", true)]
+#[case("", true)]
+fn test_input_is_complete(#[case] code: &str, #[case] expected: bool) {
+    assert_eq!(rholang_shell::input_is_complete(code), expected, "{code:?}");
+}
@@ -32,6 +32,46 @@ fn run_rhosh(args: &[&str]) -> (String, String, bool) {
     (stdout, stderr, success)
 }
 
+/// Helper to drive the interactive shell in a pseudo-terminal provided by `script`, typing `keys`
+/// once the first prompt is shown. Returns `None` when `script` cannot be run
+#[cfg(target_os = "linux")]
+fn run_rhosh_in_terminal(keys: &str) -> Option<String> {
+    use std::io::{Read, Write};
+    use std::process::Stdio;
+
+    let command = format!("'{}'", env!("CARGO_BIN_EXE_rhosh"));
+    let mut child = Command::new("script")
+        .args(["-qec", &command, "/dev/null"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn()
+        .ok()?;
+    let mut stdin = child.stdin.take().expect("Failed to open stdin");
+    let mut stdout = child.stdout.take().expect("Failed to open stdout");
+
+    // Keys typed before the shell puts the terminal in raw mode would be discarded
+    let mut output = Vec::new();
+    let mut chunk = [0u8; 4096];
+    while !String::from_utf8_lossy(&output).contains(">>> ") {
+        let n = stdout.read(&mut chunk).expect("Failed to read output");
+        if n == 0 {
+            break;
+        }
+        output.extend_from_slice(&chunk[..n]);
+    }
+
+    stdin
+        .write_all(keys.as_bytes())
+        .expect("Failed to write to stdin");
+    stdout
+        .read_to_end(&mut output)
+        .expect("Failed to read output");
+    drop(stdin);
+    child.wait().expect("Failed to wait on child");
+    Some(String::from_utf8_lossy(&output).into_owned())
+}
+
 /// Helper to run rhosh with stdin input
 fn run_rhosh_with_stdin(args: &[&str], input: &str) -> (String, String, bool) {
     use std::io::Write;
@@ -295,6 +335,21 @@ fn test_stdin_both() {
     assert!(stdout.contains("7"), "Expected result 7");
 }
 
+#[test]
+#[cfg(target_os = "linux")]
+fn test_interactive_output_is_flushed_on_exit() {
+    // Ctrl+D straight after the run ends the loop while the result may still be queued for printing
+    let Some(output) = run_rhosh_in_terminal("1 + 2\r\r\r\x04") else {
+        eprintln!("skipping: `script` is not available to provide a terminal");
+        return;
+    };
+    let result = output
+        .split("Output:")
+        .nth(1)
+        .unwrap_or_else(|| panic!("Expected the result to be printed, got: {}", output));
+    assert!(result.contains(" 3"), "Expected result 3, got: {}", output);
+}
+
 #[test]
 fn test_stdin_multiline_code() {
     let code = r#"new x in {
@@ -0,0 +1,77 @@
+use anyhow::Result;
+
+use rholang_shell::{
+    process_special_command_with_session, providers::FakeInterpreterProvider, AstFormat,
+    ShellSession,
+};
+
+fn run(command: &str, session: &mut ShellSession) -> Result<String> {
+    let mut stdout = Vec::new();
+    process_special_command_with_session(
+        command,
+        &mut Vec::new(),
+        &mut stdout,
+        |_| Ok(()),
+        &FakeInterpreterProvider,
+        session,
+    )?;
+    Ok(String::from_utf8(stdout)?)
+}
+
+#[test]
+fn set_ast_format_toggles_the_session_mode() -> Result<()> {
+    let mut session = ShellSession::default();
+    assert_eq!(session.ast_format, AstFormat::Pretty);
+    assert_eq!(
+        run(".set ast-format", &mut session)?,
+        "ast-format is pretty\n"
+    );
+
+    assert_eq!(
+        run(".set ast-format compact", &mut session)?,
+        "ast-format set to compact\n"
+    );
+    assert_eq!(session.ast_format, AstFormat::Compact);
+
+    assert_eq!(
+        run(".set ast-format pretty", &mut session)?,
+        "ast-format set to pretty\n"
+    );
+    assert_eq!(session.ast_format, AstFormat::Pretty);
+    Ok(())
+}
+
+#[test]
+fn set_rejects_unknown_settings_and_values() -> Result<()> {
+    let mut session = ShellSession::default();
+    let output = run(".set ast-format wide", &mut session)?;
+    assert!(output.contains("unknown ast-format 'wide'"), "{output}");
+    assert_eq!(session.ast_format, AstFormat::Pretty);
+
+    assert_eq!(
+        run(".set color on", &mut session)?,
+        "Usage: .set ast-format pretty|compact\n"
+    );
+    Ok(())
+}
+
+#[derive(Debug)]
+#[allow(dead_code)]
+struct Node {
+    kind: &'static str,
+    children: Vec<Option<(i64, String)>>,
+}
+
+#[test]
+fn compact_format_matches_single_line_debug() {
+    let node = Node {
+        kind: "Par",
+        children: vec![Some((1, "a, }".to_string())), None],
+    };
+    let pretty = format!("{node:#?}");
+    assert_eq!(AstFormat::Pretty.render(&pretty), pretty);
+    assert_eq!(AstFormat::Compact.render(&pretty), format!("{node:?}"));
+
+    // plain multi-line results are left alone
+    assert_eq!(AstFormat::Compact.render("3\n\"ok\""), "3\n\"ok\"");
+}
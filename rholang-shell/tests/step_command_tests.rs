@@ -0,0 +1,144 @@
+use anyhow::Result;
+use std::io::Cursor;
+
+use rholang_shell::{
+    advance_step_session, process_special_command, process_special_command_with_session,
+    providers::{FakeInterpreterProvider, InterpreterProvider, RholangCompilerInterpreterProvider},
+    ShellSession,
+};
+
+fn output_of(stdout: Cursor<Vec<u8>>) -> Result<String> {
+    Ok(String::from_utf8(stdout.into_inner())?)
+}
+
+#[test]
+fn step_with_empty_buffer() -> Result<()> {
+    let interpreter = RholangCompilerInterpreterProvider::new()?;
+    let mut buffer = vec!["   ".to_string()];
+    let mut stdout = Cursor::new(Vec::new());
+    let mut session = ShellSession::default();
+
+    process_special_command_with_session(
+        ".step",
+        &mut buffer,
+        &mut stdout,
+        |_| Ok(()),
+        &interpreter,
+        &mut session,
+    )?;
+
+    assert!(session.step.is_none());
+    assert!(output_of(stdout)?.contains("Buffer is empty, nothing to step through"));
+    Ok(())
+}
+
+#[test]
+fn step_with_parse_error() -> Result<()> {
+    let interpreter = RholangCompilerInterpreterProvider::new()?;
+    let mut buffer = vec!["(".to_string()];
+    let mut stdout = Cursor::new(Vec::new());
+    let mut session = ShellSession::default();
+
+    process_special_command_with_session(
+        ".step",
+        &mut buffer,
+        &mut stdout,
+        |_| Ok(()),
+        &interpreter,
+        &mut session,
+    )?;
+
+    assert!(session.step.is_none());
+    let output = output_of(stdout)?;
+    assert!(output.contains("Step error:"));
+    assert!(output.contains("Parsing failed: unable to build AST"));
+    Ok(())
+}
+
+#[test]
+fn step_unsupported_in_fake_provider() {
+    let fake = FakeInterpreterProvider;
+    let err = fake
+        .step_session("Nil")
+        .err()
+        .expect("stepping should fail");
+    assert!(format!("{}", err).contains("Stepping is not supported by this provider"));
+}
+
+#[test]
+fn step_through_to_completion() -> Result<()> {
+    let interpreter = RholangCompilerInterpreterProvider::new()?;
+    let mut buffer = vec!["1 + 2".to_string()];
+    let mut stdout = Cursor::new(Vec::new());
+    let mut session = ShellSession::default();
+
+    process_special_command_with_session(
+        ".step",
+        &mut buffer,
+        &mut stdout,
+        |_| Ok(()),
+        &interpreter,
+        &mut session,
+    )?;
+    assert!(
+        session.step.is_some(),
+        "session should stay active after first step"
+    );
+
+    let mut guard = 0;
+    while session.step.is_some() && guard < 100 {
+        advance_step_session(&mut session.step, &mut stdout)?;
+        guard += 1;
+    }
+
+    let output = output_of(stdout)?;
+    assert!(output.contains("[step 1] 0000: PUSH_INT"));
+    assert!(output.contains("ADD"));
+    assert!(output.contains("stack: [3]"));
+    assert!(output.contains("Finished: 3"));
+    Ok(())
+}
+
+#[test]
+fn continue_finishes_session() -> Result<()> {
+    let interpreter = RholangCompilerInterpreterProvider::new()?;
+    let mut buffer = vec!["1 + 2".to_string()];
+    let mut stdout = Cursor::new(Vec::new());
+    let mut session = ShellSession::default();
+
+    for cmd in [".step", ".continue"] {
+        process_special_command_with_session(
+            cmd,
+            &mut buffer,
+            &mut stdout,
+            |_| Ok(()),
+            &interpreter,
+            &mut session,
+        )?;
+    }
+
+    assert!(session.step.is_none());
+    let output = output_of(stdout)?;
+    assert!(output.contains("[step 1]"));
+    assert!(!output.contains("[step 2]"));
+    assert!(output.contains("Finished: 3"));
+    Ok(())
+}
+
+#[test]
+fn continue_without_session() -> Result<()> {
+    let interpreter = FakeInterpreterProvider;
+    let mut buffer = Vec::new();
+    let mut stdout = Cursor::new(Vec::new());
+
+    process_special_command(
+        ".continue",
+        &mut buffer,
+        &mut stdout,
+        |_| Ok(()),
+        &interpreter,
+    )?;
+
+    assert!(output_of(stdout)?.contains("No active step session"));
+    Ok(())
+}
@@ -0,0 +1,98 @@
+use anyhow::Result;
+use std::io::Cursor;
+
+use rholang_shell::{
+    process_special_command,
+    providers::FakeInterpreterProvider,
+    types::{classify_code, StaticType},
+};
+
+fn type_of(code: &str) -> StaticType {
+    let labels = classify_code(code).expect("code should parse");
+    assert_eq!(labels.len(), 1, "expected a single process in {code:?}");
+    labels[0]
+}
+
+#[test]
+fn literals_and_collections() {
+    assert_eq!(type_of("42"), StaticType::Int);
+    assert_eq!(type_of("\"hello\""), StaticType::String);
+    assert_eq!(type_of("true"), StaticType::Bool);
+    assert_eq!(type_of("Nil"), StaticType::Nil);
+    assert_eq!(type_of("[1, 2, 3]"), StaticType::List);
+    assert_eq!(type_of("{\"a\": 1}"), StaticType::Map);
+    assert_eq!(type_of("(1, \"x\")"), StaticType::Tuple);
+}
+
+#[test]
+fn binary_operators() {
+    assert_eq!(type_of("1 + 2 * 3"), StaticType::Int);
+    assert_eq!(type_of("1 < 2"), StaticType::Bool);
+    assert_eq!(type_of("1 == 2 and true"), StaticType::Bool);
+    assert_eq!(type_of("\"a\" ++ \"b\""), StaticType::String);
+    assert_eq!(type_of("[1] ++ [2]"), StaticType::List);
+    assert_eq!(type_of("\"${x}\" %% {\"x\": 1}"), StaticType::String);
+}
+
+#[test]
+fn names_processes_and_unknowns() {
+    assert_eq!(type_of("new x in { x }"), StaticType::Name);
+    assert_eq!(type_of("new x in { x!(1) }"), StaticType::Process);
+    assert_eq!(type_of("for (y <- x) { Nil }"), StaticType::Process);
+    assert_eq!(type_of("x"), StaticType::Unknown);
+    assert_eq!(
+        type_of("if (true) { 1 } else { \"one\" }"),
+        StaticType::Unknown
+    );
+    assert_eq!(StaticType::Unknown.to_string(), "unknown");
+}
+
+#[test]
+fn unit_is_not_a_tuple() {
+    assert_eq!(type_of("()"), StaticType::Unit);
+    assert_eq!(StaticType::Unit.to_string(), "Unit");
+}
+
+#[test]
+fn let_shadows_new_names() {
+    assert_eq!(
+        type_of("new x in { let x <- 1 in { x } }"),
+        StaticType::Unknown
+    );
+    assert_eq!(
+        type_of("new x in { let @x <- 1 in { x } }"),
+        StaticType::Unknown
+    );
+    assert_eq!(
+        type_of("new x, y in { let x <- 1 in { y } }"),
+        StaticType::Name
+    );
+}
+
+#[test]
+fn arithmetic_needs_numeric_operands() {
+    assert_eq!(type_of("x + y"), StaticType::Unknown);
+    assert_eq!(type_of("x * 2"), StaticType::Int);
+    assert_eq!(type_of("\"a\" + \"b\""), StaticType::Unknown);
+    assert_eq!(type_of("new x in { x - 1 }"), StaticType::Unknown);
+}
+
+#[test]
+fn type_command_prints_labels() -> Result<()> {
+    let interpreter = FakeInterpreterProvider;
+    let mut buffer = vec!["1 < 2".to_string()];
+    let mut stdout = Cursor::new(Vec::new());
+
+    process_special_command(
+        ".type [1] ++ [2]",
+        &mut buffer,
+        &mut stdout,
+        |_| Ok(()),
+        &interpreter,
+    )?;
+    process_special_command(".type", &mut buffer, &mut stdout, |_| Ok(()), &interpreter)?;
+
+    let output = String::from_utf8(stdout.into_inner())?;
+    assert_eq!(output, "List\nBool\n");
+    Ok(())
+}
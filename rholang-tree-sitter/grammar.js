@@ -398,8 +398,12 @@ module.exports = grammar({
         bigrat_literal: $ => token(/-?\d+r/),
         float_literal: $ => token(/-?(?:\d+\.\d*|\.\d+|\d+)(?:[eE][+-]?\d+)?f(?:32|64|128|256)/),
         fixed_point_literal: $ => token(/-?(?:\d+\.\d*|\.\d+|\d+)p\d+/),
-        long_literal: $ => token(/-?\d+/),
-        string_literal: $ => token(/"([^"\\]|(\\[0nrt\\"])|(\\[0-9]+))*"/),
+        long_literal: $ => token(/-?(?:0x[0-9a-fA-F]+|0b[01]+|0o[0-7]+|\d+)/),
+        // """raw""" strings keep their body verbatim, without escape sequences
+        string_literal: $ => token(choice(
+            /"""([^"]|"[^"]|""[^"])*"""/,
+            /"([^"\\]|\\[^\n])*"/,
+        )),
         uri_literal: $ => token(/`[^`]+`/),
 
         unit: $ => seq('(', ')'),
@@ -3285,7 +3285,7 @@ static bool ts_lex(TSLexer *lexer, TSStateId state) {
       if (eof) ADVANCE(44);
       ADVANCE_MAP(
         '!', 48,
-        '"', 7,
+        '"', 130,
         '%', 74,
         '&', 86,
         '(', 49,
@@ -3316,6 +3316,7 @@ static bool ts_lex(TSLexer *lexer, TSStateId state) {
       );
       if (('\t' <= lookahead && lookahead <= '\r') ||
           lookahead == ' ') SKIP(0);
+      if (lookahead == '0') ADVANCE(122);
       if (('0' <= lookahead && lookahead <= '9')) ADVANCE(100);
       if (('A' <= lookahead && lookahead <= 'Z') ||
           ('a' <= lookahead && lookahead <= 'z')) ADVANCE(119);
@@ -3497,6 +3498,8 @@ static bool ts_lex(TSLexer *lexer, TSStateId state) {
           lookahead == 'r' ||
           lookahead == 't') ADVANCE(7);
       if (('1' <= lookahead && lookahead <= '9')) ADVANCE(6);
+      if (lookahead != 0 &&
+          lookahead != '\n') ADVANCE(7);
       END_STATE();
     case 20:
       if (lookahead == '1') ADVANCE(21);
@@ -3582,7 +3585,7 @@ static bool ts_lex(TSLexer *lexer, TSStateId state) {
       if (eof) ADVANCE(44);
       ADVANCE_MAP(
         '!', 48,
-        '"', 7,
+        '"', 130,
         '%', 74,
         '&', 86,
         '(', 49,
@@ -3611,6 +3614,7 @@ static bool ts_lex(TSLexer *lexer, TSStateId state) {
       );
       if (('\t' <= lookahead && lookahead <= '\r') ||
           lookahead == ' ') SKIP(41);
+      if (lookahead == '0') ADVANCE(122);
       if (('0' <= lookahead && lookahead <= '9')) ADVANCE(100);
       if (('A' <= lookahead && lookahead <= 'Z') ||
           ('a' <= lookahead && lookahead <= 'z')) ADVANCE(119);
@@ -3619,7 +3623,7 @@ static bool ts_lex(TSLexer *lexer, TSStateId state) {
       if (eof) ADVANCE(44);
       ADVANCE_MAP(
         '!', 48,
-        '"', 7,
+        '"', 130,
         '%', 74,
         '(', 49,
         '*', 72,
@@ -3643,6 +3647,7 @@ static bool ts_lex(TSLexer *lexer, TSStateId state) {
       );
       if (('\t' <= lookahead && lookahead <= '\r') ||
           lookahead == ' ') SKIP(42);
+      if (lookahead == '0') ADVANCE(122);
       if (('0' <= lookahead && lookahead <= '9')) ADVANCE(100);
       if (('A' <= lookahead && lookahead <= 'Z') ||
           ('a' <= lookahead && lookahead <= 'z')) ADVANCE(119);
@@ -3650,7 +3655,7 @@ static bool ts_lex(TSLexer *lexer, TSStateId state) {
     case 43:
       if (eof) ADVANCE(44);
       ADVANCE_MAP(
-        '"', 7,
+        '"', 130,
         '&', 86,
         '(', 49,
         ')', 50,
@@ -3675,6 +3680,7 @@ static bool ts_lex(TSLexer *lexer, TSStateId state) {
       );
       if (('\t' <= lookahead && lookahead <= '\r') ||
           lookahead == ' ') SKIP(43);
+      if (lookahead == '0') ADVANCE(122);
       if (('0' <= lookahead && lookahead <= '9')) ADVANCE(100);
       if (('A' <= lookahead && lookahead <= 'Z') ||
           ('a' <= lookahead && lookahead <= 'z')) ADVANCE(119);
@@ -3776,11 +3782,13 @@ static bool ts_lex(TSLexer *lexer, TSStateId state) {
       ACCEPT_TOKEN(anon_sym_DASH);
       if (lookahead == '-') ADVANCE(66);
       if (lookahead == '.') ADVANCE(36);
+      if (lookahead == '0') ADVANCE(123);
       if (('0' <= lookahead && lookahead <= '9')) ADVANCE(101);
       END_STATE();
     case 70:
       ACCEPT_TOKEN(anon_sym_DASH);
       if (lookahead == '.') ADVANCE(36);
+      if (lookahead == '0') ADVANCE(123);
       if (('0' <= lookahead && lookahead <= '9')) ADVANCE(101);
       END_STATE();
     case 71:
@@ -4020,6 +4028,89 @@ static bool ts_lex(TSLexer *lexer, TSStateId state) {
     case 121:
       ACCEPT_TOKEN(sym__block_comment);
       END_STATE();
+    case 122:
+      ACCEPT_TOKEN(sym_long_literal);
+      ADVANCE_MAP(
+        '.', 30,
+        'b', 126,
+        'f', 20,
+        'i', 34,
+        'n', 96,
+        'o', 128,
+        'p', 37,
+        'r', 97,
+        'u', 35,
+        'x', 124,
+        'E', 33,
+        'e', 33,
+      );
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(100);
+      END_STATE();
+    case 123:
+      ACCEPT_TOKEN(sym_long_literal);
+      ADVANCE_MAP(
+        '.', 30,
+        'b', 126,
+        'f', 20,
+        'i', 34,
+        'n', 96,
+        'o', 128,
+        'p', 37,
+        'r', 97,
+        'x', 124,
+        'E', 33,
+        'e', 33,
+      );
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(101);
+      END_STATE();
+    case 124:
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(125);
+      END_STATE();
+    case 125:
+      ACCEPT_TOKEN(sym_long_literal);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(125);
+      END_STATE();
+    case 126:
+      if (lookahead == '0' ||
+          lookahead == '1') ADVANCE(127);
+      END_STATE();
+    case 127:
+      ACCEPT_TOKEN(sym_long_literal);
+      if (lookahead == '0' ||
+          lookahead == '1') ADVANCE(127);
+      END_STATE();
+    case 128:
+      if (('0' <= lookahead && lookahead <= '7')) ADVANCE(129);
+      END_STATE();
+    case 129:
+      ACCEPT_TOKEN(sym_long_literal);
+      if (('0' <= lookahead && lookahead <= '7')) ADVANCE(129);
+      END_STATE();
+    case 130:
+      if (lookahead == '"') ADVANCE(131);
+      if (lookahead == '\\') ADVANCE(19);
+      if (lookahead != 0) ADVANCE(7);
+      END_STATE();
+    case 131:
+      ACCEPT_TOKEN(sym_string_literal);
+      if (lookahead == '"') ADVANCE(132);
+      END_STATE();
+    case 132:
+      if (lookahead == '"') ADVANCE(133);
+      if (lookahead != 0) ADVANCE(132);
+      END_STATE();
+    case 133:
+      if (lookahead == '"') ADVANCE(134);
+      if (lookahead != 0) ADVANCE(132);
+      END_STATE();
+    case 134:
+      if (lookahead == '"') ADVANCE(102);
+      if (lookahead != 0) ADVANCE(132);
+      END_STATE();
     default:
       return false;
   }
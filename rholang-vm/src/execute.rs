@@ -3,11 +3,19 @@ use num_rational::BigRational;
 use num_traits::{Signed, Zero};
 use rholang_bytecode::core::instructions::Instruction as CoreInst;
 use rholang_bytecode::core::opcodes::Opcode;
+use rholang_bytecode::core::SimpleType;
 use std::cmp::Ordering;
 use std::result::Result;
 
+use crate::pattern::{is_of_simple_type, Pattern};
+use crate::vm::{ChannelEvent, OutputSink};
 use crate::VM;
-use rholang_rspace::{ExecError, Value};
+use rholang_rspace::{fresh_name, system_uri, ExecError, Value};
+
+/// System channel whose messages are written to [`VM`]'s stdout sink.
+const STDOUT_URI: &str = "rho:io:stdout";
+/// System channel whose messages are written to [`VM`]'s stderr sink.
+const STDERR_URI: &str = "rho:io:stderr";
 
 pub enum StepResult {
     Next,
@@ -15,6 +23,9 @@ pub enum StepResult {
     Jump(usize),
     /// EVAL opcode encountered - Process should handle executing the value
     Eval(Value),
+    /// ASK found no message on the channel. The stack is left as it was before the
+    /// instruction, so executing it again retries the receive.
+    Block(String),
 }
 
 /// Execute a single bytecode instruction.
@@ -88,7 +99,13 @@ pub fn step(
         Opcode::ADD => {
             let (b, a) = (vm.stack.pop(), vm.stack.pop());
             match (a, b) {
-                (Some(Value::Int(a)), Some(Value::Int(b))) => vm.stack.push(Value::Int(a.wrapping_add(b))),
+                (Some(Value::Int(a)), Some(Value::Int(b))) => {
+                    vm.stack.push(int_result(a.checked_add(b), a.wrapping_add(b), || BigInt::from(a) + b));
+                }
+                #[cfg(feature = "bigint")]
+                (Some(Value::Int(a)), Some(Value::BigInt(b))) => vm.stack.push(promoted_int(a + b)),
+                #[cfg(feature = "bigint")]
+                (Some(Value::BigInt(a)), Some(Value::Int(b))) => vm.stack.push(promoted_int(a + b)),
                 (Some(Value::Float(a)), Some(Value::Float(b))) => vm.stack.push(Value::Float(a + b)),
                 (Some(Value::BigInt(a)), Some(Value::BigInt(b))) => {
                     vm.stack.push(Value::BigInt(a + b));
@@ -114,7 +131,13 @@ pub fn step(
         Opcode::SUB => {
             let (b, a) = (vm.stack.pop(), vm.stack.pop());
             match (a, b) {
-                (Some(Value::Int(a)), Some(Value::Int(b))) => vm.stack.push(Value::Int(a.wrapping_sub(b))),
+                (Some(Value::Int(a)), Some(Value::Int(b))) => {
+                    vm.stack.push(int_result(a.checked_sub(b), a.wrapping_sub(b), || BigInt::from(a) - b));
+                }
+                #[cfg(feature = "bigint")]
+                (Some(Value::Int(a)), Some(Value::BigInt(b))) => vm.stack.push(promoted_int(a - b)),
+                #[cfg(feature = "bigint")]
+                (Some(Value::BigInt(a)), Some(Value::Int(b))) => vm.stack.push(promoted_int(a - b)),
                 (Some(Value::Float(a)), Some(Value::Float(b))) => vm.stack.push(Value::Float(a - b)),
                 (Some(Value::BigInt(a)), Some(Value::BigInt(b))) => {
                     vm.stack.push(Value::BigInt(a - b));
@@ -128,6 +151,9 @@ pub fn step(
                     }
                     vm.stack.push(Value::FixedPoint { unscaled: ua - ub, scale: sa });
                 }
+                (Some(Value::PathMap(a)), Some(Value::PathMap(b))) => {
+                    vm.stack.push(Value::PathMap(pathmap_subtract(a, &b)));
+                }
                 (Some(a), Some(b)) => return Err(type_mismatch_error("SUB", a.type_name(), b.type_name())),
                 _ => return Err(stack_underflow("SUB")),
             }
@@ -135,7 +161,13 @@ pub fn step(
         Opcode::MUL => {
             let (b, a) = (vm.stack.pop(), vm.stack.pop());
             match (a, b) {
-                (Some(Value::Int(a)), Some(Value::Int(b))) => vm.stack.push(Value::Int(a.wrapping_mul(b))),
+                (Some(Value::Int(a)), Some(Value::Int(b))) => {
+                    vm.stack.push(int_result(a.checked_mul(b), a.wrapping_mul(b), || BigInt::from(a) * b));
+                }
+                #[cfg(feature = "bigint")]
+                (Some(Value::Int(a)), Some(Value::BigInt(b))) => vm.stack.push(promoted_int(a * b)),
+                #[cfg(feature = "bigint")]
+                (Some(Value::BigInt(a)), Some(Value::Int(b))) => vm.stack.push(promoted_int(a * b)),
                 (Some(Value::Float(a)), Some(Value::Float(b))) => vm.stack.push(Value::Float(a * b)),
                 (Some(Value::BigInt(a)), Some(Value::BigInt(b))) => {
                     vm.stack.push(Value::BigInt(a * b));
@@ -171,7 +203,21 @@ pub fn step(
                     if b == 0 {
                         return Err(div_by_zero("DIV"));
                     }
-                    vm.stack.push(Value::Int(a.wrapping_div(b)));
+                    vm.stack.push(int_result(a.checked_div(b), a.wrapping_div(b), || BigInt::from(a) / b));
+                }
+                #[cfg(feature = "bigint")]
+                (Some(Value::Int(a)), Some(Value::BigInt(b))) => {
+                    if b.is_zero() {
+                        return Err(div_by_zero("DIV"));
+                    }
+                    vm.stack.push(promoted_int(a / b));
+                }
+                #[cfg(feature = "bigint")]
+                (Some(Value::BigInt(a)), Some(Value::Int(b))) => {
+                    if b == 0 {
+                        return Err(div_by_zero("DIV"));
+                    }
+                    vm.stack.push(promoted_int(a / b));
                 }
                 (Some(Value::Float(a)), Some(Value::Float(b))) => {
                     // IEEE 754: div by zero produces Inf/-Inf/NaN
@@ -212,7 +258,22 @@ pub fn step(
                     if b == 0 {
                         return Err(div_by_zero("MOD"));
                     }
-                    vm.stack.push(Value::Int(a % b));
+                    // i64::MIN % -1 overflows, but its remainder 0 always fits
+                    vm.stack.push(Value::Int(a.checked_rem(b).unwrap_or(0)));
+                }
+                #[cfg(feature = "bigint")]
+                (Some(Value::Int(a)), Some(Value::BigInt(b))) => {
+                    if b.is_zero() {
+                        return Err(div_by_zero("MOD"));
+                    }
+                    vm.stack.push(promoted_int(a % b));
+                }
+                #[cfg(feature = "bigint")]
+                (Some(Value::BigInt(a)), Some(Value::Int(b))) => {
+                    if b == 0 {
+                        return Err(div_by_zero("MOD"));
+                    }
+                    vm.stack.push(promoted_int(a % b));
                 }
                 (Some(Value::Float(_)), Some(Value::Float(_))) => {
                     return Err(ExecError::OpcodeParamError {
@@ -251,19 +312,14 @@ pub fn step(
             }
         }
         Opcode::NEG => match vm.stack.pop() {
-            Some(Value::Int(a)) => vm.stack.push(Value::Int(a.wrapping_neg())),
+            Some(Value::Int(a)) => vm.stack.push(int_result(a.checked_neg(), a.wrapping_neg(), || -BigInt::from(a))),
             Some(Value::Float(a)) => vm.stack.push(Value::Float(-a)),
             Some(Value::BigInt(a)) => vm.stack.push(Value::BigInt(-a)),
             Some(Value::BigRat(a)) => vm.stack.push(Value::BigRat(-a)),
             Some(Value::FixedPoint { unscaled, scale }) => {
                 vm.stack.push(Value::FixedPoint { unscaled: -unscaled, scale });
             }
-            Some(other) => {
-                return Err(ExecError::OpcodeParamError {
-                    opcode: "NEG",
-                    message: format!("cannot negate {}", other.type_name()),
-                });
-            }
+            Some(other) => return Err(type_mismatch_error("NEG", "number", other.type_name())),
             None => return Err(stack_underflow("NEG")),
         },
 
@@ -302,35 +358,27 @@ pub fn step(
         // Logical operators
         Opcode::NOT => match vm.stack.pop() {
             Some(Value::Bool(b)) => vm.stack.push(Value::Bool(!b)),
-            _ => {
-                return Err(ExecError::OpcodeParamError {
-                    opcode: "NOT",
-                    message: "requires Bool".to_string(),
-                })
-            }
+            Some(other) => return Err(type_mismatch_error("NOT", "Bool", other.type_name())),
+            None => return Err(stack_underflow("NOT")),
         },
         Opcode::AND => {
             let (b, a) = (vm.stack.pop(), vm.stack.pop());
             match (a, b) {
                 (Some(Value::Bool(a)), Some(Value::Bool(b))) => vm.stack.push(Value::Bool(a && b)),
-                _ => {
-                    return Err(ExecError::OpcodeParamError {
-                        opcode: "AND",
-                        message: "requires Bools".to_string(),
-                    })
+                (Some(Value::Bool(_)), Some(other)) | (Some(other), Some(_)) => {
+                    return Err(type_mismatch_error("AND", "Bool", other.type_name()))
                 }
+                _ => return Err(stack_underflow("AND")),
             }
         }
         Opcode::OR => {
             let (b, a) = (vm.stack.pop(), vm.stack.pop());
             match (a, b) {
                 (Some(Value::Bool(a)), Some(Value::Bool(b))) => vm.stack.push(Value::Bool(a || b)),
-                _ => {
-                    return Err(ExecError::OpcodeParamError {
-                        opcode: "OR",
-                        message: "requires Bools".to_string(),
-                    })
+                (Some(Value::Bool(_)), Some(other)) | (Some(other), Some(_)) => {
+                    return Err(type_mismatch_error("OR", "Bool", other.type_name()))
                 }
+                _ => return Err(stack_underflow("OR")),
             }
         }
 
@@ -339,18 +387,12 @@ pub fn step(
             if let Some(top) = vm.stack.last() {
                 vm.stack.push(top.clone());
             } else {
-                return Err(ExecError::OpcodeParamError {
-                    opcode: "DUP",
-                    message: "stack underflow".to_string(),
-                });
+                return Err(stack_underflow("DUP"));
             }
         }
         Opcode::SWAP => {
             if vm.stack.len() < 2 {
-                return Err(ExecError::OpcodeParamError {
-                    opcode: "SWAP",
-                    message: "stack underflow".to_string(),
-                });
+                return Err(stack_underflow("SWAP"));
             }
             let len = vm.stack.len();
             vm.stack.swap(len - 1, len - 2);
@@ -395,12 +437,7 @@ pub fn step(
             match cond {
                 Value::Bool(true) => return Ok(StepResult::Jump(target)),
                 Value::Bool(false) => {}
-                _ => {
-                    return Err(ExecError::OpcodeParamError {
-                        opcode: "BRANCH_TRUE",
-                        message: "expects Bool on stack".to_string(),
-                    });
-                }
+                other => return Err(type_mismatch_error("BRANCH_TRUE", "Bool", other.type_name())),
             }
         }
         Opcode::BRANCH_FALSE => {
@@ -412,12 +449,7 @@ pub fn step(
             match cond {
                 Value::Bool(false) => return Ok(StepResult::Jump(target)),
                 Value::Bool(true) => {}
-                _ => {
-                    return Err(ExecError::OpcodeParamError {
-                        opcode: "BRANCH_FALSE",
-                        message: "expects Bool on stack".to_string(),
-                    });
-                }
+                other => return Err(type_mismatch_error("BRANCH_FALSE", "Bool", other.type_name())),
             }
         }
         Opcode::BRANCH_SUCCESS => {
@@ -429,12 +461,7 @@ pub fn step(
             match cond {
                 Value::Bool(true) => return Ok(StepResult::Jump(target)),
                 Value::Bool(false) => {}
-                _ => {
-                    return Err(ExecError::OpcodeParamError {
-                        opcode: "BRANCH_SUCCESS",
-                        message: "expects Bool on stack".to_string(),
-                    });
-                }
+                other => return Err(type_mismatch_error("BRANCH_SUCCESS", "Bool", other.type_name())),
             }
         }
 
@@ -442,10 +469,7 @@ pub fn step(
         Opcode::CREATE_LIST => {
             let len = inst.op16() as usize;
             if vm.stack.len() < len {
-                return Err(ExecError::OpcodeParamError {
-                    opcode: "CREATE_LIST",
-                    message: "stack underflow".to_string(),
-                });
+                return Err(arity_mismatch("CREATE_LIST", len, vm.stack.len()));
             }
             let start = vm.stack.len() - len;
             let list = vm.stack.drain(start..).collect();
@@ -454,10 +478,7 @@ pub fn step(
         Opcode::CREATE_TUPLE => {
             let len = inst.op16() as usize;
             if vm.stack.len() < len {
-                return Err(ExecError::OpcodeParamError {
-                    opcode: "CREATE_TUPLE",
-                    message: "stack underflow".to_string(),
-                });
+                return Err(arity_mismatch("CREATE_TUPLE", len, vm.stack.len()));
             }
             let start = vm.stack.len() - len;
             let list = vm.stack.drain(start..).collect();
@@ -466,10 +487,7 @@ pub fn step(
         Opcode::CREATE_MAP => {
             let len = inst.op16() as usize;
             if vm.stack.len() < len * 2 {
-                return Err(ExecError::OpcodeParamError {
-                    opcode: "CREATE_MAP",
-                    message: "stack underflow".to_string(),
-                });
+                return Err(arity_mismatch("CREATE_MAP", len * 2, vm.stack.len()));
             }
             let start = vm.stack.len() - len * 2;
             let values: Vec<Value> = vm.stack.drain(start..).collect();
@@ -481,6 +499,15 @@ pub fn step(
             }
             vm.stack.push(Value::Map(map));
         }
+        Opcode::CREATE_SET => {
+            let len = inst.op16() as usize;
+            if vm.stack.len() < len {
+                return Err(arity_mismatch("CREATE_SET", len, vm.stack.len()));
+            }
+            let start = vm.stack.len() - len;
+            let items: Vec<Value> = vm.stack.drain(start..).collect();
+            vm.stack.push(Value::new_set(items));
+        }
         Opcode::CONCAT => {
             let (b, a) = (vm.stack.pop(), vm.stack.pop());
             match (a, b) {
@@ -521,14 +548,176 @@ pub fn step(
             }
         }
 
-        // Process ops
-        Opcode::SPAWN_ASYNC => {
+        Opcode::INTERPOLATE => {
+            let (b, a) = (vm.stack.pop(), vm.stack.pop());
+            match (a, b) {
+                (Some(Value::Str(template)), Some(Value::Map(bindings))) => {
+                    vm.stack.push(Value::Str(interpolate(&template, &bindings)))
+                }
+                _ => {
+                    return Err(ExecError::OpcodeParamError {
+                        opcode: "INTERPOLATE",
+                        message: "requires a String and a Map".to_string(),
+                    })
+                }
+            }
+        }
+
+        // PathMaps
+        Opcode::CREATE_PATHMAP => {
             let len = inst.op16() as usize;
             if vm.stack.len() < len {
+                return Err(arity_mismatch("CREATE_PATHMAP", len, vm.stack.len()));
+            }
+            let start = vm.stack.len() - len;
+            let mut paths = Vec::with_capacity(len);
+            for path in vm.stack.drain(start..) {
+                let path = make_path(path_segments(&path).to_vec());
+                if !paths.contains(&path) {
+                    paths.push(path);
+                }
+            }
+            vm.stack.push(Value::PathMap(paths));
+        }
+        Opcode::PATHMAP_SUBTRACT => {
+            let (b, a) = (vm.stack.pop(), vm.stack.pop());
+            match (a, b) {
+                (Some(Value::PathMap(a)), Some(Value::PathMap(b))) => {
+                    vm.stack.push(Value::PathMap(pathmap_subtract(a, &b)))
+                }
+                (Some(a), Some(b)) => {
+                    return Err(type_mismatch_error(
+                        "PATHMAP_SUBTRACT",
+                        a.type_name(),
+                        b.type_name(),
+                    ))
+                }
+                _ => return Err(stack_underflow("PATHMAP_SUBTRACT")),
+            }
+        }
+        Opcode::PATHMAP_RESTRICT => {
+            let (b, a) = (vm.stack.pop(), vm.stack.pop());
+            match (a, b) {
+                (Some(Value::PathMap(a)), Some(Value::PathMap(b))) => {
+                    let restricted = a
+                        .into_iter()
+                        .filter(|path| {
+                            let segments = path_segments(path);
+                            b.iter()
+                                .any(|prefix| segments.starts_with(path_segments(prefix)))
+                        })
+                        .collect();
+                    vm.stack.push(Value::PathMap(restricted));
+                }
+                (Some(a), Some(b)) => {
+                    return Err(type_mismatch_error(
+                        "PATHMAP_RESTRICT",
+                        a.type_name(),
+                        b.type_name(),
+                    ))
+                }
+                _ => return Err(stack_underflow("PATHMAP_RESTRICT")),
+            }
+        }
+        Opcode::PATHMAP_DROP => {
+            let (n, a) = (vm.stack.pop(), vm.stack.pop());
+            match (a, n) {
+                (Some(Value::PathMap(a)), Some(Value::Int(n))) if n >= 0 => {
+                    let n = n as usize;
+                    let mut dropped = Vec::with_capacity(a.len());
+                    for path in &a {
+                        let segments = path_segments(path);
+                        if segments.len() <= n {
+                            continue;
+                        }
+                        let path = make_path(segments[n..].to_vec());
+                        if !dropped.contains(&path) {
+                            dropped.push(path);
+                        }
+                    }
+                    vm.stack.push(Value::PathMap(dropped));
+                }
+                (Some(Value::PathMap(_)), Some(Value::Int(_))) => {
+                    return Err(ExecError::OpcodeParamError {
+                        opcode: "PATHMAP_DROP",
+                        message: "segment count must not be negative".to_string(),
+                    })
+                }
+                (Some(a), Some(b)) => {
+                    return Err(type_mismatch_error(
+                        "PATHMAP_DROP",
+                        a.type_name(),
+                        b.type_name(),
+                    ))
+                }
+                _ => return Err(stack_underflow("PATHMAP_DROP")),
+            }
+        }
+
+        // Pattern matching ops
+        Opcode::MATCH_TYPE => {
+            let Some(t) = SimpleType::from_tag(inst.op16()) else {
                 return Err(ExecError::OpcodeParamError {
-                    opcode: "SPAWN_ASYNC",
-                    message: "stack underflow".to_string(),
+                    opcode: "MATCH_TYPE",
+                    message: format!("unknown simple type tag {}", inst.op16()),
                 });
+            };
+            match vm.stack.pop() {
+                Some(value) => vm.stack.push(Value::Bool(is_of_simple_type(&value, t))),
+                None => return Err(stack_underflow("MATCH_TYPE")),
+            }
+        }
+        Opcode::MATCH_TEST => {
+            let idx = inst.op16() as usize;
+            let Some(pattern) = constants.get(idx).and_then(Pattern::from_value) else {
+                return Err(ExecError::OpcodeParamError {
+                    opcode: "MATCH_TEST",
+                    message: format!("constant {} is not a pattern", idx),
+                });
+            };
+            match vm.stack.pop() {
+                Some(value) => vm.stack.push(Value::Bool(pattern.matches(&value))),
+                None => return Err(stack_underflow("MATCH_TEST")),
+            }
+        }
+        Opcode::EXTRACT_BINDINGS => {
+            // Spread a message over the formals of a receive or contract: op1
+            // positional formals, op2 non-zero if a remainder formal follows
+            // them. A message is the Tuple of its arguments, unless it has a
+            // single argument that is not a Tuple itself (see TELL).
+            let positional = inst.op1() as usize;
+            let has_rest = inst.op2() != 0;
+            let message = vm
+                .stack
+                .pop()
+                .ok_or_else(|| stack_underflow("EXTRACT_BINDINGS"))?;
+            let mut args = match message {
+                Value::Tuple(items) => items,
+                other => vec![other],
+            };
+            if args.len() < positional || (!has_rest && args.len() > positional) {
+                return Err(ExecError::OpcodeParamError {
+                    opcode: "EXTRACT_BINDINGS",
+                    message: format!(
+                        "receive expects {}{} argument(s), got {}",
+                        if has_rest { "at least " } else { "" },
+                        positional,
+                        args.len()
+                    ),
+                });
+            }
+            let rest = args.split_off(positional);
+            vm.stack.extend(args);
+            if has_rest {
+                vm.stack.push(Value::List(rest));
+            }
+        }
+
+        // Process ops
+        Opcode::SPAWN_ASYNC => {
+            let len = inst.op16() as usize;
+            if vm.stack.len() < len {
+                return Err(arity_mismatch("SPAWN_ASYNC", len, vm.stack.len()));
             }
             let start = vm.stack.len() - len;
             let values: Vec<Value> = vm.stack.drain(start..).collect();
@@ -547,22 +736,53 @@ pub fn step(
             }
             vm.stack.push(Value::Par(procs));
         }
+        Opcode::PUSH_NAME => {
+            let idx = inst.op16() as usize;
+            match names.get(idx) {
+                Some(Value::Str(s)) => vm.stack.push(Value::Name(s.clone())),
+                Some(other) => {
+                    return Err(ExecError::OpcodeParamError {
+                        opcode: "PUSH_NAME",
+                        message: format!("names[{}] not a String: {:?}", idx, other),
+                    });
+                }
+                None => return Err(ExecError::UnboundChannel(format!("names[{}]", idx))),
+            }
+        }
         Opcode::NAME_CREATE => {
-            let kind = inst.op16();
-            let id = vm.next_name_id;
-            vm.next_name_id += 1;
-            let name = format!("@{}:{}", kind, id);
-            vm.stack.push(Value::Name(name));
+            vm.stack.push(Value::Name(fresh_name(inst.op16())));
         }
 
         // RSpace interactions
         // Note: kind (op16) is ignored in the new unified API - names are unique identifiers
         Opcode::TELL => {
-            let _kind = inst.op16(); // Kept for bytecode compatibility
+            // op1 is the kind, kept for bytecode compatibility. op2 is non-zero if
+            // the data is the Tuple of the arguments of a send; otherwise it is a
+            // single argument, which is wrapped in a 1-tuple if it is a Tuple so
+            // that EXTRACT_BINDINGS does not take its items for the arguments.
+            let polyadic = inst.op2() != 0;
             let data = vm.stack.pop().unwrap_or(Value::Nil);
             let chan = vm.stack.pop().unwrap_or(Value::Nil);
+            if let Value::Name(name) = &chan {
+                vm.record_channel_event(|| ChannelEvent::Send {
+                    channel: name.clone(),
+                    value: data.clone(),
+                });
+            }
             match chan {
+                Value::Name(name) if system_uri(&name) == Some(STDOUT_URI) => {
+                    write_output(&vm.stdout, &data)?;
+                    vm.stack.push(Value::Bool(true));
+                }
+                Value::Name(name) if system_uri(&name) == Some(STDERR_URI) => {
+                    write_output(&vm.stderr, &data)?;
+                    vm.stack.push(Value::Bool(true));
+                }
                 Value::Name(name) => {
+                    let data = match data {
+                        Value::Tuple(_) if !polyadic => Value::Tuple(vec![data]),
+                        data => data,
+                    };
                     if let Ok(mut rspace) = vm.rspace.lock() {
                         rspace
                             .tell(&name, data)
@@ -573,12 +793,7 @@ pub fn step(
                     }
                     vm.stack.push(Value::Bool(true));
                 }
-                _ => {
-                    return Err(ExecError::OpcodeParamError {
-                        opcode: "TELL",
-                        message: "requires Name channel".to_string(),
-                    })
-                }
+                other => return Err(type_mismatch_error("TELL", "Name", other.type_name())),
             }
         }
         Opcode::ASK => {
@@ -586,23 +801,62 @@ pub fn step(
             let chan = vm.stack.pop().unwrap_or(Value::Nil);
             match chan {
                 Value::Name(name) => {
-                    if let Ok(mut rspace) = vm.rspace.lock() {
-                        let result =
+                    let result = match vm.rspace.lock() {
+                        Ok(mut rspace) => {
                             rspace.ask(&name).map_err(|e| ExecError::OpcodeParamError {
                                 opcode: "ASK",
                                 message: e.to_string(),
-                            })?;
-                        vm.stack.push(result.unwrap_or(Value::Nil));
-                    } else {
-                        vm.stack.push(Value::Nil);
+                            })?
+                        }
+                        Err(_) => Some(Value::Nil),
+                    };
+                    match result {
+                        Some(value) => {
+                            vm.record_channel_event(|| ChannelEvent::Receive {
+                                channel: name.clone(),
+                                value: value.clone(),
+                            });
+                            vm.stack.push(value)
+                        }
+                        None => {
+                            vm.stack.push(Value::Name(name.clone()));
+                            return Ok(StepResult::Block(name));
+                        }
                     }
                 }
-                _ => {
-                    return Err(ExecError::OpcodeParamError {
-                        opcode: "ASK",
-                        message: "requires Name channel".to_string(),
-                    })
+                other => return Err(type_mismatch_error("ASK", "Name", other.type_name())),
+            }
+        }
+        Opcode::ASK_NB => {
+            // Like ASK, but reports whether a message was taken: pushes the
+            // message and Bool(true), or only Bool(false) on an empty channel,
+            // so that a Nil message is not mistaken for an empty channel
+            let _kind = inst.op16(); // Kept for bytecode compatibility
+            let chan = vm.stack.pop().unwrap_or(Value::Nil);
+            match chan {
+                Value::Name(name) => {
+                    let result = match vm.rspace.lock() {
+                        Ok(mut rspace) => {
+                            rspace.ask(&name).map_err(|e| ExecError::OpcodeParamError {
+                                opcode: "ASK_NB",
+                                message: e.to_string(),
+                            })?
+                        }
+                        Err(_) => None,
+                    };
+                    match result {
+                        Some(value) => {
+                            vm.record_channel_event(|| ChannelEvent::Receive {
+                                channel: name.clone(),
+                                value: value.clone(),
+                            });
+                            vm.stack.push(value);
+                            vm.stack.push(Value::Bool(true));
+                        }
+                        None => vm.stack.push(Value::Bool(false)),
+                    }
                 }
+                other => return Err(type_mismatch_error("ASK_NB", "Name", other.type_name())),
             }
         }
         Opcode::PEEK => {
@@ -610,25 +864,26 @@ pub fn step(
             let chan = vm.stack.pop().unwrap_or(Value::Nil);
             match chan {
                 Value::Name(name) => {
-                    if let Ok(rspace) = vm.rspace.lock() {
-                        let result =
+                    let result = match vm.rspace.lock() {
+                        Ok(rspace) => {
                             rspace
                                 .peek(&name)
                                 .map_err(|e| ExecError::OpcodeParamError {
                                     opcode: "PEEK",
                                     message: e.to_string(),
-                                })?;
-                        vm.stack.push(result.unwrap_or(Value::Nil));
-                    } else {
-                        vm.stack.push(Value::Nil);
+                                })?
+                        }
+                        Err(_) => None,
+                    };
+                    if let Some(value) = &result {
+                        vm.record_channel_event(|| ChannelEvent::Receive {
+                            channel: name.clone(),
+                            value: value.clone(),
+                        });
                     }
+                    vm.stack.push(result.unwrap_or(Value::Nil));
                 }
-                _ => {
-                    return Err(ExecError::OpcodeParamError {
-                        opcode: "PEEK",
-                        message: "requires Name channel".to_string(),
-                    })
-                }
+                other => return Err(type_mismatch_error("PEEK", "Name", other.type_name())),
             }
         }
 
@@ -654,12 +909,7 @@ pub fn step(
                         vm.stack.push(Value::Nil);
                     }
                 }
-                _ => {
-                    return Err(ExecError::OpcodeParamError {
-                        opcode: "CONT_RESUME",
-                        message: "requires Int id".to_string(),
-                    })
-                }
+                other => return Err(type_mismatch_error("CONT_RESUME", "Int", other.type_name())),
             }
         }
 
@@ -673,6 +923,17 @@ pub fn step(
             return Ok(StepResult::Eval(target));
         }
 
+        // Methods are lowered to dedicated opcodes by the compiler, so the VM has no
+        // method table to load from.
+        Opcode::LOAD_METHOD => {
+            let idx = inst.op16() as usize;
+            let method = match names.get(idx) {
+                Some(Value::Str(s)) => s.clone(),
+                _ => format!("#{}", idx),
+            };
+            return Err(ExecError::UnknownMethod(method));
+        }
+
         // Fallback for unimplemented opcodes
         _ => {
             return Err(ExecError::OpcodeParamError {
@@ -689,33 +950,138 @@ pub fn step(
 // Helper functions for arithmetic/comparison opcodes
 // ---------------------------------------------------------------------------
 
-fn type_mismatch_error(opcode: &'static str, type_a: &str, type_b: &str) -> ExecError {
-    ExecError::OpcodeParamError {
-        opcode,
-        message: format!("type mismatch: {} vs {}", type_a, type_b),
+/// The result of an `Int` operation, given the checked, wrapping and exact variants of it.
+///
+/// On overflow the result wraps around, unless the `bigint` feature is enabled: then it is
+/// promoted to the exact result as a `BigInt`, see [`promoted_int`].
+fn int_result(checked: Option<i64>, wrapped: i64, exact: impl FnOnce() -> BigInt) -> Value {
+    match checked {
+        Some(n) => Value::Int(n),
+        None if cfg!(feature = "bigint") => Value::BigInt(exact()),
+        None => Value::Int(wrapped),
     }
 }
 
-fn stack_underflow(opcode: &'static str) -> ExecError {
-    ExecError::OpcodeParamError {
-        opcode,
-        message: "stack underflow".to_string(),
+/// An `Int` that overflowed into a `BigInt`, demoted back to the `i64` fast path once it
+/// fits again
+#[cfg(feature = "bigint")]
+fn promoted_int(n: BigInt) -> Value {
+    i64::try_from(&n).map_or(Value::BigInt(n), Value::Int)
+}
+
+fn type_mismatch_error(op: &'static str, expected: &str, got: &str) -> ExecError {
+    ExecError::TypeMismatch {
+        op,
+        expected: expected.to_string(),
+        got: got.to_string(),
     }
 }
 
-fn div_by_zero(opcode: &'static str) -> ExecError {
-    ExecError::OpcodeParamError {
-        opcode,
-        message: "division by zero".to_string(),
+fn stack_underflow(op: &'static str) -> ExecError {
+    ExecError::StackUnderflow { op }
+}
+
+fn div_by_zero(op: &'static str) -> ExecError {
+    ExecError::DivisionByZero { op }
+}
+
+/// The collection and spawn opcodes consume `expected` stack entries at once.
+fn arity_mismatch(op: &'static str, expected: usize, got: usize) -> ExecError {
+    ExecError::ArityMismatch { op, expected, got }
+}
+
+/// Print a message sent on a system output channel: strings verbatim, other values with
+/// their `Display` implementation, one message per line.
+fn write_output(sink: &OutputSink, data: &Value) -> Result<(), ExecError> {
+    let mut out = sink.lock().map_err(|_| ExecError::OpcodeParamError {
+        opcode: "TELL",
+        message: "output sink poisoned".to_string(),
+    })?;
+    let written = match data {
+        Value::Str(s) => writeln!(out, "{}", s),
+        other => writeln!(out, "{}", other),
+    };
+    written.map_err(|e| ExecError::OpcodeParamError {
+        opcode: "TELL",
+        message: format!("failed to write output: {}", e),
+    })
+}
+
+/// Substitute the `${key}` placeholders of `template` with the values bound to `"key"` in
+/// `bindings`.
+///
+/// Strings are inserted verbatim, any other value is rendered with its `Display` implementation
+/// (so `Nil` becomes `Nil`). Placeholders without a binding are left untouched and `$${` stands
+/// for a literal `${`. Substituted text is not scanned again, which keeps nesting explicit:
+/// `("${a}" %% {"a": "${b}"}) %% {"b": 1}` evaluates to `"1"`.
+fn interpolate(template: &str, bindings: &[(Value, Value)]) -> String {
+    let mut out = String::with_capacity(template.len());
+    let mut rest = template;
+
+    while let Some(start) = rest.find("${") {
+        let (before, placeholder) = rest.split_at(start);
+        if let Some(before) = before.strip_suffix('$') {
+            out.push_str(before);
+            out.push_str("${");
+            rest = &placeholder[2..];
+            continue;
+        }
+        out.push_str(before);
+
+        let Some(end) = placeholder.find('}') else {
+            rest = placeholder;
+            break;
+        };
+        let key = &placeholder[2..end];
+        match bindings
+            .iter()
+            .find(|(k, _)| matches!(k, Value::Str(s) if s == key))
+        {
+            Some((_, Value::Str(s))) => out.push_str(s),
+            Some((_, value)) => out.push_str(&value.to_string()),
+            None => out.push_str(&placeholder[..=end]),
+        }
+        rest = &placeholder[end + 1..];
+    }
+
+    out.push_str(rest);
+    out
+}
+
+/// Segments of a pathmap entry: a `List` is a multi-segment path, anything else a
+/// single segment.
+fn path_segments(path: &Value) -> &[Value] {
+    match path {
+        Value::List(segments) => segments,
+        other => std::slice::from_ref(other),
+    }
+}
+
+/// Inverse of [`path_segments`]: a lone segment is stored bare.
+fn make_path(mut segments: Vec<Value>) -> Value {
+    if segments.len() == 1 {
+        segments.pop().unwrap_or(Value::Nil)
+    } else {
+        Value::List(segments)
     }
 }
 
+/// Paths of `a` that do not occur in `b`.
+fn pathmap_subtract(a: Vec<Value>, b: &[Value]) -> Vec<Value> {
+    a.into_iter().filter(|path| !b.contains(path)).collect()
+}
+
 fn compare_values(
     opcode: &'static str,
     a: &Option<Value>,
     b: &Option<Value>,
 ) -> Result<Ordering, ExecError> {
     match (a, b) {
+        // an Int and a BigInt it overflowed into are the same kind of number
+        #[cfg(feature = "bigint")]
+        (Some(Value::Int(a)), Some(Value::BigInt(b))) => Ok(BigInt::from(*a).cmp(b)),
+        #[cfg(feature = "bigint")]
+        (Some(Value::BigInt(a)), Some(Value::Int(b))) => Ok(a.cmp(&BigInt::from(*b))),
         (Some(a_val), Some(b_val)) => a_val
             .partial_cmp(b_val)
             .ok_or_else(|| ExecError::OpcodeParamError {
@@ -14,6 +14,7 @@
 //! ```
 
 mod execute;
+mod pattern;
 mod vm;
 
 // Re-export core types from rholang-rspace
@@ -21,14 +22,21 @@ pub use rholang_rspace::{
     Entry, ExecError, InMemoryRSpace, ProcessHolder, ProcessState, RSpace, SharedRSpace, Value,
 };
 
+pub use rholang_bytecode::core::SimpleType;
+
 // Export VM and execution
 pub use crate::execute::{step, StepResult};
-pub use crate::vm::VM;
+pub use crate::pattern::Pattern;
+pub use crate::vm::{ChannelEvent, OutputSink, VM};
 
 // Re-export a lightweight API for users
 pub mod api {
-    pub use crate::vm::VM;
+    pub use crate::pattern::Pattern;
+    pub use crate::vm::{ChannelEvent, OutputSink, VM};
     pub use rholang_bytecode::core::instructions::Instruction;
     pub use rholang_bytecode::core::opcodes::Opcode;
-    pub use rholang_rspace::{Entry, ProcessHolder, Value};
+    pub use rholang_bytecode::core::SimpleType;
+    pub use rholang_rspace::{
+        Entry, InMemoryRSpace, NameRenderStyle, ProcessHolder, RSpace, SharedRSpace, Value,
+    };
 }
@@ -0,0 +1,173 @@
+//! Structural patterns tested by `MATCH_TEST`.
+//!
+//! A pattern is stored in the constant pool of a process, encoded as a
+//! [`Value`] by [`Pattern::to_value`]: every node is a tuple whose first
+//! element is an `Int` tag.
+//!
+//! ```text
+//! (0)                    wildcard
+//! (1, value)             ground value, compared with ==
+//! (2, tag)               simple type, tag as for MATCH_TYPE
+//! (3, [pattern..], rest) list, rest is true if a remainder may follow
+//! (4, [pattern..])       tuple
+//! ```
+
+use rholang_bytecode::core::SimpleType;
+use rholang_rspace::Value;
+
+const TAG_WILDCARD: i64 = 0;
+const TAG_GROUND: i64 = 1;
+const TAG_TYPE: i64 = 2;
+const TAG_LIST: i64 = 3;
+const TAG_TUPLE: i64 = 4;
+
+/// The right-hand side of `matches`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Pattern {
+    /// `_` or a free variable; matches anything and binds nothing
+    Wildcard,
+    /// A pattern without holes
+    Ground(Value),
+    /// `Bool`, `Int`, `String`, `Uri` or `ByteArray`
+    Type(SimpleType),
+    /// `[p1, .., pn]`, or `[p1, .., pn ...rest]` if `rest` is set
+    List { elements: Vec<Pattern>, rest: bool },
+    /// `(p1, .., pn)`
+    Tuple(Vec<Pattern>),
+}
+
+impl Pattern {
+    /// Whether `value` has the shape of this pattern.
+    pub fn matches(&self, value: &Value) -> bool {
+        match (self, value) {
+            (Pattern::Wildcard, _) => true,
+            (Pattern::Ground(expected), value) => expected == value,
+            (Pattern::Type(t), value) => is_of_simple_type(value, *t),
+            (Pattern::List { elements, rest }, Value::List(items)) => {
+                let fits = if *rest {
+                    items.len() >= elements.len()
+                } else {
+                    items.len() == elements.len()
+                };
+                fits && all_match(elements, items)
+            }
+            (Pattern::Tuple(elements), Value::Tuple(items)) => {
+                items.len() == elements.len() && all_match(elements, items)
+            }
+            _ => false,
+        }
+    }
+
+    /// Encodes this pattern for the constant pool.
+    pub fn to_value(&self) -> Value {
+        let tagged = |tag: i64, rest: Vec<Value>| {
+            Value::Tuple(std::iter::once(Value::Int(tag)).chain(rest).collect())
+        };
+        let encode_all =
+            |elements: &[Pattern]| Value::List(elements.iter().map(Pattern::to_value).collect());
+        match self {
+            Pattern::Wildcard => tagged(TAG_WILDCARD, vec![]),
+            Pattern::Ground(value) => tagged(TAG_GROUND, vec![value.clone()]),
+            Pattern::Type(t) => tagged(TAG_TYPE, vec![Value::Int(*t as i64)]),
+            Pattern::List { elements, rest } => {
+                tagged(TAG_LIST, vec![encode_all(elements), Value::Bool(*rest)])
+            }
+            Pattern::Tuple(elements) => tagged(TAG_TUPLE, vec![encode_all(elements)]),
+        }
+    }
+
+    /// Decodes a pattern written by [`Pattern::to_value`], or `None` if
+    /// `value` is not one.
+    pub fn from_value(value: &Value) -> Option<Pattern> {
+        let Value::Tuple(fields) = value else {
+            return None;
+        };
+        let decode_all = |value: &Value| match value {
+            Value::List(items) => items.iter().map(Pattern::from_value).collect(),
+            _ => None,
+        };
+        match fields.as_slice() {
+            [Value::Int(TAG_WILDCARD)] => Some(Pattern::Wildcard),
+            [Value::Int(TAG_GROUND), value] => Some(Pattern::Ground(value.clone())),
+            [Value::Int(TAG_TYPE), Value::Int(tag)] => u16::try_from(*tag)
+                .ok()
+                .and_then(SimpleType::from_tag)
+                .map(Pattern::Type),
+            [Value::Int(TAG_LIST), elements, Value::Bool(rest)] => Some(Pattern::List {
+                elements: decode_all(elements)?,
+                rest: *rest,
+            }),
+            [Value::Int(TAG_TUPLE), elements] => decode_all(elements).map(Pattern::Tuple),
+            _ => None,
+        }
+    }
+}
+
+/// Check whether `value` inhabits the given simple type, as in `x matches Int`.
+///
+/// There are no runtime URI or byte array values yet, so `Uri` and `ByteArray`
+/// match nothing.
+pub fn is_of_simple_type(value: &Value, t: SimpleType) -> bool {
+    match t {
+        SimpleType::Bool => matches!(value, Value::Bool(_)),
+        SimpleType::Int => matches!(value, Value::Int(_)),
+        SimpleType::String => matches!(value, Value::Str(_)),
+        SimpleType::Uri | SimpleType::ByteArray => false,
+    }
+}
+
+fn all_match(patterns: &[Pattern], values: &[Value]) -> bool {
+    patterns.iter().zip(values).all(|(p, v)| p.matches(v))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encoding_round_trips() {
+        let pattern = Pattern::List {
+            elements: vec![
+                Pattern::Wildcard,
+                Pattern::Tuple(vec![
+                    Pattern::Ground(Value::Str("a".to_string())),
+                    Pattern::Type(SimpleType::Int),
+                ]),
+            ],
+            rest: true,
+        };
+        assert_eq!(Pattern::from_value(&pattern.to_value()), Some(pattern));
+        assert_eq!(Pattern::from_value(&Value::Int(1)), None);
+        assert_eq!(
+            Pattern::from_value(&Value::Tuple(vec![Value::Int(2), Value::Int(9)])),
+            None
+        );
+    }
+
+    #[test]
+    fn simple_types() {
+        let str = || Value::Str("s".to_string());
+        assert!(is_of_simple_type(&Value::Int(42), SimpleType::Int));
+        assert!(is_of_simple_type(&Value::Bool(true), SimpleType::Bool));
+        assert!(is_of_simple_type(&str(), SimpleType::String));
+        assert!(!is_of_simple_type(&str(), SimpleType::Int));
+        assert!(!is_of_simple_type(
+            &Value::List(vec![Value::Int(1)]),
+            SimpleType::String
+        ));
+    }
+
+    #[test]
+    fn list_remainders() {
+        let head_is_one = Pattern::List {
+            elements: vec![Pattern::Ground(Value::Int(1))],
+            rest: true,
+        };
+        let list = |items: &[i64]| Value::List(items.iter().copied().map(Value::Int).collect());
+        assert!(head_is_one.matches(&list(&[1])));
+        assert!(head_is_one.matches(&list(&[1, 2, 3])));
+        assert!(!head_is_one.matches(&list(&[])));
+        assert!(!head_is_one.matches(&list(&[2, 1])));
+        assert!(!head_is_one.matches(&Value::Tuple(vec![Value::Int(1)])));
+    }
+}
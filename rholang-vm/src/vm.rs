@@ -2,11 +2,24 @@
 
 use anyhow::Result;
 use rholang_bytecode::core::instructions::Instruction as CoreInst;
+use std::io::Write;
 use std::sync::{Arc, Mutex};
 
 use crate::execute::{self, StepResult};
 use rholang_rspace::{ExecError, InMemoryRSpace, RSpace, SharedRSpace, Value};
 
+/// Shared writer receiving the messages sent on a system output channel.
+pub type OutputSink = Arc<Mutex<dyn Write + Send>>;
+
+/// A message going through a channel, as reported by [`VM::take_channel_event`].
+#[derive(Clone, Debug, PartialEq)]
+pub enum ChannelEvent {
+    /// `TELL` put `value` on `channel`
+    Send { channel: String, value: Value },
+    /// `ASK`, `ASK_NB` or `PEEK` read `value` from `channel`
+    Receive { channel: String, value: Value },
+}
+
 /// Virtual Machine for Rholang bytecode execution.
 ///
 /// The VM maintains:
@@ -14,6 +27,8 @@ use rholang_rspace::{ExecError, InMemoryRSpace, RSpace, SharedRSpace, Value};
 /// - A shared RSpace for tuple space operations
 /// - Continuation state for async operations
 /// - A name counter for fresh channel generation
+/// - Output sinks for the `rho:io:stdout` and `rho:io:stderr` system channels
+/// - Optionally, the last message sent or received, see [`VM::trace_channels`]
 #[derive(Clone)]
 pub struct VM {
     /// Value stack for operand storage during execution.
@@ -24,8 +39,14 @@ pub struct VM {
     pub(crate) cont_last: Option<(u32, Value)>,
     /// Counter for generating unique continuation IDs.
     pub(crate) next_cont_id: u32,
-    /// Monotonic counter for generating fresh channel names.
-    pub(crate) next_name_id: u64,
+    /// Destination of messages sent on `rho:io:stdout`.
+    pub(crate) stdout: OutputSink,
+    /// Destination of messages sent on `rho:io:stderr`.
+    pub(crate) stderr: OutputSink,
+    /// Whether sends and receives are recorded in `channel_event`.
+    pub(crate) trace_channels: bool,
+    /// The message moved by the last instruction, if traced.
+    pub(crate) channel_event: Option<ChannelEvent>,
 }
 
 impl std::fmt::Debug for VM {
@@ -34,7 +55,6 @@ impl std::fmt::Debug for VM {
             .field("stack", &self.stack)
             .field("cont_last", &self.cont_last)
             .field("next_cont_id", &self.next_cont_id)
-            .field("next_name_id", &self.next_name_id)
             .finish()
     }
 }
@@ -44,7 +64,6 @@ impl PartialEq for VM {
         self.stack == other.stack
             && self.cont_last == other.cont_last
             && self.next_cont_id == other.next_cont_id
-            && self.next_name_id == other.next_name_id
         // We skip RSpace for equality as it's a shared resource
     }
 }
@@ -68,7 +87,10 @@ impl VM {
             rspace: Arc::new(Mutex::new(Box::new(InMemoryRSpace::new()))),
             cont_last: None,
             next_cont_id: 1,
-            next_name_id: 1,
+            stdout: Arc::new(Mutex::new(std::io::stdout())),
+            stderr: Arc::new(Mutex::new(std::io::stderr())),
+            trace_channels: false,
+            channel_event: None,
         }
     }
 
@@ -90,7 +112,10 @@ impl VM {
             rspace: Arc::new(Mutex::new(rspace)),
             cont_last: None,
             next_cont_id: 1,
-            next_name_id: 1,
+            stdout: Arc::new(Mutex::new(std::io::stdout())),
+            stderr: Arc::new(Mutex::new(std::io::stderr())),
+            trace_channels: false,
+            channel_event: None,
         }
     }
 
@@ -104,7 +129,45 @@ impl VM {
             rspace,
             cont_last: None,
             next_cont_id: 1,
-            next_name_id: 1,
+            stdout: Arc::new(Mutex::new(std::io::stdout())),
+            stderr: Arc::new(Mutex::new(std::io::stderr())),
+            trace_channels: false,
+            channel_event: None,
+        }
+    }
+
+    /// Redirect the messages sent on `rho:io:stdout` to `sink`.
+    pub fn with_stdout(mut self, sink: OutputSink) -> Self {
+        self.stdout = sink;
+        self
+    }
+
+    /// Redirect the messages sent on `rho:io:stderr` to `sink`.
+    pub fn with_stderr(mut self, sink: OutputSink) -> Self {
+        self.stderr = sink;
+        self
+    }
+
+    /// Record the message each send or receive moves, to be collected with
+    /// [`VM::take_channel_event`] after the instruction. Off by default, as it
+    /// clones every message.
+    pub fn trace_channels(&mut self, enabled: bool) {
+        self.trace_channels = enabled;
+        if !enabled {
+            self.channel_event = None;
+        }
+    }
+
+    /// Take the message sent or received by the last instruction, if channels
+    /// are traced.
+    pub fn take_channel_event(&mut self) -> Option<ChannelEvent> {
+        self.channel_event.take()
+    }
+
+    /// Note a message moved on `channel`; `event` builds the record only when tracing.
+    pub(crate) fn record_channel_event(&mut self, event: impl FnOnce() -> ChannelEvent) {
+        if self.trace_channels {
+            self.channel_event = Some(event());
         }
     }
 
@@ -158,7 +221,6 @@ mod tests {
         assert!(vm.stack.is_empty());
         assert!(vm.cont_last.is_none());
         assert_eq!(vm.next_cont_id, 1);
-        assert_eq!(vm.next_name_id, 1);
     }
 
     #[test]
@@ -1,5 +1,6 @@
 use rholang_process::Process;
 use rholang_vm::api::{Instruction, Opcode, Value};
+use rholang_vm::ExecError;
 
 #[test]
 fn test_mul_div_mod_neg() {
@@ -51,3 +52,35 @@ fn test_div_mod_by_zero_errors() {
     let err2 = process4.execute().expect_err("should error mod by zero");
     assert!(err2.to_string().to_lowercase().contains("division by zero"));
 }
+
+#[test]
+fn test_structured_exec_errors() {
+    let prog = vec![
+        Instruction::unary(Opcode::PUSH_INT, 1),
+        Instruction::unary(Opcode::PUSH_INT, 0),
+        Instruction::nullary(Opcode::DIV),
+    ];
+    let err = Process::new(prog, "arithmetic")
+        .execute()
+        .expect_err("div by zero");
+    assert_eq!(err, ExecError::DivisionByZero { op: "DIV" });
+
+    let prog2 = vec![
+        Instruction::unary(Opcode::PUSH_INT, 1),
+        Instruction::nullary(Opcode::PUSH_NIL),
+        Instruction::nullary(Opcode::ADD),
+    ];
+    let err2 = Process::new(prog2, "arithmetic")
+        .execute()
+        .expect_err("type mismatch");
+    assert!(matches!(
+        err2,
+        ExecError::TypeMismatch { op: "ADD", ref expected, ref got } if expected == "Int" && got == "Nil"
+    ));
+
+    let prog3 = vec![Instruction::nullary(Opcode::NEG)];
+    let err3 = Process::new(prog3, "arithmetic")
+        .execute()
+        .expect_err("underflow");
+    assert_eq!(err3, ExecError::StackUnderflow { op: "NEG" });
+}
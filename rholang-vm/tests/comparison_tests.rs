@@ -176,17 +176,17 @@ fn test_relational_ops_with_negatives() {
 
 #[test]
 fn test_relational_ops_type_errors_and_underflow() {
-    // Type mismatch: Int vs Str for CMP_LT -> error
+    // Mixed numeric types do not compare: Int vs Float for CMP_LT -> error
     let prog1 = vec![
         Instruction::unary(Opcode::PUSH_INT, 1),
-        Instruction::unary(Opcode::PUSH_STR, 0),
+        Instruction::unary(Opcode::PUSH_CONST, 0),
         Instruction::nullary(Opcode::CMP_LT),
     ];
     let mut p1 = Process::new(prog1, "lt_type_err");
-    p1.names = vec![label("x")];
+    p1.constants = vec![Value::Float(1.0)];
     let err1 = p1
         .execute()
-        .expect_err("should error for type mismatch on CMP_LT");
+        .expect_err("should error for mixed numeric types on CMP_LT");
     let msg1 = err1.to_string().to_lowercase();
     assert!(msg1.contains("cmp_lt") && msg1.contains("int"));
 
@@ -202,3 +202,51 @@ fn test_relational_ops_type_errors_and_underflow() {
     let msg2 = err2.to_string().to_lowercase();
     assert!(msg2.contains("cmp_gt") && msg2.contains("stack underflow"));
 }
+
+#[test]
+fn test_relational_ops_on_strings_and_collections() {
+    // "a" < "b"
+    let prog1 = vec![
+        Instruction::unary(Opcode::PUSH_STR, 0),
+        Instruction::unary(Opcode::PUSH_STR, 1),
+        Instruction::nullary(Opcode::CMP_LT),
+        Instruction::nullary(Opcode::HALT),
+    ];
+    let mut p1 = Process::new(prog1, "lt_str");
+    p1.names = vec![label("a"), label("b")];
+    assert_eq!(p1.execute().expect("exec ok"), Value::Bool(true));
+
+    // [1] < [2]
+    let prog2 = vec![
+        Instruction::unary(Opcode::PUSH_INT, 1),
+        Instruction::unary(Opcode::CREATE_LIST, 1),
+        Instruction::unary(Opcode::PUSH_INT, 2),
+        Instruction::unary(Opcode::CREATE_LIST, 1),
+        Instruction::nullary(Opcode::CMP_LT),
+        Instruction::nullary(Opcode::HALT),
+    ];
+    let mut p2 = Process::new(prog2, "lt_list");
+    assert_eq!(p2.execute().expect("exec ok"), Value::Bool(true));
+
+    // Across types, Int < String
+    let prog3 = vec![
+        Instruction::unary(Opcode::PUSH_INT, 100),
+        Instruction::unary(Opcode::PUSH_STR, 0),
+        Instruction::nullary(Opcode::CMP_LT),
+        Instruction::nullary(Opcode::HALT),
+    ];
+    let mut p3 = Process::new(prog3, "lt_int_str");
+    p3.names = vec![label("a")];
+    assert_eq!(p3.execute().expect("exec ok"), Value::Bool(true));
+
+    // ... and a String is never <= an Int
+    let prog4 = vec![
+        Instruction::unary(Opcode::PUSH_STR, 0),
+        Instruction::unary(Opcode::PUSH_INT, 100),
+        Instruction::nullary(Opcode::CMP_LTE),
+        Instruction::nullary(Opcode::HALT),
+    ];
+    let mut p4 = Process::new(prog4, "lte_str_int");
+    p4.names = vec![label("a")];
+    assert_eq!(p4.execute().expect("exec ok"), Value::Bool(false));
+}
@@ -719,6 +719,9 @@ fn test_vm_maximum_complexity() {
             main!("test") |
             main!(true) |
             main!(false) |
+            worker1!(1) |
+            worker2!(2) |
+            worker3!(3) |
             
             // Section 2: Multiple workers with receives
             for (a <- worker1) {
@@ -216,3 +216,73 @@ fn test_cross_type_errors() {
     // Float - BigInt
     assert!(binop_err(Value::Float(1.0), bi, Opcode::SUB).contains("type mismatch"));
 }
+
+#[test]
+fn int_overflow_wraps_without_bigint_feature() {
+    if cfg!(feature = "bigint") {
+        return;
+    }
+    assert_eq!(
+        binop(Value::Int(i64::MAX), Value::Int(1), Opcode::ADD),
+        Ok(Value::Int(i64::MIN))
+    );
+}
+
+#[cfg(feature = "bigint")]
+#[test]
+fn int_overflow_promotes_to_bigint() {
+    let two_pow_63 = binop(Value::Int(i64::MAX), Value::Int(1), Opcode::ADD).unwrap();
+    assert_eq!(two_pow_63, Value::BigInt(BigInt::from(1u64 << 63)));
+
+    let two_pow_64 = binop(two_pow_63.clone(), Value::Int(2), Opcode::MUL).unwrap();
+    assert_eq!(two_pow_64, Value::BigInt(BigInt::from(1u128 << 64)));
+
+    // back on the i64 fast path once the result fits again
+    assert_eq!(
+        binop(two_pow_63, Value::Int(1), Opcode::SUB),
+        Ok(Value::Int(i64::MAX))
+    );
+    assert_eq!(
+        binop(Value::Int(i64::MIN), Value::Int(-1), Opcode::DIV),
+        Ok(Value::BigInt(BigInt::from(1u64 << 63)))
+    );
+}
+
+#[cfg(feature = "bigint")]
+#[test]
+fn promoted_ints_compare_with_ints() {
+    let two_pow_63 = binop(Value::Int(i64::MAX), Value::Int(1), Opcode::ADD).unwrap();
+
+    assert_eq!(
+        binop(two_pow_63.clone(), Value::Int(0), Opcode::CMP_GT),
+        Ok(Value::Bool(true))
+    );
+    assert_eq!(
+        binop(Value::Int(i64::MAX), two_pow_63.clone(), Opcode::CMP_LT),
+        Ok(Value::Bool(true))
+    );
+    assert_eq!(
+        binop(
+            Value::Int(5),
+            Value::BigInt(BigInt::from(5)),
+            Opcode::CMP_GTE
+        ),
+        Ok(Value::Bool(true))
+    );
+    assert_eq!(
+        binop(two_pow_63, Value::Int(i64::MIN), Opcode::CMP_LTE),
+        Ok(Value::Bool(false))
+    );
+}
+
+#[test]
+fn int_min_mod_minus_one_is_zero() {
+    assert_eq!(
+        binop(Value::Int(i64::MIN), Value::Int(-1), Opcode::MOD),
+        Ok(Value::Int(0))
+    );
+    assert_eq!(
+        binop(Value::Int(-7), Value::Int(2), Opcode::MOD),
+        Ok(Value::Int(-1))
+    );
+}
@@ -1,6 +1,8 @@
 use rholang_bytecode::core::instructions::Instruction;
 use rholang_bytecode::core::Opcode;
-use rholang_process::{execute_ready_processes, Process, ProcessEvent, ProcessState};
+use rholang_process::{
+    execute_ready_processes, Process, ProcessEvent, ProcessEventHandler, ProcessState, Value, VM,
+};
 use std::sync::{Arc, Mutex};
 
 #[test]
@@ -39,3 +41,66 @@ fn test_execute_ready_processes_emits_events() {
     assert_eq!(captured.len(), 1);
     assert!(matches!(captured[0], ProcessEvent::Value(_)));
 }
+
+#[test]
+fn test_send_and_receive_emit_channel_events() {
+    // c!(1) | for(@v <- c) { v }, as two processes sharing an RSpace
+    let vm = VM::new();
+    let mut sender = Process::with_vm(
+        vec![
+            Instruction::unary(Opcode::PUSH_NAME, 0),
+            Instruction::unary(Opcode::PUSH_INT, 1),
+            Instruction::unary(Opcode::TELL, 0),
+            Instruction::nullary(Opcode::HALT),
+        ],
+        "send",
+        vm.clone(),
+    );
+    let mut receiver = Process::with_vm(
+        vec![
+            Instruction::unary(Opcode::PUSH_NAME, 0),
+            Instruction::unary(Opcode::ASK, 0),
+            Instruction::nullary(Opcode::HALT),
+        ],
+        "receive",
+        vm,
+    );
+    sender.names = vec![Value::Str("c".to_string())];
+    receiver.names = sender.names.clone();
+
+    let events: Arc<Mutex<Vec<ProcessEvent>>> = Arc::new(Mutex::new(Vec::new()));
+    let handler_events = events.clone();
+    let handler: ProcessEventHandler = Arc::new(move |event: ProcessEvent| {
+        handler_events.lock().unwrap().push(event);
+    });
+
+    sender.execute_with_event(Some(&handler)).unwrap();
+    assert_eq!(
+        receiver.execute_with_event(Some(&handler)).unwrap(),
+        Value::Int(1)
+    );
+
+    let channel_events: Vec<ProcessEvent> = events
+        .lock()
+        .unwrap()
+        .iter()
+        .filter(|e| matches!(e, ProcessEvent::Send { .. } | ProcessEvent::Receive { .. }))
+        .cloned()
+        .collect();
+    assert_eq!(
+        channel_events,
+        [
+            ProcessEvent::Send {
+                channel: "c".to_string(),
+                value: Value::Int(1)
+            },
+            ProcessEvent::Receive {
+                channel: "c".to_string(),
+                value: Value::Int(1)
+            },
+        ]
+    );
+
+    // without a handler nothing is traced
+    assert_eq!(receiver.vm.take_channel_event(), None);
+}
@@ -1,4 +1,4 @@
-use rholang_process::Process;
+use rholang_process::{ExecError, Process, ProcessHolder, ProcessState};
 use rholang_vm::api::{Instruction, Opcode, Value};
 
 // Helper constants for kind codes (encoded in op16 immediate)
@@ -155,3 +155,74 @@ fn test_let_binding_and_persistent_peek() {
     let out2 = p2.execute().expect("exec ok");
     assert_eq!(out2, Value::List(vec![Value::Int(1)]));
 }
+
+#[test]
+fn test_receive_blocks_until_a_send_and_resumes() {
+    // for (x <- c) { x + 1 }
+    let mut receiver = Process::new(
+        vec![
+            Instruction::unary(Opcode::PUSH_NAME, 0),
+            Instruction::unary(Opcode::ASK, STORE_CONC),
+            Instruction::unary(Opcode::PUSH_INT, 1),
+            Instruction::nullary(Opcode::ADD),
+            Instruction::nullary(Opcode::HALT),
+        ],
+        "receiver",
+    );
+    receiver.names = vec![Value::Str("c".to_string())];
+
+    let blocked = ExecError::Blocked {
+        channel: "c".to_string(),
+    };
+    assert_eq!(receiver.execute(), Err(blocked.clone()));
+    assert_eq!(receiver.blocked_on(), Some("c"));
+    assert_eq!(receiver.state, ProcessState::WaitOn(vec!["c".to_string()]));
+    assert!(!receiver.is_ready());
+
+    // Nothing was sent yet, so resuming blocks again at the same receive
+    assert_eq!(receiver.execute(), Err(blocked));
+    assert_eq!(receiver.blocked_on(), Some("c"));
+
+    // c!(41), sharing the receiver's RSpace
+    let mut sender = Process::with_vm(
+        vec![
+            Instruction::unary(Opcode::PUSH_NAME, 0),
+            Instruction::unary(Opcode::PUSH_INT, 41),
+            Instruction::unary(Opcode::TELL, STORE_CONC),
+            Instruction::nullary(Opcode::HALT),
+        ],
+        "sender",
+        receiver.vm.clone(),
+    );
+    sender.names = vec![Value::Str("c".to_string())];
+    assert_eq!(sender.execute(), Ok(Value::Bool(true)));
+
+    assert_eq!(receiver.execute(), Ok(Value::Int(42)));
+    assert_eq!(receiver.blocked_on(), None);
+    assert_eq!(receiver.state, ProcessState::Value(Value::Int(42)));
+}
+
+#[test]
+fn test_blocked_receive_is_listed_as_waiting_on_its_channel() {
+    // for (x <- c) { x }
+    let mut receiver = Process::new(
+        vec![
+            Instruction::unary(Opcode::PUSH_NAME, 0),
+            Instruction::unary(Opcode::ASK, STORE_CONC),
+            Instruction::nullary(Opcode::HALT),
+        ],
+        "receiver",
+    );
+    receiver.names = vec![Value::Str("c".to_string())];
+    assert!(matches!(
+        receiver.execute(),
+        Err(ExecError::Blocked { channel }) if channel == "c"
+    ));
+
+    let mut rspace = receiver.vm.rspace.lock().unwrap();
+    rspace
+        .register_process("receiver", receiver.state.clone())
+        .expect("register ok");
+    assert_eq!(rspace.waiting_on("c"), ["receiver"]);
+    assert!(rspace.waiting_on("d").is_empty());
+}
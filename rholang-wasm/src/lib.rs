@@ -24,13 +24,26 @@ fn pretty_value(v: &Value) -> String {
             let elems: Vec<String> = xs.iter().map(pretty_value).collect();
             format!("Tuple({})", elems.join(", "))
         }
+        // Maps and sets in canonical order, as in `Value`'s `Display`
         Value::Map(kvs) => {
+            let mut kvs: Vec<_> = kvs.iter().collect();
+            kvs.sort_by(|(ka, va), (kb, vb)| ka.total_cmp(kb).then_with(|| va.total_cmp(vb)));
             let elems: Vec<String> = kvs
                 .iter()
                 .map(|(k, v)| format!("{} => {}", pretty_value(k), pretty_value(v)))
                 .collect();
             format!("Map({{{}}})", elems.join(", "))
         }
+        Value::Set(xs) => {
+            let mut xs: Vec<_> = xs.iter().collect();
+            xs.sort_by(|a, b| a.total_cmp(b));
+            let elems: Vec<String> = xs.into_iter().map(pretty_value).collect();
+            format!("Set({})", elems.join(", "))
+        }
+        Value::PathMap(xs) => {
+            let elems: Vec<String> = xs.iter().map(pretty_value).collect();
+            format!("PathMap({{|{}|}})", elems.join(", "))
+        }
         Value::Par(ps) => {
             let elems: Vec<String> = ps.iter().map(|p| format!("<{}>", p.source_ref())).collect();
             format!("Par({})", elems.join(" | "))
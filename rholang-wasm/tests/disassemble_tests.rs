@@ -15,10 +15,10 @@ fn disassemble_handles_empty_input_and_matches_eval_nil() {
     let input = "   \n\t";
     let disasm = disassemble(input);
     let output = eval(input);
-    // For empty sources, the compiler emits an empty process; ensure we render it.
+    // For empty sources, the compiler emits a process that just yields Nil.
     assert!(
-        disasm.contains("<empty>") || disasm.contains("Nil"),
-        "disassembly should mention empty/Nil for empty input, got: {disasm}"
+        disasm.contains("PUSH_NIL"),
+        "disassembly should push Nil for empty input, got: {disasm}"
     );
     assert_eq!("Nil", output);
 }